@@ -26,13 +26,37 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .unwrap_or_else(|_| "3001".to_string())
             .parse()
             .unwrap_or(3001),
-        cors_origins: vec!["http://localhost:3000".to_string()],
+        cors_origins: std::env::var("CORS_ORIGINS")
+            .map(|origins| {
+                origins.split(',')
+                    .map(|origin| origin.trim().to_string())
+                    .filter(|origin| !origin.is_empty())
+                    .collect()
+            })
+            .unwrap_or_else(|_| vec!["http://localhost:3000".to_string()]),
         enable_metrics: true,
         metrics_path: "/metrics".to_string(),
+        admin_api_key: std::env::var("ADMIN_API_KEY").ok(),
+        api_keys: std::env::var("API_KEYS")
+            .map(|keys| {
+                keys.split(',')
+                    .map(|key| key.trim().to_string())
+                    .filter(|key| !key.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        rate_limit_per_minute: std::env::var("API_RATE_LIMIT_PER_MINUTE")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(100),
+        rate_limit_burst: std::env::var("API_RATE_LIMIT_BURST")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(200),
     };
 
     // Create and start API server
-    let api_server = ApiServer::new(api_config, coordinator);
+    let api_server = ApiServer::new(api_config, coordinator.clone());
     
     info!("API server starting...");
     if let Err(e) = api_server.start().await {
@@ -40,6 +64,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Err(e.into());
     }
 
+    // Requests have drained; release the coordinator's resources
+    if let Err(e) = coordinator.shutdown().await {
+        error!("Error during shutdown: {}", e);
+    }
+
+    info!("API server shutdown complete");
     Ok(())
 }
 
@@ -2,11 +2,11 @@
 
 use thiserror::Error;
 use axum::{
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
-use serde_json::json;
+use crate::server::ErrorResponse;
 
 /// Result type for API operations
 pub type Result<T> = std::result::Result<T, ApiError>;
@@ -20,9 +20,18 @@ pub enum ApiError {
     #[error("Validation error: {message}")]
     Validation { message: String },
 
+    #[error("Unauthorized: {message}")]
+    Unauthorized { message: String },
+
     #[error("Not found: {resource}")]
     NotFound { resource: String },
 
+    #[error("Rate limited: retry after {retry_after_secs}s")]
+    RateLimited { retry_after_secs: u64 },
+
+    #[error("Service unavailable: {message}")]
+    Unavailable { message: String },
+
     #[error("Internal server error: {message}")]
     Internal { message: String },
 
@@ -33,22 +42,36 @@ pub enum ApiError {
     ThresholdSignature(#[from] threshold::ThresholdError),
 }
 
+impl ApiError {
+    /// HTTP status the error is reported with, also embedded as `code`
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            ApiError::Validation { .. } => StatusCode::BAD_REQUEST,
+            ApiError::Unauthorized { .. } => StatusCode::UNAUTHORIZED,
+            ApiError::NotFound { .. } => StatusCode::NOT_FOUND,
+            ApiError::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
+            ApiError::Unavailable { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            ApiError::Config { .. }
+            | ApiError::Internal { .. }
+            | ApiError::Relayer(_)
+            | ApiError::ThresholdSignature(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
 impl IntoResponse for ApiError {
+    /// Respond with an [`ErrorResponse`] body whose `code` matches the status
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            ApiError::Config { message } => (StatusCode::INTERNAL_SERVER_ERROR, message),
-            ApiError::Validation { message } => (StatusCode::BAD_REQUEST, message),
-            ApiError::NotFound { resource } => (StatusCode::NOT_FOUND, format!("Not found: {}", resource)),
-            ApiError::Internal { message } => (StatusCode::INTERNAL_SERVER_ERROR, message),
-            ApiError::Relayer(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
-            ApiError::ThresholdSignature(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+        let status = self.status_code();
+        let retry_after = match self {
+            ApiError::RateLimited { retry_after_secs } => Some(retry_after_secs),
+            _ => None,
         };
+        let body = Json(ErrorResponse::from(self));
 
-        let body = Json(json!({
-            "error": status.canonical_reason().unwrap_or("Unknown"),
-            "message": error_message,
-        }));
-
-        (status, body).into_response()
+        match retry_after {
+            Some(secs) => (status, [(header::RETRY_AFTER, secs.to_string())], body).into_response(),
+            None => (status, body).into_response(),
+        }
     }
 }
@@ -0,0 +1,247 @@
+//! Admin handlers for privileged bridge operations
+
+use crate::{
+    error::{ApiError, Result},
+    middleware::drain::DrainState,
+    server::{AdminState, ApiState, TransactionResponse},
+};
+use axum::{
+    extract::{Extension, Path},
+    http::{header, HeaderMap},
+    Json,
+};
+use relayer::{coordinator::BridgeEvent, database::DeadLetter};
+use serde::Serialize;
+use tracing::info;
+
+#[derive(Serialize)]
+pub struct AdminCallResponse {
+    pub paused: bool,
+    pub extrinsic_hash: String,
+}
+
+#[derive(Serialize)]
+pub struct KeySetResponse {
+    pub key_epoch: u64,
+    pub members: Vec<String>,
+    pub group_public_key: String,
+}
+
+/// Pause the bridge pallet
+pub async fn pause(
+    Extension(admin): Extension<AdminState>,
+    headers: HeaderMap,
+) -> Result<Json<AdminCallResponse>> {
+    set_paused(&admin, &headers, true).await
+}
+
+/// Resume the bridge pallet
+pub async fn resume(
+    Extension(admin): Extension<AdminState>,
+    headers: HeaderMap,
+) -> Result<Json<AdminCallResponse>> {
+    set_paused(&admin, &headers, false).await
+}
+
+#[derive(Serialize)]
+pub struct DrainResponse {
+    pub draining: bool,
+}
+
+/// Start draining this instance ahead of a rolling deploy
+///
+/// New `/bridge` writes and WebSocket subscriptions are refused and
+/// `/readyz` reports `draining`; in-flight work is left to finish.
+pub async fn drain(
+    Extension(admin): Extension<AdminState>,
+    Extension(drain): Extension<DrainState>,
+    headers: HeaderMap,
+) -> Result<Json<DrainResponse>> {
+    authorize(&admin, &headers)?;
+
+    if !drain.is_draining() {
+        info!("Draining API instance");
+        drain.drain();
+    }
+
+    Ok(Json(DrainResponse { draining: true }))
+}
+
+#[derive(Serialize)]
+pub struct ReconcileResponse {
+    /// Ethereum locks never minted on Polkadot
+    pub unmatched_locks: Vec<TransactionResponse>,
+    /// Polkadot burns never unlocked on Ethereum
+    pub unmatched_burns: Vec<TransactionResponse>,
+}
+
+/// List transfers detected on their source chain but never settled on the
+/// destination chain, oldest first
+pub async fn reconcile(
+    Extension(admin): Extension<AdminState>,
+    Extension(state): Extension<ApiState>,
+    headers: HeaderMap,
+) -> Result<Json<ReconcileResponse>> {
+    authorize(&admin, &headers)?;
+
+    let database = state.coordinator.database();
+    let unmatched_locks = database.unmatched_locks().await?;
+    let unmatched_burns = database.unmatched_burns().await?;
+
+    Ok(Json(ReconcileResponse {
+        unmatched_locks: unmatched_locks.into_iter().map(TransactionResponse::from).collect(),
+        unmatched_burns: unmatched_burns.into_iter().map(TransactionResponse::from).collect(),
+    }))
+}
+
+#[derive(Serialize)]
+pub struct ReleaseResponse {
+    pub tx_hash: String,
+    pub released: bool,
+}
+
+/// Relay an event that was held as stale for exceeding the maximum event age
+pub async fn release(
+    Extension(admin): Extension<AdminState>,
+    Extension(state): Extension<ApiState>,
+    Path(tx_hash): Path<String>,
+    headers: HeaderMap,
+) -> Result<Json<ReleaseResponse>> {
+    authorize(&admin, &headers)?;
+
+    if !state.coordinator.release_stale_event(&tx_hash).await? {
+        return Err(ApiError::NotFound {
+            resource: format!("stale event {}", tx_hash),
+        });
+    }
+    info!("Released stale event {}", tx_hash);
+
+    Ok(Json(ReleaseResponse {
+        tx_hash,
+        released: true,
+    }))
+}
+
+/// An event that exhausted its retries
+#[derive(Serialize)]
+pub struct DeadLetterResponse {
+    pub id: i64,
+    pub chain: String,
+    pub tx_hash: String,
+    /// The event as the relayer received it
+    pub event: BridgeEvent,
+    pub attempts: u32,
+    pub last_error: String,
+    pub failed_at: String,
+}
+
+impl From<DeadLetter> for DeadLetterResponse {
+    fn from(dead_letter: DeadLetter) -> Self {
+        Self {
+            id: dead_letter.id,
+            chain: dead_letter.event.chain().to_string(),
+            tx_hash: dead_letter.event.tx_hash().to_string(),
+            event: dead_letter.event,
+            attempts: dead_letter.attempts,
+            last_error: dead_letter.last_error,
+            failed_at: dead_letter.failed_at.to_rfc3339(),
+        }
+    }
+}
+
+/// List events that kept failing to process, oldest first
+pub async fn dead_letters(
+    Extension(admin): Extension<AdminState>,
+    Extension(state): Extension<ApiState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<DeadLetterResponse>>> {
+    authorize(&admin, &headers)?;
+
+    let dead_letters = state.coordinator.database().list_dead_letters().await?;
+    Ok(Json(dead_letters.into_iter().map(DeadLetterResponse::from).collect()))
+}
+
+#[derive(Serialize)]
+pub struct RetryResponse {
+    pub id: i64,
+    pub retried: bool,
+}
+
+/// Relay a dead-lettered event again
+///
+/// An event that fails again is dead-lettered under a new id and the error is
+/// returned.
+pub async fn retry_dead_letter(
+    Extension(admin): Extension<AdminState>,
+    Extension(state): Extension<ApiState>,
+    Path(id): Path<i64>,
+    headers: HeaderMap,
+) -> Result<Json<RetryResponse>> {
+    authorize(&admin, &headers)?;
+
+    if !state.coordinator.retry_dead_letter(id).await? {
+        return Err(ApiError::NotFound {
+            resource: format!("dead letter {}", id),
+        });
+    }
+    info!("Retried dead-lettered event {}", id);
+
+    Ok(Json(RetryResponse { id, retried: true }))
+}
+
+/// Report the active threshold key epoch, members and group public key
+pub async fn keyset(
+    Extension(admin): Extension<AdminState>,
+    headers: HeaderMap,
+) -> Result<Json<KeySetResponse>> {
+    authorize(&admin, &headers)?;
+
+    let key_set = admin.threshold_manager.key_set().ok_or_else(|| ApiError::NotFound {
+        resource: "key set".to_string(),
+    })?;
+
+    Ok(Json(KeySetResponse {
+        key_epoch: key_set.key_epoch,
+        members: key_set.members,
+        group_public_key: format!("0x{}", hex::encode(key_set.group_public_key)),
+    }))
+}
+
+async fn set_paused(
+    admin: &AdminState,
+    headers: &HeaderMap,
+    paused: bool,
+) -> Result<Json<AdminCallResponse>> {
+    authorize(admin, headers)?;
+
+    let pallet = admin.pallet.as_ref().ok_or_else(|| ApiError::Config {
+        message: "No Polkadot admin key configured".to_string(),
+    })?;
+
+    let extrinsic_hash = pallet.set_paused(paused).await?;
+    info!("Bridge pallet set_paused({}) submitted: {}", paused, extrinsic_hash);
+
+    Ok(Json(AdminCallResponse {
+        paused,
+        extrinsic_hash,
+    }))
+}
+
+/// Check the `Authorization: Bearer <key>` header against the admin key
+pub(crate) fn authorize(admin: &AdminState, headers: &HeaderMap) -> Result<()> {
+    let expected = admin.api_key.as_deref().ok_or_else(|| ApiError::Unauthorized {
+        message: "Admin API is disabled".to_string(),
+    })?;
+
+    let provided = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match provided {
+        Some(key) if key == expected => Ok(()),
+        _ => Err(ApiError::Unauthorized {
+            message: "Invalid admin credentials".to_string(),
+        }),
+    }
+}
@@ -1,6 +1,10 @@
 //! Bridge operation handlers
 
-use crate::{error::Result, server::ApiState};
+use crate::{
+    error::Result,
+    server::{ApiState, FeeEstimateParams, FeeEstimateResponse},
+    validation::ValidatedQuery,
+};
 use axum::{extract::Extension, Json};
 use serde::{Deserialize, Serialize};
 
@@ -48,3 +52,28 @@ pub async fn burn_tokens(
 ) -> Result<Json<serde_json::Value>> {
     Ok(Json(serde_json::json!({"status": "pending"})))
 }
+
+/// Expected fee of completing a transfer of `amount` of `token` on `chain`
+///
+/// The transfer is priced as it would be relayed: an Ethereum unlock or a
+/// Polkadot mint carrying as many signatures as the current threshold.
+pub async fn estimate_fee(
+    ValidatedQuery(params): ValidatedQuery<FeeEstimateParams>,
+    Extension(state): Extension<ApiState>,
+) -> Result<Json<FeeEstimateResponse>> {
+    let signature_count = state.coordinator.signature_coordinator().threshold().await as usize;
+
+    let response = match params.chain.as_str() {
+        "polkadot" => FeeEstimateResponse::polkadot(
+            state.coordinator.polkadot_client()
+                .estimate_mint_fee(&params.token, &params.amount, signature_count)
+                .await?,
+        ),
+        _ => state.coordinator.ethereum_client()
+            .estimate_unlock_fee(&params.token, &params.amount, signature_count)
+            .await?
+            .into(),
+    };
+
+    Ok(Json(response))
+}
@@ -0,0 +1,22 @@
+//! Checkpoint handlers
+
+use crate::{
+    error::{ApiError, Result},
+    server::ApiState,
+};
+use axum::{extract::Extension, Json};
+use relayer::checkpoint::SignedCheckpoint;
+
+/// Most recent threshold-signed checkpoint of bridge state
+pub async fn latest_checkpoint(
+    Extension(state): Extension<ApiState>,
+) -> Result<Json<SignedCheckpoint>> {
+    let checkpoint = state.coordinator.database()
+        .latest_checkpoint()
+        .await?
+        .ok_or_else(|| ApiError::NotFound {
+            resource: "checkpoint".to_string(),
+        })?;
+
+    Ok(Json(checkpoint))
+}
@@ -1,22 +1,106 @@
 //! Event handlers
 
-use crate::{error::Result, server::ApiState};
-use axum::{extract::Extension, Json};
+use crate::{
+    error::{ApiError, Result},
+    server::{parse_time_window, ApiState, EventFilters, EventResponse, PaginationParams},
+    validation::ValidatedQuery,
+};
+use axum::{
+    extract::{Extension, Query},
+    Json,
+};
+use relayer::database::{TransactionQuery, TransactionRecord};
 
 pub async fn list_events(
-    Extension(_state): Extension<ApiState>,
+    ValidatedQuery(filters): ValidatedQuery<EventFilters>,
+    Query(pagination): Query<PaginationParams>,
+    Extension(state): Extension<ApiState>,
 ) -> Result<Json<serde_json::Value>> {
-    Ok(Json(serde_json::json!({"events": []})))
+    let chain = filters.chain.clone();
+    query_events(&state, &pagination, filters, chain).await
 }
 
 pub async fn ethereum_events(
-    Extension(_state): Extension<ApiState>,
+    ValidatedQuery(filters): ValidatedQuery<EventFilters>,
+    Query(pagination): Query<PaginationParams>,
+    Extension(state): Extension<ApiState>,
 ) -> Result<Json<serde_json::Value>> {
-    Ok(Json(serde_json::json!({"events": []})))
+    query_events(&state, &pagination, filters, Some("ethereum".to_string())).await
 }
 
 pub async fn polkadot_events(
-    Extension(_state): Extension<ApiState>,
+    ValidatedQuery(filters): ValidatedQuery<EventFilters>,
+    Query(pagination): Query<PaginationParams>,
+    Extension(state): Extension<ApiState>,
 ) -> Result<Json<serde_json::Value>> {
-    Ok(Json(serde_json::json!({"events": []})))
+    query_events(&state, &pagination, filters, Some("polkadot".to_string())).await
+}
+
+/// Load bridge events from the relayer database
+async fn query_events(
+    state: &ApiState,
+    pagination: &PaginationParams,
+    filters: EventFilters,
+    chain: Option<String>,
+) -> Result<Json<serde_json::Value>> {
+    let (since, until) = parse_time_window(filters.since.as_deref(), filters.until.as_deref())?;
+
+    // Each stored transaction corresponds to exactly one event type per chain
+    let chain = match filters.event_type.as_deref() {
+        None => chain,
+        Some(event_type) => {
+            let event_chain = match event_type {
+                "bridge_lock" => "ethereum",
+                "bridge_burn" => "polkadot",
+                other => {
+                    return Err(ApiError::Validation {
+                        message: format!("Unknown event type: {}", other),
+                    })
+                }
+            };
+            if chain.as_deref().is_some_and(|c| c != event_chain) {
+                return Ok(Json(serde_json::json!({"events": []})));
+            }
+            Some(event_chain.to_string())
+        }
+    };
+
+    let query = TransactionQuery {
+        chain,
+        since,
+        until,
+        limit: pagination.limit(),
+        offset: pagination.offset(),
+        ..Default::default()
+    };
+
+    let events: Vec<EventResponse> = state.coordinator.database()
+        .query_transactions(&query)
+        .await?
+        .into_iter()
+        .map(EventResponse::from)
+        .collect();
+
+    Ok(Json(serde_json::json!({"events": events})))
+}
+
+impl From<TransactionRecord> for EventResponse {
+    fn from(record: TransactionRecord) -> Self {
+        let event_type = if record.chain == "polkadot" { "bridge_burn" } else { "bridge_lock" };
+
+        Self {
+            id: format!("{}_{}", record.chain, record.tx_hash),
+            event_type: event_type.to_string(),
+            chain: record.chain,
+            tx_hash: record.tx_hash,
+            block_number: record.block_number,
+            timestamp: record.created_at.to_rfc3339(),
+            data: serde_json::json!({
+                "user": record.user,
+                "token": record.token,
+                "amount": record.amount,
+                "recipient": record.recipient,
+            }),
+        }
+    }
 }
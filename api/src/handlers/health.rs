@@ -2,39 +2,102 @@
 
 use crate::{
     error::{ApiError, Result},
-    server::{ApiState, HealthResponse, BridgeStatsResponse},
+    middleware::drain::DrainState,
+    server::{HealthResponse, HealthState, ReadinessResponse},
 };
-use axum::{extract::Extension, Json};
-use tracing::debug;
+use axum::{async_trait, extract::Extension, http::StatusCode, Json};
+use futures::future::join_all;
+use relayer::{ethereum::EthereumClient, polkadot::PolkadotClient};
+use tracing::{debug, warn};
+
+/// A chain whose RPC endpoint the health check probes
+#[async_trait]
+pub trait ChainProbe: Send + Sync {
+    /// Chain name reported when the probe fails
+    fn chain(&self) -> &'static str;
+
+    /// Latest block the chain's RPC endpoint knows of
+    async fn latest_block(&self) -> relayer::Result<u64>;
+}
+
+#[async_trait]
+impl ChainProbe for EthereumClient {
+    fn chain(&self) -> &'static str {
+        "ethereum"
+    }
+
+    async fn latest_block(&self) -> relayer::Result<u64> {
+        self.get_block_number().await
+    }
+}
+
+#[async_trait]
+impl ChainProbe for PolkadotClient {
+    fn chain(&self) -> &'static str {
+        "polkadot"
+    }
+
+    async fn latest_block(&self) -> relayer::Result<u64> {
+        self.get_block_number().await.map(u64::from)
+    }
+}
 
 /// Health check endpoint
+///
+/// Reports `degraded` rather than failing when a chain does not respond in
+/// time or transfers are lagging, so load balancers keep the instance in
+/// rotation while the response says what is wrong.
 pub async fn health_check(
-    Extension(state): Extension<ApiState>,
+    Extension(state): Extension<HealthState>,
 ) -> Result<Json<HealthResponse>> {
     debug!("Health check requested");
 
     // Get bridge statistics
-    let bridge_stats = state.coordinator.get_stats().await
-        .map_err(|e| ApiError::Relayer(e))?;
+    let bridge_stats = state.stats.bridge_stats().await
+        .map_err(ApiError::Relayer)?;
+
+    let probes = state.chains.iter().map(|chain| async {
+        match tokio::time::timeout(state.probe_timeout, chain.latest_block()).await {
+            Ok(Ok(_)) => None,
+            Ok(Err(e)) => {
+                warn!("Health check could not reach {}: {}", chain.chain(), e);
+                Some(chain.chain().to_string())
+            }
+            Err(_) => {
+                warn!("Health check timed out waiting for {}", chain.chain());
+                Some(chain.chain().to_string())
+            }
+        }
+    });
+    let unavailable: Vec<String> = join_all(probes).await.into_iter().flatten().collect();
+
+    let status = if !unavailable.is_empty() || state.transfer_health.is_degraded().await {
+        "degraded"
+    } else {
+        "healthy"
+    };
 
     let response = HealthResponse {
-        status: "healthy".to_string(),
+        status: status.to_string(),
         version: env!("CARGO_PKG_VERSION").to_string(),
-        uptime: get_uptime_seconds(),
-        bridge_stats: BridgeStatsResponse {
-            ethereum_processed_txs: bridge_stats.ethereum_processed_txs,
-            polkadot_processed_txs: bridge_stats.polkadot_processed_txs,
-            pending_signatures: bridge_stats.pending_signatures,
-            active_validators: bridge_stats.active_validators,
-        },
+        uptime: state.started_at.elapsed().as_secs(),
+        bridge_stats: bridge_stats.into(),
+        unavailable,
     };
 
     Ok(Json(response))
 }
 
-/// Get uptime in seconds (simplified implementation)
-fn get_uptime_seconds() -> u64 {
-    // In a real implementation, this would track actual uptime
-    // For now, return a mock value
-    3600 // 1 hour
+/// Readiness check for the load balancer
+///
+/// Returns `503` with status `draining` once the instance is draining, so it
+/// is taken out of rotation.
+pub async fn readiness_check(
+    Extension(drain): Extension<DrainState>,
+) -> (StatusCode, Json<ReadinessResponse>) {
+    if drain.is_draining() {
+        (StatusCode::SERVICE_UNAVAILABLE, Json(ReadinessResponse { status: "draining".to_string() }))
+    } else {
+        (StatusCode::OK, Json(ReadinessResponse { status: "ready".to_string() }))
+    }
 }
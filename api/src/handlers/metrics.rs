@@ -1,28 +1,44 @@
 //! Metrics handlers
 
-use crate::{error::Result, server::ApiState};
-use axum::{extract::Extension, response::Response};
+use crate::{
+    error::{ApiError, Result},
+    server::{ApiState, MetricsState},
+};
+use axum::{async_trait, extract::Extension, response::Response};
+use prometheus::TextEncoder;
+use relayer::{coordinator::BridgeCoordinator, metrics::CONTENT_TYPE};
 
+/// Updates the metrics that are read at scrape time rather than counted
+#[async_trait]
+pub trait MetricsSource: Send + Sync {
+    /// Set the scrape-time gauges in the registry served at `/metrics`
+    async fn refresh(&self) -> relayer::Result<()>;
+}
+
+#[async_trait]
+impl MetricsSource for BridgeCoordinator {
+    /// Signing gauges and recent completion times
+    async fn refresh(&self) -> relayer::Result<()> {
+        self.metrics().refresh(self.signature_coordinator()).await;
+        self.metrics().set_completion_times(&self.completion_times().await?);
+        Ok(())
+    }
+}
+
+/// Every metric in the shared registry, in Prometheus text exposition format
 pub async fn prometheus_metrics(
-    Extension(_state): Extension<ApiState>,
+    Extension(state): Extension<MetricsState>,
 ) -> Result<Response> {
-    let metrics = r#"
-# HELP bridge_processed_transactions_total Total number of processed transactions
-# TYPE bridge_processed_transactions_total counter
-bridge_processed_transactions_total{chain="ethereum"} 100
-bridge_processed_transactions_total{chain="polkadot"} 95
-
-# HELP bridge_active_validators Number of active validators
-# TYPE bridge_active_validators gauge
-bridge_active_validators 3
+    state.source.refresh().await.map_err(ApiError::Relayer)?;
 
-# HELP bridge_pending_signatures Number of pending signatures
-# TYPE bridge_pending_signatures gauge
-bridge_pending_signatures 2
-"#;
+    let metrics = TextEncoder::new()
+        .encode_to_string(&state.registry.gather())
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to encode metrics: {}", e),
+        })?;
 
     Ok(Response::builder()
-        .header("content-type", "text/plain; version=0.0.4; charset=utf-8")
+        .header("content-type", CONTENT_TYPE)
         .body(metrics.into())
         .unwrap())
 }
@@ -11,3 +11,5 @@ pub mod blocks;
 pub mod events;
 pub mod websocket;
 pub mod metrics;
+pub mod admin;
+pub mod checkpoints;
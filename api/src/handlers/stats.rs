@@ -2,11 +2,37 @@
 
 use crate::{
     error::{ApiError, Result},
-    server::{ApiState, BridgeStatsResponse},
+    server::{ApiState, BridgeStatsResponse, TokenStatsResponse},
 };
 use axum::{extract::Extension, Json};
+use relayer::coordinator::BridgeStats;
 use tracing::debug;
 
+impl From<BridgeStats> for BridgeStatsResponse {
+    fn from(stats: BridgeStats) -> Self {
+        Self {
+            ethereum_processed_txs: stats.ethereum_processed_txs,
+            polkadot_processed_txs: stats.polkadot_processed_txs,
+            pending_signatures: stats.pending_signatures,
+            active_validators: stats.active_validators,
+            tokens: stats
+                .tokens
+                .into_iter()
+                .map(|(token, stats)| {
+                    let response = TokenStatsResponse {
+                        transfers_in: stats.transfers_in,
+                        transfers_out: stats.transfers_out,
+                        volume: stats.volume,
+                    };
+                    (token, response)
+                })
+                .collect(),
+            average_completion_secs: stats.completion_times.average_secs,
+            p95_completion_secs: stats.completion_times.p95_secs,
+        }
+    }
+}
+
 /// Get bridge statistics
 pub async fn bridge_stats(
     Extension(state): Extension<ApiState>,
@@ -14,14 +40,7 @@ pub async fn bridge_stats(
     debug!("Bridge stats requested");
 
     let bridge_stats = state.coordinator.get_stats().await
-        .map_err(|e| ApiError::Relayer(e))?;
-
-    let response = BridgeStatsResponse {
-        ethereum_processed_txs: bridge_stats.ethereum_processed_txs,
-        polkadot_processed_txs: bridge_stats.polkadot_processed_txs,
-        pending_signatures: bridge_stats.pending_signatures,
-        active_validators: bridge_stats.active_validators,
-    };
+        .map_err(ApiError::Relayer)?;
 
-    Ok(Json(response))
+    Ok(Json(bridge_stats.into()))
 }
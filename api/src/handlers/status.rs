@@ -2,7 +2,7 @@
 
 use crate::{
     error::{ApiError, Result},
-    server::{ApiState, BridgeStatusResponse, ValidatorResponse, TransactionResponse},
+    server::{ApiState, BridgeStatusResponse, ValidatorResponse, ValidatorState, TransactionResponse},
 };
 use axum::{extract::Extension, Json};
 use tracing::debug;
@@ -10,37 +10,15 @@ use tracing::debug;
 /// Get bridge status
 pub async fn bridge_status(
     Extension(state): Extension<ApiState>,
+    Extension(validators): Extension<ValidatorState>,
 ) -> Result<Json<BridgeStatusResponse>> {
     debug!("Bridge status requested");
 
     // Get bridge statistics
-    let bridge_stats = state.coordinator.get_stats().await
-        .map_err(|e| ApiError::Relayer(e))?;
+    let _bridge_stats = state.coordinator.get_stats().await
+        .map_err(ApiError::Relayer)?;
 
-    // Mock data for demonstration
-    let validators = vec![
-        ValidatorResponse {
-            id: "validator_0".to_string(),
-            address: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
-            active: true,
-            stake: "1000".to_string(),
-            uptime: 99.5,
-        },
-        ValidatorResponse {
-            id: "validator_1".to_string(),
-            address: "0x3C44CdDdB6a900fa2b585dd299e03d12FA4293BC".to_string(),
-            active: true,
-            stake: "1000".to_string(),
-            uptime: 98.2,
-        },
-        ValidatorResponse {
-            id: "validator_2".to_string(),
-            address: "0x90F79bf6EB2c4f870365E785982E1f101E93b906".to_string(),
-            active: true,
-            stake: "1000".to_string(),
-            uptime: 99.8,
-        },
-    ];
+    let validator_set = validators.source.validator_set().await;
 
     let recent_transactions = vec![
         TransactionResponse {
@@ -59,7 +37,8 @@ pub async fn bridge_status(
         status: "operational".to_string(),
         ethereum_block: 12345,
         polkadot_block: 6789,
-        validators,
+        validators: validator_set.validators.into_iter().map(ValidatorResponse::from).collect(),
+        threshold: validator_set.threshold,
         recent_transactions,
     };
 
@@ -1,17 +1,36 @@
 //! Token handlers
 
-use crate::{error::Result, server::ApiState};
-use axum::{extract::{Extension, Path}, Json};
+use crate::{
+    error::{ApiError, Result},
+    server::{ApiState, TokenPath, TokenResponse},
+    validation::ValidatedPath,
+};
+use axum::{extract::Extension, Json};
+use relayer::database::Database;
 
+/// List every token the bridge maps to a Polkadot asset
 pub async fn list_tokens(
-    Extension(_state): Extension<ApiState>,
-) -> Result<Json<serde_json::Value>> {
-    Ok(Json(serde_json::json!({"tokens": []})))
+    Extension(state): Extension<ApiState>,
+) -> Result<Json<Vec<TokenResponse>>> {
+    let tokens = state.coordinator.database().list_tokens().await?;
+    Ok(Json(tokens.into_iter().map(TokenResponse::from).collect()))
 }
 
+/// Get a bridged token by its Ethereum address
 pub async fn get_token(
-    Extension(_state): Extension<ApiState>,
-    Path(_token_address): Path<String>,
-) -> Result<Json<serde_json::Value>> {
-    Ok(Json(serde_json::json!({"token": {}})))
+    ValidatedPath(TokenPath { token_address }): ValidatedPath<TokenPath>,
+    Extension(state): Extension<ApiState>,
+) -> Result<Json<TokenResponse>> {
+    token_by_address(state.coordinator.database(), &token_address).await.map(Json)
+}
+
+/// Look up a bridged token, or a 404 if `token_address` is not mapped
+pub async fn token_by_address(database: &Database, token_address: &str) -> Result<TokenResponse> {
+    database
+        .get_token_by_address(token_address)
+        .await?
+        .map(TokenResponse::from)
+        .ok_or_else(|| ApiError::NotFound {
+            resource: format!("token {}", token_address),
+        })
 }
@@ -2,57 +2,250 @@
 
 use crate::{
     error::{ApiError, Result},
-    server::{ApiState, TransactionResponse, PaginationParams, TransactionFilters},
+    handlers::admin::authorize,
+    server::{
+        parse_time_window, AdminState, ApiState, ExportParams, PaginationParams,
+        TransactionFilters, TransactionPage, TransactionPath, TransactionResponse,
+    },
+    validation::{ValidatedPath, ValidatedQuery},
 };
 use axum::{
-    extract::{Extension, Path, Query},
+    body::Body,
+    extract::{Extension, Query},
+    http::{header, HeaderMap},
+    response::{IntoResponse, Response},
     Json,
 };
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use relayer::{
+    database::{Database, TransactionQuery, TransactionRecord},
+    signature_coordinator::{SignatureFormat, SignatureSession},
+};
+use std::sync::Arc;
 use tracing::debug;
 
+/// Columns of a CSV transaction export
+pub const CSV_HEADER: &str = "tx_hash,chain,status,user,token,amount,recipient,block_number,created_at\n";
+
+/// Rows fetched per database query while exporting
+const EXPORT_PAGE_SIZE: u32 = 500;
+
+impl From<TransactionRecord> for TransactionResponse {
+    fn from(record: TransactionRecord) -> Self {
+        Self {
+            tx_hash: record.tx_hash,
+            chain: record.chain,
+            status: record.status,
+            amount: record.amount,
+            token: record.token,
+            user: record.user,
+            block_number: record.block_number,
+            timestamp: record.created_at.to_rfc3339(),
+        }
+    }
+}
+
 /// List transactions with pagination and filtering
 pub async fn list_transactions(
-    Extension(_state): Extension<ApiState>,
+    ValidatedQuery(filters): ValidatedQuery<TransactionFilters>,
     Query(pagination): Query<PaginationParams>,
-    Query(_filters): Query<TransactionFilters>,
-) -> Result<Json<Vec<TransactionResponse>>> {
+    Extension(state): Extension<ApiState>,
+) -> Result<Json<TransactionPage>> {
     debug!("List transactions requested with pagination: {:?}", pagination);
 
-    // Mock response for demonstration
-    let transactions = vec![
-        TransactionResponse {
-            tx_hash: "0x1234567890abcdef".to_string(),
-            chain: "ethereum".to_string(),
-            status: "confirmed".to_string(),
-            amount: "1000".to_string(),
-            token: "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8".to_string(),
-            user: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
-            block_number: 12345,
-            timestamp: "2024-01-01T12:00:00Z".to_string(),
-        },
+    let query = transaction_query(filters, pagination.limit(), pagination.offset())?;
+    let page = transaction_page(state.coordinator.database(), &query, &pagination).await?;
+
+    Ok(Json(page))
+}
+
+/// One page of the transactions matching `query`, with the total across all pages
+pub async fn transaction_page(
+    database: &Database,
+    query: &TransactionQuery,
+    pagination: &PaginationParams,
+) -> Result<TransactionPage> {
+    let transactions = database
+        .query_transactions(query)
+        .await?
+        .into_iter()
+        .map(TransactionResponse::from)
+        .collect();
+
+    Ok(TransactionPage {
+        transactions,
+        total: database.count_transactions(query).await?,
+        page: pagination.page(),
+        limit: pagination.limit(),
+    })
+}
+
+/// Export transactions matching the list filters
+///
+/// Requires the admin bearer key. Rows are streamed page by page, so large
+/// exports are never held in memory.
+pub async fn export_transactions(
+    ValidatedQuery(filters): ValidatedQuery<TransactionFilters>,
+    ValidatedQuery(_params): ValidatedQuery<ExportParams>,
+    Extension(admin): Extension<AdminState>,
+    Extension(state): Extension<ApiState>,
+    headers: HeaderMap,
+) -> Result<Response> {
+    authorize(&admin, &headers)?;
+    debug!("Transaction export requested");
+
+    let query = transaction_query(filters, EXPORT_PAGE_SIZE, 0)?;
+    let body = export_csv(state.coordinator.database().clone(), query);
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (header::CONTENT_DISPOSITION, "attachment; filename=\"transactions.csv\""),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// Build the database query for a set of list filters
+pub fn transaction_query(filters: TransactionFilters, limit: u32, offset: u32) -> Result<TransactionQuery> {
+    let (since, until) = parse_time_window(filters.since.as_deref(), filters.until.as_deref())?;
+
+    Ok(TransactionQuery {
+        chain: filters.chain,
+        status: filters.status,
+        user: filters.user,
+        token: filters.token,
+        since,
+        until,
+        from_block: filters.from_block,
+        to_block: filters.to_block,
+        limit,
+        offset,
+    })
+}
+
+/// Stream every transaction matching `query` as CSV, starting at its offset
+///
+/// Without an upper time bound the export is pinned to the moment it starts,
+/// so rows recorded mid-export cannot shift later pages.
+pub fn export_csv(database: Arc<Database>, mut query: TransactionQuery) -> Body {
+    query.until.get_or_insert_with(Utc::now);
+
+    let header = stream::once(async { Ok::<_, ApiError>(CSV_HEADER.to_string()) });
+    let rows = stream::try_unfold(Some(query), move |query| {
+        let database = database.clone();
+        async move {
+            let Some(mut query) = query else {
+                return Ok(None);
+            };
+
+            let records = database.query_transactions(&query).await?;
+            let next = if records.len() < query.limit as usize {
+                None
+            } else {
+                query.offset += query.limit;
+                Some(query)
+            };
+
+            let chunk: String = records.iter().map(csv_row).collect();
+            Ok(Some((chunk, next)))
+        }
+    });
+
+    Body::from_stream(header.chain(rows))
+}
+
+/// Format a transaction as a CSV line
+fn csv_row(record: &TransactionRecord) -> String {
+    let block_number = record.block_number.to_string();
+    let created_at = record.created_at.to_rfc3339();
+
+    let fields = [
+        record.tx_hash.as_str(),
+        &record.chain,
+        &record.status,
+        &record.user,
+        &record.token,
+        &record.amount,
+        &record.recipient,
+        &block_number,
+        &created_at,
     ];
 
-    Ok(Json(transactions))
+    let mut line = fields.map(csv_field).join(",");
+    line.push('\n');
+    line
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or newline
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
 }
 
 /// Get a specific transaction by hash
+///
+/// Known transactions with an in-flight signature session are reported as
+/// `signing`; only hashes unknown to both the database and the signature
+/// coordinator are a 404. Malformed hashes are a 400.
 pub async fn get_transaction(
-    Extension(_state): Extension<ApiState>,
-    Path(tx_hash): Path<String>,
+    ValidatedPath(TransactionPath { tx_hash }): ValidatedPath<TransactionPath>,
+    Extension(state): Extension<ApiState>,
 ) -> Result<Json<TransactionResponse>> {
     debug!("Get transaction requested: {}", tx_hash);
 
-    // Mock response for demonstration
-    let transaction = TransactionResponse {
-        tx_hash: tx_hash.clone(),
-        chain: "ethereum".to_string(),
-        status: "confirmed".to_string(),
-        amount: "1000".to_string(),
-        token: "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8".to_string(),
-        user: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
-        block_number: 12345,
-        timestamp: "2024-01-01T12:00:00Z".to_string(),
-    };
-
-    Ok(Json(transaction))
+    let record = state.coordinator.database().get_transaction_by_hash(&tx_hash).await?;
+    let session = state.coordinator.signature_coordinator().session(&tx_hash).await;
+
+    resolve_transaction(&tx_hash, record, session.as_ref()).map(Json)
+}
+
+/// Combine the stored record and in-flight session for a transaction
+pub fn resolve_transaction(
+    tx_hash: &str,
+    record: Option<TransactionRecord>,
+    session: Option<&SignatureSession>,
+) -> Result<TransactionResponse> {
+    match (record, session) {
+        (Some(record), session) => {
+            let mut response = TransactionResponse::from(record);
+            if session.is_some() && response.status == "pending" {
+                response.status = "signing".to_string();
+            }
+            Ok(response)
+        }
+        (None, Some(session)) => session_response(session).ok_or_else(|| ApiError::Internal {
+            message: format!("Malformed signature session for transaction {}", tx_hash),
+        }),
+        (None, None) => Err(ApiError::NotFound {
+            resource: format!("transaction {}", tx_hash),
+        }),
+    }
+}
+
+/// Describe a transaction from its signing message alone
+///
+/// Mints and unlocks are both signed as a digest, which reveals neither the
+/// chain the transfer started on nor its token or amount. Other sessions are
+/// not transfers.
+fn session_response(session: &SignatureSession) -> Option<TransactionResponse> {
+    if session.format != SignatureFormat::Recoverable {
+        return None;
+    }
+
+    Some(TransactionResponse {
+        tx_hash: session.tx_hash.clone(),
+        chain: String::new(),
+        status: "signing".to_string(),
+        amount: String::new(),
+        token: String::new(),
+        user: String::new(),
+        block_number: 0,
+        timestamp: DateTime::<Utc>::from(session.created_at).to_rfc3339(),
+    })
 }
@@ -2,38 +2,53 @@
 
 use crate::{
     error::{ApiError, Result},
-    server::{ApiState, ValidatorResponse},
+    server::{ApiState, ValidatorEthAddressesResponse, ValidatorResponse, ValidatorState},
 };
 use axum::{
+    async_trait,
     extract::{Extension, Path},
     Json,
 };
+use relayer::{coordinator::BridgeCoordinator, signature_coordinator::ValidatorSet};
+
+/// Source of the bridge's signing set
+#[async_trait]
+pub trait ValidatorSource: Send + Sync {
+    /// Current validators and threshold
+    async fn validator_set(&self) -> ValidatorSet;
+}
+
+#[async_trait]
+impl ValidatorSource for BridgeCoordinator {
+    async fn validator_set(&self) -> ValidatorSet {
+        self.signature_coordinator().validator_set().await
+    }
+}
 
 pub async fn list_validators(
-    Extension(_state): Extension<ApiState>,
+    Extension(state): Extension<ValidatorState>,
 ) -> Result<Json<Vec<ValidatorResponse>>> {
-    let validators = vec![
-        ValidatorResponse {
-            id: "validator_0".to_string(),
-            address: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
-            active: true,
-            stake: "1000".to_string(),
-            uptime: 99.5,
-        },
-    ];
-    Ok(Json(validators))
+    let validators = state.source.validator_set().await.validators;
+    Ok(Json(validators.into_iter().map(ValidatorResponse::from).collect()))
 }
 
 pub async fn get_validator(
-    Extension(_state): Extension<ApiState>,
+    Extension(state): Extension<ValidatorState>,
     Path(validator_id): Path<String>,
 ) -> Result<Json<ValidatorResponse>> {
-    let validator = ValidatorResponse {
-        id: validator_id,
-        address: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
-        active: true,
-        stake: "1000".to_string(),
-        uptime: 99.5,
-    };
-    Ok(Json(validator))
+    state.source.validator_set().await.validators
+        .into_iter()
+        .find(|validator| validator.id == validator_id)
+        .map(|validator| Json(validator.into()))
+        .ok_or_else(|| ApiError::NotFound {
+            resource: format!("validator {}", validator_id),
+        })
+}
+
+/// Ethereum addresses of the active validators, as configured in the pallet
+pub async fn eth_addresses(
+    Extension(state): Extension<ApiState>,
+) -> Result<Json<ValidatorEthAddressesResponse>> {
+    let addresses = state.coordinator.polkadot_client().get_validator_eth_addresses().await?;
+    Ok(Json(ValidatorEthAddressesResponse { addresses }))
 }
@@ -1,32 +1,38 @@
 //! WebSocket handlers
 
-use crate::{error::Result, server::ApiState};
-use axum::{extract::Extension, response::Response};
+use crate::websocket::{self, EventStreamState, Subscription};
+use axum::{
+    extract::{ws::WebSocketUpgrade, Extension},
+    response::Response,
+};
 
+/// Stream bridge events and statistics
 pub async fn websocket_handler(
-    Extension(_state): Extension<ApiState>,
-) -> Result<Response> {
-    // Placeholder for WebSocket upgrade
-    Ok(Response::builder()
-        .status(501)
-        .body("WebSocket not implemented yet".into())
-        .unwrap())
+    ws: WebSocketUpgrade,
+    Extension(state): Extension<EventStreamState>,
+) -> Response {
+    upgrade(ws, state, Subscription::ALL)
 }
 
+/// Stream bridge events only
 pub async fn events_websocket(
-    Extension(_state): Extension<ApiState>,
-) -> Result<Response> {
-    Ok(Response::builder()
-        .status(501)
-        .body("Events WebSocket not implemented yet".into())
-        .unwrap())
+    ws: WebSocketUpgrade,
+    Extension(state): Extension<EventStreamState>,
+) -> Response {
+    upgrade(ws, state, Subscription::EVENTS)
 }
 
+/// Stream statistics only
 pub async fn stats_websocket(
-    Extension(_state): Extension<ApiState>,
-) -> Result<Response> {
-    Ok(Response::builder()
-        .status(501)
-        .body("Stats WebSocket not implemented yet".into())
-        .unwrap())
+    ws: WebSocketUpgrade,
+    Extension(state): Extension<EventStreamState>,
+) -> Response {
+    upgrade(ws, state, Subscription::STATS)
+}
+
+fn upgrade(ws: WebSocketUpgrade, state: EventStreamState, subscription: Subscription) -> Response {
+    // Subscribe before the handshake completes so the client misses nothing
+    // published after it connects
+    let events = state.events.subscribe();
+    ws.on_upgrade(move |socket| websocket::stream(socket, state, subscription, events))
 }
@@ -9,6 +9,7 @@ pub mod error;
 pub mod server;
 pub mod websocket;
 pub mod middleware;
+pub mod validation;
 
 pub use error::{ApiError, Result};
 pub use server::ApiServer;
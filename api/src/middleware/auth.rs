@@ -1,19 +1,51 @@
 //! Authentication middleware
+//!
+//! Requests that change bridge state must present one of the configured API
+//! keys, either as an `Authorization: Bearer` token or in an `X-API-Key`
+//! header. Read-only requests such as `/health` and `/metrics` stay public,
+//! and `/admin` endpoints check the separate admin key themselves. With no
+//! API keys configured, state-changing requests are refused.
 
+use crate::error::ApiError;
 use axum::{
     extract::Request,
-    http::StatusCode,
-    middleware::Next,
-    response::Response,
+    http::{header, HeaderMap, Method},
+    response::{IntoResponse, Response},
 };
+use std::sync::Arc;
 use tower::{Layer, Service};
 
-#[derive(Clone)]
-pub struct AuthLayer;
+/// Header carrying an API key as an alternative to a bearer token
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Whether a request must present an API key
+fn requires_auth(request: &Request) -> bool {
+    let read_only = matches!(*request.method(), Method::GET | Method::HEAD | Method::OPTIONS);
+
+    !read_only && !request.uri().path().starts_with("/admin/")
+}
+
+/// API key presented with a request, if any
+pub(crate) fn presented_key(headers: &HeaderMap) -> Option<&str> {
+    let bearer = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    bearer.or_else(|| headers.get(API_KEY_HEADER).and_then(|value| value.to_str().ok()))
+}
+
+#[derive(Clone, Default)]
+pub struct AuthLayer {
+    api_keys: Arc<Vec<String>>,
+}
 
 impl AuthLayer {
-    pub fn new() -> Self {
-        Self
+    /// Accept any of `api_keys` on state-changing requests
+    pub fn new(api_keys: Vec<String>) -> Self {
+        Self {
+            api_keys: Arc::new(api_keys),
+        }
     }
 }
 
@@ -21,13 +53,40 @@ impl<S> Layer<S> for AuthLayer {
     type Service = AuthService<S>;
 
     fn layer(&self, inner: S) -> Self::Service {
-        AuthService { inner }
+        AuthService {
+            inner,
+            api_keys: self.api_keys.clone(),
+        }
     }
 }
 
 #[derive(Clone)]
 pub struct AuthService<S> {
     inner: S,
+    api_keys: Arc<Vec<String>>,
+}
+
+impl<S> AuthService<S> {
+    fn authorize(&self, request: &Request) -> Result<(), ApiError> {
+        if !requires_auth(request) {
+            return Ok(());
+        }
+        if self.api_keys.is_empty() {
+            return Err(ApiError::Unauthorized {
+                message: "No API keys are configured for this endpoint".to_string(),
+            });
+        }
+
+        match presented_key(request.headers()) {
+            Some(key) if self.api_keys.iter().any(|api_key| api_key == key) => Ok(()),
+            Some(_) => Err(ApiError::Unauthorized {
+                message: "Invalid API key".to_string(),
+            }),
+            None => Err(ApiError::Unauthorized {
+                message: "Missing API key".to_string(),
+            }),
+        }
+    }
 }
 
 impl<S> Service<Request> for AuthService<S>
@@ -44,11 +103,13 @@ where
     }
 
     fn call(&mut self, request: Request) -> Self::Future {
-        // For now, allow all requests (no authentication)
-        // In production, implement proper authentication here
-        
+        if let Err(e) = self.authorize(&request) {
+            let response = e.into_response();
+            return Box::pin(async move { Ok(response) });
+        }
+
         let mut inner = self.inner.clone();
-        
+
         Box::pin(async move {
             inner.call(request).await
         })
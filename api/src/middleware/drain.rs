@@ -0,0 +1,105 @@
+//! Drain middleware for rolling deploys
+
+use crate::error::ApiError;
+use axum::{
+    extract::Request,
+    http::Method,
+    response::{IntoResponse, Response},
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tower::{Layer, Service};
+
+/// Whether this instance is draining ahead of a shutdown
+///
+/// Once draining, new writes and WebSocket subscriptions are refused and
+/// `/readyz` reports `draining`, while requests already in flight and open
+/// connections finish normally. Draining cannot be undone; the instance is
+/// expected to be replaced.
+#[derive(Clone, Default)]
+pub struct DrainState {
+    draining: Arc<AtomicBool>,
+}
+
+impl DrainState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start draining
+    pub fn drain(&self) {
+        self.draining.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether draining has started
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+}
+
+/// Whether a request starts new work that a draining instance refuses
+fn starts_new_work(request: &Request) -> bool {
+    let path = request.uri().path();
+
+    (request.method() == Method::POST && path.starts_with("/bridge/"))
+        || path == "/ws"
+        || path.starts_with("/ws/")
+}
+
+#[derive(Clone)]
+pub struct DrainLayer {
+    state: DrainState,
+}
+
+impl DrainLayer {
+    pub fn new(state: DrainState) -> Self {
+        Self { state }
+    }
+}
+
+impl<S> Layer<S> for DrainLayer {
+    type Service = DrainService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        DrainService {
+            inner,
+            state: self.state.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct DrainService<S> {
+    inner: S,
+    state: DrainState,
+}
+
+impl<S> Service<Request> for DrainService<S>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<Box<dyn std::future::Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut std::task::Context<'_>) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        if self.state.is_draining() && starts_new_work(&request) {
+            let response = ApiError::Unavailable {
+                message: "Instance is draining".to_string(),
+            }
+            .into_response();
+            return Box::pin(async move { Ok(response) });
+        }
+
+        let mut inner = self.inner.clone();
+
+        Box::pin(async move {
+            inner.call(request).await
+        })
+    }
+}
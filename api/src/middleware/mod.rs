@@ -2,3 +2,5 @@
 
 pub mod request_id;
 pub mod auth;
+pub mod drain;
+pub mod rate_limit;
@@ -0,0 +1,169 @@
+//! Rate limiting middleware
+//!
+//! Each client gets a token bucket holding up to `burst` requests and
+//! refilled at `requests_per_minute`. Clients presenting a configured API key
+//! are told apart by that key, and all others by their IP address, so made-up
+//! keys cannot be used to dodge the limit. A request arriving at an empty
+//! bucket is refused with `429 Too Many Requests` and a `Retry-After` header.
+//! Buckets that have refilled completely are indistinguishable from new ones
+//! and are swept out periodically, so idle clients take no memory.
+
+use crate::{error::ApiError, middleware::auth};
+use axum::{
+    extract::{ConnectInfo, Request},
+    response::{IntoResponse, Response},
+};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tower::{Layer, Service};
+
+/// How often full buckets are swept from the limiter
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Requests a client may still make, refilled continuously
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Token buckets of every recently seen client
+struct Buckets {
+    clients: HashMap<String, Bucket>,
+    swept_at: Instant,
+}
+
+/// Per-client token bucket rate limiter
+#[derive(Clone)]
+pub struct RateLimiter {
+    /// Tokens added per second
+    rate: f64,
+    burst: f64,
+    api_keys: Arc<Vec<String>>,
+    buckets: Arc<Mutex<Buckets>>,
+}
+
+impl RateLimiter {
+    /// Allow `requests_per_minute` per client, with bursts of up to `burst`
+    pub fn new(requests_per_minute: u32, burst: u32) -> Self {
+        Self {
+            rate: f64::from(requests_per_minute.max(1)) / 60.0,
+            burst: f64::from(burst.max(1)),
+            api_keys: Arc::new(vec![]),
+            buckets: Arc::new(Mutex::new(Buckets {
+                clients: HashMap::new(),
+                swept_at: Instant::now(),
+            })),
+        }
+    }
+
+    /// Limit clients presenting one of `api_keys` by key rather than by IP
+    pub fn with_api_keys(mut self, api_keys: Vec<String>) -> Self {
+        self.api_keys = Arc::new(api_keys);
+        self
+    }
+
+    /// Take a token for `client`, or return how long until one is available
+    pub fn acquire(&self, client: &str) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+
+        if now.duration_since(buckets.swept_at) >= SWEEP_INTERVAL {
+            buckets.clients.retain(|_, bucket| self.refilled(bucket, now) < self.burst);
+            buckets.swept_at = now;
+        }
+
+        let bucket = buckets.clients.entry(client.to_string()).or_insert(Bucket {
+            tokens: self.burst,
+            refilled_at: now,
+        });
+        bucket.tokens = self.refilled(bucket, now);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate))
+        }
+    }
+
+    /// Tokens in `bucket` at `now`
+    fn refilled(&self, bucket: &Bucket, now: Instant) -> f64 {
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        (bucket.tokens + elapsed * self.rate).min(self.burst)
+    }
+
+    /// Key `request` is rate limited under
+    fn client_key(&self, request: &Request) -> String {
+        if let Some(key) = auth::presented_key(request.headers()) {
+            if self.api_keys.iter().any(|api_key| api_key == key) {
+                return format!("key:{}", key);
+            }
+        }
+
+        match request.extensions().get::<ConnectInfo<SocketAddr>>() {
+            Some(ConnectInfo(addr)) => format!("ip:{}", addr.ip()),
+            None => "unknown".to_string(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    limiter: RateLimiter,
+}
+
+impl RateLimitLayer {
+    pub fn new(limiter: RateLimiter) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    limiter: RateLimiter,
+}
+
+impl<S> Service<Request> for RateLimitService<S>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<Box<dyn std::future::Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut std::task::Context<'_>) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        if let Err(retry_after) = self.limiter.acquire(&self.limiter.client_key(&request)) {
+            let response = ApiError::RateLimited {
+                retry_after_secs: retry_after.as_secs_f64().ceil().max(1.0) as u64,
+            }
+            .into_response();
+            return Box::pin(async move { Ok(response) });
+        }
+
+        let mut inner = self.inner.clone();
+
+        Box::pin(async move {
+            inner.call(request).await
+        })
+    }
+}
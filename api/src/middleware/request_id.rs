@@ -2,13 +2,48 @@
 
 use axum::{
     extract::Request,
-    http::HeaderValue,
-    middleware::Next,
-    response::Response,
+    http::{HeaderValue, Response},
 };
 use tower::{Layer, Service};
+use tracing::Instrument;
 use uuid::Uuid;
 
+/// Header carrying a request's correlation id, in both directions
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Longest client-supplied request id that is accepted
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Correlation id of the request being handled
+///
+/// Taken from the client's `X-Request-Id` header when it sends a usable one,
+/// otherwise a fresh UUID. Handlers can read it from request extensions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    /// The id `request` supplied, or a new UUID if it supplied none or an
+    /// unusable one
+    fn for_request(request: &Request) -> Self {
+        let supplied = request
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|id| !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN);
+
+        match supplied {
+            Some(id) => Self(id.to_string()),
+            None => Self(Uuid::new_v4().to_string()),
+        }
+    }
+}
+
+/// Tags every request with a [`RequestId`]
+///
+/// The id is stored in request extensions, recorded on a `request` span that
+/// covers everything the request does, including coordinator calls made by
+/// its handler, and echoed in the `X-Request-Id` response header. Layer it
+/// outermost so tracing and rejections by other middleware carry the id too.
 #[derive(Clone)]
 pub struct RequestIdLayer;
 
@@ -18,6 +53,12 @@ impl RequestIdLayer {
     }
 }
 
+impl Default for RequestIdLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<S> Layer<S> for RequestIdLayer {
     type Service = RequestIdService<S>;
 
@@ -31,9 +72,9 @@ pub struct RequestIdService<S> {
     inner: S,
 }
 
-impl<S> Service<Request> for RequestIdService<S>
+impl<S, ResBody> Service<Request> for RequestIdService<S>
 where
-    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S: Service<Request, Response = Response<ResBody>> + Clone + Send + 'static,
     S::Future: Send + 'static,
 {
     type Response = S::Response;
@@ -45,26 +86,23 @@ where
     }
 
     fn call(&mut self, mut request: Request) -> Self::Future {
-        let request_id = Uuid::new_v4().to_string();
-        
-        // Add request ID to headers
-        request.headers_mut().insert(
-            "x-request-id",
-            HeaderValue::from_str(&request_id).unwrap(),
-        );
+        let request_id = RequestId::for_request(&request);
+        // Checked to be a valid header value when supplied; a UUID always is
+        let header = HeaderValue::from_str(&request_id.0).expect("request id is a valid header value");
+        let span = tracing::info_span!("request", request_id = %request_id.0);
+
+        request.headers_mut().insert(REQUEST_ID_HEADER, header.clone());
+        request.extensions_mut().insert(request_id);
 
         let mut inner = self.inner.clone();
-        
-        Box::pin(async move {
-            let mut response = inner.call(request).await?;
-            
-            // Add request ID to response headers
-            response.headers_mut().insert(
-                "x-request-id",
-                HeaderValue::from_str(&request_id).unwrap(),
-            );
-            
-            Ok(response)
-        })
+
+        Box::pin(
+            async move {
+                let mut response = inner.call(request).await?;
+                response.headers_mut().insert(REQUEST_ID_HEADER, header);
+                Ok(response)
+            }
+            .instrument(span),
+        )
     }
 }
@@ -11,15 +11,18 @@ pub fn create_api_routes() -> Router {
     Router::new()
         // Health and status endpoints
         .route("/health", get(handlers::health::health_check))
+        .route("/readyz", get(handlers::health::readiness_check))
         .route("/status", get(handlers::status::bridge_status))
         .route("/stats", get(handlers::stats::bridge_stats))
         
         // Transaction endpoints
         .route("/transactions", get(handlers::transactions::list_transactions))
+        .route("/transactions/export", get(handlers::transactions::export_transactions))
         .route("/transactions/:tx_hash", get(handlers::transactions::get_transaction))
         
         // Validator endpoints
         .route("/validators", get(handlers::validators::list_validators))
+        .route("/validators/eth-addresses", get(handlers::validators::eth_addresses))
         .route("/validators/:validator_id", get(handlers::validators::get_validator))
         
         // Bridge operation endpoints
@@ -27,6 +30,7 @@ pub fn create_api_routes() -> Router {
         .route("/bridge/unlock", post(handlers::bridge::initiate_unlock))
         .route("/bridge/mint", post(handlers::bridge::mint_tokens))
         .route("/bridge/burn", post(handlers::bridge::burn_tokens))
+        .route("/bridge/estimate-fee", get(handlers::bridge::estimate_fee))
         
         // Token endpoints
         .route("/tokens", get(handlers::tokens::list_tokens))
@@ -36,6 +40,19 @@ pub fn create_api_routes() -> Router {
         .route("/blocks/ethereum/latest", get(handlers::blocks::latest_ethereum_block))
         .route("/blocks/polkadot/latest", get(handlers::blocks::latest_polkadot_block))
         
+        // Checkpoint endpoints
+        .route("/checkpoints/latest", get(handlers::checkpoints::latest_checkpoint))
+
+        // Admin endpoints
+        .route("/admin/pause", post(handlers::admin::pause))
+        .route("/admin/resume", post(handlers::admin::resume))
+        .route("/admin/keyset", get(handlers::admin::keyset))
+        .route("/admin/drain", post(handlers::admin::drain))
+        .route("/admin/reconcile", get(handlers::admin::reconcile))
+        .route("/admin/release/:tx_hash", post(handlers::admin::release))
+        .route("/admin/dead-letters", get(handlers::admin::dead_letters))
+        .route("/admin/dead-letters/:id/retry", post(handlers::admin::retry_dead_letter))
+
         // Event endpoints
         .route("/events", get(handlers::events::list_events))
         .route("/events/ethereum", get(handlers::events::ethereum_events))
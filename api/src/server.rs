@@ -2,25 +2,36 @@
 
 use crate::{
     error::{ApiError, Result},
-    handlers,
     routes,
-    middleware,
-    websocket,
+    handlers::{health::ChainProbe, metrics::MetricsSource, validators::ValidatorSource},
+    middleware::{self, drain::DrainState, rate_limit::RateLimiter},
+    validation::{self, Validate},
+    websocket::{EventStreamState, StatsSource},
 };
 use axum::{
     extract::Extension,
-    http::{header, Method},
+    http::{header, HeaderName, HeaderValue, Method},
     Router,
 };
-use relayer::BridgeCoordinator;
+use chrono::{DateTime, Utc};
+use relayer::{
+    database::TokenMapping, ethereum::FeeEstimate, metrics::{Registry, TransferHealth}, polkadot::PalletAdmin,
+    signature_coordinator::ValidatorInfo, BridgeCoordinator,
+};
+use std::collections::HashMap;
+use std::future::Future;
+use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use threshold::SimpleThresholdManager;
 use tokio::net::TcpListener;
+use tokio::signal;
 use tower::ServiceBuilder;
 use tower_http::{
-    cors::{Any, CorsLayer},
+    cors::{AllowOrigin, Any, CorsLayer},
     trace::TraceLayer,
 };
-use tracing::{info, error};
+use tracing::info;
 
 /// Configuration for the API server
 #[derive(Debug, Clone)]
@@ -30,6 +41,15 @@ pub struct ApiConfig {
     pub cors_origins: Vec<String>,
     pub enable_metrics: bool,
     pub metrics_path: String,
+    /// Bearer token required by `/admin` endpoints; they are disabled when unset
+    pub admin_api_key: Option<String>,
+    /// Keys accepted on state-changing endpoints such as `/bridge/lock`;
+    /// those endpoints are refused when empty
+    pub api_keys: Vec<String>,
+    /// Requests each client may make per minute on average
+    pub rate_limit_per_minute: u32,
+    /// Requests each client may make in a burst
+    pub rate_limit_burst: u32,
 }
 
 impl Default for ApiConfig {
@@ -40,6 +60,10 @@ impl Default for ApiConfig {
             cors_origins: vec!["http://localhost:3000".to_string()],
             enable_metrics: true,
             metrics_path: "/metrics".to_string(),
+            admin_api_key: None,
+            api_keys: vec![],
+            rate_limit_per_minute: 100,
+            rate_limit_burst: 200,
         }
     }
 }
@@ -50,22 +74,111 @@ pub struct ApiState {
     pub coordinator: Arc<BridgeCoordinator>,
 }
 
+/// State for privileged `/admin` endpoints
+#[derive(Clone)]
+pub struct AdminState {
+    /// Pallet admin client, present only when a sudo key is configured
+    pub pallet: Option<Arc<dyn PalletAdmin>>,
+    /// Bearer token callers must present
+    pub api_key: Option<String>,
+    /// Threshold manager holding the active key set
+    pub threshold_manager: Arc<SimpleThresholdManager>,
+}
+
+/// State for the `/validators` endpoints and the validator list in `/status`
+#[derive(Clone)]
+pub struct ValidatorState {
+    /// Where the signing set is read from
+    pub source: Arc<dyn ValidatorSource>,
+}
+
+/// How long `/health` waits for a chain to report its latest block
+pub const HEALTH_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// State for the `/health` endpoint
+#[derive(Clone)]
+pub struct HealthState {
+    /// Statistics reported in `bridge_stats`
+    pub stats: Arc<dyn StatsSource>,
+    /// Chains whose RPC endpoints must respond for the bridge to be healthy
+    pub chains: Vec<Arc<dyn ChainProbe>>,
+    /// Detected-vs-completed transfer tracker
+    pub transfer_health: Arc<TransferHealth>,
+    /// When the server started, for `uptime`
+    pub started_at: Instant,
+    /// How long each chain probe may take
+    pub probe_timeout: Duration,
+}
+
+/// State for the `/metrics` endpoint
+#[derive(Clone)]
+pub struct MetricsState {
+    /// Registry the relayer's metrics are gathered from, shared with its
+    /// monitoring port
+    pub registry: Registry,
+    /// Refreshes the registry's scrape-time gauges
+    pub source: Arc<dyn MetricsSource>,
+}
+
 /// Main API server
 pub struct ApiServer {
     config: ApiConfig,
     state: ApiState,
+    admin: AdminState,
+    events: EventStreamState,
+    validators: ValidatorState,
+    metrics: MetricsState,
+    health: HealthState,
+    drain: DrainState,
 }
 
 impl ApiServer {
     /// Create a new API server
     pub fn new(config: ApiConfig, coordinator: Arc<BridgeCoordinator>) -> Self {
+        let polkadot_client = coordinator.polkadot_client();
+        let admin = AdminState {
+            pallet: polkadot_client
+                .has_admin_key()
+                .then(|| polkadot_client.clone() as Arc<dyn PalletAdmin>),
+            api_key: config.admin_api_key.clone(),
+            threshold_manager: coordinator.threshold_manager().clone(),
+        };
+        let events = EventStreamState::new(coordinator.event_stream().clone(), coordinator.clone());
+        let validators = ValidatorState {
+            source: coordinator.clone(),
+        };
+        let metrics = MetricsState {
+            registry: coordinator.metrics().registry().clone(),
+            source: coordinator.clone(),
+        };
+        let health = HealthState {
+            stats: coordinator.clone(),
+            chains: vec![
+                coordinator.ethereum_client().clone() as Arc<dyn ChainProbe>,
+                coordinator.polkadot_client().clone() as Arc<dyn ChainProbe>,
+            ],
+            transfer_health: coordinator.transfer_health().clone(),
+            started_at: Instant::now(),
+            probe_timeout: HEALTH_PROBE_TIMEOUT,
+        };
         let state = ApiState { coordinator };
 
-        Self { config, state }
+        Self { config, state, admin, events, validators, metrics, health, drain: DrainState::new() }
     }
 
-    /// Start the API server
+    /// Start the API server, serving until Ctrl+C or SIGTERM
+    ///
+    /// Returns once in-flight requests have finished, so the caller can clean
+    /// up the coordinator.
     pub async fn start(&self) -> Result<()> {
+        self.start_with_shutdown(shutdown_signal()).await
+    }
+
+    /// Start the API server, serving until `signal` resolves
+    ///
+    /// On shutdown the instance starts draining, WebSocket clients are sent a
+    /// close frame, and requests already in flight are allowed to finish.
+    pub async fn start_with_shutdown(&self, signal: impl Future<Output = ()> + Send + 'static) -> Result<()> {
         let app = self.create_app().await?;
         let addr = format!("{}:{}", self.config.host, self.config.port);
 
@@ -77,58 +190,169 @@ impl ApiServer {
                 message: format!("Failed to bind to {}: {}", addr, e),
             })?;
 
-        axum::serve(listener, app)
-            .await
-            .map_err(|e| ApiError::Internal {
-                message: format!("Server error: {}", e),
-            })?;
+        let drain = self.drain.clone();
+        let signal = async move {
+            signal.await;
+            drain.drain();
+        };
 
-        Ok(())
+        serve(listener, app, self.events.clone(), signal).await
     }
 
     /// Create the Axum application
     async fn create_app(&self) -> Result<Router> {
-        // Create CORS layer
-        let cors = CorsLayer::new()
-            .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
-            .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION])
-            .allow_origin(Any); // In production, use specific origins
+        let cors = cors_layer(&self.config.cors_origins)?;
 
         // Create middleware stack
         let middleware = ServiceBuilder::new()
+            .layer(middleware::request_id::RequestIdLayer::new())
             .layer(TraceLayer::new_for_http())
             .layer(cors)
-            .layer(middleware::request_id::RequestIdLayer::new())
-            .layer(middleware::auth::AuthLayer::new());
+            .layer(middleware::rate_limit::RateLimitLayer::new(
+                RateLimiter::new(self.config.rate_limit_per_minute, self.config.rate_limit_burst)
+                    .with_api_keys(self.config.api_keys.clone()),
+            ))
+            .layer(middleware::auth::AuthLayer::new(self.config.api_keys.clone()))
+            .layer(middleware::drain::DrainLayer::new(self.drain.clone()));
 
         // Create routes
         let api_routes = routes::create_api_routes();
         let websocket_routes = routes::create_websocket_routes();
 
-        // Combine all routes
-        let app = Router::new()
+        // Combine all routes, adding the metrics endpoint if enabled
+        let mut router = Router::new()
             .merge(api_routes)
-            .merge(websocket_routes)
-            .layer(middleware)
-            .layer(Extension(self.state.clone()));
-
-        // Add metrics endpoint if enabled
+            .merge(websocket_routes);
         if self.config.enable_metrics {
-            let metrics_routes = routes::create_metrics_routes();
-            return Ok(app.merge(metrics_routes));
+            router = router.merge(routes::create_metrics_routes());
         }
 
+        // Layer after merging so the metrics routes see the shared state too
+        let app = router
+            .layer(middleware)
+            .layer(Extension(self.state.clone()))
+            .layer(Extension(self.admin.clone()))
+            .layer(Extension(self.events.clone()))
+            .layer(Extension(self.validators.clone()))
+            .layer(Extension(self.metrics.clone()))
+            .layer(Extension(self.health.clone()))
+            .layer(Extension(self.drain.clone()));
+
         Ok(app)
     }
 }
 
+/// Serve `app` on `listener` until `signal` resolves
+///
+/// Open WebSocket streams of `events` are then closed, and the call returns
+/// once in-flight requests have finished.
+pub async fn serve(
+    listener: TcpListener,
+    app: Router,
+    events: EventStreamState,
+    signal: impl Future<Output = ()> + Send + 'static,
+) -> Result<()> {
+    let shutdown = async move {
+        signal.await;
+        info!("Shutting down API server, finishing in-flight requests");
+        events.close_all();
+    };
+
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+        .with_graceful_shutdown(shutdown)
+        .await
+        .map_err(|e| ApiError::Internal {
+            message: format!("Server error: {}", e),
+        })?;
+
+    info!("API server stopped");
+    Ok(())
+}
+
+/// Resolve on Ctrl+C or, on Unix, SIGTERM
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c()
+            .await
+            .expect("Failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("Failed to install signal handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {
+            info!("Received Ctrl+C signal");
+        },
+        _ = terminate => {
+            info!("Received terminate signal");
+        },
+    }
+}
+
+/// CORS layer allowing browser requests from `origins`
+///
+/// A matching `Origin` is echoed back in `Access-Control-Allow-Origin`; other
+/// origins get no CORS headers. With no origins configured any origin is
+/// allowed, which is only meant for local development.
+pub fn cors_layer(origins: &[String]) -> Result<CorsLayer> {
+    let allow_origin = if origins.is_empty() {
+        AllowOrigin::from(Any)
+    } else {
+        let origins = origins.iter().map(|origin| parse_origin(origin)).collect::<Result<Vec<_>>>()?;
+        AllowOrigin::list(origins)
+    };
+
+    Ok(CorsLayer::new()
+        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
+        .allow_headers([
+            header::CONTENT_TYPE,
+            header::AUTHORIZATION,
+            HeaderName::from_static(middleware::auth::API_KEY_HEADER),
+            HeaderName::from_static(middleware::request_id::REQUEST_ID_HEADER),
+        ])
+        .expose_headers([HeaderName::from_static(middleware::request_id::REQUEST_ID_HEADER)])
+        .allow_origin(allow_origin))
+}
+
+/// Parse a `scheme://host[:port]` origin as browsers send it in `Origin`
+fn parse_origin(origin: &str) -> Result<HeaderValue> {
+    let well_formed = origin.split_once("://").is_some_and(|(scheme, host)| {
+        !scheme.is_empty() && !host.is_empty() && !host.contains(['/', '?', '#'])
+    });
+    let value = well_formed.then(|| HeaderValue::from_str(origin).ok()).flatten();
+
+    value.ok_or_else(|| ApiError::Config {
+        message: format!("Invalid CORS origin '{}', expected scheme://host[:port]", origin),
+    })
+}
+
 /// Health check response
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct HealthResponse {
+    /// `healthy`, or `degraded` while a dependency is down or transfers lag
     pub status: String,
     pub version: String,
+    /// Seconds since the server started
     pub uptime: u64,
     pub bridge_stats: BridgeStatsResponse,
+    /// Dependencies that did not respond, such as `ethereum`
+    pub unavailable: Vec<String>,
+}
+
+/// Readiness check response
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ReadinessResponse {
+    /// `ready`, or `draining` once the instance is being drained
+    pub status: String,
 }
 
 /// Bridge statistics response
@@ -138,6 +362,28 @@ pub struct BridgeStatsResponse {
     pub polkadot_processed_txs: u64,
     pub pending_signatures: u64,
     pub active_validators: u64,
+    /// Transfer counts and volume keyed by lowercase token address, or by
+    /// asset ID for burns of unmapped assets
+    #[serde(default)]
+    pub tokens: HashMap<String, TokenStatsResponse>,
+    /// Mean seconds from detecting a transfer to completing it, over the
+    /// completion-time window; null when nothing completed in it
+    #[serde(default)]
+    pub average_completion_secs: Option<f64>,
+    /// 95th percentile completion time in seconds over the same window
+    #[serde(default)]
+    pub p95_completion_secs: Option<f64>,
+}
+
+/// Per-token statistics
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TokenStatsResponse {
+    /// Ethereum locks bridged into Polkadot
+    pub transfers_in: u64,
+    /// Polkadot burns bridged back to Ethereum
+    pub transfers_out: u64,
+    /// Total amount transferred in both directions, in base units
+    pub volume: String,
 }
 
 /// Transaction response
@@ -154,13 +400,104 @@ pub struct TransactionResponse {
 }
 
 /// Validator response
+///
+/// Stake and uptime are not tracked by the bridge, so they are `null` rather
+/// than guessed.
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct ValidatorResponse {
     pub id: String,
-    pub address: String,
+    /// Network address partials are exchanged on
+    pub address: Option<String>,
     pub active: bool,
-    pub stake: String,
-    pub uptime: f64,
+    pub stake: Option<String>,
+    /// Percentage of time the validator was reachable
+    pub uptime: Option<f64>,
+}
+
+impl From<ValidatorInfo> for ValidatorResponse {
+    fn from(validator: ValidatorInfo) -> Self {
+        Self {
+            id: validator.id,
+            address: validator.address,
+            active: validator.active,
+            stake: None,
+            uptime: None,
+        }
+    }
+}
+
+/// Ethereum addresses of the bridge's current signers
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ValidatorEthAddressesResponse {
+    pub addresses: Vec<String>,
+}
+
+/// A bridged token
+///
+/// The relayer keeps no token metadata, so `name`, `symbol` and `decimals`
+/// are `null`.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct TokenResponse {
+    pub ethereum_address: String,
+    pub polkadot_asset_id: u32,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+}
+
+impl From<TokenMapping> for TokenResponse {
+    fn from(mapping: TokenMapping) -> Self {
+        Self {
+            ethereum_address: mapping.ethereum_address,
+            polkadot_asset_id: mapping.polkadot_asset_id,
+            name: None,
+            symbol: None,
+            decimals: None,
+        }
+    }
+}
+
+/// Expected fee of completing a transfer on `chain`
+///
+/// Ethereum unlocks report their gas limit and price, and their fee in wei;
+/// Polkadot mints report the extrinsic's partial fee. Amounts are decimal
+/// strings, and fields that do not apply to the chain are omitted.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FeeEstimateResponse {
+    pub chain: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_fee_wei: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_limit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partial_fee: Option<String>,
+}
+
+impl FeeEstimateResponse {
+    /// Partial fee of a Polkadot mint
+    pub fn polkadot(partial_fee: u128) -> Self {
+        Self {
+            chain: "polkadot".to_string(),
+            estimated_fee_wei: None,
+            gas_limit: None,
+            gas_price: None,
+            partial_fee: Some(partial_fee.to_string()),
+        }
+    }
+}
+
+impl From<FeeEstimate> for FeeEstimateResponse {
+    fn from(estimate: FeeEstimate) -> Self {
+        Self {
+            chain: "ethereum".to_string(),
+            estimated_fee_wei: Some(estimate.fee_wei.to_string()),
+            gas_limit: Some(estimate.gas_limit.to_string()),
+            gas_price: Some(estimate.gas_price.to_string()),
+            partial_fee: None,
+        }
+    }
 }
 
 /// Bridge status response
@@ -170,11 +507,15 @@ pub struct BridgeStatusResponse {
     pub ethereum_block: u64,
     pub polkadot_block: u32,
     pub validators: Vec<ValidatorResponse>,
+    /// Signatures required for a transfer to proceed
+    pub threshold: u32,
     pub recent_transactions: Vec<TransactionResponse>,
 }
 
 /// Error response
-#[derive(serde::Serialize)]
+///
+/// Every error is reported in this shape, with `code` equal to the HTTP status.
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct ErrorResponse {
     pub error: String,
     pub message: String,
@@ -183,37 +524,26 @@ pub struct ErrorResponse {
 
 impl From<ApiError> for ErrorResponse {
     fn from(error: ApiError) -> Self {
-        match error {
-            ApiError::NotFound { resource } => ErrorResponse {
-                error: "Not Found".to_string(),
-                message: format!("Resource not found: {}", resource),
-                code: 404,
-            },
-            ApiError::Validation { message } => ErrorResponse {
-                error: "Validation Error".to_string(),
-                message,
-                code: 400,
-            },
-            ApiError::Internal { message } => ErrorResponse {
-                error: "Internal Server Error".to_string(),
-                message,
-                code: 500,
-            },
-            ApiError::Config { message } => ErrorResponse {
-                error: "Configuration Error".to_string(),
-                message,
-                code: 500,
-            },
-            ApiError::Relayer(e) => ErrorResponse {
-                error: "Relayer Error".to_string(),
-                message: e.to_string(),
-                code: 500,
-            },
-            ApiError::ThresholdSignature(e) => ErrorResponse {
-                error: "Threshold Signature Error".to_string(),
-                message: e.to_string(),
-                code: 500,
-            },
+        let code = error.status_code().as_u16();
+        let (error, message) = match error {
+            ApiError::NotFound { resource } => ("Not Found", format!("Resource not found: {}", resource)),
+            ApiError::Unauthorized { message } => ("Unauthorized", message),
+            ApiError::Validation { message } => ("Validation Error", message),
+            ApiError::Unavailable { message } => ("Service Unavailable", message),
+            ApiError::RateLimited { retry_after_secs } => (
+                "Too Many Requests",
+                format!("Rate limit exceeded, retry after {} seconds", retry_after_secs),
+            ),
+            ApiError::Internal { message } => ("Internal Server Error", message),
+            ApiError::Config { message } => ("Configuration Error", message),
+            ApiError::Relayer(e) => ("Relayer Error", e.to_string()),
+            ApiError::ThresholdSignature(e) => ("Threshold Signature Error", e.to_string()),
+        };
+
+        ErrorResponse {
+            error: error.to_string(),
+            message,
+            code,
         }
     }
 }
@@ -234,6 +564,36 @@ impl Default for PaginationParams {
     }
 }
 
+impl PaginationParams {
+    /// Maximum number of items per page
+    pub const MAX_LIMIT: u32 = 100;
+
+    /// Items per page, clamped to `1..=MAX_LIMIT`
+    pub fn limit(&self) -> u32 {
+        self.limit.unwrap_or(20).clamp(1, Self::MAX_LIMIT)
+    }
+
+    /// Requested page, counting from 1
+    pub fn page(&self) -> u32 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    /// Number of items to skip for the requested page
+    pub fn offset(&self) -> u32 {
+        self.page().saturating_sub(1).saturating_mul(self.limit())
+    }
+}
+
+/// One page of transactions
+#[derive(serde::Serialize)]
+pub struct TransactionPage {
+    pub transactions: Vec<TransactionResponse>,
+    /// Transactions matching the filters across all pages
+    pub total: u64,
+    pub page: u32,
+    pub limit: u32,
+}
+
 /// Filter parameters for transactions
 #[derive(serde::Deserialize)]
 pub struct TransactionFilters {
@@ -243,19 +603,243 @@ pub struct TransactionFilters {
     pub token: Option<String>,
     pub from_block: Option<u64>,
     pub to_block: Option<u64>,
+    /// RFC3339 lower bound on the recorded time
+    pub since: Option<String>,
+    /// RFC3339 upper bound on the recorded time
+    pub until: Option<String>,
+}
+
+impl Validate for TransactionFilters {
+    fn validate(&self) -> Result<()> {
+        for (name, value) in [
+            ("chain", &self.chain),
+            ("status", &self.status),
+            ("user", &self.user),
+            ("token", &self.token),
+            ("since", &self.since),
+            ("until", &self.until),
+        ] {
+            validation::check_length(name, value.as_deref())?;
+        }
+
+        let chain = self.chain.as_deref();
+        validation::check_one_of("chain", chain, &validation::CHAINS)?;
+        validation::check_one_of("status", self.status.as_deref(), &validation::STATUSES)?;
+        validation::check_format(
+            "user",
+            self.user.as_deref(),
+            chain,
+            validation::is_ethereum_address,
+            validation::is_ss58_address,
+        )?;
+        validation::check_format(
+            "token",
+            self.token.as_deref(),
+            chain,
+            validation::is_ethereum_address,
+            validation::is_asset_id,
+        )?;
+
+        if let (Some(from_block), Some(to_block)) = (self.from_block, self.to_block) {
+            if from_block > to_block {
+                return Err(ApiError::Validation {
+                    message: "`from_block` must not be greater than `to_block`".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Path of a single transaction
+#[derive(serde::Deserialize)]
+pub struct TransactionPath {
+    pub tx_hash: String,
+}
+
+impl Validate for TransactionPath {
+    fn validate(&self) -> Result<()> {
+        if validation::is_tx_hash(&self.tx_hash) {
+            Ok(())
+        } else {
+            Err(ApiError::Validation {
+                message: format!("Malformed transaction hash '{}'", self.tx_hash),
+            })
+        }
+    }
+}
+
+/// Path of a single token
+#[derive(serde::Deserialize)]
+pub struct TokenPath {
+    pub token_address: String,
+}
+
+impl Validate for TokenPath {
+    fn validate(&self) -> Result<()> {
+        if validation::is_ethereum_address(&self.token_address) {
+            Ok(())
+        } else {
+            Err(ApiError::Validation {
+                message: format!("Malformed token address '{}'", self.token_address),
+            })
+        }
+    }
+}
+
+/// Parameters for estimating a transfer's fee
+#[derive(serde::Deserialize)]
+pub struct FeeEstimateParams {
+    /// Chain the transfer completes on
+    pub chain: String,
+    /// Ethereum address of the bridged token
+    pub token: String,
+    /// Amount in the token's smallest unit
+    pub amount: String,
+}
+
+impl Validate for FeeEstimateParams {
+    fn validate(&self) -> Result<()> {
+        validation::check_length("amount", Some(&self.amount))?;
+        validation::check_one_of("chain", Some(&self.chain), &validation::CHAINS)?;
+
+        if !validation::is_ethereum_address(&self.token) {
+            return Err(ApiError::Validation {
+                message: format!("Malformed token address '{}'", self.token),
+            });
+        }
+
+        match self.amount.parse::<u128>() {
+            Ok(amount) if amount > 0 => Ok(()),
+            _ => Err(ApiError::Validation {
+                message: format!("`amount` must be a positive integer, got '{}'", self.amount),
+            }),
+        }
+    }
+}
+
+/// Parameters for exporting transactions
+#[derive(serde::Deserialize)]
+pub struct ExportParams {
+    /// Export format, currently only `csv`
+    pub format: Option<String>,
+}
+
+impl ExportParams {
+    /// Formats transactions can be exported in
+    pub const FORMATS: [&'static str; 1] = ["csv"];
+}
+
+impl Validate for ExportParams {
+    fn validate(&self) -> Result<()> {
+        validation::check_length("format", self.format.as_deref())?;
+        validation::check_one_of("format", self.format.as_deref(), &Self::FORMATS)
+    }
+}
+
+/// Filter parameters for events
+#[derive(serde::Deserialize)]
+pub struct EventFilters {
+    pub chain: Option<String>,
+    pub event_type: Option<String>,
+    /// RFC3339 lower bound on the recorded time
+    pub since: Option<String>,
+    /// RFC3339 upper bound on the recorded time
+    pub until: Option<String>,
+}
+
+impl EventFilters {
+    /// Event types recorded by the bridge
+    pub const EVENT_TYPES: [&'static str; 2] = ["bridge_lock", "bridge_burn"];
+}
+
+impl Validate for EventFilters {
+    fn validate(&self) -> Result<()> {
+        for (name, value) in [
+            ("chain", &self.chain),
+            ("event_type", &self.event_type),
+            ("since", &self.since),
+            ("until", &self.until),
+        ] {
+            validation::check_length(name, value.as_deref())?;
+        }
+
+        validation::check_one_of("chain", self.chain.as_deref(), &validation::CHAINS)?;
+        validation::check_one_of("event_type", self.event_type.as_deref(), &Self::EVENT_TYPES)
+    }
+}
+
+/// Bridge event response
+#[derive(serde::Serialize)]
+pub struct EventResponse {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub chain: String,
+    pub tx_hash: String,
+    pub block_number: u64,
+    pub timestamp: String,
+    pub data: serde_json::Value,
+}
+
+/// Optional `(since, until)` bounds on a record's timestamp
+pub type TimeWindow = (Option<DateTime<Utc>>, Option<DateTime<Utc>>);
+
+/// Parse optional RFC3339 `since`/`until` bounds into a time window
+pub fn parse_time_window(since: Option<&str>, until: Option<&str>) -> Result<TimeWindow> {
+    let parse = |name: &str, value: Option<&str>| -> Result<Option<DateTime<Utc>>> {
+        value
+            .map(|v| {
+                DateTime::parse_from_rfc3339(v)
+                    .map(|t| t.with_timezone(&Utc))
+                    .map_err(|e| ApiError::Validation {
+                        message: format!("Invalid `{}` timestamp '{}': {}", name, v, e),
+                    })
+            })
+            .transpose()
+    };
+
+    let since = parse("since", since)?;
+    let until = parse("until", until)?;
+
+    if let (Some(since), Some(until)) = (since, until) {
+        if since > until {
+            return Err(ApiError::Validation {
+                message: "`since` must not be later than `until`".to_string(),
+            });
+        }
+    }
+
+    Ok((since, until))
 }
 
 /// WebSocket message types
+///
+/// Messages about a transfer carry its `transfer_id`, the source chain
+/// transaction hash, so clients can follow one transfer from the
+/// `bridge_event` that announced it through its later updates.
 #[derive(serde::Serialize, serde::Deserialize)]
 #[serde(tag = "type")]
 pub enum WebSocketMessage {
     #[serde(rename = "bridge_event")]
     BridgeEvent {
+        transfer_id: String,
+        /// Lifecycle stage, as in [`TransactionResponse::status`]
+        status: String,
         event_type: String,
         data: serde_json::Value,
     },
+    /// A previously announced transfer moved to a new lifecycle stage
+    #[serde(rename = "transfer_update")]
+    TransferUpdate {
+        transfer_id: String,
+        status: String,
+    },
     #[serde(rename = "stats_update")]
     StatsUpdate {
+        /// Transfer whose progress triggered the update, if any
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        transfer_id: Option<String>,
         stats: BridgeStatsResponse,
     },
     #[serde(rename = "validator_update")]
@@ -271,3 +855,24 @@ pub enum WebSocketMessage {
     #[serde(rename = "pong")]
     Pong,
 }
+
+impl WebSocketMessage {
+    /// Update announcing `transaction`'s current lifecycle stage
+    pub fn transfer_update(transaction: &TransactionResponse) -> Self {
+        Self::TransferUpdate {
+            transfer_id: transaction.tx_hash.clone(),
+            status: transaction.status.clone(),
+        }
+    }
+
+    /// Transfer the message is about, if any
+    pub fn transfer_id(&self) -> Option<&str> {
+        match self {
+            Self::BridgeEvent { transfer_id, .. } | Self::TransferUpdate { transfer_id, .. } => {
+                Some(transfer_id)
+            }
+            Self::StatsUpdate { transfer_id, .. } => transfer_id.as_deref(),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Validation of query parameters before they reach the database
+
+use crate::error::{ApiError, Result};
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Path, Query},
+    http::request::Parts,
+};
+use serde::de::DeserializeOwned;
+
+/// Maximum length of any string query parameter
+pub const MAX_PARAM_LEN: usize = 128;
+
+/// Chains the bridge records transactions for
+pub const CHAINS: [&str; 2] = ["ethereum", "polkadot"];
+
+/// Transaction statuses reported by the API
+pub const STATUSES: [&str; 3] = ["pending", "confirmed", "skipped_stale"];
+
+/// Query parameters that can check themselves
+pub trait Validate {
+    /// Reject malformed or out-of-range values
+    fn validate(&self) -> Result<()>;
+}
+
+/// Query extractor that rejects invalid parameters with a 400 `Validation` error
+///
+/// Place it before any `Extension` arguments so bad requests fail before
+/// touching shared state.
+#[derive(Debug, Clone)]
+pub struct ValidatedQuery<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for ValidatedQuery<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
+        let Query(value) = Query::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(|e| ApiError::Validation {
+                message: e.body_text(),
+            })?;
+
+        value.validate()?;
+        Ok(Self(value))
+    }
+}
+
+/// Path extractor that rejects invalid parameters with a 400 `Validation` error
+///
+/// Like [`ValidatedQuery`], it belongs before any `Extension` arguments.
+#[derive(Debug, Clone)]
+pub struct ValidatedPath<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for ValidatedPath<T>
+where
+    T: DeserializeOwned + Validate + Send,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
+        let Path(value) = Path::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(|e| ApiError::Validation {
+                message: e.body_text(),
+            })?;
+
+        value.validate()?;
+        Ok(Self(value))
+    }
+}
+
+/// Reject a parameter longer than [`MAX_PARAM_LEN`]
+pub fn check_length(name: &str, value: Option<&str>) -> Result<()> {
+    match value {
+        Some(v) if v.len() > MAX_PARAM_LEN => Err(ApiError::Validation {
+            message: format!("`{}` must be at most {} characters", name, MAX_PARAM_LEN),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Require a parameter to be one of `allowed`
+pub fn check_one_of(name: &str, value: Option<&str>, allowed: &[&str]) -> Result<()> {
+    match value {
+        Some(v) if !allowed.contains(&v) => Err(ApiError::Validation {
+            message: format!("Unknown `{}` '{}', expected one of: {}", name, v, allowed.join(", ")),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Whether `value` is a `0x`-prefixed 20-byte hex address
+pub fn is_ethereum_address(value: &str) -> bool {
+    value
+        .strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Whether `value` is a `0x`-prefixed 32-byte hex transaction hash
+///
+/// Ethereum transactions and Polkadot extrinsics share this format.
+pub fn is_tx_hash(value: &str) -> bool {
+    value
+        .strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Whether `value` looks like an SS58-encoded Substrate account
+pub fn is_ss58_address(value: &str) -> bool {
+    const BASE58: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    (46..=48).contains(&value.len()) && value.chars().all(|c| BASE58.contains(c))
+}
+
+/// Whether `value` is a Polkadot asset ID
+pub fn is_asset_id(value: &str) -> bool {
+    value.parse::<u32>().is_ok()
+}
+
+/// Require `value` to match one of the formats valid on `chain`
+///
+/// Without a chain, a format valid on either chain is accepted.
+pub fn check_format(
+    name: &str,
+    value: Option<&str>,
+    chain: Option<&str>,
+    ethereum: fn(&str) -> bool,
+    polkadot: fn(&str) -> bool,
+) -> Result<()> {
+    let Some(v) = value else {
+        return Ok(());
+    };
+
+    let valid = match chain {
+        Some("ethereum") => ethereum(v),
+        Some("polkadot") => polkadot(v),
+        _ => ethereum(v) || polkadot(v),
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ApiError::Validation {
+            message: format!("Malformed `{}` '{}'", name, v),
+        })
+    }
+}
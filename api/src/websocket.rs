@@ -1,8 +1,189 @@
-//! WebSocket implementation
-
-// Placeholder for WebSocket functionality
-// In a full implementation, this would handle:
-// - WebSocket connection management
-// - Real-time event broadcasting
-// - Client subscription management
-// - Message routing and filtering
+//! Streaming of bridge events and statistics to WebSocket clients
+//!
+//! Every connection subscribes to the coordinator's broadcast of processed
+//! events and receives each one as a `bridge_event` message, along with a
+//! `stats_update` when it connects and periodically after that. Clients may
+//! send `{"type":"ping"}` at any time and are answered with `{"type":"pong"}`.
+//! A client that falls too far behind the broadcast is sent an `error`
+//! message saying how many events it missed, then continues from the newest.
+//! When the server shuts down, every client is sent a `going away` close frame.
+
+use crate::server::WebSocketMessage;
+use axum::{
+    async_trait,
+    extract::ws::{close_code, CloseFrame, Message, WebSocket},
+};
+use relayer::{
+    coordinator::{BridgeCoordinator, BridgeStats},
+    event_sink::{EventStatus, PublishedEvent},
+};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::time::MissedTickBehavior;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+/// How often connected clients are sent fresh statistics
+pub const STATS_UPDATE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Source of the statistics pushed to clients
+#[async_trait]
+pub trait StatsSource: Send + Sync {
+    /// Current bridge statistics
+    async fn bridge_stats(&self) -> relayer::Result<BridgeStats>;
+}
+
+#[async_trait]
+impl StatsSource for BridgeCoordinator {
+    async fn bridge_stats(&self) -> relayer::Result<BridgeStats> {
+        self.get_stats().await
+    }
+}
+
+/// State for the `/ws` endpoints
+#[derive(Clone)]
+pub struct EventStreamState {
+    /// Broadcast of processed events, subscribed to by each connection
+    pub events: broadcast::Sender<PublishedEvent>,
+    /// Statistics sent in `stats_update` messages
+    pub stats: Arc<dyn StatsSource>,
+    /// Time between `stats_update` messages
+    pub stats_interval: Duration,
+    /// Cancelled to close every open connection
+    pub closing: CancellationToken,
+}
+
+impl EventStreamState {
+    /// Stream `events`, with statistics from `stats` every [`STATS_UPDATE_INTERVAL`]
+    pub fn new(events: broadcast::Sender<PublishedEvent>, stats: Arc<dyn StatsSource>) -> Self {
+        Self {
+            events,
+            stats,
+            stats_interval: STATS_UPDATE_INTERVAL,
+            closing: CancellationToken::new(),
+        }
+    }
+
+    /// Send statistics every `interval` instead
+    pub fn with_stats_interval(mut self, interval: Duration) -> Self {
+        self.stats_interval = interval;
+        self
+    }
+
+    /// Close every open connection, as on server shutdown
+    pub fn close_all(&self) {
+        self.closing.cancel();
+    }
+}
+
+/// Messages a connection is sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Subscription {
+    pub events: bool,
+    pub stats: bool,
+}
+
+impl Subscription {
+    /// Bridge events and statistics
+    pub const ALL: Self = Self { events: true, stats: true };
+    /// Bridge events only
+    pub const EVENTS: Self = Self { events: true, stats: false };
+    /// Statistics only
+    pub const STATS: Self = Self { events: false, stats: true };
+}
+
+impl From<&PublishedEvent> for WebSocketMessage {
+    /// A processed event is announced as `pending` until its transfer
+    /// completes on the destination chain; a rejected one as `failed`
+    fn from(published: &PublishedEvent) -> Self {
+        let mut data = serde_json::to_value(&published.event).unwrap_or_default();
+        let event_type = data
+            .as_object_mut()
+            .and_then(|fields| fields.remove("type"))
+            .and_then(|event_type| event_type.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        let status = match published.status {
+            EventStatus::Processed => "pending",
+            EventStatus::Failed => "failed",
+        };
+
+        Self::BridgeEvent {
+            transfer_id: published.tx_hash.clone(),
+            status: status.to_string(),
+            event_type,
+            data,
+        }
+    }
+}
+
+/// Serve one connection until the client disconnects
+///
+/// `events` should be subscribed before the connection is upgraded, so the
+/// client sees every event published once its handshake completes.
+pub async fn stream(
+    mut socket: WebSocket,
+    state: EventStreamState,
+    subscription: Subscription,
+    mut events: broadcast::Receiver<PublishedEvent>,
+) {
+    let mut stats = tokio::time::interval(state.stats_interval);
+    stats.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    loop {
+        let reply = tokio::select! {
+            _ = state.closing.cancelled() => {
+                let _ = socket.send(Message::Close(Some(CloseFrame {
+                    code: close_code::AWAY,
+                    reason: "Server shutting down".into(),
+                }))).await;
+                break;
+            },
+            message = socket.recv() => match message {
+                None | Some(Err(_)) | Some(Ok(Message::Close(_))) => break,
+                Some(Ok(Message::Text(text))) => match serde_json::from_str(&text) {
+                    Ok(WebSocketMessage::Ping) => Some(WebSocketMessage::Pong),
+                    Ok(_) => None,
+                    Err(e) => Some(WebSocketMessage::Error {
+                        message: format!("Unrecognized message: {}", e),
+                    }),
+                },
+                // Protocol-level pings are answered by the socket itself
+                Some(Ok(_)) => None,
+            },
+            event = events.recv(), if subscription.events => match event {
+                Ok(event) => Some(WebSocketMessage::from(&event)),
+                Err(RecvError::Lagged(missed)) => Some(WebSocketMessage::Error {
+                    message: format!("Missed {} events", missed),
+                }),
+                Err(RecvError::Closed) => break,
+            },
+            _ = stats.tick(), if subscription.stats => match state.stats.bridge_stats().await {
+                Ok(stats) => Some(WebSocketMessage::StatsUpdate {
+                    transfer_id: None,
+                    stats: stats.into(),
+                }),
+                Err(e) => {
+                    warn!("Failed to load stats for WebSocket client: {}", e);
+                    None
+                }
+            },
+        };
+
+        if let Some(reply) = reply {
+            let text = match serde_json::to_string(&reply) {
+                Ok(text) => text,
+                Err(e) => {
+                    warn!("Failed to encode WebSocket message: {}", e);
+                    continue;
+                }
+            };
+            if socket.send(Message::Text(text)).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    debug!("WebSocket client disconnected");
+}
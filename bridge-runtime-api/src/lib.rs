@@ -0,0 +1,45 @@
+//! Types returned by the cross-chain bridge pallet's runtime API
+//!
+//! Shared by the pallet, which stores them, and off-chain clients such as the
+//! relayer, which decode runtime API responses into them. The crate depends
+//! only on SCALE codec so clients need not pull in the Substrate primitives.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use primitive_types::{H160, H256};
+use scale_info::TypeInfo;
+
+/// Information about a mint request
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub struct MintRequest<AccountId, AssetId, Balance> {
+    /// The account to mint tokens to
+    pub recipient: AccountId,
+    /// The asset to mint
+    pub asset_id: AssetId,
+    /// The amount to mint
+    pub amount: Balance,
+    /// The Ethereum transaction hash
+    pub ethereum_tx_hash: H256,
+    /// Block number when request was created
+    pub block_number: u32,
+    /// Whether the request has been processed
+    pub processed: bool,
+}
+
+/// Information about a burn request
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub struct BurnRequest<AccountId, AssetId, Balance> {
+    /// The account burning tokens
+    pub burner: AccountId,
+    /// The asset being burned
+    pub asset_id: AssetId,
+    /// The amount being burned
+    pub amount: Balance,
+    /// The Ethereum recipient address
+    pub ethereum_recipient: H160,
+    /// Block number when request was created
+    pub block_number: u32,
+    /// Whether the request has been processed
+    pub processed: bool,
+}
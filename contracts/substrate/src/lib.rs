@@ -17,6 +17,8 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+pub mod runtime_api;
 pub mod weights;
 pub use weights::*;
 
@@ -28,20 +30,22 @@ pub mod pallet {
         pallet_prelude::*,
         traits::{
             fungibles::{Create, Inspect, Mutate},
-            tokens::{Fortitude, Precision, Preservation},
+            tokens::{DepositConsequence, Fortitude, Precision, Preservation, Provenance},
         },
+        storage::with_storage_layer,
         PalletId,
     };
     use frame_system::pallet_prelude::*;
     use sp_core::{H160, H256};
+    use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
     use sp_runtime::{
         traits::{AccountIdConversion, Saturating, Zero},
-        ArithmeticError,
+        ArithmeticError, DispatchError,
     };
     use sp_std::{vec, vec::Vec};
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -67,6 +71,26 @@ pub mod pallet {
         /// Maximum length of signature data.
         #[pallet::constant]
         type MaxSignatureLength: Get<u32>;
+
+        /// Maximum number of tokens that can be registered in a single batch.
+        #[pallet::constant]
+        type MaxTokensPerBatch: Get<u32>;
+
+        /// Maximum number of mints that can be processed in a single batch.
+        #[pallet::constant]
+        type MaxMintsPerBatch: Get<u32>;
+
+        /// Length of the window token daily limits apply to, in blocks.
+        #[pallet::constant]
+        type BlocksPerDay: Get<BlockNumberFor<Self>>;
+
+        /// Smallest amount a single mint or burn may move, in base units.
+        #[pallet::constant]
+        type MinTransferAmount: Get<Self::Balance>;
+
+        /// Number of most recent mint, and separately burn, requests indexed per account.
+        #[pallet::constant]
+        type MaxRequestsPerAccount: Get<u32>;
     }
 
     /// Information about a bridged token
@@ -80,47 +104,31 @@ pub mod pallet {
         pub total_supply: Balance,
         /// Whether the token is active
         pub is_active: bool,
+        /// Most that may be minted, and separately burned, per day (zero for no limit)
+        pub daily_limit: Balance,
     }
 
-    /// Information about a mint request
+    /// Amounts of a token bridged during one day
     #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-    pub struct MintRequest<AccountId, AssetId, Balance> {
-        /// The account to mint tokens to
-        pub recipient: AccountId,
-        /// The asset to mint
-        pub asset_id: AssetId,
-        /// The amount to mint
-        pub amount: Balance,
-        /// The Ethereum transaction hash
-        pub ethereum_tx_hash: H256,
-        /// Block number when request was created
-        pub block_number: u32,
-        /// Whether the request has been processed
-        pub processed: bool,
+    pub struct DailyUsage<Balance> {
+        /// Day number, the block number divided by `BlocksPerDay`
+        pub day: u32,
+        /// Amount minted during the day
+        pub minted: Balance,
+        /// Amount burned during the day
+        pub burned: Balance,
     }
 
-    /// Information about a burn request
-    #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-    pub struct BurnRequest<AccountId, AssetId, Balance> {
-        /// The account burning tokens
-        pub burner: AccountId,
-        /// The asset being burned
-        pub asset_id: AssetId,
-        /// The amount being burned
-        pub amount: Balance,
-        /// The Ethereum recipient address
-        pub ethereum_recipient: H160,
-        /// Block number when request was created
-        pub block_number: u32,
-        /// Whether the request has been processed
-        pub processed: bool,
-    }
+    /// Information about mint and burn requests, shared with runtime API clients
+    pub use bridge_runtime_api::{BurnRequest, MintRequest};
 
     /// Validator information
     #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub struct ValidatorInfo<AccountId> {
         /// The validator's account ID
         pub account: AccountId,
+        /// Ethereum address the validator signs unlocks with
+        pub ethereum_address: H160,
         /// Whether the validator is active
         pub is_active: bool,
     }
@@ -170,6 +178,9 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn threshold)]
     /// Signature threshold for validator consensus
+    ///
+    /// Zero until `set_threshold` is first called, and no signatures are
+    /// accepted while it is.
     pub type Threshold<T: Config> = StorageValue<_, u32, ValueQuery>;
 
     #[pallet::storage]
@@ -205,6 +216,28 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn mint_requests_by_recipient)]
+    /// IDs of the most recent mint requests to each recipient, oldest first
+    pub type MintRequestsByRecipient<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<u64, T::MaxRequestsPerAccount>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn burn_requests_by_burner)]
+    /// IDs of the most recent burn requests by each burner, oldest first
+    pub type BurnRequestsByBurner<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<u64, T::MaxRequestsPerAccount>,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn next_mint_request_id)]
     /// Next mint request ID
@@ -215,6 +248,38 @@ pub mod pallet {
     /// Next burn request ID
     pub type NextBurnRequestId<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn allowed_recipients)]
+    /// Accounts mints may be sent to; every account is allowed while empty
+    pub type AllowedRecipients<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn allowed_recipient_count)]
+    /// Number of entries in `AllowedRecipients`
+    pub type AllowedRecipientCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn daily_usage)]
+    /// Amounts minted and burned per token on the most recent day it was bridged
+    pub type TokenDailyUsage<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        H160,
+        DailyUsage<T::Balance>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn paused)]
+    /// Whether mints and burns are halted
+    pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -246,6 +311,31 @@ pub mod pallet {
             old_threshold: u32,
             new_threshold: u32,
         },
+        /// A token batch registration was rolled back. [index, error]
+        TokenBatchInterrupted { index: u32, error: DispatchError },
+        /// An account was added to the mint recipient allowlist. [recipient]
+        RecipientAllowed { recipient: T::AccountId },
+        /// An account was removed from the mint recipient allowlist. [recipient]
+        RecipientDisallowed { recipient: T::AccountId },
+        /// Mints and burns were halted.
+        BridgePaused,
+        /// Mints and burns were resumed.
+        BridgeUnpaused,
+        /// A token's daily limit was changed. [ethereum_address, limit]
+        TokenLimitSet {
+            ethereum_address: H160,
+            limit: T::Balance,
+        },
+        /// A burn request was unlocked on Ethereum. [request_id, ethereum_tx_hash]
+        BurnRequestCompleted {
+            request_id: u64,
+            ethereum_tx_hash: H256,
+        },
+        /// A bridged token was retired. [ethereum_address, asset_id]
+        BridgedTokenDeregistered {
+            ethereum_address: H160,
+            asset_id: T::AssetId,
+        },
     }
 
     #[pallet::error]
@@ -280,6 +370,34 @@ pub mod pallet {
         CannotRemoveValidatorBelowThreshold,
         /// Invalid amount (zero)
         InvalidAmount,
+        /// Too many tokens in a single batch
+        TooManyTokens,
+        /// Too many mints in a single batch
+        TooManyMints,
+        /// Mint recipient is not on the allowlist
+        RecipientNotAllowed,
+        /// Recipient is already on the allowlist
+        RecipientAlreadyAllowed,
+        /// Recipient is not on the allowlist
+        RecipientNotFound,
+        /// Mint would leave a new recipient account below the asset's minimum balance
+        RecipientBelowMinBalance,
+        /// Recipient has no account to hold the asset and the asset cannot create one
+        RecipientCannotHoldAsset,
+        /// A signature was made by a key that is not an active validator's
+        UnknownSigner,
+        /// Two signatures were made by the same validator
+        DuplicateSigner,
+        /// The bridge is paused
+        BridgePausedError,
+        /// The transfer would exceed the token's daily limit
+        DailyLimitExceeded,
+        /// Token was deregistered and can no longer be bridged
+        TokenInactive,
+        /// The amount is below `MinTransferAmount`
+        AmountBelowMinimum,
+        /// More signatures than there can be validators
+        TooManySignatures,
     }
 
     #[pallet::call]
@@ -297,81 +415,460 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_root(origin)?;
 
-            ensure!(
-                !BridgedTokens::<T>::contains_key(&ethereum_address),
-                Error::<T>::TokenAlreadyRegistered
-            );
+            Self::do_register_token(ethereum_address, asset_id, name, symbol, decimals)
+        }
 
-            // Create the asset
-            let pallet_account = Self::account_id();
-            pallet_assets::Pallet::<T>::create(
-                T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(pallet_account.clone())),
-                asset_id.clone().into(),
-                pallet_account.into(),
-                1u32.into(), // min_balance
-            )
-            .map_err(|_| Error::<T>::AssetCreationFailed)?;
+        /// Mint tokens based on Ethereum lock transaction
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::mint_tokens(signatures.len() as u32))]
+        pub fn mint_tokens(
+            origin: OriginFor<T>,
+            recipient: T::AccountId,
+            ethereum_address: H160,
+            amount: T::Balance,
+            ethereum_tx_hash: H256,
+            signatures: Vec<Vec<u8>>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
 
-            // Set asset metadata
-            pallet_assets::Pallet::<T>::set_metadata(
-                T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(Self::account_id())),
+            Self::do_mint(recipient, ethereum_address, amount, ethereum_tx_hash, signatures)
+        }
+
+        /// Burn tokens to unlock on Ethereum
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::burn_tokens())]
+        pub fn burn_tokens(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            amount: T::Balance,
+            ethereum_recipient: H160,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!Paused::<T>::get(), Error::<T>::BridgePausedError);
+
+            // Get Ethereum address for this asset
+            let ethereum_address = AssetToEthereum::<T>::get(&asset_id)
+                .ok_or(Error::<T>::TokenNotRegistered)?;
+
+            let mut bridged_token = BridgedTokens::<T>::get(&ethereum_address)
+                .ok_or(Error::<T>::TokenNotRegistered)?;
+
+            ensure!(bridged_token.is_active, Error::<T>::TokenInactive);
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            ensure!(amount >= T::MinTransferAmount::get(), Error::<T>::AmountBelowMinimum);
+
+            Self::record_daily_usage(&bridged_token, amount, |usage| &mut usage.burned)?;
+
+            // Burn tokens from user
+            pallet_assets::Pallet::<T>::burn(
+                T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(who.clone())),
                 asset_id.clone().into(),
-                name,
-                symbol,
-                decimals,
+                who.clone().into(),
+                amount,
             )
-            .map_err(|_| Error::<T>::AssetCreationFailed)?;
+            .map_err(|_| Error::<T>::InsufficientBalance)?;
 
-            let bridged_token = BridgedToken {
+            // Update total supply
+            bridged_token.total_supply = bridged_token.total_supply.saturating_sub(amount);
+            BridgedTokens::<T>::insert(&ethereum_address, &bridged_token);
+
+            // Create burn request record
+            let request_id = NextBurnRequestId::<T>::get();
+            let burn_request = BurnRequest {
+                burner: who.clone(),
                 asset_id: asset_id.clone(),
-                ethereum_address,
-                total_supply: Zero::zero(),
-                is_active: true,
+                amount,
+                ethereum_recipient,
+                block_number: frame_system::Pallet::<T>::block_number().saturated_into(),
+                processed: false,
             };
 
-            BridgedTokens::<T>::insert(&ethereum_address, &bridged_token);
-            AssetToEthereum::<T>::insert(&asset_id, &ethereum_address);
+            BurnRequests::<T>::insert(&request_id, &burn_request);
+            NextBurnRequestId::<T>::put(request_id.saturating_add(1));
+            BurnRequestsByBurner::<T>::mutate(&who, |ids| Self::index_request(ids, request_id));
 
-            Self::deposit_event(Event::BridgedTokenRegistered {
-                ethereum_address,
+            Self::deposit_event(Event::TokensBurned {
+                burner: who,
                 asset_id,
+                amount,
+                ethereum_recipient,
             });
 
             Ok(())
         }
 
-        /// Mint tokens based on Ethereum lock transaction
-        #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::mint_tokens())]
-        pub fn mint_tokens(
+        /// Register several tokens for bridging in one call
+        ///
+        /// Registration is all-or-nothing: if any token fails, every registration
+        /// in the batch is rolled back and `TokenBatchInterrupted` reports the
+        /// index of the first failing entry.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::register_tokens_batch(tokens.len() as u32))]
+        pub fn register_tokens_batch(
+            origin: OriginFor<T>,
+            tokens: Vec<(H160, T::AssetId, Vec<u8>, Vec<u8>, u8)>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ensure!(
+                tokens.len() as u32 <= T::MaxTokensPerBatch::get(),
+                Error::<T>::TooManyTokens
+            );
+
+            let mut failed_index = 0u32;
+            let result = with_storage_layer(|| -> DispatchResult {
+                for (index, (ethereum_address, asset_id, name, symbol, decimals)) in
+                    tokens.into_iter().enumerate()
+                {
+                    failed_index = index as u32;
+                    Self::do_register_token(ethereum_address, asset_id, name, symbol, decimals)?;
+                }
+                Ok(())
+            });
+
+            if let Err(error) = result {
+                Self::deposit_event(Event::TokenBatchInterrupted {
+                    index: failed_index,
+                    error,
+                });
+                return Ok(Some(T::WeightInfo::register_tokens_batch(failed_index + 1)).into());
+            }
+
+            Ok(().into())
+        }
+
+        /// Allow mints to an account
+        ///
+        /// The allowlist is opt-in: while it is empty, any account may receive
+        /// mints.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::add_allowed_recipient())]
+        pub fn add_allowed_recipient(
             origin: OriginFor<T>,
             recipient: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                !AllowedRecipients::<T>::contains_key(&recipient),
+                Error::<T>::RecipientAlreadyAllowed
+            );
+
+            AllowedRecipients::<T>::insert(&recipient, ());
+            AllowedRecipientCount::<T>::mutate(|count| *count = count.saturating_add(1));
+
+            Self::deposit_event(Event::RecipientAllowed { recipient });
+            Ok(())
+        }
+
+        /// Stop allowing mints to an account
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::remove_allowed_recipient())]
+        pub fn remove_allowed_recipient(
+            origin: OriginFor<T>,
+            recipient: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                AllowedRecipients::<T>::contains_key(&recipient),
+                Error::<T>::RecipientNotFound
+            );
+
+            AllowedRecipients::<T>::remove(&recipient);
+            AllowedRecipientCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+            Self::deposit_event(Event::RecipientDisallowed { recipient });
+            Ok(())
+        }
+
+        /// Mint tokens for several Ethereum lock transactions in one call
+        ///
+        /// Each mint is validated independently against its own signatures and
+        /// emits its own `TokensMinted` event. The batch is atomic: if any mint
+        /// is invalid, none of them are applied and the call fails with that
+        /// mint's error.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::mint_tokens_batch(
+            mints.len() as u32,
+            mints.iter().fold(0u32, |total, mint| total.saturating_add(mint.4.len() as u32)),
+        ))]
+        pub fn mint_tokens_batch(
+            origin: OriginFor<T>,
+            mints: Vec<(T::AccountId, H160, T::Balance, H256, Vec<Vec<u8>>)>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            ensure!(
+                mints.len() as u32 <= T::MaxMintsPerBatch::get(),
+                Error::<T>::TooManyMints
+            );
+
+            with_storage_layer(|| {
+                for (recipient, ethereum_address, amount, ethereum_tx_hash, signatures) in mints {
+                    Self::do_mint(recipient, ethereum_address, amount, ethereum_tx_hash, signatures)?;
+                }
+                Ok(())
+            })
+        }
+
+        /// Add a bridge validator signing with `ethereum_address`
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::add_validator())]
+        pub fn add_validator(
+            origin: OriginFor<T>,
+            validator: T::AccountId,
             ethereum_address: H160,
-            amount: T::Balance,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                !Validators::<T>::contains_key(&validator),
+                Error::<T>::ValidatorAlreadyExists
+            );
+
+            ValidatorList::<T>::try_mutate(|list| list.try_push(validator.clone()))
+                .map_err(|_| Error::<T>::TooManyValidators)?;
+            Validators::<T>::insert(
+                &validator,
+                ValidatorInfo {
+                    account: validator.clone(),
+                    ethereum_address,
+                    is_active: true,
+                },
+            );
+
+            Self::deposit_event(Event::ValidatorAdded { validator });
+            Ok(())
+        }
+
+        /// Remove a bridge validator
+        ///
+        /// Fails if fewer validators than the signature threshold would remain.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::remove_validator())]
+        pub fn remove_validator(
+            origin: OriginFor<T>,
+            validator: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                Validators::<T>::contains_key(&validator),
+                Error::<T>::ValidatorNotFound
+            );
+
+            let mut list = ValidatorList::<T>::get();
+            ensure!(
+                list.len().saturating_sub(1) >= Threshold::<T>::get() as usize,
+                Error::<T>::CannotRemoveValidatorBelowThreshold
+            );
+            list.retain(|account| account != &validator);
+
+            ValidatorList::<T>::put(list);
+            Validators::<T>::remove(&validator);
+
+            Self::deposit_event(Event::ValidatorRemoved { validator });
+            Ok(())
+        }
+
+        /// Set how many validator signatures a mint requires
+        ///
+        /// The threshold must be non-zero and no larger than the validator
+        /// set, so consensus always stays reachable.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::update_threshold())]
+        pub fn set_threshold(
+            origin: OriginFor<T>,
+            new_threshold: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                new_threshold > 0
+                    && new_threshold as usize <= ValidatorList::<T>::get().len(),
+                Error::<T>::InvalidThreshold
+            );
+
+            let old_threshold = Threshold::<T>::get();
+            Threshold::<T>::put(new_threshold);
+
+            Self::deposit_event(Event::ThresholdUpdated {
+                old_threshold,
+                new_threshold,
+            });
+            Ok(())
+        }
+
+        /// Halt all mints and burns
+        ///
+        /// Emergency switch for when a validator key or the Ethereum side is
+        /// compromised. Registration and validator management stay available.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::pause())]
+        pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+
+            Paused::<T>::put(true);
+
+            Self::deposit_event(Event::BridgePaused);
+            Ok(())
+        }
+
+        /// Resume mints and burns after a pause
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::unpause())]
+        pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+
+            Paused::<T>::put(false);
+
+            Self::deposit_event(Event::BridgeUnpaused);
+            Ok(())
+        }
+
+        /// Cap how much of a token may be minted, and separately burned, per day
+        ///
+        /// A limit of zero removes the cap. Amounts already bridged today
+        /// count towards a newly set limit.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::set_token_limit())]
+        pub fn set_token_limit(
+            origin: OriginFor<T>,
+            ethereum_address: H160,
+            limit: T::Balance,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            BridgedTokens::<T>::try_mutate(&ethereum_address, |token| -> DispatchResult {
+                let token = token.as_mut().ok_or(Error::<T>::TokenNotRegistered)?;
+                token.daily_limit = limit;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::TokenLimitSet {
+                ethereum_address,
+                limit,
+            });
+            Ok(())
+        }
+
+        /// Mark a burn request as unlocked on Ethereum
+        ///
+        /// `signatures` are the validators' approvals of
+        /// [`Pallet::burn_completion_message`] for the request and the
+        /// Ethereum unlock transaction.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::complete_burn_request(signatures.len() as u32))]
+        pub fn complete_burn_request(
+            origin: OriginFor<T>,
+            request_id: u64,
             ethereum_tx_hash: H256,
             signatures: Vec<Vec<u8>>,
         ) -> DispatchResult {
             ensure_signed(origin)?;
 
+            let mut request = BurnRequests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+            ensure!(!request.processed, Error::<T>::RequestAlreadyProcessed);
+
+            let message = Self::burn_completion_message(request_id, &request, &ethereum_tx_hash);
+            Self::verify_signatures(&message, &signatures)?;
+
+            request.processed = true;
+            BurnRequests::<T>::insert(request_id, &request);
+
+            Self::deposit_event(Event::BurnRequestCompleted {
+                request_id,
+                ethereum_tx_hash,
+            });
+            Ok(())
+        }
+
+        /// Retire a bridged token, rejecting further mints and burns of it
+        ///
+        /// The token's record and asset mapping are kept for audit, so its
+        /// Ethereum address cannot be registered again.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::deregister_token())]
+        pub fn deregister_token(origin: OriginFor<T>, ethereum_address: H160) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let asset_id = BridgedTokens::<T>::try_mutate(&ethereum_address, |token| {
+                let token = token.as_mut().ok_or(Error::<T>::TokenNotRegistered)?;
+                ensure!(token.is_active, Error::<T>::TokenInactive);
+                token.is_active = false;
+                Ok::<_, DispatchError>(token.asset_id.clone())
+            })?;
+
+            Self::deposit_event(Event::BridgedTokenDeregistered {
+                ethereum_address,
+                asset_id,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Ethereum addresses of the active validators, in the order they were added
+        ///
+        /// Served by the `CrossChainBridgeApi` runtime API so the Ethereum
+        /// contract and dashboards can see the current signer set.
+        pub fn validator_eth_addresses() -> Vec<H160> {
+            ValidatorList::<T>::get()
+                .iter()
+                .filter_map(Validators::<T>::get)
+                .filter(|info| info.is_active)
+                .map(|info| info.ethereum_address)
+                .collect()
+        }
+
+        /// Whether mints may be sent to `recipient`
+        pub fn is_recipient_allowed(recipient: &T::AccountId) -> bool {
+            AllowedRecipientCount::<T>::get() == 0
+                || AllowedRecipients::<T>::contains_key(recipient)
+        }
+
+        /// Get the account ID of the pallet
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Validate a mint against its signatures and mint to the recipient
+        fn do_mint(
+            recipient: T::AccountId,
+            ethereum_address: H160,
+            amount: T::Balance,
+            ethereum_tx_hash: H256,
+            signatures: Vec<Vec<u8>>,
+        ) -> DispatchResult {
+            ensure!(!Paused::<T>::get(), Error::<T>::BridgePausedError);
+
             // Check if transaction already processed
             ensure!(
                 !ProcessedEthereumTxs::<T>::get(&ethereum_tx_hash),
                 Error::<T>::TransactionAlreadyProcessed
             );
 
+            ensure!(
+                Self::is_recipient_allowed(&recipient),
+                Error::<T>::RecipientNotAllowed
+            );
+
             // Get bridged token info
             let mut bridged_token = BridgedTokens::<T>::get(&ethereum_address)
                 .ok_or(Error::<T>::TokenNotRegistered)?;
 
-            ensure!(bridged_token.is_active, Error::<T>::TokenNotRegistered);
+            ensure!(bridged_token.is_active, Error::<T>::TokenInactive);
             ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            ensure!(amount >= T::MinTransferAmount::get(), Error::<T>::AmountBelowMinimum);
 
-            // Verify signatures (simplified for now)
-            let threshold = Threshold::<T>::get();
-            ensure!(
-                signatures.len() >= threshold as usize,
-                Error::<T>::InsufficientSignatures
-            );
+            let message = Self::mint_message(&recipient, &ethereum_address, &amount, &ethereum_tx_hash);
+            Self::verify_signatures(&message, &signatures)?;
+
+            Self::ensure_can_receive(&recipient, bridged_token.asset_id.clone(), amount)?;
+
+            Self::record_daily_usage(&bridged_token, amount, |usage| &mut usage.minted)?;
 
             // Mark transaction as processed
             ProcessedEthereumTxs::<T>::insert(&ethereum_tx_hash, true);
@@ -403,6 +900,7 @@ pub mod pallet {
 
             MintRequests::<T>::insert(&request_id, &mint_request);
             NextMintRequestId::<T>::put(request_id.saturating_add(1));
+            MintRequestsByRecipient::<T>::mutate(&recipient, |ids| Self::index_request(ids, request_id));
 
             Self::deposit_event(Event::TokensMinted {
                 recipient,
@@ -414,69 +912,243 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Burn tokens to unlock on Ethereum
-        #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::burn_tokens())]
-        pub fn burn_tokens(
-            origin: OriginFor<T>,
+        /// Digest validators sign to approve a mint
+        ///
+        /// The Keccak-256 hash of the SCALE-encoded `(b"mint", recipient,
+        /// ethereum_address, amount, ethereum_tx_hash)`.
+        pub fn mint_message(
+            recipient: &T::AccountId,
+            ethereum_address: &H160,
+            amount: &T::Balance,
+            ethereum_tx_hash: &H256,
+        ) -> [u8; 32] {
+            keccak_256(&(b"mint", recipient, ethereum_address, amount, ethereum_tx_hash).encode())
+        }
+
+        /// Burn requests not yet completed, in the order they were made
+        ///
+        /// Served by the `BridgeApi` runtime API so relayers can find the
+        /// burns still to unlock on Ethereum without scanning storage.
+        pub fn pending_burn_requests() -> Vec<(u64, BurnRequest<T::AccountId, T::AssetId, T::Balance>)> {
+            let mut pending: Vec<_> = BurnRequests::<T>::iter()
+                .filter(|(_, request)| !request.processed)
+                .collect();
+            pending.sort_by_key(|(id, _)| *id);
+            pending
+        }
+
+        /// The most recent mint requests to `account`, oldest first
+        ///
+        /// Served by the `BridgeApi` runtime API so wallets can show a user's
+        /// transfers without scanning every request. Only the last
+        /// `MaxRequestsPerAccount` are kept.
+        pub fn mints_for(account: &T::AccountId) -> Vec<(u64, MintRequest<T::AccountId, T::AssetId, T::Balance>)> {
+            MintRequestsByRecipient::<T>::get(account)
+                .into_iter()
+                .filter_map(|id| MintRequests::<T>::get(id).map(|request| (id, request)))
+                .collect()
+        }
+
+        /// The most recent burn requests by `account`, oldest first
+        ///
+        /// Like [`Self::mints_for`], only the last `MaxRequestsPerAccount` are kept.
+        pub fn burns_for(account: &T::AccountId) -> Vec<(u64, BurnRequest<T::AccountId, T::AssetId, T::Balance>)> {
+            BurnRequestsByBurner::<T>::get(account)
+                .into_iter()
+                .filter_map(|id| BurnRequests::<T>::get(id).map(|request| (id, request)))
+                .collect()
+        }
+
+        /// Append `request_id` to an account's index, dropping its oldest entry when full
+        fn index_request(ids: &mut BoundedVec<u64, T::MaxRequestsPerAccount>, request_id: u64) {
+            if ids.is_full() && !ids.is_empty() {
+                ids.remove(0);
+            }
+            // Only fails when `MaxRequestsPerAccount` is zero, which disables the index
+            let _ = ids.try_push(request_id);
+        }
+
+        /// Digest validators sign to approve completing a burn request
+        ///
+        /// The Keccak-256 hash of the SCALE-encoded `(b"complete_burn",
+        /// request_id, burner, asset_id, amount, ethereum_recipient,
+        /// ethereum_tx_hash)`.
+        pub fn burn_completion_message(
+            request_id: u64,
+            request: &BurnRequest<T::AccountId, T::AssetId, T::Balance>,
+            ethereum_tx_hash: &H256,
+        ) -> [u8; 32] {
+            keccak_256(
+                &(
+                    b"complete_burn",
+                    request_id,
+                    &request.burner,
+                    &request.asset_id,
+                    &request.amount,
+                    &request.ethereum_recipient,
+                    ethereum_tx_hash,
+                )
+                    .encode(),
+            )
+        }
+
+        /// Check that `signatures` over `message` come from at least
+        /// `Threshold` distinct active validators
+        ///
+        /// Every signature must recover to an active validator's Ethereum
+        /// address, and each validator may sign only once. A threshold of zero
+        /// means none was ever set, so nothing is approved until one is.
+        fn verify_signatures(message: &[u8; 32], signatures: &[Vec<u8>]) -> DispatchResult {
+            let threshold = Threshold::<T>::get();
+            ensure!(threshold > 0, Error::<T>::InsufficientSignatures);
+            ensure!(
+                signatures.len() <= T::MaxValidators::get() as usize,
+                Error::<T>::TooManySignatures
+            );
+
+            let mut signers: Vec<T::AccountId> = Vec::with_capacity(signatures.len());
+
+            for signature in signatures {
+                let signature: [u8; 65] = signature
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| Error::<T>::InvalidSignature)?;
+                let public = secp256k1_ecdsa_recover(&signature, message)
+                    .map_err(|_| Error::<T>::InvalidSignature)?;
+                let address = H160::from_slice(&keccak_256(&public)[12..]);
+
+                let signer = Self::validator_by_eth_address(&address)
+                    .ok_or(Error::<T>::UnknownSigner)?;
+                ensure!(!signers.contains(&signer), Error::<T>::DuplicateSigner);
+                signers.push(signer);
+            }
+
+            ensure!(
+                signers.len() >= threshold as usize,
+                Error::<T>::InsufficientSignatures
+            );
+            Ok(())
+        }
+
+        /// Active validator signing with the Ethereum address `address`
+        fn validator_by_eth_address(address: &H160) -> Option<T::AccountId> {
+            ValidatorList::<T>::get()
+                .iter()
+                .filter_map(Validators::<T>::get)
+                .find(|info| info.is_active && &info.ethereum_address == address)
+                .map(|info| info.account)
+        }
+
+        /// Check that `recipient` can be credited `amount` of `asset_id`
+        ///
+        /// `pallet_assets` rejects deposits that would create an account below
+        /// the asset's minimum balance, or for an account with no provider.
+        /// Reporting these as specific errors spares operators from guessing
+        /// why a mint failed.
+        fn ensure_can_receive(
+            recipient: &T::AccountId,
             asset_id: T::AssetId,
             amount: T::Balance,
-            ethereum_recipient: H160,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+            match <pallet_assets::Pallet<T> as Inspect<T::AccountId>>::can_deposit(
+                asset_id,
+                recipient,
+                amount,
+                Provenance::Minted,
+            ) {
+                DepositConsequence::Success => Ok(()),
+                DepositConsequence::BelowMinimum => Err(Error::<T>::RecipientBelowMinBalance.into()),
+                DepositConsequence::CannotCreate => Err(Error::<T>::RecipientCannotHoldAsset.into()),
+                other => other.into_result(),
+            }
+        }
 
-            // Get Ethereum address for this asset
-            let ethereum_address = AssetToEthereum::<T>::get(&asset_id)
-                .ok_or(Error::<T>::TokenNotRegistered)?;
+        /// Day number of the current block
+        fn current_day() -> u32 {
+            let now: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            let blocks_per_day: u32 = T::BlocksPerDay::get().saturated_into();
+            now / blocks_per_day.max(1)
+        }
 
-            let mut bridged_token = BridgedTokens::<T>::get(&ethereum_address)
-                .ok_or(Error::<T>::TokenNotRegistered)?;
+        /// Add `amount` to the total `field` selects from the token's usage
+        /// today, failing if that takes it past the token's daily limit
+        ///
+        /// Usage from an earlier day is discarded, so each day starts from zero.
+        fn record_daily_usage(
+            token: &BridgedToken<T::AssetId, T::Balance>,
+            amount: T::Balance,
+            field: impl FnOnce(&mut DailyUsage<T::Balance>) -> &mut T::Balance,
+        ) -> DispatchResult {
+            let day = Self::current_day();
+            let mut usage = TokenDailyUsage::<T>::get(&token.ethereum_address)
+                .filter(|usage| usage.day == day)
+                .unwrap_or(DailyUsage {
+                    day,
+                    minted: Zero::zero(),
+                    burned: Zero::zero(),
+                });
 
-            ensure!(bridged_token.is_active, Error::<T>::TokenNotRegistered);
-            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            let total = field(&mut usage);
+            *total = total.saturating_add(amount);
+            ensure!(
+                token.daily_limit.is_zero() || *total <= token.daily_limit,
+                Error::<T>::DailyLimitExceeded
+            );
 
-            // Burn tokens from user
-            pallet_assets::Pallet::<T>::burn(
-                T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(who.clone())),
+            TokenDailyUsage::<T>::insert(&token.ethereum_address, usage);
+            Ok(())
+        }
+
+        /// Create the wrapped asset and record the token mapping
+        fn do_register_token(
+            ethereum_address: H160,
+            asset_id: T::AssetId,
+            name: Vec<u8>,
+            symbol: Vec<u8>,
+            decimals: u8,
+        ) -> DispatchResult {
+            ensure!(
+                !BridgedTokens::<T>::contains_key(&ethereum_address),
+                Error::<T>::TokenAlreadyRegistered
+            );
+
+            // Create the asset
+            let pallet_account = Self::account_id();
+            pallet_assets::Pallet::<T>::create(
+                T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(pallet_account.clone())),
                 asset_id.clone().into(),
-                who.clone().into(),
-                amount,
+                pallet_account.into(),
+                1u32.into(), // min_balance
             )
-            .map_err(|_| Error::<T>::InsufficientBalance)?;
+            .map_err(|_| Error::<T>::AssetCreationFailed)?;
 
-            // Update total supply
-            bridged_token.total_supply = bridged_token.total_supply.saturating_sub(amount);
-            BridgedTokens::<T>::insert(&ethereum_address, &bridged_token);
+            // Set asset metadata
+            pallet_assets::Pallet::<T>::set_metadata(
+                T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(Self::account_id())),
+                asset_id.clone().into(),
+                name,
+                symbol,
+                decimals,
+            )
+            .map_err(|_| Error::<T>::AssetCreationFailed)?;
 
-            // Create burn request record
-            let request_id = NextBurnRequestId::<T>::get();
-            let burn_request = BurnRequest {
-                burner: who.clone(),
+            let bridged_token = BridgedToken {
                 asset_id: asset_id.clone(),
-                amount,
-                ethereum_recipient,
-                block_number: frame_system::Pallet::<T>::block_number().saturated_into(),
-                processed: false,
+                ethereum_address,
+                total_supply: Zero::zero(),
+                is_active: true,
+                daily_limit: Zero::zero(),
             };
 
-            BurnRequests::<T>::insert(&request_id, &burn_request);
-            NextBurnRequestId::<T>::put(request_id.saturating_add(1));
+            BridgedTokens::<T>::insert(&ethereum_address, &bridged_token);
+            AssetToEthereum::<T>::insert(&asset_id, &ethereum_address);
 
-            Self::deposit_event(Event::TokensBurned {
-                burner: who,
+            Self::deposit_event(Event::BridgedTokenRegistered {
+                ethereum_address,
                 asset_id,
-                amount,
-                ethereum_recipient,
             });
 
             Ok(())
         }
     }
-
-    impl<T: Config> Pallet<T> {
-        /// Get the account ID of the pallet
-        pub fn account_id() -> T::AccountId {
-            T::PalletId::get().into_account_truncating()
-        }
-    }
 }
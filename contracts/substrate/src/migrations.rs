@@ -0,0 +1,75 @@
+//! Storage migrations for the cross-chain bridge pallet
+
+use crate::pallet::{BridgedToken, BridgedTokens, Config, Pallet};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_core::H160;
+use sp_runtime::traits::Zero;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Version 2: bridged tokens gain a daily limit
+pub mod v2 {
+    use super::*;
+
+    /// `BridgedToken` as stored before version 2
+    #[derive(Decode)]
+    struct OldBridgedToken<AssetId, Balance> {
+        asset_id: AssetId,
+        ethereum_address: H160,
+        total_supply: Balance,
+        is_active: bool,
+    }
+
+    /// Give every registered token no daily limit, keeping its behaviour
+    pub struct AddDailyLimit<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for AddDailyLimit<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 1 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut translated = 0u64;
+            BridgedTokens::<T>::translate::<OldBridgedToken<T::AssetId, T::Balance>, _>(|_, old| {
+                translated += 1;
+                Some(BridgedToken {
+                    asset_id: old.asset_id,
+                    ethereum_address: old.ethereum_address,
+                    total_supply: old.total_supply,
+                    is_active: old.is_active,
+                    daily_limit: Zero::zero(),
+                })
+            });
+
+            StorageVersion::new(2).put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+        }
+
+        /// Count the stored tokens, so `post_upgrade` can check none were dropped
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let tokens = BridgedTokens::<T>::iter_keys().count() as u32;
+            Ok(tokens.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let before = u32::decode(&mut state.as_slice())
+                .map_err(|_| TryRuntimeError::Other("invalid pre_upgrade state"))?;
+            // Counting by value decodes every entry as the new struct
+            let after = BridgedTokens::<T>::iter_values().count() as u32;
+
+            ensure!(before == after, "bridged token count changed during migration");
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "storage version not bumped to 2"
+            );
+            Ok(())
+        }
+    }
+}
@@ -2,12 +2,12 @@
 
 use crate as pallet_cross_chain_bridge;
 use frame_support::{
-    construct_runtime, parameter_types,
+    assert_ok, construct_runtime, parameter_types,
     traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64},
     PalletId,
 };
 use frame_system as system;
-use sp_core::{H256, H160};
+use sp_core::{ecdsa, Pair, H256, H160};
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
@@ -113,6 +113,11 @@ parameter_types! {
     pub const BridgePalletId: PalletId = PalletId(*b"py/bridg");
     pub const MaxValidators: u32 = 100;
     pub const MaxSignatureLength: u32 = 65;
+    pub const MaxTokensPerBatch: u32 = 10;
+    pub const MaxMintsPerBatch: u32 = 5;
+    pub const BlocksPerDay: u64 = 100;
+    pub static MinTransferAmount: u128 = 1;
+    pub const MaxRequestsPerAccount: u32 = 3;
 }
 
 impl pallet_cross_chain_bridge::Config for Test {
@@ -121,6 +126,11 @@ impl pallet_cross_chain_bridge::Config for Test {
     type PalletId = BridgePalletId;
     type MaxValidators = MaxValidators;
     type MaxSignatureLength = MaxSignatureLength;
+    type MaxTokensPerBatch = MaxTokensPerBatch;
+    type MaxMintsPerBatch = MaxMintsPerBatch;
+    type BlocksPerDay = BlocksPerDay;
+    type MinTransferAmount = MinTransferAmount;
+    type MaxRequestsPerAccount = MaxRequestsPerAccount;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -168,3 +178,52 @@ pub fn token_symbol() -> Vec<u8> {
 pub fn token_decimals() -> u8 {
     18
 }
+
+/// Signing key of the mock validator `index`
+pub fn validator_pair(index: u8) -> ecdsa::Pair {
+    ecdsa::Pair::from_seed(&[index + 1; 32])
+}
+
+/// Account of the mock validator `index`
+pub fn validator_account(index: u8) -> u64 {
+    100 + index as u64
+}
+
+/// Ethereum address a key signs as
+pub fn eth_address(pair: &ecdsa::Pair) -> H160 {
+    let message = [0u8; 32];
+    let signature = pair.sign_prehashed(&message);
+    let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &message).unwrap();
+    H160::from_slice(&sp_io::hashing::keccak_256(&public)[12..])
+}
+
+/// Register mock validators `0..count` and require `threshold` of them to sign
+pub fn setup_validators(count: u8, threshold: u32) {
+    for index in 0..count {
+        assert_ok!(CrossChainBridge::add_validator(
+            RuntimeOrigin::root(),
+            validator_account(index),
+            eth_address(&validator_pair(index)),
+        ));
+    }
+    assert_ok!(CrossChainBridge::set_threshold(RuntimeOrigin::root(), threshold));
+}
+
+/// Signatures of the validators `signers` over a mint of the test token
+pub fn sign_mint(recipient: u64, amount: u128, ethereum_tx_hash: H256, signers: &[u8]) -> Vec<Vec<u8>> {
+    let message = CrossChainBridge::mint_message(&recipient, &ethereum_address(), &amount, &ethereum_tx_hash);
+    signers
+        .iter()
+        .map(|index| validator_pair(*index).sign_prehashed(&message).0.to_vec())
+        .collect()
+}
+
+/// Signatures of the validators `signers` over completing burn request `request_id`
+pub fn sign_burn_completion(request_id: u64, ethereum_tx_hash: H256, signers: &[u8]) -> Vec<Vec<u8>> {
+    let request = CrossChainBridge::burn_requests(request_id).expect("burn request exists");
+    let message = CrossChainBridge::burn_completion_message(request_id, &request, &ethereum_tx_hash);
+    signers
+        .iter()
+        .map(|index| validator_pair(*index).sign_prehashed(&message).0.to_vec())
+        .collect()
+}
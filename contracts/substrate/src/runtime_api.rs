@@ -0,0 +1,45 @@
+//! Runtime API exposing bridge pallet state to off-chain clients
+
+use bridge_runtime_api::{BurnRequest, MintRequest};
+use codec::Codec;
+use sp_core::H160;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Queries for the relayer, the Ethereum contract and dashboards
+    ///
+    /// Runtimes implement it by delegating to the pallet, e.g.
+    /// `CrossChainBridge::validator_eth_addresses()`.
+    pub trait CrossChainBridgeApi {
+        /// Ethereum addresses of the active validators, in the order they were added
+        fn validator_eth_addresses() -> Vec<H160>;
+    }
+
+    /// Mint and burn request queries for relayers and wallets
+    ///
+    /// Runtimes implement it with `CrossChainBridge::pending_burn_requests()`,
+    /// `CrossChainBridge::burn_requests(id)`, `CrossChainBridge::mints_for(&account)`
+    /// and `CrossChainBridge::burns_for(&account)`. Responses decode into the
+    /// types of the `bridge-runtime-api` crate.
+    #[api_version(2)]
+    pub trait BridgeApi<AccountId, AssetId, Balance>
+    where
+        AccountId: Codec,
+        AssetId: Codec,
+        Balance: Codec,
+    {
+        /// Burn requests not yet completed, in the order they were made
+        fn pending_burn_requests() -> Vec<(u64, BurnRequest<AccountId, AssetId, Balance>)>;
+
+        /// The burn request `id`, processed or not
+        fn burn_request(id: u64) -> Option<BurnRequest<AccountId, AssetId, Balance>>;
+
+        /// The most recent mint requests to `account`, oldest first
+        #[api_version(2)]
+        fn mints_for(account: AccountId) -> Vec<(u64, MintRequest<AccountId, AssetId, Balance>)>;
+
+        /// The most recent burn requests by `account`, oldest first
+        #[api_version(2)]
+        fn burns_for(account: AccountId) -> Vec<(u64, BurnRequest<AccountId, AssetId, Balance>)>;
+    }
+}
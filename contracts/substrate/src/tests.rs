@@ -102,10 +102,9 @@ fn mint_tokens_works() {
 
         let recipient = 1u64;
         let amount = 1000u128;
-        let signatures = vec![vec![1u8; 65], vec![2u8; 65]]; // Mock signatures
-
-        // Set threshold to 2
-        crate::Threshold::<Test>::put(2u32);
+        // Two of three validators sign the mint
+        setup_validators(3, 2);
+        let signatures = sign_mint(recipient, amount, ethereum_tx_hash(), &[0, 1]);
 
         // Mint tokens
         assert_ok!(CrossChainBridge::mint_tokens(
@@ -180,10 +179,8 @@ fn mint_tokens_fails_for_processed_transaction() {
 
         let recipient = 1u64;
         let amount = 1000u128;
-        let signatures = vec![vec![1u8; 65], vec![2u8; 65]];
-
-        // Set threshold
-        crate::Threshold::<Test>::put(2u32);
+        setup_validators(3, 2);
+        let signatures = sign_mint(recipient, amount, ethereum_tx_hash(), &[0, 1]);
 
         // Mint tokens first time
         assert_ok!(CrossChainBridge::mint_tokens(
@@ -225,10 +222,8 @@ fn mint_tokens_fails_with_insufficient_signatures() {
 
         let recipient = 1u64;
         let amount = 1000u128;
-        let signatures = vec![vec![1u8; 65]]; // Only one signature
-
-        // Set threshold to 2
-        crate::Threshold::<Test>::put(2u32);
+        setup_validators(3, 2);
+        let signatures = sign_mint(recipient, amount, ethereum_tx_hash(), &[0]); // Only one signature
 
         // Try to mint tokens with insufficient signatures
         assert_noop!(
@@ -245,6 +240,187 @@ fn mint_tokens_fails_with_insufficient_signatures() {
     });
 }
 
+#[test]
+fn mint_tokens_rejects_forged_signatures() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        setup_validators(3, 2);
+
+        let recipient = 1u64;
+        let amount = 1000u128;
+
+        // Bytes that recover to no key at all
+        let mut signatures = sign_mint(recipient, amount, ethereum_tx_hash(), &[0]);
+        signatures.push(vec![0u8; 65]);
+        assert_noop!(
+            CrossChainBridge::mint_tokens(
+                RuntimeOrigin::signed(1),
+                recipient,
+                ethereum_address(),
+                amount,
+                ethereum_tx_hash(),
+                signatures,
+            ),
+            Error::<Test>::InvalidSignature
+        );
+
+        // Validator signatures over a smaller amount do not approve a larger one
+        assert_noop!(
+            CrossChainBridge::mint_tokens(
+                RuntimeOrigin::signed(1),
+                recipient,
+                ethereum_address(),
+                amount * 1000,
+                ethereum_tx_hash(),
+                sign_mint(recipient, amount, ethereum_tx_hash(), &[0, 1]),
+            ),
+            Error::<Test>::UnknownSigner
+        );
+    });
+}
+
+#[test]
+fn mint_tokens_rejects_duplicate_signer() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        setup_validators(3, 2);
+
+        // One validator's signature twice does not make two signers
+        assert_noop!(
+            CrossChainBridge::mint_tokens(
+                RuntimeOrigin::signed(1),
+                1u64,
+                ethereum_address(),
+                1000u128,
+                ethereum_tx_hash(),
+                sign_mint(1u64, 1000u128, ethereum_tx_hash(), &[0, 0]),
+            ),
+            Error::<Test>::DuplicateSigner
+        );
+    });
+}
+
+#[test]
+fn mint_tokens_rejects_non_validator_signer() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+
+        // Key 3 is never registered, and a removed validator no longer counts
+        setup_validators(3, 1);
+        assert_ok!(CrossChainBridge::remove_validator(RuntimeOrigin::root(), validator_account(2)));
+
+        for signer in [3u8, 2] {
+            assert_noop!(
+                CrossChainBridge::mint_tokens(
+                    RuntimeOrigin::signed(1),
+                    1u64,
+                    ethereum_address(),
+                    1000u128,
+                    ethereum_tx_hash(),
+                    sign_mint(1u64, 1000u128, ethereum_tx_hash(), &[0, signer]),
+                ),
+                Error::<Test>::UnknownSigner
+            );
+        }
+    });
+}
+
+#[test]
+fn mint_tokens_rejected_until_threshold_is_set() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+
+        // With no threshold configured, not even zero signatures suffice
+        assert_eq!(CrossChainBridge::threshold(), 0);
+        assert_noop!(
+            CrossChainBridge::mint_tokens(
+                RuntimeOrigin::signed(1),
+                1u64,
+                ethereum_address(),
+                1000u128,
+                ethereum_tx_hash(),
+                vec![],
+            ),
+            Error::<Test>::InsufficientSignatures
+        );
+    });
+}
+
+#[test]
+fn mint_tokens_weight_grows_with_signatures() {
+    use frame_support::dispatch::GetDispatchInfo;
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        setup_validators(3, 2);
+
+        let weight = |signatures: Vec<Vec<u8>>| {
+            crate::Call::<Test>::mint_tokens {
+                recipient: 1u64,
+                ethereum_address: ethereum_address(),
+                amount: 1000u128,
+                ethereum_tx_hash: ethereum_tx_hash(),
+                signatures,
+            }
+            .get_dispatch_info()
+            .weight
+        };
+        let two = sign_mint(1u64, 1000u128, ethereum_tx_hash(), &[0, 1]);
+        let three = sign_mint(1u64, 1000u128, ethereum_tx_hash(), &[0, 1, 2]);
+        assert!(weight(three).ref_time() > weight(two).ref_time());
+
+        // Signatures beyond what the validator set could produce are refused
+        // before any is recovered
+        let max_validators = MaxValidators::get() as usize;
+        assert_noop!(
+            CrossChainBridge::mint_tokens(
+                RuntimeOrigin::signed(1),
+                1u64,
+                ethereum_address(),
+                1000u128,
+                ethereum_tx_hash(),
+                vec![vec![0u8; 65]; max_validators + 1],
+            ),
+            Error::<Test>::TooManySignatures
+        );
+    });
+}
+
 #[test]
 fn burn_tokens_works() {
     new_test_ext().execute_with(|| {
@@ -260,10 +436,8 @@ fn burn_tokens_works() {
 
         let user = 1u64;
         let amount = 1000u128;
-        let signatures = vec![vec![1u8; 65], vec![2u8; 65]];
-
-        // Set threshold
-        crate::Threshold::<Test>::put(2u32);
+        setup_validators(3, 2);
+        let signatures = sign_mint(user, amount, ethereum_tx_hash(), &[0, 1]);
 
         // Mint tokens first
         assert_ok!(CrossChainBridge::mint_tokens(
@@ -357,3 +531,1137 @@ fn burn_tokens_fails_with_zero_amount() {
         );
     });
 }
+
+#[test]
+fn register_tokens_batch_works() {
+    new_test_ext().execute_with(|| {
+        let tokens: Vec<_> = (1u8..=3)
+            .map(|i| {
+                (
+                    H160::from_slice(&[i; 20]),
+                    i as u32,
+                    token_name(),
+                    token_symbol(),
+                    token_decimals(),
+                )
+            })
+            .collect();
+
+        assert_ok!(CrossChainBridge::register_tokens_batch(
+            RuntimeOrigin::root(),
+            tokens,
+        ));
+
+        // Every token should be registered with its reverse mapping
+        for i in 1u8..=3 {
+            let ethereum_address = H160::from_slice(&[i; 20]);
+            let bridged_token = CrossChainBridge::bridged_tokens(ethereum_address).unwrap();
+            assert_eq!(bridged_token.asset_id, i as u32);
+            assert!(bridged_token.is_active);
+            assert_eq!(
+                CrossChainBridge::asset_to_ethereum(i as u32),
+                Some(ethereum_address)
+            );
+
+            System::assert_has_event(
+                Event::BridgedTokenRegistered {
+                    ethereum_address,
+                    asset_id: i as u32,
+                }
+                .into(),
+            );
+        }
+    });
+}
+
+#[test]
+fn register_tokens_batch_is_all_or_nothing() {
+    new_test_ext().execute_with(|| {
+        // The second entry reuses the first entry's Ethereum address
+        let tokens = vec![
+            (ethereum_address(), 1u32, token_name(), token_symbol(), token_decimals()),
+            (ethereum_address(), 2u32, token_name(), token_symbol(), token_decimals()),
+            (H160::from_slice(&[3u8; 20]), 3u32, token_name(), token_symbol(), token_decimals()),
+        ];
+
+        assert_ok!(CrossChainBridge::register_tokens_batch(
+            RuntimeOrigin::root(),
+            tokens,
+        ));
+
+        // Nothing from the batch should have been kept
+        assert!(CrossChainBridge::bridged_tokens(ethereum_address()).is_none());
+        assert!(CrossChainBridge::bridged_tokens(H160::from_slice(&[3u8; 20])).is_none());
+        assert_eq!(CrossChainBridge::asset_to_ethereum(1u32), None);
+
+        // The failing index is reported
+        System::assert_last_event(
+            Event::TokenBatchInterrupted {
+                index: 1,
+                error: Error::<Test>::TokenAlreadyRegistered.into(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn register_tokens_batch_fails_when_too_large() {
+    new_test_ext().execute_with(|| {
+        let tokens: Vec<_> = (1u8..=11)
+            .map(|i| {
+                (
+                    H160::from_slice(&[i; 20]),
+                    i as u32,
+                    token_name(),
+                    token_symbol(),
+                    token_decimals(),
+                )
+            })
+            .collect();
+
+        assert_noop!(
+            CrossChainBridge::register_tokens_batch(RuntimeOrigin::root(), tokens),
+            Error::<Test>::TooManyTokens
+        );
+    });
+}
+
+#[test]
+fn mint_tokens_allows_any_recipient_when_allowlist_empty() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        setup_validators(1, 1);
+
+        assert_eq!(CrossChainBridge::allowed_recipient_count(), 0);
+        assert_ok!(CrossChainBridge::mint_tokens(
+            RuntimeOrigin::signed(1),
+            3u64,
+            ethereum_address(),
+            1000u128,
+            ethereum_tx_hash(),
+            sign_mint(3u64, 1000u128, ethereum_tx_hash(), &[0]),
+        ));
+        assert_eq!(Assets::balance(asset_id(), &3u64), 1000u128);
+    });
+}
+
+#[test]
+fn mint_tokens_enforces_recipient_allowlist() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        setup_validators(1, 1);
+
+        assert_ok!(CrossChainBridge::add_allowed_recipient(RuntimeOrigin::root(), 2u64));
+        System::assert_last_event(Event::RecipientAllowed { recipient: 2u64 }.into());
+
+        // A recipient off the list is rejected
+        assert_noop!(
+            CrossChainBridge::mint_tokens(
+                RuntimeOrigin::signed(1),
+                3u64,
+                ethereum_address(),
+                1000u128,
+                ethereum_tx_hash(),
+                sign_mint(3u64, 1000u128, ethereum_tx_hash(), &[0]),
+            ),
+            Error::<Test>::RecipientNotAllowed
+        );
+
+        // An allowlisted recipient is minted to
+        assert_ok!(CrossChainBridge::mint_tokens(
+            RuntimeOrigin::signed(1),
+            2u64,
+            ethereum_address(),
+            1000u128,
+            ethereum_tx_hash(),
+            sign_mint(2u64, 1000u128, ethereum_tx_hash(), &[0]),
+        ));
+        assert_eq!(Assets::balance(asset_id(), &2u64), 1000u128);
+
+        // Removing the last entry makes the allowlist open again
+        assert_ok!(CrossChainBridge::remove_allowed_recipient(RuntimeOrigin::root(), 2u64));
+        assert!(CrossChainBridge::is_recipient_allowed(&3u64));
+    });
+}
+
+#[test]
+fn allowlist_management_requires_root() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CrossChainBridge::add_allowed_recipient(RuntimeOrigin::signed(1), 2u64),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CrossChainBridge::remove_allowed_recipient(RuntimeOrigin::root(), 2u64),
+            Error::<Test>::RecipientNotFound
+        );
+    });
+}
+
+#[test]
+fn mint_tokens_batch_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        setup_validators(3, 2);
+
+        let mints = vec![
+            (2u64, ethereum_address(), 1000u128, H256::from_slice(&[1u8; 32]),
+                sign_mint(2u64, 1000u128, H256::from_slice(&[1u8; 32]), &[0, 1])),
+            (3u64, ethereum_address(), 500u128, H256::from_slice(&[2u8; 32]),
+                sign_mint(3u64, 500u128, H256::from_slice(&[2u8; 32]), &[1, 2])),
+        ];
+
+        assert_ok!(CrossChainBridge::mint_tokens_batch(RuntimeOrigin::signed(1), mints));
+
+        assert_eq!(Assets::balance(asset_id(), &2u64), 1000u128);
+        assert_eq!(Assets::balance(asset_id(), &3u64), 500u128);
+        assert!(CrossChainBridge::processed_ethereum_txs(H256::from_slice(&[1u8; 32])));
+        assert!(CrossChainBridge::processed_ethereum_txs(H256::from_slice(&[2u8; 32])));
+        assert_eq!(CrossChainBridge::bridged_tokens(ethereum_address()).unwrap().total_supply, 1500u128);
+        assert_eq!(CrossChainBridge::next_mint_request_id(), 2);
+
+        // Every mint in the batch emits its own event
+        System::assert_has_event(
+            Event::TokensMinted {
+                recipient: 2u64,
+                asset_id: asset_id(),
+                amount: 1000u128,
+                ethereum_tx_hash: H256::from_slice(&[1u8; 32]),
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::TokensMinted {
+                recipient: 3u64,
+                asset_id: asset_id(),
+                amount: 500u128,
+                ethereum_tx_hash: H256::from_slice(&[2u8; 32]),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn mint_tokens_batch_is_atomic() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        setup_validators(3, 2);
+
+        // The second mint carries too few signatures
+        let mints = vec![
+            (2u64, ethereum_address(), 1000u128, H256::from_slice(&[1u8; 32]),
+                sign_mint(2u64, 1000u128, H256::from_slice(&[1u8; 32]), &[0, 1])),
+            (3u64, ethereum_address(), 500u128, H256::from_slice(&[2u8; 32]),
+                sign_mint(3u64, 500u128, H256::from_slice(&[2u8; 32]), &[0])),
+        ];
+
+        assert_noop!(
+            CrossChainBridge::mint_tokens_batch(RuntimeOrigin::signed(1), mints),
+            Error::<Test>::InsufficientSignatures
+        );
+
+        // The valid first mint was rolled back too
+        assert_eq!(Assets::balance(asset_id(), &2u64), 0u128);
+        assert!(!CrossChainBridge::processed_ethereum_txs(H256::from_slice(&[1u8; 32])));
+    });
+}
+
+#[test]
+fn mint_tokens_batch_fails_when_too_large() {
+    new_test_ext().execute_with(|| {
+        let mints: Vec<_> = (1u8..=6)
+            .map(|i| (2u64, ethereum_address(), 1000u128, H256::from_slice(&[i; 32]), vec![vec![1u8; 65]]))
+            .collect();
+
+        assert_noop!(
+            CrossChainBridge::mint_tokens_batch(RuntimeOrigin::signed(1), mints),
+            Error::<Test>::TooManyMints
+        );
+    });
+}
+
+#[test]
+fn mint_tokens_reports_recipient_below_min_balance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        setup_validators(1, 1);
+
+        // Raise the wrapped asset's minimum balance to 100
+        let pallet_account = CrossChainBridge::account_id();
+        assert_ok!(Assets::force_asset_status(
+            RuntimeOrigin::root(),
+            asset_id().into(),
+            pallet_account,
+            pallet_account,
+            pallet_account,
+            pallet_account,
+            100u128,
+            false,
+            false,
+        ));
+
+        // Account 2 holds none of the asset yet, so 99 would leave it below the minimum
+        assert_noop!(
+            CrossChainBridge::mint_tokens(
+                RuntimeOrigin::signed(1),
+                2u64,
+                ethereum_address(),
+                99u128,
+                H256::from_slice(&[1u8; 32]),
+                sign_mint(2u64, 99u128, H256::from_slice(&[1u8; 32]), &[0]),
+            ),
+            Error::<Test>::RecipientBelowMinBalance
+        );
+
+        // Exactly the minimum creates the asset account
+        assert_ok!(CrossChainBridge::mint_tokens(
+            RuntimeOrigin::signed(1),
+            2u64,
+            ethereum_address(),
+            100u128,
+            H256::from_slice(&[2u8; 32]),
+            sign_mint(2u64, 100u128, H256::from_slice(&[2u8; 32]), &[0]),
+        ));
+        assert_eq!(Assets::balance(asset_id(), &2u64), 100u128);
+
+        // Once the account exists, smaller mints are fine
+        assert_ok!(CrossChainBridge::mint_tokens(
+            RuntimeOrigin::signed(1),
+            2u64,
+            ethereum_address(),
+            1u128,
+            H256::from_slice(&[3u8; 32]),
+            sign_mint(2u64, 1u128, H256::from_slice(&[3u8; 32]), &[0]),
+        ));
+
+        // An account with no native balance cannot hold a non-sufficient asset
+        assert_noop!(
+            CrossChainBridge::mint_tokens(
+                RuntimeOrigin::signed(1),
+                99u64,
+                ethereum_address(),
+                1000u128,
+                H256::from_slice(&[4u8; 32]),
+                sign_mint(99u64, 1000u128, H256::from_slice(&[4u8; 32]), &[0]),
+            ),
+            Error::<Test>::RecipientCannotHoldAsset
+        );
+    });
+}
+
+#[test]
+fn adding_validators_updates_eth_addresses() {
+    new_test_ext().execute_with(|| {
+        assert!(CrossChainBridge::validator_eth_addresses().is_empty());
+
+        let first = H160::from_slice(&[0xaa; 20]);
+        let second = H160::from_slice(&[0xbb; 20]);
+
+        assert_ok!(CrossChainBridge::add_validator(RuntimeOrigin::root(), 1u64, first));
+        System::assert_last_event(Event::ValidatorAdded { validator: 1u64 }.into());
+        assert_eq!(CrossChainBridge::validator_eth_addresses(), vec![first]);
+
+        assert_ok!(CrossChainBridge::add_validator(RuntimeOrigin::root(), 2u64, second));
+        assert_eq!(CrossChainBridge::validator_eth_addresses(), vec![first, second]);
+
+        // A validator can only be added once
+        assert_noop!(
+            CrossChainBridge::add_validator(RuntimeOrigin::root(), 1u64, second),
+            Error::<Test>::ValidatorAlreadyExists
+        );
+
+        // Removing a validator drops its address from the set
+        assert_ok!(CrossChainBridge::remove_validator(RuntimeOrigin::root(), 1u64));
+        assert_eq!(CrossChainBridge::validator_eth_addresses(), vec![second]);
+    });
+}
+
+#[test]
+fn validator_management_requires_root() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CrossChainBridge::add_validator(RuntimeOrigin::signed(1), 1u64, H160::from_slice(&[0xaa; 20])),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CrossChainBridge::remove_validator(RuntimeOrigin::signed(1), 1u64),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn removing_validator_preserves_list_order() {
+    new_test_ext().execute_with(|| {
+        for account in 1u64..=4 {
+            assert_ok!(CrossChainBridge::add_validator(
+                RuntimeOrigin::root(),
+                account,
+                H160::from_low_u64_be(account),
+            ));
+        }
+
+        assert_ok!(CrossChainBridge::remove_validator(RuntimeOrigin::root(), 2u64));
+        System::assert_last_event(Event::ValidatorRemoved { validator: 2u64 }.into());
+
+        // The remaining validators keep the order they were added in
+        assert_eq!(CrossChainBridge::validator_list().into_inner(), vec![1u64, 3, 4]);
+        assert!(CrossChainBridge::validators(2u64).is_none());
+        assert_eq!(
+            CrossChainBridge::validator_eth_addresses(),
+            vec![H160::from_low_u64_be(1), H160::from_low_u64_be(3), H160::from_low_u64_be(4)]
+        );
+    });
+}
+
+#[test]
+fn add_validator_fails_when_list_is_full() {
+    new_test_ext().execute_with(|| {
+        let max = MaxValidators::get() as u64;
+        for account in 0..max {
+            assert_ok!(CrossChainBridge::add_validator(
+                RuntimeOrigin::root(),
+                account,
+                H160::from_low_u64_be(account),
+            ));
+        }
+
+        assert_noop!(
+            CrossChainBridge::add_validator(RuntimeOrigin::root(), max, H160::from_low_u64_be(max)),
+            Error::<Test>::TooManyValidators
+        );
+        assert_eq!(CrossChainBridge::validator_list().len() as u64, max);
+    });
+}
+
+#[test]
+fn remove_validator_fails_for_unknown_validator() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CrossChainBridge::remove_validator(RuntimeOrigin::root(), 1u64),
+            Error::<Test>::ValidatorNotFound
+        );
+    });
+}
+
+#[test]
+fn remove_validator_fails_below_threshold() {
+    new_test_ext().execute_with(|| {
+        // Two validators with a threshold of two leave no room to remove either
+        setup_validators(2, 2);
+
+        assert_noop!(
+            CrossChainBridge::remove_validator(RuntimeOrigin::root(), validator_account(0)),
+            Error::<Test>::CannotRemoveValidatorBelowThreshold
+        );
+
+        // A third validator makes one removable
+        assert_ok!(CrossChainBridge::add_validator(
+            RuntimeOrigin::root(),
+            validator_account(2),
+            eth_address(&validator_pair(2)),
+        ));
+        assert_ok!(CrossChainBridge::remove_validator(RuntimeOrigin::root(), validator_account(0)));
+        assert_eq!(
+            CrossChainBridge::validator_list().into_inner(),
+            vec![validator_account(1), validator_account(2)]
+        );
+    });
+}
+
+#[test]
+fn set_threshold_rejects_zero_and_unreachable_values() {
+    new_test_ext().execute_with(|| {
+        setup_validators(3, 2);
+
+        assert_noop!(
+            CrossChainBridge::set_threshold(RuntimeOrigin::root(), 0),
+            Error::<Test>::InvalidThreshold
+        );
+        // Four signatures can never be collected from three validators
+        assert_noop!(
+            CrossChainBridge::set_threshold(RuntimeOrigin::root(), 4),
+            Error::<Test>::InvalidThreshold
+        );
+        assert_noop!(
+            CrossChainBridge::set_threshold(RuntimeOrigin::signed(1), 3),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_eq!(CrossChainBridge::threshold(), 2);
+    });
+}
+
+#[test]
+fn set_threshold_is_enforced_by_mint_tokens() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        setup_validators(3, 2);
+
+        assert_ok!(CrossChainBridge::set_threshold(RuntimeOrigin::root(), 3));
+        System::assert_last_event(
+            Event::ThresholdUpdated {
+                old_threshold: 2,
+                new_threshold: 3,
+            }
+            .into(),
+        );
+
+        // Two signatures met the old threshold but not the new one
+        assert_noop!(
+            CrossChainBridge::mint_tokens(
+                RuntimeOrigin::signed(1),
+                1u64,
+                ethereum_address(),
+                1000u128,
+                ethereum_tx_hash(),
+                sign_mint(1u64, 1000u128, ethereum_tx_hash(), &[0, 1]),
+            ),
+            Error::<Test>::InsufficientSignatures
+        );
+
+        assert_ok!(CrossChainBridge::mint_tokens(
+            RuntimeOrigin::signed(1),
+            1u64,
+            ethereum_address(),
+            1000u128,
+            ethereum_tx_hash(),
+            sign_mint(1u64, 1000u128, ethereum_tx_hash(), &[0, 1, 2]),
+        ));
+        assert_eq!(Assets::balance(asset_id(), &1u64), 1000u128);
+    });
+}
+
+#[test]
+fn pause_and_unpause_require_root() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CrossChainBridge::pause(RuntimeOrigin::signed(1)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CrossChainBridge::unpause(RuntimeOrigin::signed(1)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert!(!CrossChainBridge::paused());
+    });
+}
+
+#[test]
+fn paused_bridge_rejects_mint_and_burn_until_unpaused() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        setup_validators(1, 1);
+
+        assert_ok!(CrossChainBridge::mint_tokens(
+            RuntimeOrigin::signed(1),
+            1u64,
+            ethereum_address(),
+            1000u128,
+            ethereum_tx_hash(),
+            sign_mint(1u64, 1000u128, ethereum_tx_hash(), &[0]),
+        ));
+
+        assert_ok!(CrossChainBridge::pause(RuntimeOrigin::root()));
+        System::assert_last_event(Event::BridgePaused.into());
+        assert!(CrossChainBridge::paused());
+
+        let second_tx_hash = H256::from_slice(&[2u8; 32]);
+        let ethereum_recipient = H160::from_slice(&[3u8; 20]);
+        assert_noop!(
+            CrossChainBridge::mint_tokens(
+                RuntimeOrigin::signed(1),
+                1u64,
+                ethereum_address(),
+                1000u128,
+                second_tx_hash,
+                sign_mint(1u64, 1000u128, second_tx_hash, &[0]),
+            ),
+            Error::<Test>::BridgePausedError
+        );
+        assert_noop!(
+            CrossChainBridge::burn_tokens(
+                RuntimeOrigin::signed(1),
+                asset_id(),
+                500u128,
+                ethereum_recipient,
+            ),
+            Error::<Test>::BridgePausedError
+        );
+
+        assert_ok!(CrossChainBridge::unpause(RuntimeOrigin::root()));
+        System::assert_last_event(Event::BridgeUnpaused.into());
+        assert!(!CrossChainBridge::paused());
+
+        assert_ok!(CrossChainBridge::mint_tokens(
+            RuntimeOrigin::signed(1),
+            1u64,
+            ethereum_address(),
+            1000u128,
+            second_tx_hash,
+            sign_mint(1u64, 1000u128, second_tx_hash, &[0]),
+        ));
+        assert_ok!(CrossChainBridge::burn_tokens(
+            RuntimeOrigin::signed(1),
+            asset_id(),
+            500u128,
+            ethereum_recipient,
+        ));
+        assert_eq!(Assets::balance(asset_id(), &1u64), 1500u128);
+    });
+}
+
+/// Register the test token with one validator signing mints
+fn setup_limited_token(limit: u128) {
+    assert_ok!(CrossChainBridge::register_token(
+        RuntimeOrigin::root(),
+        ethereum_address(),
+        asset_id(),
+        token_name(),
+        token_symbol(),
+        token_decimals(),
+    ));
+    setup_validators(1, 1);
+    assert_ok!(CrossChainBridge::set_token_limit(RuntimeOrigin::root(), ethereum_address(), limit));
+}
+
+/// Mint `amount` to account 1 for the Ethereum transaction `[tx; 32]`
+fn mint_to_user(amount: u128, tx: u8) -> sp_runtime::DispatchResult {
+    let tx_hash = H256::from_slice(&[tx; 32]);
+    CrossChainBridge::mint_tokens(
+        RuntimeOrigin::signed(1),
+        1u64,
+        ethereum_address(),
+        amount,
+        tx_hash,
+        sign_mint(1u64, amount, tx_hash, &[0]),
+    )
+}
+
+#[test]
+fn set_token_limit_requires_root_and_registered_token() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CrossChainBridge::set_token_limit(RuntimeOrigin::root(), ethereum_address(), 1000),
+            Error::<Test>::TokenNotRegistered
+        );
+
+        setup_limited_token(1000);
+        System::assert_last_event(
+            Event::TokenLimitSet {
+                ethereum_address: ethereum_address(),
+                limit: 1000,
+            }
+            .into(),
+        );
+        assert_eq!(
+            CrossChainBridge::bridged_tokens(ethereum_address()).unwrap().daily_limit,
+            1000
+        );
+
+        assert_noop!(
+            CrossChainBridge::set_token_limit(RuntimeOrigin::signed(1), ethereum_address(), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn daily_limit_rejects_mints_and_burns_past_the_limit() {
+    new_test_ext().execute_with(|| {
+        setup_limited_token(1500);
+
+        assert_ok!(mint_to_user(1000, 1));
+        assert_noop!(mint_to_user(600, 2), Error::<Test>::DailyLimitExceeded);
+        // Exactly reaching the limit is allowed
+        assert_ok!(mint_to_user(500, 3));
+        assert_noop!(mint_to_user(1, 4), Error::<Test>::DailyLimitExceeded);
+
+        // Burns are capped separately from mints
+        let ethereum_recipient = H160::from_slice(&[3u8; 20]);
+        assert_ok!(CrossChainBridge::burn_tokens(
+            RuntimeOrigin::signed(1),
+            asset_id(),
+            1000u128,
+            ethereum_recipient,
+        ));
+        assert_noop!(
+            CrossChainBridge::burn_tokens(RuntimeOrigin::signed(1), asset_id(), 501u128, ethereum_recipient),
+            Error::<Test>::DailyLimitExceeded
+        );
+
+        let usage = CrossChainBridge::daily_usage(ethereum_address()).unwrap();
+        assert_eq!((usage.minted, usage.burned), (1500, 1000));
+    });
+}
+
+#[test]
+fn daily_limit_resets_when_the_day_rolls_over() {
+    new_test_ext().execute_with(|| {
+        setup_limited_token(1000);
+
+        assert_ok!(mint_to_user(1000, 1));
+
+        // Still the same day one block before it ends
+        System::set_block_number(BlocksPerDay::get() - 1);
+        assert_noop!(mint_to_user(1, 2), Error::<Test>::DailyLimitExceeded);
+
+        System::set_block_number(BlocksPerDay::get());
+        assert_ok!(mint_to_user(1000, 3));
+
+        let usage = CrossChainBridge::daily_usage(ethereum_address()).unwrap();
+        assert_eq!((usage.day, usage.minted), (1, 1000));
+        assert_eq!(Assets::balance(asset_id(), &1u64), 2000u128);
+    });
+}
+
+#[test]
+fn zero_daily_limit_is_unlimited() {
+    new_test_ext().execute_with(|| {
+        setup_limited_token(100);
+        assert_noop!(mint_to_user(1000, 1), Error::<Test>::DailyLimitExceeded);
+
+        assert_ok!(CrossChainBridge::set_token_limit(RuntimeOrigin::root(), ethereum_address(), 0));
+        assert_ok!(mint_to_user(1000, 1));
+        assert_ok!(mint_to_user(1_000_000, 2));
+        assert_eq!(Assets::balance(asset_id(), &1u64), 1_001_000u128);
+    });
+}
+
+/// Store `(asset_id, ethereum_address, total_supply, is_active)` as a version 1 `BridgedToken`
+fn put_v1_token(asset_id: u32, ethereum_address: H160, total_supply: u128, is_active: bool) {
+    use codec::Encode;
+
+    frame_support::storage::unhashed::put_raw(
+        &crate::BridgedTokens::<Test>::hashed_key_for(ethereum_address),
+        &(asset_id, ethereum_address, total_supply, is_active).encode(),
+    );
+}
+
+#[test]
+fn migration_to_v2_gives_tokens_no_daily_limit() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        // Tokens stored before `daily_limit` existed
+        let retired_address = H160::from_slice(&[7u8; 20]);
+        put_v1_token(asset_id(), ethereum_address(), 500u128, true);
+        put_v1_token(asset_id() + 1, retired_address, 0u128, false);
+        StorageVersion::new(1).put::<CrossChainBridge>();
+
+        #[cfg(feature = "try-runtime")]
+        let state = crate::migrations::v2::AddDailyLimit::<Test>::pre_upgrade().unwrap();
+
+        crate::migrations::v2::AddDailyLimit::<Test>::on_runtime_upgrade();
+
+        #[cfg(feature = "try-runtime")]
+        crate::migrations::v2::AddDailyLimit::<Test>::post_upgrade(state).unwrap();
+
+        let token = CrossChainBridge::bridged_tokens(ethereum_address()).unwrap();
+        assert_eq!(token.asset_id, asset_id());
+        assert_eq!(token.total_supply, 500);
+        assert!(token.is_active);
+        assert_eq!(token.daily_limit, 0);
+
+        let retired = CrossChainBridge::bridged_tokens(retired_address).unwrap();
+        assert_eq!(retired.asset_id, asset_id() + 1);
+        assert!(!retired.is_active);
+        assert_eq!(retired.daily_limit, 0);
+
+        assert_eq!(crate::BridgedTokens::<Test>::iter().count(), 2);
+        assert_eq!(CrossChainBridge::on_chain_storage_version(), 2);
+    });
+}
+
+#[test]
+fn migration_to_v2_skips_already_migrated_storage() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        assert_ok!(CrossChainBridge::set_token_limit(RuntimeOrigin::root(), ethereum_address(), 100));
+        StorageVersion::new(2).put::<CrossChainBridge>();
+
+        crate::migrations::v2::AddDailyLimit::<Test>::on_runtime_upgrade();
+
+        assert_eq!(CrossChainBridge::bridged_tokens(ethereum_address()).unwrap().daily_limit, 100);
+        assert_eq!(CrossChainBridge::on_chain_storage_version(), 2);
+    });
+}
+
+/// Mint to account 1 and burn part of it, returning the burn request ID
+fn mint_and_burn(amount: u128) -> u64 {
+    assert_ok!(CrossChainBridge::register_token(
+        RuntimeOrigin::root(),
+        ethereum_address(),
+        asset_id(),
+        token_name(),
+        token_symbol(),
+        token_decimals(),
+    ));
+    setup_validators(3, 2);
+
+    assert_ok!(CrossChainBridge::mint_tokens(
+        RuntimeOrigin::signed(1),
+        1u64,
+        ethereum_address(),
+        1000u128,
+        ethereum_tx_hash(),
+        sign_mint(1u64, 1000u128, ethereum_tx_hash(), &[0, 1]),
+    ));
+
+    let request_id = CrossChainBridge::next_burn_request_id();
+    assert_ok!(CrossChainBridge::burn_tokens(
+        RuntimeOrigin::signed(1),
+        asset_id(),
+        amount,
+        H160::from_slice(&[3u8; 20]),
+    ));
+    request_id
+}
+
+#[test]
+fn complete_burn_request_marks_request_processed() {
+    new_test_ext().execute_with(|| {
+        let request_id = mint_and_burn(500u128);
+        let unlock_tx_hash = H256::from_slice(&[9u8; 32]);
+
+        assert_ok!(CrossChainBridge::complete_burn_request(
+            RuntimeOrigin::signed(2),
+            request_id,
+            unlock_tx_hash,
+            sign_burn_completion(request_id, unlock_tx_hash, &[0, 2]),
+        ));
+
+        assert!(CrossChainBridge::burn_requests(request_id).unwrap().processed);
+        System::assert_last_event(
+            Event::BurnRequestCompleted {
+                request_id,
+                ethereum_tx_hash: unlock_tx_hash,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn complete_burn_request_fails_for_unknown_request() {
+    new_test_ext().execute_with(|| {
+        let request_id = mint_and_burn(500u128);
+        let unlock_tx_hash = H256::from_slice(&[9u8; 32]);
+        let signatures = sign_burn_completion(request_id, unlock_tx_hash, &[0, 1]);
+
+        assert_noop!(
+            CrossChainBridge::complete_burn_request(
+                RuntimeOrigin::signed(2),
+                request_id + 1,
+                unlock_tx_hash,
+                signatures,
+            ),
+            Error::<Test>::RequestNotFound
+        );
+    });
+}
+
+#[test]
+fn complete_burn_request_rejects_double_completion() {
+    new_test_ext().execute_with(|| {
+        let request_id = mint_and_burn(500u128);
+        let unlock_tx_hash = H256::from_slice(&[9u8; 32]);
+        let signatures = sign_burn_completion(request_id, unlock_tx_hash, &[0, 1]);
+
+        assert_ok!(CrossChainBridge::complete_burn_request(
+            RuntimeOrigin::signed(2),
+            request_id,
+            unlock_tx_hash,
+            signatures.clone(),
+        ));
+        assert_noop!(
+            CrossChainBridge::complete_burn_request(
+                RuntimeOrigin::signed(2),
+                request_id,
+                unlock_tx_hash,
+                signatures,
+            ),
+            Error::<Test>::RequestAlreadyProcessed
+        );
+    });
+}
+
+#[test]
+fn deregister_token_requires_root() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+
+        assert_noop!(
+            CrossChainBridge::deregister_token(RuntimeOrigin::signed(1), ethereum_address()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CrossChainBridge::deregister_token(RuntimeOrigin::root(), H160::from_slice(&[7u8; 20])),
+            Error::<Test>::TokenNotRegistered
+        );
+    });
+}
+
+#[test]
+fn deregistered_token_rejects_mint_and_burn() {
+    new_test_ext().execute_with(|| {
+        let request_id = mint_and_burn(500u128);
+
+        assert_ok!(CrossChainBridge::deregister_token(RuntimeOrigin::root(), ethereum_address()));
+        System::assert_last_event(
+            Event::BridgedTokenDeregistered {
+                ethereum_address: ethereum_address(),
+                asset_id: asset_id(),
+            }
+            .into(),
+        );
+
+        // Kept for audit, but inactive
+        let token = CrossChainBridge::bridged_tokens(ethereum_address()).unwrap();
+        assert!(!token.is_active);
+        assert_eq!(token.total_supply, 500u128);
+        assert_eq!(CrossChainBridge::asset_to_ethereum(asset_id()), Some(ethereum_address()));
+        assert!(CrossChainBridge::burn_requests(request_id).is_some());
+
+        let second_tx_hash = H256::from_slice(&[2u8; 32]);
+        assert_noop!(
+            CrossChainBridge::mint_tokens(
+                RuntimeOrigin::signed(1),
+                1u64,
+                ethereum_address(),
+                1000u128,
+                second_tx_hash,
+                sign_mint(1u64, 1000u128, second_tx_hash, &[0, 1]),
+            ),
+            Error::<Test>::TokenInactive
+        );
+        assert_noop!(
+            CrossChainBridge::burn_tokens(
+                RuntimeOrigin::signed(1),
+                asset_id(),
+                100u128,
+                H160::from_slice(&[3u8; 20]),
+            ),
+            Error::<Test>::TokenInactive
+        );
+        assert_noop!(
+            CrossChainBridge::deregister_token(RuntimeOrigin::root(), ethereum_address()),
+            Error::<Test>::TokenInactive
+        );
+    });
+}
+
+#[test]
+fn deregistered_token_address_cannot_be_registered_again() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        assert_ok!(CrossChainBridge::deregister_token(RuntimeOrigin::root(), ethereum_address()));
+
+        assert_noop!(
+            CrossChainBridge::register_token(
+                RuntimeOrigin::root(),
+                ethereum_address(),
+                asset_id() + 1,
+                token_name(),
+                token_symbol(),
+                token_decimals(),
+            ),
+            Error::<Test>::TokenAlreadyRegistered
+        );
+    });
+}
+
+#[test]
+fn pending_burn_requests_skips_completed_requests() {
+    new_test_ext().execute_with(|| {
+        let first = mint_and_burn(100u128);
+        let ethereum_recipient = H160::from_slice(&[3u8; 20]);
+        for amount in [200u128, 300u128] {
+            assert_ok!(CrossChainBridge::burn_tokens(
+                RuntimeOrigin::signed(1),
+                asset_id(),
+                amount,
+                ethereum_recipient,
+            ));
+        }
+        let (second, third) = (first + 1, first + 2);
+
+        let unlock_tx_hash = H256::from_slice(&[9u8; 32]);
+        assert_ok!(CrossChainBridge::complete_burn_request(
+            RuntimeOrigin::signed(2),
+            second,
+            unlock_tx_hash,
+            sign_burn_completion(second, unlock_tx_hash, &[0, 1]),
+        ));
+
+        let pending = CrossChainBridge::pending_burn_requests();
+        let ids: Vec<u64> = pending.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![first, third]);
+        assert_eq!(pending[0].1.amount, 100u128);
+        assert_eq!(pending[1].1.amount, 300u128);
+        assert!(pending.iter().all(|(_, request)| !request.processed && request.burner == 1u64));
+
+        // Completed requests stay queryable by ID
+        assert!(CrossChainBridge::burn_requests(second).unwrap().processed);
+        assert!(CrossChainBridge::burn_requests(third + 1).is_none());
+    });
+}
+
+#[test]
+fn transfers_below_the_minimum_are_rejected() {
+    new_test_ext().execute_with(|| {
+        MinTransferAmount::set(100);
+        assert_ok!(CrossChainBridge::register_token(
+            RuntimeOrigin::root(),
+            ethereum_address(),
+            asset_id(),
+            token_name(),
+            token_symbol(),
+            token_decimals(),
+        ));
+        setup_validators(1, 1);
+
+        // Zero keeps its own error
+        assert_noop!(mint_to_user(0, 1), Error::<Test>::InvalidAmount);
+        assert_noop!(mint_to_user(99, 1), Error::<Test>::AmountBelowMinimum);
+        assert_ok!(mint_to_user(100, 1));
+        assert_ok!(mint_to_user(1000, 2));
+
+        let ethereum_recipient = H160::from_slice(&[3u8; 20]);
+        assert_noop!(
+            CrossChainBridge::burn_tokens(RuntimeOrigin::signed(1), asset_id(), 0u128, ethereum_recipient),
+            Error::<Test>::InvalidAmount
+        );
+        assert_noop!(
+            CrossChainBridge::burn_tokens(RuntimeOrigin::signed(1), asset_id(), 99u128, ethereum_recipient),
+            Error::<Test>::AmountBelowMinimum
+        );
+        assert_ok!(CrossChainBridge::burn_tokens(
+            RuntimeOrigin::signed(1),
+            asset_id(),
+            100u128,
+            ethereum_recipient,
+        ));
+        assert_eq!(Assets::balance(asset_id(), &1u64), 1000u128);
+    });
+}
+
+#[test]
+fn transfers_are_indexed_by_account() {
+    new_test_ext().execute_with(|| {
+        let burn_id = mint_and_burn(100u128);
+
+        let mints = CrossChainBridge::mints_for(&1u64);
+        assert_eq!(mints.len(), 1);
+        assert_eq!(mints[0].1.recipient, 1u64);
+        assert_eq!(mints[0].1.ethereum_tx_hash, ethereum_tx_hash());
+        assert_eq!(CrossChainBridge::mint_requests(mints[0].0), Some(mints[0].1.clone()));
+
+        let burns = CrossChainBridge::burns_for(&1u64);
+        assert_eq!(burns.len(), 1);
+        assert_eq!(burns[0].0, burn_id);
+        assert_eq!(burns[0].1.amount, 100u128);
+
+        // Other accounts see none of them
+        assert!(CrossChainBridge::mints_for(&2u64).is_empty());
+        assert!(CrossChainBridge::burns_for(&2u64).is_empty());
+    });
+}
+
+#[test]
+fn account_index_keeps_the_most_recent_requests() {
+    new_test_ext().execute_with(|| {
+        let first = mint_and_burn(100u128);
+        let ethereum_recipient = H160::from_slice(&[3u8; 20]);
+        for amount in [200u128, 300u128, 400u128] {
+            assert_ok!(CrossChainBridge::burn_tokens(
+                RuntimeOrigin::signed(1),
+                asset_id(),
+                amount,
+                ethereum_recipient,
+            ));
+        }
+
+        // MaxRequestsPerAccount is 3, so the first burn drops out of the index
+        let burns = CrossChainBridge::burns_for(&1u64);
+        let ids: Vec<u64> = burns.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![first + 1, first + 2, first + 3]);
+        assert_eq!(burns[2].1.amount, 400u128);
+
+        // but stays queryable by ID
+        assert_eq!(CrossChainBridge::burn_requests(first).unwrap().amount, 100u128);
+    });
+}
@@ -30,18 +30,27 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_cross_chain_bridge.
 pub trait WeightInfo {
     fn register_token() -> Weight;
-    fn mint_tokens() -> Weight;
+    fn mint_tokens(s: u32, ) -> Weight;
     fn burn_tokens() -> Weight;
     fn add_validator() -> Weight;
     fn remove_validator() -> Weight;
     fn update_threshold() -> Weight;
+    fn register_tokens_batch(n: u32, ) -> Weight;
+    fn add_allowed_recipient() -> Weight;
+    fn remove_allowed_recipient() -> Weight;
+    fn mint_tokens_batch(n: u32, s: u32, ) -> Weight;
+    fn pause() -> Weight;
+    fn unpause() -> Weight;
+    fn set_token_limit() -> Weight;
+    fn complete_burn_request(s: u32, ) -> Weight;
+    fn deregister_token() -> Weight;
 }
 
 /// Weights for pallet_cross_chain_bridge using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: CrossChainBridge BridgedTokens (r:1 w:1)
-    /// Proof: CrossChainBridge BridgedTokens (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Proof: CrossChainBridge BridgedTokens (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
     /// Storage: Assets Asset (r:1 w:1)
     /// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
     /// Storage: Assets Metadata (r:1 w:1)
@@ -58,38 +67,61 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 
     /// Storage: CrossChainBridge ProcessedEthereumTxs (r:1 w:1)
     /// Proof: CrossChainBridge ProcessedEthereumTxs (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge AllowedRecipientCount (r:1 w:0)
+    /// Proof: CrossChainBridge AllowedRecipientCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge AllowedRecipients (r:1 w:0)
+    /// Proof: CrossChainBridge AllowedRecipients (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
     /// Storage: CrossChainBridge BridgedTokens (r:1 w:1)
-    /// Proof: CrossChainBridge BridgedTokens (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Proof: CrossChainBridge BridgedTokens (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
     /// Storage: Assets Asset (r:1 w:1)
     /// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
     /// Storage: Assets Account (r:1 w:1)
     /// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
-    fn mint_tokens() -> Weight {
+    /// Storage: CrossChainBridge TokenDailyUsage (r:1 w:1)
+    /// Proof: CrossChainBridge TokenDailyUsage (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge MintRequestsByRecipient (r:1 w:1)
+    /// Proof: CrossChainBridge MintRequestsByRecipient (max_values: None, max_size: Some(849), added: 3324, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge ValidatorList (r:1 w:0)
+    /// Proof: CrossChainBridge ValidatorList (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge Threshold (r:1 w:0)
+    /// Proof: CrossChainBridge Threshold (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge Validators (r:100 w:0)
+    /// Proof: CrossChainBridge Validators (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+    /// The range of component `s` is `[0, 100]`.
+    fn mint_tokens(s: u32, ) -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `351`
-        //  Estimated: `3675`
-        // Minimum execution time: 35_000_000 picoseconds.
-        Weight::from_parts(36_000_000, 3675)
-            .saturating_add(T::DbWeight::get().reads(4_u64))
-            .saturating_add(T::DbWeight::get().writes(4_u64))
+        //  Measured:  `420 + s * (64 ±0)`
+        //  Estimated: `4687 + s * (2539 ±0)`
+        // Minimum execution time: 37_000_000 picoseconds.
+        Weight::from_parts(37_000_000, 4687)
+            // Standard Error: 9_000
+            .saturating_add(Weight::from_parts(29_000_000, 0).saturating_mul(s.into()))
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+            .saturating_add(Weight::from_parts(0, 2539).saturating_mul(s.into()))
     }
 
     /// Storage: CrossChainBridge AssetToEthereum (r:1 w:0)
     /// Proof: CrossChainBridge AssetToEthereum (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
     /// Storage: CrossChainBridge BridgedTokens (r:1 w:1)
-    /// Proof: CrossChainBridge BridgedTokens (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Proof: CrossChainBridge BridgedTokens (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
     /// Storage: Assets Asset (r:1 w:1)
     /// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
     /// Storage: Assets Account (r:1 w:1)
     /// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge TokenDailyUsage (r:1 w:1)
+    /// Proof: CrossChainBridge TokenDailyUsage (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge BurnRequestsByBurner (r:1 w:1)
+    /// Proof: CrossChainBridge BurnRequestsByBurner (max_values: None, max_size: Some(849), added: 3324, mode: MaxEncodedLen)
     fn burn_tokens() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `485`
         //  Estimated: `3675`
         // Minimum execution time: 30_000_000 picoseconds.
         Weight::from_parts(31_000_000, 3675)
-            .saturating_add(T::DbWeight::get().reads(4_u64))
-            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
     }
 
     /// Storage: CrossChainBridge Validators (r:1 w:1)
@@ -132,6 +164,164 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
         Weight::from_parts(9_000_000, 0)
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
+
+    /// Storage: CrossChainBridge BridgedTokens (r:50 w:50)
+    /// Proof: CrossChainBridge BridgedTokens (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+    /// Storage: Assets Asset (r:50 w:50)
+    /// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+    /// Storage: Assets Metadata (r:50 w:50)
+    /// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 50]`.
+    fn register_tokens_batch(n: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `42`
+        //  Estimated: `990 + n * (2685 ±0)`
+        // Minimum execution time: 27_000_000 picoseconds.
+        Weight::from_parts(4_000_000, 990)
+            // Standard Error: 12_000
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2685).saturating_mul(n.into()))
+    }
+
+    /// Storage: CrossChainBridge AllowedRecipients (r:1 w:1)
+    /// Proof: CrossChainBridge AllowedRecipients (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge AllowedRecipientCount (r:1 w:1)
+    /// Proof: CrossChainBridge AllowedRecipientCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    fn add_allowed_recipient() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `42`
+        //  Estimated: `3529`
+        // Minimum execution time: 12_000_000 picoseconds.
+        Weight::from_parts(13_000_000, 3529)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: CrossChainBridge AllowedRecipients (r:1 w:1)
+    /// Proof: CrossChainBridge AllowedRecipients (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge AllowedRecipientCount (r:1 w:1)
+    /// Proof: CrossChainBridge AllowedRecipientCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    fn remove_allowed_recipient() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `110`
+        //  Estimated: `3529`
+        // Minimum execution time: 13_000_000 picoseconds.
+        Weight::from_parts(14_000_000, 3529)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: CrossChainBridge ProcessedEthereumTxs (r:20 w:20)
+    /// Proof: CrossChainBridge ProcessedEthereumTxs (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge AllowedRecipientCount (r:20 w:0)
+    /// Proof: CrossChainBridge AllowedRecipientCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge AllowedRecipients (r:20 w:0)
+    /// Proof: CrossChainBridge AllowedRecipients (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge BridgedTokens (r:20 w:20)
+    /// Proof: CrossChainBridge BridgedTokens (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+    /// Storage: Assets Asset (r:20 w:20)
+    /// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+    /// Storage: Assets Account (r:20 w:20)
+    /// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge TokenDailyUsage (r:20 w:20)
+    /// Proof: CrossChainBridge TokenDailyUsage (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge MintRequestsByRecipient (r:20 w:20)
+    /// Proof: CrossChainBridge MintRequestsByRecipient (max_values: None, max_size: Some(849), added: 3324, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge ValidatorList (r:20 w:0)
+    /// Proof: CrossChainBridge ValidatorList (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge Threshold (r:20 w:0)
+    /// Proof: CrossChainBridge Threshold (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge Validators (r:2000 w:0)
+    /// Proof: CrossChainBridge Validators (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 20]`.
+    /// The range of component `s` is `[0, 2000]`.
+    fn mint_tokens_batch(n: u32, s: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `420 + s * (64 ±0)`
+        //  Estimated: `4687 + n * (2685 ±0) + s * (2539 ±0)`
+        // Minimum execution time: 39_000_000 picoseconds.
+        Weight::from_parts(5_000_000, 4687)
+            // Standard Error: 15_000
+            .saturating_add(Weight::from_parts(34_000_000, 0).saturating_mul(n.into()))
+            // Standard Error: 9_000
+            .saturating_add(Weight::from_parts(29_000_000, 0).saturating_mul(s.into()))
+            .saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+            .saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2685).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 2539).saturating_mul(s.into()))
+    }
+
+    /// Storage: CrossChainBridge Paused (r:0 w:1)
+    /// Proof: CrossChainBridge Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+    fn pause() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `0`
+        // Minimum execution time: 7_000_000 picoseconds.
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: CrossChainBridge Paused (r:0 w:1)
+    /// Proof: CrossChainBridge Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+    fn unpause() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `0`
+        // Minimum execution time: 7_000_000 picoseconds.
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: CrossChainBridge BridgedTokens (r:1 w:1)
+    /// Proof: CrossChainBridge BridgedTokens (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+    fn set_token_limit() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `171`
+        //  Estimated: `3609`
+        // Minimum execution time: 11_000_000 picoseconds.
+        Weight::from_parts(12_000_000, 3609)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: CrossChainBridge BurnRequests (r:1 w:1)
+    /// Proof: CrossChainBridge BurnRequests (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge ValidatorList (r:1 w:0)
+    /// Proof: CrossChainBridge ValidatorList (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge Validators (r:100 w:0)
+    /// Proof: CrossChainBridge Validators (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+    /// Storage: CrossChainBridge Threshold (r:1 w:0)
+    /// Proof: CrossChainBridge Threshold (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// The range of component `s` is `[0, 100]`.
+    fn complete_burn_request(s: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `220 + s * (64 ±0)`
+        //  Estimated: `4687 + s * (2539 ±0)`
+        // Minimum execution time: 33_000_000 picoseconds.
+        Weight::from_parts(33_000_000, 4687)
+            // Standard Error: 9_000
+            .saturating_add(Weight::from_parts(29_000_000, 0).saturating_mul(s.into()))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(Weight::from_parts(0, 2539).saturating_mul(s.into()))
+    }
+
+    /// Storage: CrossChainBridge BridgedTokens (r:1 w:1)
+    /// Proof: CrossChainBridge BridgedTokens (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+    fn deregister_token() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `171`
+        //  Estimated: `3609`
+        // Minimum execution time: 11_000_000 picoseconds.
+        Weight::from_parts(12_000_000, 3609)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
 }
 
 // For backwards compatibility and tests
@@ -142,16 +332,19 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
 
-    fn mint_tokens() -> Weight {
-        Weight::from_parts(36_000_000, 3675)
-            .saturating_add(RocksDbWeight::get().reads(4_u64))
-            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    fn mint_tokens(s: u32, ) -> Weight {
+        Weight::from_parts(37_000_000, 4687)
+            .saturating_add(Weight::from_parts(29_000_000, 0).saturating_mul(s.into()))
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+            .saturating_add(Weight::from_parts(0, 2539).saturating_mul(s.into()))
     }
 
     fn burn_tokens() -> Weight {
         Weight::from_parts(31_000_000, 3675)
-            .saturating_add(RocksDbWeight::get().reads(4_u64))
-            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
     }
 
     fn add_validator() -> Weight {
@@ -170,4 +363,66 @@ impl WeightInfo for () {
         Weight::from_parts(9_000_000, 0)
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
+
+    fn register_tokens_batch(n: u32, ) -> Weight {
+        Weight::from_parts(4_000_000, 990)
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2685).saturating_mul(n.into()))
+    }
+
+    fn add_allowed_recipient() -> Weight {
+        Weight::from_parts(13_000_000, 3529)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn remove_allowed_recipient() -> Weight {
+        Weight::from_parts(14_000_000, 3529)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn mint_tokens_batch(n: u32, s: u32, ) -> Weight {
+        Weight::from_parts(5_000_000, 4687)
+            .saturating_add(Weight::from_parts(34_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(29_000_000, 0).saturating_mul(s.into()))
+            .saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+            .saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2685).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 2539).saturating_mul(s.into()))
+    }
+
+    fn pause() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn unpause() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn set_token_limit() -> Weight {
+        Weight::from_parts(12_000_000, 3609)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn complete_burn_request(s: u32, ) -> Weight {
+        Weight::from_parts(33_000_000, 4687)
+            .saturating_add(Weight::from_parts(29_000_000, 0).saturating_mul(s.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(Weight::from_parts(0, 2539).saturating_mul(s.into()))
+    }
+
+    fn deregister_token() -> Weight {
+        Weight::from_parts(12_000_000, 3609)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
 }
@@ -0,0 +1,22 @@
+//! Generates the validator-to-validator gRPC service
+//!
+//! The service is described in Rust rather than a `.proto` file, so building
+//! the relayer does not need `protoc`. Its messages live in `src/p2p.rs`.
+
+fn main() {
+    let validator = tonic_build::manual::Service::builder()
+        .name("Validator")
+        .package("bridge.p2p")
+        .method(
+            tonic_build::manual::Method::builder()
+                .name("submit_partial")
+                .route_name("SubmitPartial")
+                .input_type("crate::p2p::PartialSignatureMsg")
+                .output_type("crate::p2p::SubmitPartialResponse")
+                .codec_path("tonic::codec::ProstCodec")
+                .build(),
+        )
+        .build();
+
+    tonic_build::manual::Builder::new().compile(&[validator]);
+}
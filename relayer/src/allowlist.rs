@@ -0,0 +1,50 @@
+//! Recipient allowlist for mints
+
+use crate::error::{RelayerError, Result};
+
+/// Polkadot accounts the relayer will mint to
+///
+/// Each pattern is either an exact account or a prefix ending in `*`. The
+/// allowlist is opt-in: with no patterns, every recipient is allowed.
+#[derive(Debug, Clone, Default)]
+pub struct RecipientAllowlist {
+    patterns: Vec<String>,
+}
+
+impl RecipientAllowlist {
+    /// Create an allowlist from recipient patterns
+    pub fn new(patterns: Vec<String>) -> Self {
+        let patterns = patterns
+            .into_iter()
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty())
+            .collect();
+
+        Self { patterns }
+    }
+
+    /// Whether the allowlist restricts recipients at all
+    pub fn is_enabled(&self) -> bool {
+        !self.patterns.is_empty()
+    }
+
+    /// Whether mints may be sent to `recipient`
+    pub fn is_allowed(&self, recipient: &str) -> bool {
+        !self.is_enabled()
+            || self.patterns.iter().any(|pattern| match pattern.strip_suffix('*') {
+                Some(prefix) => recipient.starts_with(prefix),
+                None => recipient == pattern,
+            })
+    }
+
+    /// Fail with `RecipientNotAllowed` unless mints may be sent to `recipient`
+    pub fn check(&self, recipient: &str) -> Result<()> {
+        if self.is_allowed(recipient) {
+            Ok(())
+        } else {
+            Err(RelayerError::RecipientNotAllowed {
+                recipient: recipient.to_string(),
+            })
+        }
+    }
+}
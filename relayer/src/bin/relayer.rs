@@ -8,6 +8,11 @@ use tokio::signal;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("generate-config") {
+        return generate_config(&args[1..]);
+    }
+
     // Initialize logging
     init_logging();
 
@@ -47,6 +52,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Write an annotated config template: `relayer generate-config <path> [--force]`
+fn generate_config(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let force = args.iter().any(|arg| arg == "--force");
+    let paths: Vec<&String> = args.iter().filter(|arg| *arg != "--force").collect();
+
+    let [path] = paths.as_slice() else {
+        return Err("usage: relayer generate-config <path> [--force]".into());
+    };
+
+    RelayerConfig::write_template(path, force)?;
+    println!("Wrote config template to {}", path);
+    Ok(())
+}
+
 /// Initialize logging based on environment
 fn init_logging() {
     let log_level = env::var("LOG_LEVEL").unwrap_or_else(|_| "info".to_string());
@@ -0,0 +1,173 @@
+//! Threshold-signed checkpoints of bridge state
+//!
+//! A checkpoint commits to how far the relayer has read each chain, how many
+//! transfers it has completed, and a state root over every processed
+//! transaction. External watchers can check a checkpoint's aggregate
+//! signature against the validators' keys, and replay the processed
+//! transactions to reproduce its state root, without access to the relayer.
+
+use crate::{
+    database::Database,
+    error::{RelayerError, Result},
+    signature_coordinator::SignatureCoordinator,
+};
+use chrono::{DateTime, Utc};
+use ethers::utils::keccak256;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use threshold::{AggregatedSignature, PublicKeyShare, SimpleThresholdManager};
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+/// Bridge progress at the time a checkpoint was taken
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BridgeCheckpoint {
+    pub last_ethereum_block: u64,
+    pub last_polkadot_block: u64,
+    /// Ethereum locks completed by a mint
+    pub ethereum_processed: u64,
+    /// Polkadot burns completed by an unlock
+    pub polkadot_processed: u64,
+    /// Hash chain over the processed transactions, see [`state_root`]
+    pub state_root: String,
+}
+
+impl BridgeCheckpoint {
+    /// Message the validators sign
+    pub fn message(&self) -> Vec<u8> {
+        format!(
+            "checkpoint:{}:{}:{}:{}:{}",
+            self.last_ethereum_block,
+            self.last_polkadot_block,
+            self.ethereum_processed,
+            self.polkadot_processed,
+            self.state_root
+        )
+        .into_bytes()
+    }
+
+    /// Signing session the validators' partials are collected under
+    pub fn session_id(&self) -> String {
+        format!(
+            "checkpoint:{}:{}:{}",
+            self.last_ethereum_block, self.last_polkadot_block, self.state_root
+        )
+    }
+}
+
+/// Hash chain over processed `(chain, tx_hash)` pairs in processing order
+///
+/// Starting from 32 zero bytes, each transaction folds in as
+/// `keccak256(root || chain || ":" || tx_hash)`. Appending transactions
+/// extends the chain, so a watcher holding an earlier root only needs the
+/// transactions processed since.
+pub fn state_root<'a>(processed: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+    let root = processed.into_iter().fold([0u8; 32], |root, (chain, tx_hash)| {
+        let mut preimage = root.to_vec();
+        preimage.extend_from_slice(chain.as_bytes());
+        preimage.push(b':');
+        preimage.extend_from_slice(tx_hash.as_bytes());
+        keccak256(preimage)
+    });
+    format!("0x{}", hex::encode(root))
+}
+
+/// A checkpoint with the validators' aggregate signature over it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedCheckpoint {
+    pub id: u64,
+    pub checkpoint: BridgeCheckpoint,
+    pub signature: AggregatedSignature,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SignedCheckpoint {
+    /// Verify the aggregate signature against the first signer's public share
+    pub async fn verify(
+        &self,
+        manager: &SimpleThresholdManager,
+        public_key_shares: &[PublicKeyShare],
+    ) -> Result<bool> {
+        let signer = self.signature.signers.first().ok_or_else(|| RelayerError::Generic {
+            message: format!("Checkpoint {} has no signers", self.id),
+        })?;
+        let share = public_key_shares
+            .iter()
+            .find(|share| &share.validator_id == signer)
+            .ok_or_else(|| RelayerError::Generic {
+                message: format!("No public key share for checkpoint signer {}", signer),
+            })?;
+
+        manager
+            .verify_signature(
+                &self.signature,
+                &self.checkpoint.message(),
+                &share.public_share,
+                &self.checkpoint.session_id(),
+            )
+            .await
+            .map_err(RelayerError::ThresholdSignature)
+    }
+}
+
+/// Takes checkpoints and stores them once the validators have signed
+pub struct Checkpointer {
+    database: Arc<Database>,
+    signatures: Arc<SignatureCoordinator>,
+    public_key_shares: Vec<PublicKeyShare>,
+    /// Checkpoint whose signatures are still being collected
+    pending: Mutex<Option<BridgeCheckpoint>>,
+}
+
+impl Checkpointer {
+    /// Create a checkpointer aggregating with `public_key_shares`
+    pub fn new(
+        database: Arc<Database>,
+        signatures: Arc<SignatureCoordinator>,
+        public_key_shares: Vec<PublicKeyShare>,
+    ) -> Self {
+        Self {
+            database,
+            signatures,
+            public_key_shares,
+            pending: Mutex::new(None),
+        }
+    }
+
+    /// Checkpoint whose signatures are still being collected, if any
+    pub async fn pending(&self) -> Option<BridgeCheckpoint> {
+        self.pending.lock().await.clone()
+    }
+
+    /// Advance the checkpoint cycle
+    ///
+    /// Stores and returns the pending checkpoint once enough validators have
+    /// signed it. Otherwise, if none is pending or its session was dropped,
+    /// takes a new checkpoint and requests signatures for it.
+    pub async fn tick(&self) -> Result<Option<SignedCheckpoint>> {
+        let mut pending = self.pending.lock().await;
+
+        if let Some(checkpoint) = pending.as_ref() {
+            let session_id = checkpoint.session_id();
+            if let Some(signature) = self
+                .signatures
+                .get_aggregated_signature(&session_id, &self.public_key_shares)
+                .await?
+            {
+                let signed = self.database.store_checkpoint(checkpoint, &signature).await?;
+                info!("Stored checkpoint {} with state root {}", signed.id, checkpoint.state_root);
+                *pending = None;
+                return Ok(Some(signed));
+            }
+            if self.signatures.session(&session_id).await.is_some() {
+                return Ok(None);
+            }
+            warn!("Signing session for checkpoint {} was dropped, taking a new one", checkpoint.state_root);
+        }
+
+        let checkpoint = self.database.checkpoint_state().await?;
+        self.signatures.request_checkpoint_signature(&checkpoint).await?;
+        *pending = Some(checkpoint);
+        Ok(None)
+    }
+}
@@ -1,21 +1,39 @@
 //! Main bridge coordinator that orchestrates cross-chain operations
 
 use crate::{
-    config::RelayerConfig,
+    allowlist::RecipientAllowlist,
+    checkpoint::Checkpointer,
+    config::{RelayerConfig, ValidatorConfig},
     error::{RelayerError, Result},
     ethereum::EthereumClient,
-    polkadot::PolkadotClient,
-    event_monitor::EventMonitor,
-    signature_coordinator::SignatureCoordinator,
-    database::Database,
+    polkadot::{decode_destination, PendingMint, PolkadotClient},
+    event_monitor::{EventMonitor, MonitorHandle},
+    event_sink::{build_event_sink, publish_outcome, BroadcastEventSink, EventSink, PublishedEvent},
+    signature_coordinator::{SignatureCoordinator, WeightedThreshold},
+    database::{CompletionTimes, Database, TokenStats},
+    metrics::{self, ChainReorgs, DuplicateEvents, RelayerMetrics, RpcRateLimits, TokenStatsCache, TransferHealth},
+    mint_batcher::{BatchOutcome, MintBatcher},
+    p2p::{self, PartialSignatureService, PeerTls},
+    policy::RelayerPolicy,
+    price_oracle::{build_price_oracle, PriceOracle},
+    retry_queue::RetryQueue,
 };
-use threshold::{SimpleThresholdManager, ThresholdConfig};
-use tokio::sync::mpsc;
+use ethers::types::U256;
+use serde::{Deserialize, Serialize};
+use threshold::{KeyEpoch, PublicKeyShare, SimpleThresholdManager, ThresholdConfig};
+use tokio::sync::{broadcast, mpsc, Mutex};
+use tokio_util::sync::CancellationToken;
 use tracing::{info, warn, error, debug};
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Processed events buffered for each in-process subscriber
+const EVENT_BROADCAST_CAPACITY: usize = 1024;
 
 /// Events that can occur in the bridge
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum BridgeEvent {
     /// Token lock event from Ethereum
     EthereumLock {
@@ -37,15 +55,51 @@ pub enum BridgeEvent {
     },
 }
 
+impl BridgeEvent {
+    /// Chain the event was emitted on
+    pub fn chain(&self) -> &'static str {
+        match self {
+            BridgeEvent::EthereumLock { .. } => "ethereum",
+            BridgeEvent::PolkadotBurn { .. } => "polkadot",
+        }
+    }
+
+    /// Hash of the source-chain transaction
+    pub fn tx_hash(&self) -> &str {
+        match self {
+            BridgeEvent::EthereumLock { tx_hash, .. } | BridgeEvent::PolkadotBurn { tx_hash, .. } => tx_hash,
+        }
+    }
+
+    /// Block the event was emitted in
+    pub fn block_number(&self) -> u64 {
+        match self {
+            BridgeEvent::EthereumLock { block_number, .. } => *block_number,
+            BridgeEvent::PolkadotBurn { block_number, .. } => u64::from(*block_number),
+        }
+    }
+}
+
 /// Main bridge coordinator
 pub struct BridgeCoordinator {
     config: RelayerConfig,
+    policy: RelayerPolicy,
     ethereum_client: Arc<EthereumClient>,
     polkadot_client: Arc<PolkadotClient>,
     threshold_manager: Arc<SimpleThresholdManager>,
     signature_coordinator: Arc<SignatureCoordinator>,
     database: Arc<Database>,
     event_monitor: Arc<EventMonitor>,
+    monitor_handle: Mutex<Option<MonitorHandle>>,
+    transfer_health: Arc<TransferHealth>,
+    duplicate_events: Arc<DuplicateEvents>,
+    metrics: Arc<RelayerMetrics>,
+    token_stats: TokenStatsCache,
+    recipient_allowlist: RecipientAllowlist,
+    mint_batcher: Arc<MintBatcher>,
+    retry_queue: Arc<RetryQueue>,
+    price_oracle: Option<Arc<dyn PriceOracle>>,
+    event_sink: Arc<BroadcastEventSink>,
     event_sender: mpsc::UnboundedSender<BridgeEvent>,
     event_receiver: mpsc::UnboundedReceiver<BridgeEvent>,
 }
@@ -55,23 +109,32 @@ impl BridgeCoordinator {
     pub async fn new(config: RelayerConfig) -> Result<Self> {
         info!("Initializing bridge coordinator");
 
+        let policy = RelayerPolicy::from_config(&config);
+
         // Initialize threshold manager
         let threshold_config = ThresholdConfig::new(
             config.threshold.threshold,
             config.threshold.total_validators,
             config.threshold.key_size,
-        ).map_err(|e| RelayerError::ThresholdSignature(e))?;
+        ).map_err(RelayerError::ThresholdSignature)?;
 
         let threshold_manager = Arc::new(
-            SimpleThresholdManager::new(threshold_config)
-                .map_err(|e| RelayerError::ThresholdSignature(e))?
+            SimpleThresholdManager::new_with_scheme(threshold_config, &config.threshold.scheme)
+                .map_err(RelayerError::ThresholdSignature)?
+                .with_signature_timeout(policy.timeouts.signature_timeout)
         );
+        info!("Signing with the {:?} threshold scheme", threshold_manager.scheme());
 
         // Initialize database
         let database = Arc::new(Database::new(&config.database).await?);
 
         // Initialize clients
-        let ethereum_client = Arc::new(EthereumClient::new(&config.ethereum).await?);
+        let ethereum_client = Arc::new(
+            EthereumClient::new(&config.ethereum)
+                .await?
+                .with_log_batch_size(config.monitoring.batch_size.into())
+                .with_nonce_store(database.clone())
+        );
         let polkadot_client = Arc::new(PolkadotClient::new(&config.polkadot).await?);
 
         // Initialize signature coordinator
@@ -80,29 +143,88 @@ impl BridgeCoordinator {
                 config.validator.clone(),
                 threshold_manager.clone(),
                 database.clone(),
+                policy.timeouts,
             ).await?
+            .with_weighted_threshold(WeightedThreshold::from_config(&config.threshold))
+            .with_max_message_size(config.threshold.max_message_size)
+            .with_public_key_shares(&peer_public_key_shares(
+                &config.validator,
+                threshold_manager.key_set().map(|key_set| key_set.key_epoch).unwrap_or_default(),
+            )?)
         );
 
-        // Initialize event monitor
-        let event_monitor = Arc::new(
-            EventMonitor::new(
-                ethereum_client.clone(),
-                polkadot_client.clone(),
-                database.clone(),
-            ).await?
-        );
+        let metrics = Arc::new(RelayerMetrics::new());
+
+        // Initialize event monitor, watching only the chains our direction starts from
+        let mut event_monitor = EventMonitor::new(
+            ethereum_client.clone(),
+            polkadot_client.clone(),
+            database.clone(),
+        ).await?
+        .for_direction(config.direction)
+        .with_reorg_detection(database.clone())
+        .with_rpc_durations(metrics.rpc_calls.clone())
+        .with_batch_size(config.monitoring.batch_size.into());
+        if let Some(max_age_blocks) = config.monitoring.max_event_age_blocks {
+            event_monitor = event_monitor.with_max_event_age(max_age_blocks, database.clone());
+        }
+        let event_monitor = Arc::new(event_monitor);
+        info!("Relaying {:?}, monitoring {:?}", config.direction, event_monitor.chains());
+
+        let transfer_health = Arc::new(TransferHealth::new(
+            config.monitoring.incomplete_transfer_threshold,
+            Duration::from_secs(config.monitoring.incomplete_transfer_window),
+        ));
+
+        let token_stats = TokenStatsCache::new(Duration::from_secs(config.monitoring.stats_cache_ttl));
+
+        let price_oracle = build_price_oracle(config.price_oracle.as_ref());
+
+        let event_sink = Arc::new(BroadcastEventSink::new(
+            build_event_sink(config.monitoring.event_sink.as_ref()).await?,
+            EVENT_BROADCAST_CAPACITY,
+        ));
+
+        let recipient_allowlist = RecipientAllowlist::new(config.polkadot.allowed_recipients.clone());
+
+        let mint_batcher = Arc::new(MintBatcher::new(
+            polkadot_client.clone(),
+            config.polkadot.mint_batch_size as usize,
+            Duration::from_secs(config.polkadot.mint_batch_window),
+        ));
+
+        let retry_queue = Arc::new(RetryQueue::new(database.clone(), policy.retry));
+
+        // Gather everything served on the metrics port into one registry
+        let duplicate_events = Arc::new(DuplicateEvents::new());
+        transfer_health.register(metrics.registry())?;
+        duplicate_events.register(metrics.registry())?;
+        event_monitor.rate_limits().register(metrics.registry())?;
+        event_monitor.reorgs().register(metrics.registry())?;
+        signature_coordinator.register_metrics(metrics.registry())?;
 
         // Create event channel
         let (event_sender, event_receiver) = mpsc::unbounded_channel();
 
         Ok(Self {
             config,
+            policy,
             ethereum_client,
             polkadot_client,
             threshold_manager,
             signature_coordinator,
             database,
             event_monitor,
+            monitor_handle: Mutex::new(None),
+            transfer_health,
+            duplicate_events,
+            metrics,
+            token_stats,
+            recipient_allowlist,
+            mint_batcher,
+            retry_queue,
+            price_oracle,
+            event_sink,
             event_sender,
             event_receiver,
         })
@@ -113,16 +235,65 @@ impl BridgeCoordinator {
         info!("Starting bridge coordinator");
 
         // Start event monitoring
-        let event_sender = self.event_sender.clone();
-        let event_monitor = self.event_monitor.clone();
-        let monitoring_config = self.config.monitoring.clone();
+        self.restart_monitoring(self.policy).await?;
+
+        // Serve relayer metrics for Prometheus to scrape
+        let metrics_address = format!("0.0.0.0:{}", self.config.monitoring.metrics_port);
+        let listener = tokio::net::TcpListener::bind(&metrics_address)
+            .await
+            .map_err(|e| RelayerError::Network {
+                message: format!("Failed to serve metrics on {}: {}", metrics_address, e),
+            })?;
+        info!("Serving metrics on {}", metrics_address);
+
+        let relayer_metrics = self.metrics.clone();
+        let signature_coordinator = self.signature_coordinator.clone();
+        tokio::spawn(async move {
+            if let Err(e) = metrics::serve(listener, relayer_metrics, signature_coordinator, std::future::pending()).await {
+                error!("{}", e);
+            }
+        });
+
+        // Start tracking detected vs completed transfers and how long they take
+        let database = self.database.clone();
+        let transfer_health = self.transfer_health.clone();
+        let relayer_metrics = self.metrics.clone();
+        let completion_window = chrono::Duration::seconds(self.config.monitoring.completion_time_window as i64);
+        let poll_interval = self.policy.timeouts.poll_interval;
 
         tokio::spawn(async move {
-            if let Err(e) = event_monitor.start_monitoring(event_sender, monitoring_config).await {
-                error!("Event monitoring failed: {}", e);
+            loop {
+                if let Err(e) = Self::check_transfer_health(&database, &transfer_health).await {
+                    error!("Transfer health check failed: {}", e);
+                }
+                let until = chrono::Utc::now();
+                match database.completion_times(until - completion_window, until).await {
+                    Ok(times) => relayer_metrics.set_completion_times(&times),
+                    Err(e) => error!("Failed to read transfer completion times: {}", e),
+                }
+                tokio::time::sleep(poll_interval).await;
             }
         });
 
+        // Submit partially filled mint batches once their window elapses
+        if self.mint_batcher.is_batching() {
+            let database = self.database.clone();
+            let signature_coordinator = self.signature_coordinator.clone();
+            let relayer_metrics = self.metrics.clone();
+            let mint_batcher = self.mint_batcher.clone();
+            let flush_interval = mint_batcher.window().max(Duration::from_secs(1));
+
+            tokio::spawn(async move {
+                loop {
+                    tokio::time::sleep(flush_interval).await;
+                    let outcome = mint_batcher.flush_due().await;
+                    if let Err(e) = Self::record_mints(&database, &signature_coordinator, &relayer_metrics, &outcome).await {
+                        error!("Failed to record batched mints: {}", e);
+                    }
+                }
+            });
+        }
+
         // Start signature coordination if validator mode is enabled
         if self.config.validator.enabled {
             info!("Starting validator mode");
@@ -132,18 +303,105 @@ impl BridgeCoordinator {
                     error!("Signature coordination failed: {}", e);
                 }
             });
+
+            // Accept partial signatures from peers
+            if let Some(listen_address) = &self.config.validator.listen_address {
+                let listener = tokio::net::TcpListener::bind(listen_address)
+                    .await
+                    .map_err(|e| RelayerError::Network {
+                        message: format!("Failed to listen for validators on {}: {}", listen_address, e),
+                    })?;
+                info!("Accepting partial signatures on {}", listen_address);
+
+                let tls = PeerTls::from_config(&self.config.validator)?;
+                if tls.is_none() {
+                    warn!("Validator TLS is not configured, partial signatures are exchanged in plaintext");
+                }
+                let service = PartialSignatureService::new(
+                    self.signature_coordinator.clone(),
+                    &self.config.validator.peers,
+                )
+                .with_tls(tls);
+                tokio::spawn(async move {
+                    if let Err(e) = p2p::serve(listener, service, std::future::pending()).await {
+                        error!("{}", e);
+                    }
+                });
+            }
+        }
+
+        // Take threshold-signed checkpoints of bridge state
+        if let Some(interval) = self.config.monitoring.checkpoint_interval.filter(|_| self.config.validator.enabled) {
+            let key_epoch = self.threshold_manager.key_set().map(|key_set| key_set.key_epoch).unwrap_or_default();
+            let checkpointer = Checkpointer::new(
+                self.database.clone(),
+                self.signature_coordinator.clone(),
+                peer_public_key_shares(&self.config.validator, key_epoch)?,
+            );
+            let interval = Duration::from_secs(interval.max(1));
+
+            tokio::spawn(async move {
+                loop {
+                    tokio::time::sleep(interval).await;
+                    if let Err(e) = checkpointer.tick().await {
+                        error!("Checkpoint failed: {}", e);
+                    }
+                }
+            });
         }
 
         // Main event processing loop
         self.process_events().await
     }
 
+    /// Stop event monitoring, persisting each chain's cursor
+    pub async fn stop_monitoring(&self) -> Result<()> {
+        if let Some(handle) = self.monitor_handle.lock().await.take() {
+            handle.stop().await?;
+        }
+        Ok(())
+    }
+
+    /// Restart event monitoring with a new policy, resuming from the saved cursors
+    pub async fn restart_monitoring(&self, policy: RelayerPolicy) -> Result<()> {
+        let mut monitor_handle = self.monitor_handle.lock().await;
+
+        if let Some(handle) = monitor_handle.take() {
+            handle.stop().await?;
+        }
+
+        let handle = self.event_monitor.start_monitoring(
+            self.event_sender.clone(),
+            policy,
+            CancellationToken::new(),
+        ).await?;
+        *monitor_handle = Some(handle);
+
+        Ok(())
+    }
+
     /// Process bridge events
+    ///
+    /// Events that fail are handed to the retry queue and tried again, in
+    /// between new events, once their backoff elapses.
     async fn process_events(&mut self) -> Result<()> {
         info!("Starting event processing loop");
 
-        while let Some(event) = self.event_receiver.recv().await {
-            if let Err(e) = self.handle_event(event).await {
+        loop {
+            let next_retry = self.retry_queue.next_due().await;
+            let event = tokio::select! {
+                event = self.event_receiver.recv() => match event {
+                    Some(event) => Some(event),
+                    None => break,
+                },
+                _ = tokio::time::sleep_until(next_retry.unwrap_or_else(Instant::now).into()), if next_retry.is_some() => None,
+            };
+
+            let result = match event {
+                Some(event) => self.retry_queue.attempt(event, 0, |event| self.handle_event(event)).await,
+                None => self.retry_queue.retry_due(|event| self.retry_event(event)).await,
+            };
+            if let Err(e) = result {
                 error!("Failed to handle event: {}", e);
                 // Continue processing other events
             }
@@ -157,101 +415,110 @@ impl BridgeCoordinator {
     async fn handle_event(&self, event: BridgeEvent) -> Result<()> {
         debug!("Handling bridge event: {:?}", event);
 
+        let handled = match &event {
+            BridgeEvent::EthereumLock { .. } => self.config.direction.mints(),
+            BridgeEvent::PolkadotBurn { .. } => self.config.direction.unlocks(),
+        };
+        if !handled {
+            warn!("Ignoring event outside relay direction {:?}: {:?}", self.config.direction, event);
+            return Ok(());
+        }
+
+        if let BridgeEvent::EthereumLock { .. } = event {
+            return self.mint_pipeline().relay(&event).await;
+        }
+
+        // Redelivered events were already handled when first stored
+        if !Self::store_event(&self.database, &self.duplicate_events, &event).await? {
+            return Ok(());
+        }
+
+        let result = self.process_stored_event(&event).await;
+        publish_outcome(self.event_sink.as_ref(), &event, &result).await;
+        result
+    }
+
+    /// Relay an already stored event to its destination chain
+    async fn process_stored_event(&self, event: &BridgeEvent) -> Result<()> {
         match event {
-            BridgeEvent::EthereumLock {
-                user,
-                token,
-                amount,
-                polkadot_address,
-                tx_hash,
-                block_number,
-            } => {
-                self.handle_ethereum_lock(
-                    user,
-                    token,
-                    amount,
-                    polkadot_address,
-                    tx_hash,
-                    block_number,
-                ).await
-            }
+            BridgeEvent::EthereumLock { .. } => self.mint_pipeline().process(event).await,
             BridgeEvent::PolkadotBurn {
                 user,
                 asset_id,
                 amount,
                 ethereum_recipient,
                 tx_hash,
-                block_number,
+                ..
             } => {
                 self.handle_polkadot_burn(
-                    user,
-                    asset_id,
-                    amount,
-                    ethereum_recipient,
-                    tx_hash,
-                    block_number,
+                    user.clone(),
+                    *asset_id,
+                    amount.clone(),
+                    ethereum_recipient.clone(),
+                    tx_hash.clone(),
                 ).await
             }
         }
     }
 
-    /// Handle Ethereum lock event (mint on Polkadot)
-    async fn handle_ethereum_lock(
-        &self,
-        user: String,
-        token: String,
-        amount: String,
-        polkadot_address: String,
-        tx_hash: String,
-        block_number: u64,
-    ) -> Result<()> {
-        info!(
-            "Processing Ethereum lock: user={}, token={}, amount={}, tx_hash={}",
-            user, token, amount, tx_hash
-        );
-
-        // Check if already processed
-        if self.database.is_ethereum_tx_processed(&tx_hash).await? {
-            debug!("Transaction {} already processed", tx_hash);
-            return Ok(());
-        }
+    /// Relay an event that was held as stale, returning `false` if no
+    /// stored event with that hash was held
+    pub async fn release_stale_event(&self, tx_hash: &str) -> Result<bool> {
+        let Some(event) = self.database.release_stale_event(tx_hash).await? else {
+            return Ok(false);
+        };
+        info!("Releasing stale {} event {}", event.chain(), tx_hash);
 
-        // Store the lock request
-        self.database.store_ethereum_lock(
-            &user,
-            &token,
-            &amount,
-            &polkadot_address,
-            &tx_hash,
-            block_number,
-        ).await?;
+        let result = self.process_stored_event(&event).await;
+        publish_outcome(self.event_sink.as_ref(), &event, &result).await;
+        result.map(|()| true)
+    }
 
-        // If validator mode is enabled, participate in signature generation
-        if self.config.validator.enabled {
-            self.signature_coordinator.request_mint_signature(
-                &polkadot_address,
-                &token,
-                &amount,
-                &tx_hash,
-            ).await?;
-        }
+    /// Relay an event again after a failed attempt
+    ///
+    /// The event is stored first in case the failure came before it was, and
+    /// is then relayed whether or not it was already stored.
+    async fn retry_event(&self, event: BridgeEvent) -> Result<()> {
+        Self::validate_amount(&event)?;
+        self.database.store_event(&event).await?;
+
+        let result = self.process_stored_event(&event).await;
+        publish_outcome(self.event_sink.as_ref(), &event, &result).await;
+        result
+    }
 
-        // If we have enough signatures, submit to Polkadot
-        if let Some(signatures) = self.signature_coordinator.get_mint_signatures(&tx_hash).await? {
-            self.polkadot_client.mint_tokens(
-                &polkadot_address,
-                &token,
-                &amount,
-                &tx_hash,
-                signatures,
-            ).await?;
+    /// Relay a dead-lettered event, returning `false` if there is no dead
+    /// letter with that id
+    ///
+    /// The event leaves the dead-letter table while it is relayed, and goes
+    /// back in under a new id if it fails again.
+    pub async fn retry_dead_letter(&self, id: i64) -> Result<bool> {
+        let Some(dead_letter) = self.database.take_dead_letter(id).await? else {
+            return Ok(false);
+        };
+        info!("Retrying dead-lettered {} event {}", dead_letter.event.chain(), dead_letter.event.tx_hash());
 
-            // Mark as processed
-            self.database.mark_ethereum_tx_processed(&tx_hash).await?;
-            info!("Successfully minted tokens on Polkadot for tx {}", tx_hash);
+        if let Err(e) = self.retry_event(dead_letter.event.clone()).await {
+            self.database
+                .add_dead_letter(&dead_letter.event, dead_letter.attempts + 1, &e.to_string())
+                .await?;
+            return Err(e);
         }
+        Ok(true)
+    }
 
-        Ok(())
+    /// The path Ethereum locks take from storage to a Polkadot mint
+    fn mint_pipeline(&self) -> MintPipeline<'_> {
+        MintPipeline {
+            database: &self.database,
+            duplicate_events: &self.duplicate_events,
+            metrics: &self.metrics,
+            recipient_allowlist: &self.recipient_allowlist,
+            signature_coordinator: &self.signature_coordinator,
+            mint_batcher: &self.mint_batcher,
+            event_sink: self.event_sink.as_ref(),
+            validator_enabled: self.config.validator.enabled,
+        }
     }
 
     /// Handle Polkadot burn event (unlock on Ethereum)
@@ -262,60 +529,147 @@ impl BridgeCoordinator {
         amount: String,
         ethereum_recipient: String,
         tx_hash: String,
-        block_number: u32,
     ) -> Result<()> {
+        let started = Instant::now();
         info!(
             "Processing Polkadot burn: user={}, asset_id={}, amount={}, tx_hash={}",
             user, asset_id, amount, tx_hash
         );
 
-        // Check if already processed
+        // Check if already processed, or submitted by a relayer that stopped
+        // before recording it
         if self.database.is_polkadot_tx_processed(&tx_hash).await? {
             debug!("Transaction {} already processed", tx_hash);
             return Ok(());
         }
+        if self.database.is_submission_pending("polkadot", &tx_hash).await? {
+            warn!("Unlock for tx {} was already submitted, not submitting it again", tx_hash);
+            return Ok(());
+        }
 
-        // Store the burn request
-        self.database.store_polkadot_burn(
-            &user,
-            asset_id,
-            &amount,
-            &ethereum_recipient,
-            &tx_hash,
-            block_number,
-        ).await?;
+        // The contract's unlock digest names the token by its address
+        let token_address = self.database.get_token_address_by_asset_id(asset_id).await?;
 
         // If validator mode is enabled, participate in signature generation
         if self.config.validator.enabled {
             self.signature_coordinator.request_unlock_signature(
                 &ethereum_recipient,
-                asset_id,
+                &token_address,
                 &amount,
                 &tx_hash,
+                self.config.ethereum.chain_id,
             ).await?;
         }
 
         // If we have enough signatures, submit to Ethereum
         if let Some(signatures) = self.signature_coordinator.get_unlock_signatures(&tx_hash).await? {
-            // Get token address from asset_id
-            let token_address = self.database.get_token_address_by_asset_id(asset_id).await?;
-            
-            self.ethereum_client.unlock_tokens(
+            // Claim the unlock so it is sent at most once, even across restarts
+            if !self.database.claim_submission("polkadot", &tx_hash).await? {
+                debug!("Unlock for tx {} already claimed", tx_hash);
+                return Ok(());
+            }
+
+            let unlock_started = Instant::now();
+            let unlock = self.ethereum_client.unlock_tokens(
                 &ethereum_recipient,
                 &token_address,
                 &amount,
                 &tx_hash,
                 signatures,
-            ).await?;
-
-            // Mark as processed
-            self.database.mark_polkadot_tx_processed(&tx_hash).await?;
-            info!("Successfully unlocked tokens on Ethereum for tx {}", tx_hash);
+            ).await;
+            self.metrics.rpc_calls
+                .observe(&[("chain", "ethereum"), ("call", "unlock_tokens")], unlock_started.elapsed());
+            let unlock_tx_hash = match unlock {
+                Ok(unlock_tx_hash) => unlock_tx_hash,
+                Err(e) => {
+                    self.database.release_submission("polkadot", &tx_hash).await?;
+                    return Err(e);
+                }
+            };
+
+            // Mark as processed together with the unlock that settled it
+            let unlock_tx_hash = format!("{:?}", unlock_tx_hash);
+            self.database.complete_submission("polkadot", &tx_hash, &unlock_tx_hash).await?;
+            self.signature_coordinator.mark_completed(&tx_hash).await;
+            self.metrics.processed_transactions.record("polkadot");
+            info!("Successfully unlocked tokens on Ethereum for tx {} in {}", tx_hash, unlock_tx_hash);
         }
 
+        self.metrics.processing_latency.observe(&[("chain", "polkadot")], started.elapsed());
+        Ok(())
+    }
+
+    /// Record what became of a batch of mints
+    ///
+    /// Locks whose mints were submitted are marked processed and their
+    /// signature sessions closed. Claims on the ones that failed are released
+    /// so the locks can be minted again.
+    async fn record_mints(
+        database: &Database,
+        signature_coordinator: &SignatureCoordinator,
+        metrics: &RelayerMetrics,
+        outcome: &BatchOutcome,
+    ) -> Result<()> {
+        for mint in &outcome.minted {
+            let tx_hash = &mint.ethereum_tx_hash;
+            database.complete_submission("ethereum", tx_hash, &mint.extrinsic_hash).await?;
+            signature_coordinator.mark_completed(tx_hash).await;
+            metrics.processed_transactions.record("ethereum");
+            info!("Successfully minted tokens on Polkadot for tx {} in {}", tx_hash, mint.extrinsic_hash);
+        }
+        for failed in &outcome.failed {
+            database.release_submission("ethereum", &failed.ethereum_tx_hash).await?;
+            error!("Failed to mint for tx {}, released its claim: {}", failed.ethereum_tx_hash, failed.error);
+        }
         Ok(())
     }
 
+    /// Store a source-chain event, returning `false` if it was already stored
+    ///
+    /// Duplicates are counted per chain in `duplicate_events`. Events with an
+    /// invalid amount are rejected before anything is stored.
+    pub async fn store_event(
+        database: &Database,
+        duplicate_events: &DuplicateEvents,
+        event: &BridgeEvent,
+    ) -> Result<bool> {
+        Self::validate_amount(event)?;
+
+        let inserted = database.store_event(event).await?;
+        if !inserted {
+            debug!("Suppressed duplicate {} event for tx {}", event.chain(), event.tx_hash());
+            duplicate_events.record(event.chain());
+        }
+
+        Ok(inserted)
+    }
+
+    /// Reject an event whose amount is not a positive integer in range for
+    /// its chain: a `U256` for Ethereum locks, a `u128` balance for Polkadot burns
+    pub fn validate_amount(event: &BridgeEvent) -> Result<()> {
+        let (amount, tx_hash, valid) = match event {
+            BridgeEvent::EthereumLock { amount, tx_hash, .. } => (
+                amount,
+                tx_hash,
+                U256::from_dec_str(amount).is_ok_and(|amount| !amount.is_zero()),
+            ),
+            BridgeEvent::PolkadotBurn { amount, tx_hash, .. } => (
+                amount,
+                tx_hash,
+                amount.parse::<u128>().is_ok_and(|amount| amount > 0),
+            ),
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(RelayerError::InvalidAmount {
+                tx_hash: tx_hash.clone(),
+                amount: amount.clone(),
+            })
+        }
+    }
+
     /// Get bridge statistics
     pub async fn get_stats(&self) -> Result<BridgeStats> {
         let stats = BridgeStats {
@@ -323,14 +677,135 @@ impl BridgeCoordinator {
             polkadot_processed_txs: self.database.count_polkadot_processed_txs().await?,
             pending_signatures: self.signature_coordinator.count_pending_signatures().await?,
             active_validators: self.signature_coordinator.count_active_validators().await?,
+            tokens: self.token_stats.get(&self.database).await?,
+            completion_times: self.completion_times().await?,
         };
 
         Ok(stats)
     }
 
+    /// Completion times of transfers completed within the configured window
+    pub async fn completion_times(&self) -> Result<CompletionTimes> {
+        let until = chrono::Utc::now();
+        let window = chrono::Duration::seconds(self.config.monitoring.completion_time_window as i64);
+        self.database.completion_times(until - window, until).await
+    }
+
+    /// Compare detected and completed transfers for each chain
+    pub async fn check_transfer_health(
+        database: &Database,
+        transfer_health: &TransferHealth,
+    ) -> Result<()> {
+        transfer_health.observe(
+            "ethereum",
+            database.count_ethereum_locks().await?,
+            database.count_ethereum_processed_txs().await?,
+        ).await;
+        transfer_health.observe(
+            "polkadot",
+            database.count_polkadot_burns().await?,
+            database.count_polkadot_processed_txs().await?,
+        ).await;
+
+        if transfer_health.is_degraded().await {
+            for (chain, health) in transfer_health.snapshot().await {
+                if health.degraded {
+                    warn!(
+                        "Bridge degraded: {} {} transfers detected but not completed",
+                        health.incomplete(), chain
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the retry and timeout policy
+    pub fn policy(&self) -> &RelayerPolicy {
+        &self.policy
+    }
+
+    /// Get the duplicate-event counter
+    pub fn duplicate_events(&self) -> &Arc<DuplicateEvents> {
+        &self.duplicate_events
+    }
+
+    /// Get the counter of RPC polls rejected by provider rate limits
+    pub fn rpc_rate_limits(&self) -> &Arc<RpcRateLimits> {
+        self.event_monitor.rate_limits()
+    }
+
+    /// Get the counter of reorgs the event monitor rolled back over
+    pub fn chain_reorgs(&self) -> &Arc<ChainReorgs> {
+        self.event_monitor.reorgs()
+    }
+
+    /// Get the metrics served on the monitoring metrics port
+    pub fn metrics(&self) -> &Arc<RelayerMetrics> {
+        &self.metrics
+    }
+
+    /// Get the detected-vs-completed transfer tracker
+    pub fn transfer_health(&self) -> &Arc<TransferHealth> {
+        &self.transfer_health
+    }
+
+    /// Get the configured price oracle, if any
+    pub fn price_oracle(&self) -> Option<&Arc<dyn PriceOracle>> {
+        self.price_oracle.as_ref()
+    }
+
+    /// Get the mint batcher
+    pub fn mint_batcher(&self) -> &Arc<MintBatcher> {
+        &self.mint_batcher
+    }
+
+    /// Get the queue of events waiting to be retried
+    pub fn retry_queue(&self) -> &Arc<RetryQueue> {
+        &self.retry_queue
+    }
+
+    /// Get the Ethereum client
+    pub fn ethereum_client(&self) -> &Arc<EthereumClient> {
+        &self.ethereum_client
+    }
+
+    /// Get the Polkadot client
+    pub fn polkadot_client(&self) -> &Arc<PolkadotClient> {
+        &self.polkadot_client
+    }
+
+    /// Get the threshold manager holding the active key set
+    pub fn threshold_manager(&self) -> &Arc<SimpleThresholdManager> {
+        &self.threshold_manager
+    }
+
+    /// Get the signature coordinator
+    pub fn signature_coordinator(&self) -> &Arc<SignatureCoordinator> {
+        &self.signature_coordinator
+    }
+
+    /// Get the relayer database
+    pub fn database(&self) -> &Arc<Database> {
+        &self.database
+    }
+
+    /// Channel every processed event is broadcast on
+    pub fn event_stream(&self) -> &broadcast::Sender<PublishedEvent> {
+        self.event_sink.sender()
+    }
+
     /// Shutdown the coordinator gracefully
     pub async fn shutdown(&self) -> Result<()> {
         info!("Shutting down bridge coordinator");
+
+        // Stop monitoring so cursors are persisted before the pool closes
+        self.stop_monitoring().await?;
+
+        // Submit any mints still waiting for their batch to fill
+        let outcome = self.mint_batcher.flush().await;
+        Self::record_mints(&self.database, &self.signature_coordinator, &self.metrics, &outcome).await?;
         
         // Close database connections
         self.database.close().await?;
@@ -340,6 +815,115 @@ impl BridgeCoordinator {
     }
 }
 
+/// The components an Ethereum lock passes through on its way to a mint
+///
+/// Borrowed from the [`BridgeCoordinator`] for each event, and buildable
+/// directly so the mint path can run without chain clients.
+pub struct MintPipeline<'a> {
+    pub database: &'a Database,
+    pub duplicate_events: &'a DuplicateEvents,
+    pub metrics: &'a RelayerMetrics,
+    pub recipient_allowlist: &'a RecipientAllowlist,
+    pub signature_coordinator: &'a SignatureCoordinator,
+    pub mint_batcher: &'a MintBatcher,
+    pub event_sink: &'a dyn EventSink,
+    pub validator_enabled: bool,
+}
+
+impl MintPipeline<'_> {
+    /// Store an Ethereum lock and, unless it is a duplicate or already
+    /// minted, sign it and queue its mint
+    ///
+    /// Each newly stored lock is published to the event sink once processed.
+    pub async fn relay(&self, event: &BridgeEvent) -> Result<()> {
+        // Redelivered events were already handled when first stored
+        if !BridgeCoordinator::store_event(self.database, self.duplicate_events, event).await? {
+            return Ok(());
+        }
+
+        let result = self.process(event).await;
+        publish_outcome(self.event_sink, event, &result).await;
+        result
+    }
+
+    /// Sign and queue the mint of an already stored Ethereum lock, unless
+    /// it was already minted
+    pub async fn process(&self, event: &BridgeEvent) -> Result<()> {
+        let BridgeEvent::EthereumLock {
+            user,
+            token,
+            amount,
+            polkadot_address,
+            tx_hash,
+            ..
+        } = event
+        else {
+            return Err(RelayerError::Generic {
+                message: "Only Ethereum locks are minted".to_string(),
+            });
+        };
+
+        let started = Instant::now();
+        info!(
+            "Processing Ethereum lock: user={}, token={}, amount={}, tx_hash={}",
+            user, token, amount, tx_hash
+        );
+
+        // Check if already processed, or queued or submitted by a relayer
+        // that stopped before recording it
+        if self.database.is_ethereum_tx_processed(tx_hash).await? {
+            debug!("Transaction {} already processed", tx_hash);
+            return Ok(());
+        }
+        if self.database.is_submission_pending("ethereum", tx_hash).await? {
+            warn!("Mint for tx {} was already queued or submitted, not submitting it again", tx_hash);
+            return Ok(());
+        }
+
+        // Minting to a malformed or unspendable account would strand the tokens
+        decode_destination(tx_hash, polkadot_address)?;
+
+        // Compliance setups only mint to pre-approved accounts
+        self.recipient_allowlist.check(polkadot_address)?;
+
+        // If validator mode is enabled, participate in signature generation
+        if self.validator_enabled {
+            self.signature_coordinator.request_mint_signature(
+                polkadot_address,
+                token,
+                amount,
+                tx_hash,
+            ).await?;
+        }
+
+        // If we have enough signatures, queue the mint for submission to Polkadot
+        if let Some(signatures) = self.signature_coordinator.get_mint_signatures(tx_hash).await? {
+            // Claim the mint so it is submitted at most once, even across restarts
+            if !self.database.claim_submission("ethereum", tx_hash).await? {
+                debug!("Mint for tx {} already claimed", tx_hash);
+                return Ok(());
+            }
+
+            // Pushing may submit other queued mints along with this one
+            let outcome = self.mint_batcher.push(PendingMint {
+                recipient: polkadot_address.clone(),
+                ethereum_address: token.clone(),
+                amount: amount.clone(),
+                ethereum_tx_hash: tx_hash.clone(),
+                signatures,
+            }).await;
+            BridgeCoordinator::record_mints(self.database, self.signature_coordinator, self.metrics, &outcome).await?;
+
+            if let Some(failed) = outcome.failed.into_iter().find(|failed| failed.ethereum_tx_hash == *tx_hash) {
+                return Err(failed.error);
+            }
+        }
+
+        self.metrics.processing_latency.observe(&[("chain", "ethereum")], started.elapsed());
+        Ok(())
+    }
+}
+
 /// Bridge statistics
 #[derive(Debug, Clone)]
 pub struct BridgeStats {
@@ -347,4 +931,27 @@ pub struct BridgeStats {
     pub polkadot_processed_txs: u64,
     pub pending_signatures: u64,
     pub active_validators: u64,
+    /// Transfer counts and volume keyed by token
+    pub tokens: HashMap<String, TokenStats>,
+    /// Completion times over the configured window
+    pub completion_times: CompletionTimes,
+}
+
+/// Public key shares of the configured validator peers, for verifying the
+/// partials they contribute
+fn peer_public_key_shares(config: &ValidatorConfig, key_epoch: KeyEpoch) -> Result<Vec<PublicKeyShare>> {
+    config.peers
+        .iter()
+        .map(|peer| {
+            let public_share = hex::decode(peer.public_key.trim_start_matches("0x")).map_err(|e| RelayerError::Config {
+                message: format!("Invalid public key for validator {}: {}", peer.id, e),
+            })?;
+            Ok(PublicKeyShare {
+                validator_id: peer.id.clone(),
+                verification_key: public_share.clone(),
+                public_share,
+                key_epoch,
+            })
+        })
+        .collect()
 }
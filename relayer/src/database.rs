@@ -1,41 +1,384 @@
 //! Database operations for the relayer service
+//!
+//! The URL scheme picks the backend: `postgres://` for deployments, `sqlite:`
+//! for local runs and tests. Queries use SQL both dialects accept wherever
+//! possible; the few aggregates PostgreSQL computes in SQL are finished in
+//! Rust on SQLite.
 
 use crate::{
+    checkpoint::{self, BridgeCheckpoint, SignedCheckpoint},
     config::DatabaseConfig,
+    coordinator::BridgeEvent,
     error::{RelayerError, Result},
+    signature_coordinator::SignatureFormat,
 };
-use sqlx::{PgPool, Row};
-use tracing::{info, debug, error};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use ethers::types::U256;
+use sqlx::{
+    postgres::{PgPool, PgPoolOptions},
+    sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions},
+    Row,
+};
+use std::{collections::HashMap, str::FromStr, time::{Duration, SystemTime, UNIX_EPOCH}};
+use threshold::{AggregatedSignature, PartialSignature};
+use tracing::{info, debug};
 
-/// Database client for storing bridge state
-pub struct Database {
-    pool: PgPool,
+/// Ethereum locks and Polkadot burns as one relation, with a status derived
+/// from whether the transfer has been completed on the other chain
+const TRANSACTIONS_SQL: &str = r#"
+                SELECT CAST(l.tx_hash AS TEXT) AS tx_hash,
+                       CAST('ethereum' AS TEXT) AS chain,
+                       CASE WHEN p.tx_hash IS NOT NULL THEN 'confirmed'
+                            WHEN l.skipped_stale THEN 'skipped_stale'
+                            ELSE 'pending' END AS status,
+                       CAST(l.user_address AS TEXT) AS user_address,
+                       CAST(l.token_address AS TEXT) AS token,
+                       CAST(l.amount AS TEXT) AS amount,
+                       CAST(l.polkadot_address AS TEXT) AS recipient,
+                       l.block_number AS block_number,
+                       l.created_at AS created_at
+                FROM ethereum_locks l
+                LEFT JOIN processed_transactions p ON p.tx_hash = l.tx_hash AND p.chain = 'ethereum'
+                UNION ALL
+                SELECT CAST(b.tx_hash AS TEXT),
+                       CAST('polkadot' AS TEXT),
+                       CASE WHEN p.tx_hash IS NOT NULL THEN 'confirmed'
+                            WHEN b.skipped_stale THEN 'skipped_stale'
+                            ELSE 'pending' END,
+                       CAST(b.user_account AS TEXT),
+                       CAST(b.asset_id AS TEXT),
+                       CAST(b.amount AS TEXT),
+                       CAST(b.ethereum_recipient AS TEXT),
+                       CAST(b.block_number AS BIGINT),
+                       b.created_at
+                FROM polkadot_burns b
+                LEFT JOIN processed_transactions p ON p.tx_hash = b.tx_hash AND p.chain = 'polkadot'
+"#;
+
+/// Every transfer's token and amount, with burns attributed to the Ethereum
+/// token their asset ID is mapped to
+const TOKEN_TRANSFERS_SQL: &str = r#"
+                SELECT LOWER(token_address) AS token, 'in' AS direction, amount
+                FROM ethereum_locks
+                UNION ALL
+                SELECT COALESCE(LOWER(m.ethereum_address), CAST(b.asset_id AS TEXT)), 'out', b.amount
+                FROM polkadot_burns b
+                LEFT JOIN token_mappings m ON m.polkadot_asset_id = b.asset_id
+"#;
+
+/// Conditions on [`TRANSACTIONS_SQL`] rows for the filters of a
+/// [`TransactionQuery`], bound as `$1` to `$8` in field order
+const TRANSACTION_FILTERS_SQL: &str = r#"(CAST($1 AS TEXT) IS NULL OR chain = $1)
+              AND (CAST($2 AS TEXT) IS NULL OR status = $2)
+              AND (CAST($3 AS TEXT) IS NULL OR LOWER(user_address) = LOWER($3))
+              AND (CAST($4 AS TEXT) IS NULL OR LOWER(token) = LOWER($4))
+              AND (CAST($5 AS TIMESTAMP) IS NULL OR created_at >= $5)
+              AND (CAST($6 AS TIMESTAMP) IS NULL OR created_at <= $6)
+              AND (CAST($7 AS BIGINT) IS NULL OR block_number >= $7)
+              AND (CAST($8 AS BIGINT) IS NULL OR block_number <= $8)"#;
+
+/// A row selected from [`TRANSACTIONS_SQL`]
+#[derive(sqlx::FromRow)]
+struct TransactionRow {
+    tx_hash: String,
+    chain: String,
+    status: String,
+    user_address: String,
+    token: String,
+    amount: String,
+    recipient: String,
+    block_number: i64,
+    created_at: NaiveDateTime,
 }
 
-impl Database {
-    /// Create a new database client
-    pub async fn new(config: &DatabaseConfig) -> Result<Self> {
-        info!("Connecting to database at {}", config.url);
+impl From<TransactionRow> for TransactionRecord {
+    fn from(row: TransactionRow) -> Self {
+        TransactionRecord {
+            tx_hash: row.tx_hash,
+            chain: row.chain,
+            status: row.status,
+            user: row.user_address,
+            token: row.token,
+            amount: row.amount,
+            recipient: row.recipient,
+            block_number: row.block_number as u64,
+            created_at: row.created_at.and_utc(),
+        }
+    }
+}
 
-        let pool = PgPool::connect(&config.url)
-            .await
-            .map_err(|e| RelayerError::Database {
-                message: format!("Failed to connect to database: {}", e),
-            })?;
+/// A row of the `token_mappings` table
+#[derive(sqlx::FromRow)]
+struct TokenMappingRow {
+    ethereum_address: String,
+    polkadot_asset_id: i32,
+    created_at: NaiveDateTime,
+}
 
-        // Run migrations
-        let db = Self { pool };
-        db.migrate().await?;
+impl From<TokenMappingRow> for TokenMapping {
+    fn from(row: TokenMappingRow) -> Self {
+        TokenMapping {
+            ethereum_address: row.ethereum_address,
+            polkadot_asset_id: row.polkadot_asset_id as u32,
+            created_at: row.created_at.and_utc(),
+        }
+    }
+}
 
-        Ok(db)
+/// A row of the `failed_events` table
+#[derive(sqlx::FromRow)]
+struct DeadLetterRow {
+    id: i32,
+    event: String,
+    attempts: i32,
+    last_error: String,
+    failed_at: NaiveDateTime,
+}
+
+impl TryFrom<DeadLetterRow> for DeadLetter {
+    type Error = RelayerError;
+
+    fn try_from(row: DeadLetterRow) -> Result<Self> {
+        let event = serde_json::from_str(&row.event).map_err(|e| RelayerError::Database {
+            message: format!("Failed to decode dead-lettered event {}: {}", row.id, e),
+        })?;
+
+        Ok(DeadLetter {
+            id: row.id.into(),
+            event,
+            attempts: row.attempts as u32,
+            last_error: row.last_error,
+            failed_at: row.failed_at.and_utc(),
+        })
+    }
+}
+
+/// A row of the `checkpoints` table
+#[derive(sqlx::FromRow)]
+struct CheckpointRow {
+    id: i32,
+    last_ethereum_block: i64,
+    last_polkadot_block: i64,
+    ethereum_processed: i64,
+    polkadot_processed: i64,
+    state_root: String,
+    signature: String,
+    created_at: NaiveDateTime,
+}
+
+/// Filters for querying bridge transactions across both chains
+#[derive(Debug, Clone)]
+pub struct TransactionQuery {
+    /// Restrict to a single chain ("ethereum" or "polkadot")
+    pub chain: Option<String>,
+    /// Restrict to a status ("pending" or "confirmed")
+    pub status: Option<String>,
+    /// Restrict to transactions sent by this account (case-insensitive)
+    pub user: Option<String>,
+    /// Restrict to a token address or asset ID (case-insensitive)
+    pub token: Option<String>,
+    /// Only include transactions recorded at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only include transactions recorded at or before this time
+    pub until: Option<DateTime<Utc>>,
+    /// Only include transactions in this block or later
+    pub from_block: Option<u64>,
+    /// Only include transactions in this block or earlier
+    pub to_block: Option<u64>,
+    /// Maximum number of rows to return
+    pub limit: u32,
+    /// Number of rows to skip
+    pub offset: u32,
+}
+
+impl Default for TransactionQuery {
+    fn default() -> Self {
+        Self {
+            chain: None,
+            status: None,
+            user: None,
+            token: None,
+            since: None,
+            until: None,
+            from_block: None,
+            to_block: None,
+            limit: 20,
+            offset: 0,
+        }
     }
+}
 
-    /// Run database migrations
-    async fn migrate(&self) -> Result<()> {
-        info!("Running database migrations");
+/// A bridge transaction as stored by the relayer
+#[derive(Debug, Clone)]
+pub struct TransactionRecord {
+    pub tx_hash: String,
+    pub chain: String,
+    pub status: String,
+    pub user: String,
+    /// Ethereum token address for locks, asset ID for burns
+    pub token: String,
+    pub amount: String,
+    /// Destination account on the other chain
+    pub recipient: String,
+    pub block_number: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+/// An Ethereum token and the Polkadot asset it is bridged as
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenMapping {
+    pub ethereum_address: String,
+    pub polkadot_asset_id: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+/// An event that kept failing to process, set aside until an operator
+/// retries it
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    pub id: i64,
+    pub event: BridgeEvent,
+    /// Attempts made before it was dead-lettered
+    pub attempts: u32,
+    /// Error of the last attempt
+    pub last_error: String,
+    pub failed_at: DateTime<Utc>,
+}
+
+/// Transfer counts and volume for a single token
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenStats {
+    /// Ethereum locks of the token, bridged into Polkadot
+    pub transfers_in: u64,
+    /// Polkadot burns of the token, bridged back to Ethereum
+    pub transfers_out: u64,
+    /// Sum of all transferred amounts in the token's base units
+    pub volume: String,
+}
+
+/// How long transfers took from detection to completion on the other chain
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CompletionTimes {
+    /// Transfers completed within the window
+    pub completed: u64,
+    /// Mean completion time in seconds, if any transfer completed
+    pub average_secs: Option<f64>,
+    /// 95th percentile completion time in seconds, if any transfer completed
+    pub p95_secs: Option<f64>,
+}
+
+impl CompletionTimes {
+    /// Summarize completion times given in seconds, in any order
+    ///
+    /// Matches PostgreSQL's `PERCENTILE_DISC(0.95)`: the p95 is the smallest
+    /// time at or above which 95% of the times fall.
+    fn from_durations(mut durations: Vec<f64>) -> Self {
+        if durations.is_empty() {
+            return Self::default();
+        }
+        durations.sort_by(f64::total_cmp);
+
+        let completed = durations.len();
+        let p95_index = ((completed as f64 * 0.95).ceil() as usize).max(1) - 1;
+        Self {
+            completed: completed as u64,
+            average_secs: Some(durations.iter().sum::<f64>() / completed as f64),
+            p95_secs: Some(durations[p95_index]),
+        }
+    }
+}
+
+/// A relayed source-chain event and the block it was found in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeenEvent {
+    pub tx_hash: String,
+    pub block_number: u64,
+    /// Hash of the block at `block_number` when the event was relayed
+    pub block_hash: String,
+}
+
+/// A signature session as persisted, so it survives a relayer restart
+#[derive(Debug, Clone)]
+pub struct StoredSignatureSession {
+    pub tx_hash: String,
+    pub message: Vec<u8>,
+    pub format: SignatureFormat,
+    pub required_signatures: u32,
+    pub created_at: SystemTime,
+    pub partials: Vec<StoredPartialSignature>,
+}
+
+/// A partial signature of a persisted session
+#[derive(Debug, Clone)]
+pub struct StoredPartialSignature {
+    pub validator_id: String,
+    /// Connection the partial arrived from
+    pub source: String,
+    pub signature: PartialSignature,
+}
 
-        // Create tables if they don't exist
-        sqlx::query(r#"
+/// Database engine a relayer stores its state in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    Postgres,
+    Sqlite,
+}
+
+impl Backend {
+    /// Backend named by a database URL's scheme
+    pub fn from_url(url: &str) -> Result<Self> {
+        if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+            Ok(Self::Postgres)
+        } else if url.starts_with("sqlite:") {
+            Ok(Self::Sqlite)
+        } else {
+            // Only the scheme, so credentials in the URL stay out of logs
+            let scheme = url.split(':').next().unwrap_or_default();
+            Err(RelayerError::Database {
+                message: format!("Unsupported database URL scheme '{}'; expected postgres:// or sqlite:", scheme),
+            })
+        }
+    }
+}
+
+/// Advisory lock key serializing migrations across relayers sharing a database
+const MIGRATION_LOCK_ID: i64 = 0x6272_6964_6765; // "bridge"
+
+/// One schema change, applied once and recorded in `schema_migrations`
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// Position in the migration order; never reused or renumbered
+    pub version: i64,
+    pub description: &'static str,
+    /// PostgreSQL statements, run in order
+    pub postgres: &'static [&'static str],
+    /// The same change in SQLite's dialect
+    pub sqlite: &'static [&'static str],
+}
+
+impl Migration {
+    /// Statements applying this step on `backend`
+    pub fn statements(&self, backend: Backend) -> &'static [&'static str] {
+        match backend {
+            Backend::Postgres => self.postgres,
+            Backend::Sqlite => self.sqlite,
+        }
+    }
+}
+
+/// Every schema change, oldest first
+///
+/// Append new steps with the next version; editing a released step has no
+/// effect on databases that already applied it. PostgreSQL steps use `IF NOT
+/// EXISTS` so databases created before versioning adopt them without error;
+/// SQLite support postdates versioning, so its steps always run on a schema
+/// they created.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "create transfer and bridge state tables",
+        postgres: &[
+            r#"
             CREATE TABLE IF NOT EXISTS ethereum_locks (
                 id SERIAL PRIMARY KEY,
                 user_address VARCHAR(42) NOT NULL,
@@ -47,14 +390,8 @@ impl Database {
                 processed BOOLEAN DEFAULT FALSE,
                 created_at TIMESTAMP DEFAULT NOW()
             )
-        "#)
-        .execute(&self.pool)
-        .await
-        .map_err(|e| RelayerError::Database {
-            message: format!("Failed to create ethereum_locks table: {}", e),
-        })?;
-
-        sqlx::query(r#"
+            "#,
+            r#"
             CREATE TABLE IF NOT EXISTS polkadot_burns (
                 id SERIAL PRIMARY KEY,
                 user_account VARCHAR(48) NOT NULL,
@@ -66,60 +403,477 @@ impl Database {
                 processed BOOLEAN DEFAULT FALSE,
                 created_at TIMESTAMP DEFAULT NOW()
             )
-        "#)
-        .execute(&self.pool)
-        .await
-        .map_err(|e| RelayerError::Database {
-            message: format!("Failed to create polkadot_burns table: {}", e),
-        })?;
-
-        sqlx::query(r#"
+            "#,
+            r#"
             CREATE TABLE IF NOT EXISTS processed_transactions (
                 id SERIAL PRIMARY KEY,
                 tx_hash VARCHAR(66) NOT NULL UNIQUE,
                 chain VARCHAR(20) NOT NULL,
                 processed_at TIMESTAMP DEFAULT NOW()
             )
-        "#)
-        .execute(&self.pool)
-        .await
-        .map_err(|e| RelayerError::Database {
-            message: format!("Failed to create processed_transactions table: {}", e),
-        })?;
-
-        sqlx::query(r#"
+            "#,
+            r#"
             CREATE TABLE IF NOT EXISTS bridge_state (
                 id SERIAL PRIMARY KEY,
                 key VARCHAR(50) NOT NULL UNIQUE,
                 value VARCHAR(100) NOT NULL,
                 updated_at TIMESTAMP DEFAULT NOW()
             )
-        "#)
-        .execute(&self.pool)
-        .await
-        .map_err(|e| RelayerError::Database {
-            message: format!("Failed to create bridge_state table: {}", e),
-        })?;
-
-        sqlx::query(r#"
+            "#,
+            r#"
             CREATE TABLE IF NOT EXISTS token_mappings (
                 id SERIAL PRIMARY KEY,
                 ethereum_address VARCHAR(42) NOT NULL UNIQUE,
                 polkadot_asset_id INTEGER NOT NULL UNIQUE,
                 created_at TIMESTAMP DEFAULT NOW()
             )
-        "#)
-        .execute(&self.pool)
-        .await
-        .map_err(|e| RelayerError::Database {
-            message: format!("Failed to create token_mappings table: {}", e),
-        })?;
+            "#,
+        ],
+        sqlite: &[
+            r#"
+            CREATE TABLE ethereum_locks (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                user_address VARCHAR(42) NOT NULL,
+                token_address VARCHAR(42) NOT NULL,
+                amount VARCHAR(78) NOT NULL,
+                polkadot_address VARCHAR(66) NOT NULL,
+                tx_hash VARCHAR(66) NOT NULL UNIQUE,
+                block_number BIGINT NOT NULL,
+                processed BOOLEAN DEFAULT FALSE,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+            r#"
+            CREATE TABLE polkadot_burns (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                user_account VARCHAR(48) NOT NULL,
+                asset_id INTEGER NOT NULL,
+                amount VARCHAR(78) NOT NULL,
+                ethereum_recipient VARCHAR(42) NOT NULL,
+                tx_hash VARCHAR(66) NOT NULL UNIQUE,
+                block_number INTEGER NOT NULL,
+                processed BOOLEAN DEFAULT FALSE,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+            r#"
+            CREATE TABLE processed_transactions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                tx_hash VARCHAR(66) NOT NULL UNIQUE,
+                chain VARCHAR(20) NOT NULL,
+                processed_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+            r#"
+            CREATE TABLE bridge_state (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                key VARCHAR(50) NOT NULL UNIQUE,
+                value VARCHAR(100) NOT NULL,
+                updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+            r#"
+            CREATE TABLE token_mappings (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                ethereum_address VARCHAR(42) NOT NULL UNIQUE,
+                polkadot_asset_id INTEGER NOT NULL UNIQUE,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+        ],
+    },
+    Migration {
+        version: 2,
+        description: "flag events held as stale",
+        postgres: &[
+            "ALTER TABLE ethereum_locks ADD COLUMN IF NOT EXISTS skipped_stale BOOLEAN NOT NULL DEFAULT FALSE",
+            "ALTER TABLE polkadot_burns ADD COLUMN IF NOT EXISTS skipped_stale BOOLEAN NOT NULL DEFAULT FALSE",
+        ],
+        sqlite: &[
+            "ALTER TABLE ethereum_locks ADD COLUMN skipped_stale BOOLEAN NOT NULL DEFAULT FALSE",
+            "ALTER TABLE polkadot_burns ADD COLUMN skipped_stale BOOLEAN NOT NULL DEFAULT FALSE",
+        ],
+    },
+    Migration {
+        version: 3,
+        description: "create checkpoints table",
+        postgres: &[
+            r#"
+            CREATE TABLE IF NOT EXISTS checkpoints (
+                id SERIAL PRIMARY KEY,
+                last_ethereum_block BIGINT NOT NULL,
+                last_polkadot_block BIGINT NOT NULL,
+                ethereum_processed BIGINT NOT NULL,
+                polkadot_processed BIGINT NOT NULL,
+                state_root VARCHAR(66) NOT NULL,
+                signature TEXT NOT NULL,
+                created_at TIMESTAMP DEFAULT NOW()
+            )
+            "#,
+        ],
+        sqlite: &[
+            r#"
+            CREATE TABLE checkpoints (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                last_ethereum_block BIGINT NOT NULL,
+                last_polkadot_block BIGINT NOT NULL,
+                ethereum_processed BIGINT NOT NULL,
+                polkadot_processed BIGINT NOT NULL,
+                state_root VARCHAR(66) NOT NULL,
+                signature TEXT NOT NULL,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+        ],
+    },
+    Migration {
+        version: 4,
+        description: "record the blocks relayed events were found in",
+        postgres: &[
+            r#"
+            CREATE TABLE IF NOT EXISTS seen_events (
+                chain VARCHAR(20) NOT NULL,
+                tx_hash VARCHAR(66) NOT NULL,
+                block_number BIGINT NOT NULL,
+                block_hash VARCHAR(66) NOT NULL,
+                PRIMARY KEY (chain, tx_hash)
+            )
+            "#,
+            "CREATE INDEX IF NOT EXISTS seen_events_block ON seen_events (chain, block_number)",
+        ],
+        sqlite: &[
+            r#"
+            CREATE TABLE IF NOT EXISTS seen_events (
+                chain VARCHAR(20) NOT NULL,
+                tx_hash VARCHAR(66) NOT NULL,
+                block_number BIGINT NOT NULL,
+                block_hash VARCHAR(66) NOT NULL,
+                PRIMARY KEY (chain, tx_hash)
+            )
+            "#,
+            "CREATE INDEX IF NOT EXISTS seen_events_block ON seen_events (chain, block_number)",
+        ],
+    },
+    Migration {
+        version: 5,
+        description: "persist signature sessions and their partials",
+        postgres: &[
+            r#"
+            CREATE TABLE IF NOT EXISTS signature_sessions (
+                tx_hash VARCHAR(128) PRIMARY KEY,
+                message TEXT NOT NULL,
+                required_signatures INTEGER NOT NULL,
+                created_at_ms BIGINT NOT NULL
+            )
+            "#,
+            r#"
+            CREATE TABLE IF NOT EXISTS partial_signatures (
+                tx_hash VARCHAR(128) NOT NULL,
+                validator_id VARCHAR(100) NOT NULL,
+                source TEXT NOT NULL,
+                signature TEXT NOT NULL,
+                PRIMARY KEY (tx_hash, validator_id)
+            )
+            "#,
+        ],
+        sqlite: &[
+            r#"
+            CREATE TABLE IF NOT EXISTS signature_sessions (
+                tx_hash VARCHAR(128) PRIMARY KEY,
+                message TEXT NOT NULL,
+                required_signatures INTEGER NOT NULL,
+                created_at_ms BIGINT NOT NULL
+            )
+            "#,
+            r#"
+            CREATE TABLE IF NOT EXISTS partial_signatures (
+                tx_hash VARCHAR(128) NOT NULL,
+                validator_id VARCHAR(100) NOT NULL,
+                source TEXT NOT NULL,
+                signature TEXT NOT NULL,
+                PRIMARY KEY (tx_hash, validator_id)
+            )
+            "#,
+        ],
+    },
+    Migration {
+        version: 6,
+        description: "dead-letter events that exhaust their retries",
+        postgres: &[
+            r#"
+            CREATE TABLE IF NOT EXISTS failed_events (
+                id SERIAL PRIMARY KEY,
+                chain VARCHAR(20) NOT NULL,
+                tx_hash VARCHAR(128) NOT NULL,
+                event TEXT NOT NULL,
+                attempts INTEGER NOT NULL,
+                last_error TEXT NOT NULL,
+                failed_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+        ],
+        sqlite: &[
+            r#"
+            CREATE TABLE IF NOT EXISTS failed_events (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                chain VARCHAR(20) NOT NULL,
+                tx_hash VARCHAR(128) NOT NULL,
+                event TEXT NOT NULL,
+                attempts INTEGER NOT NULL,
+                last_error TEXT NOT NULL,
+                failed_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+        ],
+    },
+    Migration {
+        version: 7,
+        description: "claim destination submissions and record their transactions",
+        postgres: &[
+            "ALTER TABLE processed_transactions ADD COLUMN IF NOT EXISTS destination_tx_hash VARCHAR(128)",
+            r#"
+            CREATE TABLE IF NOT EXISTS pending_submissions (
+                chain VARCHAR(20) NOT NULL,
+                tx_hash VARCHAR(128) NOT NULL,
+                claimed_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (chain, tx_hash)
+            )
+            "#,
+        ],
+        sqlite: &[
+            "ALTER TABLE processed_transactions ADD COLUMN destination_tx_hash VARCHAR(128)",
+            r#"
+            CREATE TABLE IF NOT EXISTS pending_submissions (
+                chain VARCHAR(20) NOT NULL,
+                tx_hash VARCHAR(128) NOT NULL,
+                claimed_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (chain, tx_hash)
+            )
+            "#,
+        ],
+    },
+    Migration {
+        version: 8,
+        description: "record how each signature session's partials are signed",
+        postgres: &[
+            "ALTER TABLE signature_sessions ADD COLUMN IF NOT EXISTS signature_format VARCHAR(20) NOT NULL DEFAULT 'threshold'",
+        ],
+        sqlite: &[
+            "ALTER TABLE signature_sessions ADD COLUMN signature_format VARCHAR(20) NOT NULL DEFAULT 'threshold'",
+        ],
+    },
+];
+
+/// Describe a failed migration query as a database error
+fn migration_error(what: impl std::fmt::Display) -> impl FnOnce(sqlx::Error) -> RelayerError {
+    move |e| RelayerError::Database {
+        message: format!("{}: {}", what, e),
+    }
+}
+
+/// Whether a SQLite URL names an in-memory database
+fn is_in_memory(url: &str) -> bool {
+    url.contains(":memory:") || url.contains("mode=memory")
+}
+
+/// Connection pool of whichever backend the database URL names
+enum Pool {
+    Postgres(PgPool),
+    Sqlite(SqlitePool),
+}
+
+/// Evaluate `$body` with `$conn` bound to the backend-specific pool
+///
+/// The body is compiled once per backend, so it must use SQL both dialects
+/// accept and turn rows into backend-neutral values before returning.
+macro_rules! on_pool {
+    ($pool:expr, $conn:ident => $body:expr) => {
+        match $pool {
+            Pool::Postgres($conn) => $body,
+            Pool::Sqlite($conn) => $body,
+        }
+    };
+}
+
+/// Database client for storing bridge state
+pub struct Database {
+    pool: Pool,
+}
+
+impl Database {
+    /// Create a new database client
+    pub async fn new(config: &DatabaseConfig) -> Result<Self> {
+        info!("Connecting to database at {}", config.url);
+
+        let pool = Self::connect(config).await?;
+
+        // Run migrations
+        let db = Self { pool };
+        db.migrate().await?;
+
+        Ok(db)
+    }
+
+    /// Open a pool sized and timed out as `config` asks
+    async fn connect(config: &DatabaseConfig) -> Result<Pool> {
+        let acquire_timeout = Duration::from_secs(config.connect_timeout);
+
+        let pool = match Backend::from_url(&config.url)? {
+            Backend::Postgres => PgPoolOptions::new()
+                .max_connections(config.max_connections)
+                .min_connections(config.min_connections)
+                .acquire_timeout(acquire_timeout)
+                .connect(&config.url)
+                .await
+                .map(Pool::Postgres),
+            Backend::Sqlite => {
+                let options = SqliteConnectOptions::from_str(&config.url)
+                    .map_err(|e| RelayerError::Database {
+                        message: format!("Invalid SQLite URL: {}", e),
+                    })?
+                    .create_if_missing(true);
+
+                // An in-memory database lives only as long as a connection to
+                // it, so keep exactly one open for the life of the pool
+                let pool_options = if is_in_memory(&config.url) {
+                    SqlitePoolOptions::new()
+                        .max_connections(1)
+                        .min_connections(1)
+                        .idle_timeout(None)
+                        .max_lifetime(None)
+                } else {
+                    SqlitePoolOptions::new()
+                        .max_connections(config.max_connections)
+                        .min_connections(config.min_connections)
+                };
+
+                pool_options
+                    .acquire_timeout(acquire_timeout)
+                    .connect_with(options)
+                    .await
+                    .map(Pool::Sqlite)
+            }
+        };
+
+        pool.map_err(|e| RelayerError::Database {
+            message: format!("Failed to connect to database: {}", e),
+        })
+    }
+
+    /// Backend this client is connected to
+    pub fn backend(&self) -> Backend {
+        match self.pool {
+            Pool::Postgres(_) => Backend::Postgres,
+            Pool::Sqlite(_) => Backend::Sqlite,
+        }
+    }
+
+    /// Bring the schema up to date with [`MIGRATIONS`]
+    pub async fn migrate(&self) -> Result<()> {
+        self.apply_migrations(MIGRATIONS).await
+    }
+
+    /// Apply each of `migrations` not yet recorded in `schema_migrations`
+    ///
+    /// Runs in one transaction, so a failed step leaves the schema as it was.
+    /// On PostgreSQL the transaction also holds an advisory lock, so relayers
+    /// starting together apply each step once. Fails without changing
+    /// anything if the database records a version this binary does not know,
+    /// i.e. it was migrated by a newer relayer, or if a known step is missing
+    /// below the latest applied one.
+    pub async fn apply_migrations(&self, migrations: &[Migration]) -> Result<()> {
+        info!("Running database migrations");
+
+        let backend = self.backend();
+        on_pool!(&self.pool, pool => {
+            let mut tx = pool.begin().await.map_err(migration_error("Failed to start migration"))?;
+
+            if backend == Backend::Postgres {
+                sqlx::query("SELECT pg_advisory_xact_lock($1)")
+                    .bind(MIGRATION_LOCK_ID)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(migration_error("Failed to lock schema for migration"))?;
+            }
+
+            sqlx::query(r#"
+                CREATE TABLE IF NOT EXISTS schema_migrations (
+                    version BIGINT PRIMARY KEY,
+                    description TEXT NOT NULL,
+                    applied_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+                )
+            "#)
+            .execute(&mut *tx)
+            .await
+            .map_err(migration_error("Failed to create schema_migrations table"))?;
+
+            let applied: Vec<i64> = sqlx::query_scalar("SELECT version FROM schema_migrations ORDER BY version")
+                .fetch_all(&mut *tx)
+                .await
+                .map_err(migration_error("Failed to read applied migrations"))?;
+
+            if let Some(unknown) = applied.iter().find(|v| !migrations.iter().any(|m| m.version == **v)) {
+                return Err(RelayerError::Database {
+                    message: format!(
+                        "Database schema has migration {} that this relayer does not know; it was migrated by a newer version",
+                        unknown
+                    ),
+                });
+            }
+
+            let latest = applied.last().copied().unwrap_or(0);
+            if let Some(missing) = migrations
+                .iter()
+                .find(|m| m.version < latest && !applied.contains(&m.version))
+            {
+                return Err(RelayerError::Database {
+                    message: format!(
+                        "Migration {} ({}) was never applied, but later migration {} was",
+                        missing.version, missing.description, latest
+                    ),
+                });
+            }
+
+            for migration in migrations.iter().filter(|m| m.version > latest) {
+                info!("Applying migration {}: {}", migration.version, migration.description);
+
+                for statement in migration.statements(backend) {
+                    sqlx::query(statement)
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(migration_error(format!("Migration {} failed", migration.version)))?;
+                }
+
+                sqlx::query("INSERT INTO schema_migrations (version, description) VALUES ($1, $2)")
+                    .bind(migration.version)
+                    .bind(migration.description)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(migration_error(format!("Failed to record migration {}", migration.version)))?;
+            }
+
+            tx.commit().await.map_err(migration_error("Failed to commit migrations"))?;
+        });
 
         info!("Database migrations completed");
         Ok(())
     }
 
+    /// Versions recorded in `schema_migrations`, oldest first
+    pub async fn applied_migrations(&self) -> Result<Vec<i64>> {
+        on_pool!(&self.pool, pool => {
+            sqlx::query_scalar::<_, i64>("SELECT version FROM schema_migrations ORDER BY version")
+                .fetch_all(pool)
+                .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to read applied migrations: {}", e),
+        })
+    }
+
     /// Store an Ethereum lock event
+    ///
+    /// Returns `false` if the event was already stored.
     pub async fn store_ethereum_lock(
         &self,
         user: &str,
@@ -128,29 +882,37 @@ impl Database {
         polkadot_address: &str,
         tx_hash: &str,
         block_number: u64,
-    ) -> Result<()> {
-        sqlx::query(r#"
-            INSERT INTO ethereum_locks (user_address, token_address, amount, polkadot_address, tx_hash, block_number)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            ON CONFLICT (tx_hash) DO NOTHING
-        "#)
-        .bind(user)
-        .bind(token)
-        .bind(amount)
-        .bind(polkadot_address)
-        .bind(tx_hash)
-        .bind(block_number as i64)
-        .execute(&self.pool)
-        .await
+    ) -> Result<bool> {
+        let rows_affected = on_pool!(&self.pool, pool => {
+            sqlx::query(r#"
+                INSERT INTO ethereum_locks (user_address, token_address, amount, polkadot_address, tx_hash, block_number)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (tx_hash) DO NOTHING
+            "#)
+            .bind(user)
+            .bind(token)
+            .bind(amount)
+            .bind(polkadot_address)
+            .bind(tx_hash)
+            .bind(block_number as i64)
+            .execute(pool)
+            .await
+            .map(|result| result.rows_affected())
+        })
         .map_err(|e| RelayerError::Database {
             message: format!("Failed to store Ethereum lock: {}", e),
         })?;
 
-        debug!("Stored Ethereum lock: {}", tx_hash);
-        Ok(())
+        let inserted = rows_affected > 0;
+        if inserted {
+            debug!("Stored Ethereum lock: {}", tx_hash);
+        }
+        Ok(inserted)
     }
 
     /// Store a Polkadot burn event
+    ///
+    /// Returns `false` if the event was already stored.
     pub async fn store_polkadot_burn(
         &self,
         user: &str,
@@ -159,65 +921,374 @@ impl Database {
         ethereum_recipient: &str,
         tx_hash: &str,
         block_number: u32,
-    ) -> Result<()> {
-        sqlx::query(r#"
-            INSERT INTO polkadot_burns (user_account, asset_id, amount, ethereum_recipient, tx_hash, block_number)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            ON CONFLICT (tx_hash) DO NOTHING
-        "#)
-        .bind(user)
-        .bind(asset_id as i32)
-        .bind(amount)
-        .bind(ethereum_recipient)
-        .bind(tx_hash)
-        .bind(block_number as i32)
-        .execute(&self.pool)
-        .await
+    ) -> Result<bool> {
+        let rows_affected = on_pool!(&self.pool, pool => {
+            sqlx::query(r#"
+                INSERT INTO polkadot_burns (user_account, asset_id, amount, ethereum_recipient, tx_hash, block_number)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (tx_hash) DO NOTHING
+            "#)
+            .bind(user)
+            .bind(asset_id as i32)
+            .bind(amount)
+            .bind(ethereum_recipient)
+            .bind(tx_hash)
+            .bind(block_number as i32)
+            .execute(pool)
+            .await
+            .map(|result| result.rows_affected())
+        })
         .map_err(|e| RelayerError::Database {
             message: format!("Failed to store Polkadot burn: {}", e),
         })?;
 
-        debug!("Stored Polkadot burn: {}", tx_hash);
-        Ok(())
+        let inserted = rows_affected > 0;
+        if inserted {
+            debug!("Stored Polkadot burn: {}", tx_hash);
+        }
+        Ok(inserted)
     }
 
-    /// Check if an Ethereum transaction is processed
-    pub async fn is_ethereum_tx_processed(&self, tx_hash: &str) -> Result<bool> {
-        let row = sqlx::query("SELECT COUNT(*) as count FROM processed_transactions WHERE tx_hash = $1 AND chain = 'ethereum'")
+    /// Store a source-chain event, returning `false` if it was already stored
+    pub async fn store_event(&self, event: &BridgeEvent) -> Result<bool> {
+        match event {
+            BridgeEvent::EthereumLock {
+                user,
+                token,
+                amount,
+                polkadot_address,
+                tx_hash,
+                block_number,
+            } => {
+                self.store_ethereum_lock(user, token, amount, polkadot_address, tx_hash, *block_number)
+                    .await
+            }
+            BridgeEvent::PolkadotBurn {
+                user,
+                asset_id,
+                amount,
+                ethereum_recipient,
+                tx_hash,
+                block_number,
+            } => {
+                self.store_polkadot_burn(user, *asset_id, amount, ethereum_recipient, tx_hash, *block_number)
+                    .await
+            }
+        }
+    }
+
+    /// Flag a stored event as too old to relay until an admin releases it
+    pub async fn mark_stale(&self, event: &BridgeEvent) -> Result<()> {
+        let table = match event {
+            BridgeEvent::EthereumLock { .. } => "ethereum_locks",
+            BridgeEvent::PolkadotBurn { .. } => "polkadot_burns",
+        };
+        let sql = format!("UPDATE {} SET skipped_stale = TRUE WHERE tx_hash = $1", table);
+
+        on_pool!(&self.pool, pool => {
+            sqlx::query(&sql)
+                .bind(event.tx_hash())
+                .execute(pool)
+                .await
+                .map(|_| ())
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to mark {} stale: {}", event.tx_hash(), e),
+        })
+    }
+
+    /// Clear the stale flag of an event and return it for relaying
+    ///
+    /// Returns `None` if no stored event with that hash is flagged stale.
+    pub async fn release_stale_event(&self, tx_hash: &str) -> Result<Option<BridgeEvent>> {
+        let lock = on_pool!(&self.pool, pool => {
+            sqlx::query(r#"
+                UPDATE ethereum_locks SET skipped_stale = FALSE
+                WHERE tx_hash = $1 AND skipped_stale
+                RETURNING user_address, token_address, amount, polkadot_address, tx_hash, block_number
+            "#)
             .bind(tx_hash)
-            .fetch_one(&self.pool)
+            .fetch_optional(pool)
             .await
-            .map_err(|e| RelayerError::Database {
-                message: format!("Failed to check Ethereum tx status: {}", e),
-            })?;
+            .map(|row| row.map(|row| BridgeEvent::EthereumLock {
+                user: row.get("user_address"),
+                token: row.get("token_address"),
+                amount: row.get("amount"),
+                polkadot_address: row.get("polkadot_address"),
+                tx_hash: row.get("tx_hash"),
+                block_number: row.get::<i64, _>("block_number") as u64,
+            }))
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to release stale lock {}: {}", tx_hash, e),
+        })?;
+
+        if lock.is_some() {
+            return Ok(lock);
+        }
+
+        on_pool!(&self.pool, pool => {
+            sqlx::query(r#"
+                UPDATE polkadot_burns SET skipped_stale = FALSE
+                WHERE tx_hash = $1 AND skipped_stale
+                RETURNING user_account, asset_id, amount, ethereum_recipient, tx_hash, block_number
+            "#)
+            .bind(tx_hash)
+            .fetch_optional(pool)
+            .await
+            .map(|row| row.map(|row| BridgeEvent::PolkadotBurn {
+                user: row.get("user_account"),
+                asset_id: row.get::<i32, _>("asset_id") as u32,
+                amount: row.get("amount"),
+                ethereum_recipient: row.get("ethereum_recipient"),
+                tx_hash: row.get("tx_hash"),
+                block_number: row.get::<i32, _>("block_number") as u32,
+            }))
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to release stale burn {}: {}", tx_hash, e),
+        })
+    }
+
+    /// Set aside an event that failed `attempts` times, returning its
+    /// dead-letter id
+    pub async fn add_dead_letter(&self, event: &BridgeEvent, attempts: u32, last_error: &str) -> Result<i64> {
+        let encoded = serde_json::to_string(event).map_err(|e| RelayerError::Database {
+            message: format!("Failed to encode event {}: {}", event.tx_hash(), e),
+        })?;
+
+        let id = on_pool!(&self.pool, pool => {
+            sqlx::query_scalar::<_, i32>(r#"
+                INSERT INTO failed_events (chain, tx_hash, event, attempts, last_error)
+                VALUES ($1, $2, $3, $4, $5)
+                RETURNING id
+            "#)
+            .bind(event.chain())
+            .bind(event.tx_hash())
+            .bind(&encoded)
+            .bind(attempts as i32)
+            .bind(last_error)
+            .fetch_one(pool)
+            .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to dead-letter event {}: {}", event.tx_hash(), e),
+        })?;
+
+        debug!("Dead-lettered {} event {} as {}", event.chain(), event.tx_hash(), id);
+        Ok(id.into())
+    }
+
+    /// Every dead-lettered event, oldest first
+    pub async fn list_dead_letters(&self) -> Result<Vec<DeadLetter>> {
+        let rows = on_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, DeadLetterRow>(
+                "SELECT id, event, attempts, last_error, failed_at FROM failed_events ORDER BY id ASC",
+            )
+            .fetch_all(pool)
+            .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to list dead-lettered events: {}", e),
+        })?;
+
+        rows.into_iter().map(DeadLetter::try_from).collect()
+    }
+
+    /// Remove a dead-lettered event and return it for relaying
+    ///
+    /// Returns `None` if there is no dead letter with that id.
+    pub async fn take_dead_letter(&self, id: i64) -> Result<Option<DeadLetter>> {
+        let row = on_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, DeadLetterRow>(r#"
+                DELETE FROM failed_events WHERE id = $1
+                RETURNING id, event, attempts, last_error, failed_at
+            "#)
+            .bind(id as i32)
+            .fetch_optional(pool)
+            .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to take dead-lettered event {}: {}", id, e),
+        })?;
+
+        row.map(DeadLetter::try_from).transpose()
+    }
+
+    /// Check if an Ethereum transaction is processed
+    pub async fn is_ethereum_tx_processed(&self, tx_hash: &str) -> Result<bool> {
+        let count = on_pool!(&self.pool, pool => {
+            sqlx::query("SELECT COUNT(*) as count FROM processed_transactions WHERE tx_hash = $1 AND chain = 'ethereum'")
+                .bind(tx_hash)
+                .fetch_one(pool)
+                .await
+                .map(|row| row.get::<i64, _>("count"))
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to check Ethereum tx status: {}", e),
+        })?;
 
-        let count: i64 = row.get("count");
         Ok(count > 0)
     }
 
     /// Check if a Polkadot transaction is processed
     pub async fn is_polkadot_tx_processed(&self, tx_hash: &str) -> Result<bool> {
-        let row = sqlx::query("SELECT COUNT(*) as count FROM processed_transactions WHERE tx_hash = $1 AND chain = 'polkadot'")
-            .bind(tx_hash)
-            .fetch_one(&self.pool)
+        let count = on_pool!(&self.pool, pool => {
+            sqlx::query("SELECT COUNT(*) as count FROM processed_transactions WHERE tx_hash = $1 AND chain = 'polkadot'")
+                .bind(tx_hash)
+                .fetch_one(pool)
+                .await
+                .map(|row| row.get::<i64, _>("count"))
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to check Polkadot tx status: {}", e),
+        })?;
+
+        Ok(count > 0)
+    }
+
+    /// Claim the submission of the destination transaction for a transfer
+    /// from `chain`, returning `false` if it is already processed or claimed
+    ///
+    /// A claim is taken before anything is submitted and only cleared by
+    /// [`complete_submission`](Self::complete_submission) or
+    /// [`release_submission`](Self::release_submission), so a relayer that
+    /// stops between submitting and recording a transfer never submits it
+    /// again. The processed check and the claim share one transaction, so a
+    /// concurrent completion cannot slip between them.
+    pub async fn claim_submission(&self, chain: &str, tx_hash: &str) -> Result<bool> {
+        on_pool!(&self.pool, pool => {
+            async {
+                let mut tx = pool.begin().await?;
+
+                let processed: i64 = sqlx::query_scalar(
+                    "SELECT COUNT(*) FROM processed_transactions WHERE tx_hash = $1 AND chain = $2",
+                )
+                .bind(tx_hash)
+                .bind(chain)
+                .fetch_one(&mut *tx)
+                .await?;
+                if processed > 0 {
+                    return Ok(false);
+                }
+
+                let claimed = sqlx::query(
+                    "INSERT INTO pending_submissions (chain, tx_hash) VALUES ($1, $2) ON CONFLICT (chain, tx_hash) DO NOTHING",
+                )
+                .bind(chain)
+                .bind(tx_hash)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected() > 0;
+
+                tx.commit().await?;
+                Ok::<_, sqlx::Error>(claimed)
+            }
             .await
-            .map_err(|e| RelayerError::Database {
-                message: format!("Failed to check Polkadot tx status: {}", e),
-            })?;
+        })
+        .map_err(|e: sqlx::Error| RelayerError::Database {
+            message: format!("Failed to claim submission for {} tx {}: {}", chain, tx_hash, e),
+        })
+    }
+
+    /// Whether the submission for a transfer from `chain` is claimed but not
+    /// yet completed
+    pub async fn is_submission_pending(&self, chain: &str, tx_hash: &str) -> Result<bool> {
+        let count = on_pool!(&self.pool, pool => {
+            sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM pending_submissions WHERE chain = $1 AND tx_hash = $2")
+                .bind(chain)
+                .bind(tx_hash)
+                .fetch_one(pool)
+                .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to check submission for {} tx {}: {}", chain, tx_hash, e),
+        })?;
 
-        let count: i64 = row.get("count");
         Ok(count > 0)
     }
 
-    /// Mark an Ethereum transaction as processed
-    pub async fn mark_ethereum_tx_processed(&self, tx_hash: &str) -> Result<()> {
-        sqlx::query("INSERT INTO processed_transactions (tx_hash, chain) VALUES ($1, 'ethereum') ON CONFLICT (tx_hash) DO NOTHING")
+    /// Mark a transfer from `chain` processed by `destination_tx_hash` and
+    /// clear its claim, in one transaction
+    pub async fn complete_submission(&self, chain: &str, tx_hash: &str, destination_tx_hash: &str) -> Result<()> {
+        on_pool!(&self.pool, pool => {
+            async {
+                let mut tx = pool.begin().await?;
+
+                sqlx::query(
+                    "INSERT INTO processed_transactions (tx_hash, chain, destination_tx_hash) VALUES ($1, $2, $3) ON CONFLICT (tx_hash) DO NOTHING",
+                )
+                .bind(tx_hash)
+                .bind(chain)
+                .bind(destination_tx_hash)
+                .execute(&mut *tx)
+                .await?;
+
+                sqlx::query("DELETE FROM pending_submissions WHERE chain = $1 AND tx_hash = $2")
+                    .bind(chain)
+                    .bind(tx_hash)
+                    .execute(&mut *tx)
+                    .await?;
+
+                tx.commit().await
+            }
+            .await
+        })
+        .map_err(|e: sqlx::Error| RelayerError::Database {
+            message: format!("Failed to record submission for {} tx {}: {}", chain, tx_hash, e),
+        })?;
+
+        debug!("Marked {} tx {} as processed by {}", chain, tx_hash, destination_tx_hash);
+        Ok(())
+    }
+
+    /// Clear the claim on a transfer from `chain` whose submission failed, so
+    /// it can be submitted again
+    pub async fn release_submission(&self, chain: &str, tx_hash: &str) -> Result<()> {
+        on_pool!(&self.pool, pool => {
+            sqlx::query("DELETE FROM pending_submissions WHERE chain = $1 AND tx_hash = $2")
+                .bind(chain)
+                .bind(tx_hash)
+                .execute(pool)
+                .await
+                .map(|_| ())
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to release submission for {} tx {}: {}", chain, tx_hash, e),
+        })
+    }
+
+    /// Destination transaction that completed a transfer from `chain`, if
+    /// one was recorded
+    pub async fn destination_tx_hash(&self, chain: &str, tx_hash: &str) -> Result<Option<String>> {
+        let destination = on_pool!(&self.pool, pool => {
+            sqlx::query_scalar::<_, Option<String>>(
+                "SELECT destination_tx_hash FROM processed_transactions WHERE tx_hash = $1 AND chain = $2",
+            )
             .bind(tx_hash)
-            .execute(&self.pool)
+            .bind(chain)
+            .fetch_optional(pool)
             .await
-            .map_err(|e| RelayerError::Database {
-                message: format!("Failed to mark Ethereum tx as processed: {}", e),
-            })?;
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to look up destination of {} tx {}: {}", chain, tx_hash, e),
+        })?;
+
+        Ok(destination.flatten())
+    }
+
+    /// Mark an Ethereum transaction as processed
+    pub async fn mark_ethereum_tx_processed(&self, tx_hash: &str) -> Result<()> {
+        on_pool!(&self.pool, pool => {
+            sqlx::query("INSERT INTO processed_transactions (tx_hash, chain) VALUES ($1, 'ethereum') ON CONFLICT (tx_hash) DO NOTHING")
+                .bind(tx_hash)
+                .execute(pool)
+                .await
+                .map(|_| ())
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to mark Ethereum tx as processed: {}", e),
+        })?;
 
         debug!("Marked Ethereum tx as processed: {}", tx_hash);
         Ok(())
@@ -225,124 +1296,798 @@ impl Database {
 
     /// Mark a Polkadot transaction as processed
     pub async fn mark_polkadot_tx_processed(&self, tx_hash: &str) -> Result<()> {
-        sqlx::query("INSERT INTO processed_transactions (tx_hash, chain) VALUES ($1, 'polkadot') ON CONFLICT (tx_hash) DO NOTHING")
-            .bind(tx_hash)
-            .execute(&self.pool)
-            .await
-            .map_err(|e| RelayerError::Database {
-                message: format!("Failed to mark Polkadot tx as processed: {}", e),
-            })?;
+        on_pool!(&self.pool, pool => {
+            sqlx::query("INSERT INTO processed_transactions (tx_hash, chain) VALUES ($1, 'polkadot') ON CONFLICT (tx_hash) DO NOTHING")
+                .bind(tx_hash)
+                .execute(pool)
+                .await
+                .map(|_| ())
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to mark Polkadot tx as processed: {}", e),
+        })?;
 
         debug!("Marked Polkadot tx as processed: {}", tx_hash);
         Ok(())
     }
 
+    /// Read a value from `bridge_state`
+    async fn bridge_state(&self, key: &str) -> std::result::Result<Option<String>, sqlx::Error> {
+        on_pool!(&self.pool, pool => {
+            sqlx::query("SELECT value FROM bridge_state WHERE key = $1")
+                .bind(key)
+                .fetch_optional(pool)
+                .await
+                .map(|row| row.map(|row| row.get("value")))
+        })
+    }
+
+    /// Insert or overwrite a value in `bridge_state`
+    async fn set_bridge_state(&self, key: &str, value: String) -> std::result::Result<(), sqlx::Error> {
+        on_pool!(&self.pool, pool => {
+            sqlx::query(r#"
+                INSERT INTO bridge_state (key, value) VALUES ($1, $2)
+                ON CONFLICT (key) DO UPDATE SET value = $2, updated_at = CURRENT_TIMESTAMP
+            "#)
+            .bind(key)
+            .bind(&value)
+            .execute(pool)
+            .await
+            .map(|_| ())
+        })
+    }
+
     /// Get last processed Ethereum block
     pub async fn get_last_processed_ethereum_block(&self) -> Result<Option<u64>> {
-        let row = sqlx::query("SELECT value FROM bridge_state WHERE key = 'last_ethereum_block'")
-            .fetch_optional(&self.pool)
-            .await
-            .map_err(|e| RelayerError::Database {
-                message: format!("Failed to get last Ethereum block: {}", e),
-            })?;
+        let value = self.bridge_state("last_ethereum_block").await.map_err(|e| RelayerError::Database {
+            message: format!("Failed to get last Ethereum block: {}", e),
+        })?;
 
-        if let Some(row) = row {
-            let value: String = row.get("value");
-            Ok(Some(value.parse().unwrap_or(0)))
-        } else {
-            Ok(None)
-        }
+        Ok(value.map(|value| value.parse().unwrap_or(0)))
     }
 
     /// Set last processed Ethereum block
     pub async fn set_last_processed_ethereum_block(&self, block_number: u64) -> Result<()> {
-        sqlx::query(r#"
-            INSERT INTO bridge_state (key, value) VALUES ('last_ethereum_block', $1)
-            ON CONFLICT (key) DO UPDATE SET value = $1, updated_at = NOW()
-        "#)
-        .bind(block_number.to_string())
-        .execute(&self.pool)
-        .await
-        .map_err(|e| RelayerError::Database {
-            message: format!("Failed to set last Ethereum block: {}", e),
-        })?;
-
-        Ok(())
+        self.set_bridge_state("last_ethereum_block", block_number.to_string())
+            .await
+            .map_err(|e| RelayerError::Database {
+                message: format!("Failed to set last Ethereum block: {}", e),
+            })
     }
 
     /// Get last processed Polkadot block
     pub async fn get_last_processed_polkadot_block(&self) -> Result<Option<u32>> {
-        let row = sqlx::query("SELECT value FROM bridge_state WHERE key = 'last_polkadot_block'")
-            .fetch_optional(&self.pool)
-            .await
-            .map_err(|e| RelayerError::Database {
-                message: format!("Failed to get last Polkadot block: {}", e),
-            })?;
+        let value = self.bridge_state("last_polkadot_block").await.map_err(|e| RelayerError::Database {
+            message: format!("Failed to get last Polkadot block: {}", e),
+        })?;
 
-        if let Some(row) = row {
-            let value: String = row.get("value");
-            Ok(Some(value.parse().unwrap_or(0)))
-        } else {
-            Ok(None)
-        }
+        Ok(value.map(|value| value.parse().unwrap_or(0)))
     }
 
     /// Set last processed Polkadot block
     pub async fn set_last_processed_polkadot_block(&self, block_number: u32) -> Result<()> {
-        sqlx::query(r#"
-            INSERT INTO bridge_state (key, value) VALUES ('last_polkadot_block', $1)
-            ON CONFLICT (key) DO UPDATE SET value = $1, updated_at = NOW()
-        "#)
-        .bind(block_number.to_string())
-        .execute(&self.pool)
-        .await
-        .map_err(|e| RelayerError::Database {
-            message: format!("Failed to set last Polkadot block: {}", e),
+        self.set_bridge_state("last_polkadot_block", block_number.to_string())
+            .await
+            .map_err(|e| RelayerError::Database {
+                message: format!("Failed to set last Polkadot block: {}", e),
+            })
+    }
+
+    /// Get the next unused Ethereum nonce recorded for `address`
+    pub async fn get_ethereum_nonce(&self, address: &str) -> Result<Option<u64>> {
+        let key = format!("ethereum_nonce:{}", address);
+        let value = self.bridge_state(&key).await.map_err(|e| RelayerError::Database {
+            message: format!("Failed to get Ethereum nonce of {}: {}", address, e),
         })?;
 
-        Ok(())
+        Ok(value.map(|value| value.parse().unwrap_or(0)))
+    }
+
+    /// Record the next unused Ethereum nonce for `address`
+    pub async fn set_ethereum_nonce(&self, address: &str, nonce: u64) -> Result<()> {
+        let key = format!("ethereum_nonce:{}", address);
+        self.set_bridge_state(&key, nonce.to_string())
+            .await
+            .map_err(|e| RelayerError::Database {
+                message: format!("Failed to set Ethereum nonce of {}: {}", address, e),
+            })
+    }
+
+    /// Record the block a relayed event was found in, replacing any earlier record
+    pub async fn store_seen_event(&self, chain: &str, event: &SeenEvent) -> Result<()> {
+        on_pool!(&self.pool, pool => {
+            sqlx::query(r#"
+                INSERT INTO seen_events (chain, tx_hash, block_number, block_hash)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (chain, tx_hash) DO UPDATE SET block_number = $3, block_hash = $4
+            "#)
+            .bind(chain)
+            .bind(&event.tx_hash)
+            .bind(event.block_number as i64)
+            .bind(&event.block_hash)
+            .execute(pool)
+            .await
+            .map(|_| ())
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to record block of {} event {}: {}", chain, event.tx_hash, e),
+        })
+    }
+
+    /// The recorded block of a relayed event, if it was relayed
+    pub async fn get_seen_event(&self, chain: &str, tx_hash: &str) -> Result<Option<SeenEvent>> {
+        on_pool!(&self.pool, pool => {
+            sqlx::query("SELECT tx_hash, block_number, block_hash FROM seen_events WHERE chain = $1 AND tx_hash = $2")
+                .bind(chain)
+                .bind(tx_hash)
+                .fetch_optional(pool)
+                .await
+                .map(|row| row.map(|row| SeenEvent {
+                    tx_hash: row.get("tx_hash"),
+                    block_number: row.get::<i64, _>("block_number") as u64,
+                    block_hash: row.get("block_hash"),
+                }))
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to look up {} event {}: {}", chain, tx_hash, e),
+        })
+    }
+
+    /// Relayed events found at or above `from_block`, lowest block first
+    pub async fn seen_events_since(&self, chain: &str, from_block: u64) -> Result<Vec<SeenEvent>> {
+        on_pool!(&self.pool, pool => {
+            sqlx::query(r#"
+                SELECT tx_hash, block_number, block_hash FROM seen_events
+                WHERE chain = $1 AND block_number >= $2
+                ORDER BY block_number ASC, tx_hash ASC
+            "#)
+            .bind(chain)
+            .bind(from_block as i64)
+            .fetch_all(pool)
+            .await
+            .map(|rows| {
+                rows.iter()
+                    .map(|row| SeenEvent {
+                        tx_hash: row.get("tx_hash"),
+                        block_number: row.get::<i64, _>("block_number") as u64,
+                        block_hash: row.get("block_hash"),
+                    })
+                    .collect()
+            })
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to read relayed {} events: {}", chain, e),
+        })
     }
 
     /// Get token address by asset ID
     pub async fn get_token_address_by_asset_id(&self, asset_id: u32) -> Result<String> {
-        let row = sqlx::query("SELECT ethereum_address FROM token_mappings WHERE polkadot_asset_id = $1")
-            .bind(asset_id as i32)
-            .fetch_one(&self.pool)
+        on_pool!(&self.pool, pool => {
+            sqlx::query("SELECT ethereum_address FROM token_mappings WHERE polkadot_asset_id = $1")
+                .bind(asset_id as i32)
+                .fetch_one(pool)
+                .await
+                .map(|row| row.get("ethereum_address"))
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to get token address for asset {}: {}", asset_id, e),
+        })
+    }
+
+    /// Bridge `ethereum_address` as the Polkadot asset `asset_id`
+    pub async fn add_token_mapping(&self, ethereum_address: &str, asset_id: u32) -> Result<()> {
+        on_pool!(&self.pool, pool => {
+            sqlx::query("INSERT INTO token_mappings (ethereum_address, polkadot_asset_id) VALUES ($1, $2)")
+                .bind(ethereum_address)
+                .bind(asset_id as i32)
+                .execute(pool)
+                .await
+                .map(|_| ())
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to map token {} to asset {}: {}", ethereum_address, asset_id, e),
+        })?;
+
+        Ok(())
+    }
+
+    /// Every bridged token, by asset ID
+    pub async fn list_tokens(&self) -> Result<Vec<TokenMapping>> {
+        let rows = on_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, TokenMappingRow>(
+                "SELECT ethereum_address, polkadot_asset_id, created_at FROM token_mappings ORDER BY polkadot_asset_id ASC",
+            )
+            .fetch_all(pool)
             .await
-            .map_err(|e| RelayerError::Database {
-                message: format!("Failed to get token address for asset {}: {}", asset_id, e),
-            })?;
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to list tokens: {}", e),
+        })?;
 
-        Ok(row.get("ethereum_address"))
+        Ok(rows.into_iter().map(TokenMapping::from).collect())
+    }
+
+    /// The bridged token at `ethereum_address`, compared case-insensitively
+    pub async fn get_token_by_address(&self, ethereum_address: &str) -> Result<Option<TokenMapping>> {
+        let row = on_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, TokenMappingRow>(
+                "SELECT ethereum_address, polkadot_asset_id, created_at FROM token_mappings WHERE LOWER(ethereum_address) = LOWER($1)",
+            )
+            .bind(ethereum_address)
+            .fetch_optional(pool)
+            .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to look up token {}: {}", ethereum_address, e),
+        })?;
+
+        Ok(row.map(TokenMapping::from))
+    }
+
+    /// Run a `SELECT COUNT(*) AS count` query
+    async fn count(&self, sql: &str) -> std::result::Result<u64, sqlx::Error> {
+        on_pool!(&self.pool, pool => {
+            sqlx::query(sql)
+                .fetch_one(pool)
+                .await
+                .map(|row| row.get::<i64, _>("count") as u64)
+        })
     }
 
     /// Count processed Ethereum transactions
     pub async fn count_ethereum_processed_txs(&self) -> Result<u64> {
-        let row = sqlx::query("SELECT COUNT(*) as count FROM processed_transactions WHERE chain = 'ethereum'")
-            .fetch_one(&self.pool)
+        self.count("SELECT COUNT(*) as count FROM processed_transactions WHERE chain = 'ethereum'")
             .await
             .map_err(|e| RelayerError::Database {
                 message: format!("Failed to count Ethereum transactions: {}", e),
-            })?;
-
-        Ok(row.get::<i64, _>("count") as u64)
+            })
     }
 
     /// Count processed Polkadot transactions
     pub async fn count_polkadot_processed_txs(&self) -> Result<u64> {
-        let row = sqlx::query("SELECT COUNT(*) as count FROM processed_transactions WHERE chain = 'polkadot'")
-            .fetch_one(&self.pool)
+        self.count("SELECT COUNT(*) as count FROM processed_transactions WHERE chain = 'polkadot'")
             .await
             .map_err(|e| RelayerError::Database {
                 message: format!("Failed to count Polkadot transactions: {}", e),
+            })
+    }
+
+    /// Count Ethereum locks detected by the relayer
+    pub async fn count_ethereum_locks(&self) -> Result<u64> {
+        self.count("SELECT COUNT(*) as count FROM ethereum_locks")
+            .await
+            .map_err(|e| RelayerError::Database {
+                message: format!("Failed to count Ethereum locks: {}", e),
+            })
+    }
+
+    /// Count Polkadot burns detected by the relayer
+    pub async fn count_polkadot_burns(&self) -> Result<u64> {
+        self.count("SELECT COUNT(*) as count FROM polkadot_burns")
+            .await
+            .map_err(|e| RelayerError::Database {
+                message: format!("Failed to count Polkadot burns: {}", e),
+            })
+    }
+
+    /// Transfer counts and volume per token
+    ///
+    /// Burns are attributed to the Ethereum token their asset ID is mapped
+    /// to, so both directions of a token land under the same lowercase
+    /// address. Burns of unmapped assets are keyed by asset ID.
+    pub async fn token_stats(&self) -> Result<HashMap<String, TokenStats>> {
+        let aggregate_error = |e: sqlx::Error| RelayerError::Database {
+            message: format!("Failed to aggregate token statistics: {}", e),
+        };
+
+        match &self.pool {
+            Pool::Postgres(pool) => {
+                let rows = sqlx::query(&format!(r#"
+                    SELECT token,
+                           COUNT(*) FILTER (WHERE direction = 'in') AS transfers_in,
+                           COUNT(*) FILTER (WHERE direction = 'out') AS transfers_out,
+                           SUM(amount::NUMERIC)::TEXT AS volume
+                    FROM ({}) AS transfers
+                    GROUP BY token
+                "#, TOKEN_TRANSFERS_SQL))
+                .fetch_all(pool)
+                .await
+                .map_err(aggregate_error)?;
+
+                Ok(rows
+                    .iter()
+                    .map(|row| {
+                        let stats = TokenStats {
+                            transfers_in: row.get::<i64, _>("transfers_in") as u64,
+                            transfers_out: row.get::<i64, _>("transfers_out") as u64,
+                            volume: row.get("volume"),
+                        };
+                        (row.get("token"), stats)
+                    })
+                    .collect())
+            }
+            Pool::Sqlite(pool) => {
+                // SQLite has no exact type wide enough for U256 amounts, so sum them here
+                let rows = sqlx::query(TOKEN_TRANSFERS_SQL)
+                    .fetch_all(pool)
+                    .await
+                    .map_err(aggregate_error)?;
+
+                let mut totals: HashMap<String, (TokenStats, U256)> = HashMap::new();
+                for row in &rows {
+                    let amount: String = row.get("amount");
+                    let amount = U256::from_dec_str(&amount).map_err(|e| RelayerError::Database {
+                        message: format!("Stored amount '{}' is not a decimal integer: {}", amount, e),
+                    })?;
+
+                    let (stats, volume) = totals.entry(row.get("token")).or_default();
+                    if row.get::<String, _>("direction") == "in" {
+                        stats.transfers_in += 1;
+                    } else {
+                        stats.transfers_out += 1;
+                    }
+                    *volume = volume.saturating_add(amount);
+                }
+
+                Ok(totals
+                    .into_iter()
+                    .map(|(token, (stats, volume))| {
+                        (token, TokenStats { volume: volume.to_string(), ..stats })
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    /// Completion times of transfers completed between `since` and `until`
+    ///
+    /// A transfer's completion time runs from when its source-chain event was
+    /// recorded to when it was marked processed. The p95 is the smallest
+    /// observed time at or above the 95th percentile, not an interpolation.
+    pub async fn completion_times(&self, since: DateTime<Utc>, until: DateTime<Utc>) -> Result<CompletionTimes> {
+        let aggregate_error = |e: sqlx::Error| RelayerError::Database {
+            message: format!("Failed to aggregate completion times: {}", e),
+        };
+
+        match &self.pool {
+            Pool::Postgres(pool) => {
+                let row = sqlx::query(r#"
+                    SELECT COUNT(*) AS completed,
+                           AVG(duration)::FLOAT8 AS average,
+                           PERCENTILE_DISC(0.95) WITHIN GROUP (ORDER BY duration)::FLOAT8 AS p95
+                    FROM (
+                        SELECT EXTRACT(EPOCH FROM p.processed_at - t.created_at) AS duration
+                        FROM (
+                            SELECT tx_hash, 'ethereum' AS chain, created_at FROM ethereum_locks
+                            UNION ALL
+                            SELECT tx_hash, 'polkadot', created_at FROM polkadot_burns
+                        ) AS t
+                        JOIN processed_transactions p ON p.tx_hash = t.tx_hash AND p.chain = t.chain
+                        WHERE p.processed_at >= $1 AND p.processed_at <= $2
+                    ) AS completions
+                "#)
+                .bind(since.naive_utc())
+                .bind(until.naive_utc())
+                .fetch_one(pool)
+                .await
+                .map_err(aggregate_error)?;
+
+                Ok(CompletionTimes {
+                    completed: row.get::<i64, _>("completed") as u64,
+                    average_secs: row.get("average"),
+                    p95_secs: row.get("p95"),
+                })
+            }
+            Pool::Sqlite(pool) => {
+                let durations: Vec<f64> = sqlx::query_scalar(r#"
+                    SELECT (julianday(p.processed_at) - julianday(t.created_at)) * 86400.0
+                    FROM (
+                        SELECT tx_hash, 'ethereum' AS chain, created_at FROM ethereum_locks
+                        UNION ALL
+                        SELECT tx_hash, 'polkadot', created_at FROM polkadot_burns
+                    ) AS t
+                    JOIN processed_transactions p ON p.tx_hash = t.tx_hash AND p.chain = t.chain
+                    WHERE p.processed_at >= $1 AND p.processed_at <= $2
+                "#)
+                .bind(since.naive_utc())
+                .bind(until.naive_utc())
+                .fetch_all(pool)
+                .await
+                .map_err(aggregate_error)?;
+
+                Ok(CompletionTimes::from_durations(durations))
+            }
+        }
+    }
+
+    /// Query Ethereum locks and Polkadot burns, newest first
+    ///
+    /// Rows are in a total order: `created_at DESC, block_number DESC, chain,
+    /// tx_hash`. `(chain, tx_hash)` is unique, so rows that share a timestamp or
+    /// block always come back in the same order and pages never overlap or skip.
+    pub async fn query_transactions(&self, query: &TransactionQuery) -> Result<Vec<TransactionRecord>> {
+        let sql = format!(r#"
+            SELECT tx_hash, chain, status, user_address, token, amount, recipient, block_number, created_at
+            FROM ({}) AS transactions
+            WHERE {}
+            ORDER BY created_at DESC, block_number DESC, chain ASC, tx_hash ASC
+            LIMIT $9 OFFSET $10
+        "#, TRANSACTIONS_SQL, TRANSACTION_FILTERS_SQL);
+
+        let rows = on_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, TransactionRow>(&sql)
+                .bind(query.chain.as_deref())
+                .bind(query.status.as_deref())
+                .bind(query.user.as_deref())
+                .bind(query.token.as_deref())
+                .bind(query.since.map(|t| t.naive_utc()))
+                .bind(query.until.map(|t| t.naive_utc()))
+                .bind(query.from_block.map(|block| block as i64))
+                .bind(query.to_block.map(|block| block as i64))
+                .bind(query.limit as i64)
+                .bind(query.offset as i64)
+                .fetch_all(pool)
+                .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to query transactions: {}", e),
+        })?;
+
+        Ok(rows.into_iter().map(TransactionRecord::from).collect())
+    }
+
+    /// Count the transactions matching `query`'s filters, ignoring its page
+    pub async fn count_transactions(&self, query: &TransactionQuery) -> Result<u64> {
+        let sql = format!(
+            "SELECT COUNT(*) FROM ({}) AS transactions WHERE {}",
+            TRANSACTIONS_SQL, TRANSACTION_FILTERS_SQL,
+        );
+
+        let count = on_pool!(&self.pool, pool => {
+            sqlx::query_scalar::<_, i64>(&sql)
+                .bind(query.chain.as_deref())
+                .bind(query.status.as_deref())
+                .bind(query.user.as_deref())
+                .bind(query.token.as_deref())
+                .bind(query.since.map(|t| t.naive_utc()))
+                .bind(query.until.map(|t| t.naive_utc()))
+                .bind(query.from_block.map(|block| block as i64))
+                .bind(query.to_block.map(|block| block as i64))
+                .fetch_one(pool)
+                .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to count transactions: {}", e),
+        })?;
+
+        Ok(count as u64)
+    }
+
+    /// Ethereum locks with no confirmed mint on Polkadot, oldest first
+    pub async fn unmatched_locks(&self) -> Result<Vec<TransactionRecord>> {
+        self.unmatched("ethereum_locks", "ethereum").await
+    }
+
+    /// Polkadot burns with no confirmed unlock on Ethereum, oldest first
+    pub async fn unmatched_burns(&self) -> Result<Vec<TransactionRecord>> {
+        self.unmatched("polkadot_burns", "polkadot").await
+    }
+
+    /// Source-chain events in `table` with no settlement recorded for `chain`
+    async fn unmatched(&self, table: &str, chain: &str) -> Result<Vec<TransactionRecord>> {
+        let sql = format!(r#"
+            SELECT tx_hash, chain, status, user_address, token, amount, recipient, block_number, created_at
+            FROM ({}) AS transactions
+            WHERE chain = $1
+              AND NOT EXISTS (
+                  SELECT 1 FROM processed_transactions p
+                  WHERE p.tx_hash = transactions.tx_hash AND p.chain = $1
+              )
+            ORDER BY created_at ASC, block_number ASC, tx_hash ASC
+        "#, TRANSACTIONS_SQL);
+
+        let rows = on_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, TransactionRow>(&sql)
+                .bind(chain)
+                .fetch_all(pool)
+                .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to find unmatched {}: {}", table, e),
+        })?;
+
+        Ok(rows.into_iter().map(TransactionRecord::from).collect())
+    }
+
+    /// Find a transaction on either chain by hash
+    ///
+    /// Locks and burns are reported in the same shape, so callers need not
+    /// know which chain a hash came from.
+    pub async fn get_transaction_by_hash(&self, tx_hash: &str) -> Result<Option<TransactionRecord>> {
+        let sql = format!(r#"
+            SELECT tx_hash, chain, status, user_address, token, amount, recipient, block_number, created_at
+            FROM ({}) AS transactions
+            WHERE tx_hash = $1
+            ORDER BY chain ASC
+            LIMIT 1
+        "#, TRANSACTIONS_SQL);
+
+        let row = on_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, TransactionRow>(&sql)
+                .bind(tx_hash)
+                .fetch_optional(pool)
+                .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to find transaction: {}", e),
+        })?;
+
+        Ok(row.map(TransactionRecord::from))
+    }
+
+    /// Current bridge progress, for a new checkpoint
+    pub async fn checkpoint_state(&self) -> Result<BridgeCheckpoint> {
+        let processed: Vec<(String, String)> = on_pool!(&self.pool, pool => {
+            sqlx::query_as("SELECT chain, tx_hash FROM processed_transactions ORDER BY id ASC")
+                .fetch_all(pool)
+                .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to read processed transactions: {}", e),
+        })?;
+
+        Ok(BridgeCheckpoint {
+            last_ethereum_block: self.get_last_processed_ethereum_block().await?.unwrap_or(0),
+            last_polkadot_block: self.get_last_processed_polkadot_block().await?.unwrap_or(0) as u64,
+            ethereum_processed: processed.iter().filter(|(chain, _)| chain == "ethereum").count() as u64,
+            polkadot_processed: processed.iter().filter(|(chain, _)| chain == "polkadot").count() as u64,
+            state_root: checkpoint::state_root(
+                processed.iter().map(|(chain, tx_hash)| (chain.as_str(), tx_hash.as_str())),
+            ),
+        })
+    }
+
+    /// Store a checkpoint with the validators' aggregate signature over it
+    pub async fn store_checkpoint(
+        &self,
+        checkpoint: &BridgeCheckpoint,
+        signature: &AggregatedSignature,
+    ) -> Result<SignedCheckpoint> {
+        let encoded = serde_json::to_string(signature).map_err(|e| RelayerError::Database {
+            message: format!("Failed to encode checkpoint signature: {}", e),
+        })?;
+
+        let (id, created_at) = on_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, (i32, NaiveDateTime)>(r#"
+                INSERT INTO checkpoints
+                    (last_ethereum_block, last_polkadot_block, ethereum_processed, polkadot_processed, state_root, signature)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING id, created_at
+            "#)
+            .bind(checkpoint.last_ethereum_block as i64)
+            .bind(checkpoint.last_polkadot_block as i64)
+            .bind(checkpoint.ethereum_processed as i64)
+            .bind(checkpoint.polkadot_processed as i64)
+            .bind(&checkpoint.state_root)
+            .bind(&encoded)
+            .fetch_one(pool)
+            .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to store checkpoint: {}", e),
+        })?;
+
+        Ok(SignedCheckpoint {
+            id: id as u64,
+            checkpoint: checkpoint.clone(),
+            signature: signature.clone(),
+            created_at: created_at.and_utc(),
+        })
+    }
+
+    /// Most recently stored checkpoint
+    pub async fn latest_checkpoint(&self) -> Result<Option<SignedCheckpoint>> {
+        let row = on_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, CheckpointRow>(r#"
+                SELECT id, last_ethereum_block, last_polkadot_block, ethereum_processed,
+                       polkadot_processed, state_root, signature, created_at
+                FROM checkpoints
+                ORDER BY id DESC
+                LIMIT 1
+            "#)
+            .fetch_optional(pool)
+            .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to get latest checkpoint: {}", e),
+        })?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let signature = serde_json::from_str(&row.signature).map_err(|e| RelayerError::Database {
+            message: format!("Failed to decode checkpoint signature: {}", e),
+        })?;
+
+        Ok(Some(SignedCheckpoint {
+            id: row.id as u64,
+            checkpoint: BridgeCheckpoint {
+                last_ethereum_block: row.last_ethereum_block as u64,
+                last_polkadot_block: row.last_polkadot_block as u64,
+                ethereum_processed: row.ethereum_processed as u64,
+                polkadot_processed: row.polkadot_processed as u64,
+                state_root: row.state_root,
+            },
+            signature,
+            created_at: row.created_at.and_utc(),
+        }))
+    }
+
+    /// Persist a signature session, replacing any earlier copy and its partials
+    pub async fn save_signature_session(&self, session: &StoredSignatureSession) -> Result<()> {
+        let created_at_ms = session.created_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as i64;
+
+        on_pool!(&self.pool, pool => {
+            sqlx::query(r#"
+                INSERT INTO signature_sessions (tx_hash, message, required_signatures, created_at_ms, signature_format)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (tx_hash) DO UPDATE
+                SET message = $2, required_signatures = $3, created_at_ms = $4, signature_format = $5
+            "#)
+            .bind(&session.tx_hash)
+            .bind(hex::encode(&session.message))
+            .bind(session.required_signatures as i32)
+            .bind(created_at_ms)
+            .bind(session.format.as_str())
+            .execute(pool)
+            .await
+            .map(|_| ())
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to store signature session {}: {}", session.tx_hash, e),
+        })?;
+
+        self.delete_partial_signatures(&session.tx_hash, None).await?;
+        for partial in &session.partials {
+            self.save_partial_signature(&session.tx_hash, partial).await?;
+        }
+        Ok(())
+    }
+
+    /// Persist a partial signature of a session, replacing the signer's earlier one
+    pub async fn save_partial_signature(&self, tx_hash: &str, partial: &StoredPartialSignature) -> Result<()> {
+        let encoded = serde_json::to_string(&partial.signature).map_err(|e| RelayerError::Database {
+            message: format!("Failed to encode partial signature: {}", e),
+        })?;
+
+        on_pool!(&self.pool, pool => {
+            sqlx::query(r#"
+                INSERT INTO partial_signatures (tx_hash, validator_id, source, signature)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (tx_hash, validator_id) DO UPDATE SET source = $3, signature = $4
+            "#)
+            .bind(tx_hash)
+            .bind(&partial.validator_id)
+            .bind(&partial.source)
+            .bind(&encoded)
+            .execute(pool)
+            .await
+            .map(|_| ())
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to store partial signature from {} for {}: {}", partial.validator_id, tx_hash, e),
+        })
+    }
+
+    /// Forget a session's partials, or only `validator_id`'s if given
+    pub async fn delete_partial_signatures(&self, tx_hash: &str, validator_id: Option<&str>) -> Result<()> {
+        on_pool!(&self.pool, pool => {
+            sqlx::query("DELETE FROM partial_signatures WHERE tx_hash = $1 AND ($2 IS NULL OR validator_id = $2)")
+                .bind(tx_hash)
+                .bind(validator_id)
+                .execute(pool)
+                .await
+                .map(|_| ())
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to delete partial signatures for {}: {}", tx_hash, e),
+        })
+    }
+
+    /// Forget a signature session and its partials
+    pub async fn delete_signature_session(&self, tx_hash: &str) -> Result<()> {
+        self.delete_partial_signatures(tx_hash, None).await?;
+
+        on_pool!(&self.pool, pool => {
+            sqlx::query("DELETE FROM signature_sessions WHERE tx_hash = $1")
+                .bind(tx_hash)
+                .execute(pool)
+                .await
+                .map(|_| ())
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to delete signature session {}: {}", tx_hash, e),
+        })
+    }
+
+    /// A persisted signature session with its partials
+    pub async fn load_signature_session(&self, tx_hash: &str) -> Result<Option<StoredSignatureSession>> {
+        let sessions = self.load_signature_sessions_where(Some(tx_hash)).await?;
+        Ok(sessions.into_iter().next())
+    }
+
+    /// Every persisted signature session with its partials, oldest first
+    pub async fn load_signature_sessions(&self) -> Result<Vec<StoredSignatureSession>> {
+        self.load_signature_sessions_where(None).await
+    }
+
+    /// Persisted sessions, only `tx_hash`'s if given
+    async fn load_signature_sessions_where(&self, tx_hash: Option<&str>) -> Result<Vec<StoredSignatureSession>> {
+        let rows = on_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, (String, String, i32, i64, String)>(r#"
+                SELECT tx_hash, message, required_signatures, created_at_ms, signature_format FROM signature_sessions
+                WHERE $1 IS NULL OR tx_hash = $1
+                ORDER BY created_at_ms ASC
+            "#)
+            .bind(tx_hash)
+            .fetch_all(pool)
+            .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to load signature sessions: {}", e),
+        })?;
+
+        let partial_rows = on_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, (String, String, String, String)>(r#"
+                SELECT tx_hash, validator_id, source, signature FROM partial_signatures
+                WHERE $1 IS NULL OR tx_hash = $1
+            "#)
+            .bind(tx_hash)
+            .fetch_all(pool)
+            .await
+        })
+        .map_err(|e| RelayerError::Database {
+            message: format!("Failed to load partial signatures: {}", e),
+        })?;
+
+        let mut partials: HashMap<String, Vec<StoredPartialSignature>> = HashMap::new();
+        for (tx_hash, validator_id, source, signature) in partial_rows {
+            let signature = serde_json::from_str(&signature).map_err(|e| RelayerError::Database {
+                message: format!("Failed to decode partial signature from {}: {}", validator_id, e),
             })?;
+            partials.entry(tx_hash).or_default().push(StoredPartialSignature {
+                validator_id,
+                source,
+                signature,
+            });
+        }
 
-        Ok(row.get::<i64, _>("count") as u64)
+        rows.into_iter()
+            .map(|(tx_hash, message, required_signatures, created_at_ms, format)| {
+                let message = hex::decode(&message).map_err(|e| RelayerError::Database {
+                    message: format!("Failed to decode message of signature session {}: {}", tx_hash, e),
+                })?;
+                let format = format.parse().map_err(|e| RelayerError::Database {
+                    message: format!("Failed to decode signature session {}: {}", tx_hash, e),
+                })?;
+                Ok(StoredSignatureSession {
+                    partials: partials.remove(&tx_hash).unwrap_or_default(),
+                    tx_hash,
+                    message,
+                    format,
+                    required_signatures: required_signatures as u32,
+                    created_at: UNIX_EPOCH + Duration::from_millis(created_at_ms as u64),
+                })
+            })
+            .collect()
     }
 
     /// Close database connections
     pub async fn close(&self) -> Result<()> {
-        self.pool.close().await;
+        on_pool!(&self.pool, pool => pool.close().await);
         info!("Database connections closed");
         Ok(())
     }
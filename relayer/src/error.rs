@@ -26,6 +26,88 @@ pub enum RelayerError {
     #[error("Network error: {message}")]
     Network { message: String },
 
+    #[error("Price unavailable for token {token}")]
+    PriceUnavailable { token: String },
+
+    #[error("Recipient {recipient} is not on the mint allowlist")]
+    RecipientNotAllowed { recipient: String },
+
+    #[error("Mint to {recipient} would leave it below the asset's minimum balance")]
+    RecipientBelowMinBalance { recipient: String },
+
+    #[error("Recipient {recipient} has no account that can hold the asset")]
+    RecipientCannotHoldAsset { recipient: String },
+
+    #[error("Invalid amount '{amount}' in tx {tx_hash}: expected a positive integer")]
+    InvalidAmount { tx_hash: String, amount: String },
+
+    #[error("Invalid destination '{destination}' in tx {tx_hash}: {reason}")]
+    InvalidDestination {
+        tx_hash: String,
+        destination: String,
+        reason: String,
+    },
+
+    #[error("Partials for tx {tx_hash} came from {sources} distinct source(s), {required} required")]
+    InsufficientSourceDiversity {
+        tx_hash: String,
+        sources: usize,
+        required: u32,
+    },
+
+    #[error("Tx {tx_hash} needs {required} from active validators, only {active} available")]
+    InsufficientActiveValidators {
+        tx_hash: String,
+        /// Active validator count, or their total weight under a weighted threshold
+        active: u64,
+        required: u64,
+    },
+
+    #[error("Partial signature from {validator_id} is timestamped {offset_secs}s from local time, beyond the {tolerance_secs}s clock-skew tolerance")]
+    ClockSkew {
+        validator_id: String,
+        /// Positive when the timestamp is in the future
+        offset_secs: i64,
+        tolerance_secs: u64,
+    },
+
+    #[error("Message for tx {tx_hash} is {size} bytes, over the {max}-byte session limit")]
+    MessageTooLarge {
+        tx_hash: String,
+        size: usize,
+        max: usize,
+    },
+
     #[error("Generic error: {message}")]
     Generic { message: String },
 }
+
+impl RelayerError {
+    /// Whether an RPC provider rejected the request for exceeding its rate limit
+    ///
+    /// Neither `ethers` nor `subxt` exposes the HTTP status, so the message is
+    /// matched for a 429 or the phrases providers use alongside it.
+    pub fn is_rate_limited(&self) -> bool {
+        match self {
+            Self::Ethereum { message } | Self::Polkadot { message } | Self::Network { message } => {
+                let message = message.to_lowercase();
+                message.contains("429")
+                    || message.contains("too many requests")
+                    || message.contains("rate limit")
+                    || message.contains("rate-limit")
+            }
+            _ => false,
+        }
+    }
+
+    /// Short machine-readable reason a transfer was rejected, for consumers
+    /// of published events
+    pub fn failure_reason(&self) -> Option<&'static str> {
+        match self {
+            Self::InvalidDestination { .. } => Some("invalid_destination"),
+            Self::InvalidAmount { .. } => Some("invalid_amount"),
+            Self::RecipientNotAllowed { .. } => Some("recipient_not_allowed"),
+            _ => None,
+        }
+    }
+}
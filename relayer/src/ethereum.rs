@@ -1,27 +1,42 @@
 //! Ethereum client for interacting with the bridge contract
 
 use crate::{
-    config::EthereumConfig,
+    config::{EthereumConfig, GasStrategy},
     error::{RelayerError, Result},
+    nonce_manager::{NonceManager, NonceStore},
 };
 use ethers::{
     prelude::*,
-    abi::Abi,
+    abi::{self, Abi, ParamType, Token},
     providers::{Provider, Http},
-    contract::Contract,
+    types::transaction::eip2718::TypedTransaction,
     types::{Address, U256, H256},
     signers::{LocalWallet, Signer},
-    middleware::SignerMiddleware,
+    utils::{hash_message, keccak256},
 };
 use std::sync::Arc;
-use tracing::{info, debug, error};
-use futures::Stream;
+use tracing::{debug, info, warn};
+
+/// Blocks covered by one `eth_getLogs` request unless configured otherwise
+const DEFAULT_LOG_BATCH_SIZE: u64 = 1000;
+
+/// Recent blocks sampled for priority fees when pricing EIP-1559 transactions
+const FEE_HISTORY_BLOCKS: u64 = 10;
+
+/// Percentile of each block's priority fees taken as its typical tip
+const PRIORITY_FEE_PERCENTILE: f64 = 50.0;
+
+/// Extra gas on top of an `eth_estimateGas` result, in percent
+const GAS_LIMIT_HEADROOM_PERCENT: u64 = 20;
 
 /// Ethereum client for bridge operations
 pub struct EthereumClient {
     config: EthereumConfig,
     provider: Arc<Provider<Http>>,
     wallet: Option<LocalWallet>,
+    nonces: Option<NonceManager>,
+    log_batch_size: u64,
+    gas_oracle: GasOracle,
 }
 
 impl EthereumClient {
@@ -48,13 +63,30 @@ impl EthereumClient {
             None
         };
 
+        let nonces = wallet.as_ref().map(|wallet| NonceManager::new(wallet.address()));
+
         Ok(Self {
             config: config.clone(),
             provider,
             wallet,
+            nonces,
+            log_batch_size: DEFAULT_LOG_BATCH_SIZE,
+            gas_oracle: GasOracle::from_config(config),
         })
     }
 
+    /// Request logs at most `batch_size` blocks at a time
+    pub fn with_log_batch_size(mut self, batch_size: u64) -> Self {
+        self.log_batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Persist the wallet's next nonce to `store`, resuming from it on restart
+    pub fn with_nonce_store(mut self, store: Arc<dyn NonceStore>) -> Self {
+        self.nonces = self.nonces.map(|nonces| nonces.with_store(store));
+        self
+    }
+
     /// Get bridge contract ABI
     fn get_bridge_abi() -> Abi {
         // Simplified ABI for the bridge contract
@@ -63,6 +95,7 @@ impl EthereumClient {
             {
                 "type": "event",
                 "name": "BridgeLock",
+                "anonymous": false,
                 "inputs": [
                     {"name": "user", "type": "address", "indexed": true},
                     {"name": "token", "type": "address", "indexed": true},
@@ -74,6 +107,7 @@ impl EthereumClient {
             {
                 "type": "event",
                 "name": "BridgeUnlock",
+                "anonymous": false,
                 "inputs": [
                     {"name": "user", "type": "address", "indexed": true},
                     {"name": "token", "type": "address", "indexed": true},
@@ -106,15 +140,28 @@ impl EthereumClient {
         Ok(vec![])
     }
 
-    /// Get past BridgeLock events from a specific block
-    pub async fn get_past_lock_events(&self, from_block: u64) -> Result<Vec<BridgeLockEvent>> {
-        info!("Getting past BridgeLock events from block {}", from_block);
+    /// Get the BridgeLock events emitted in `[from_block, to_block]`, with
+    /// the transaction and block each was emitted in
+    pub async fn get_past_lock_events(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<(BridgeLockEvent, LogMeta)>> {
+        info!("Getting past BridgeLock events from block {} to {}", from_block, to_block);
 
-        // Simplified implementation - return empty vector
-        Ok(vec![])
+        let contract: Address = self.config.bridge_contract.parse()
+            .map_err(|e| RelayerError::Ethereum {
+                message: format!("Invalid contract address: {}", e),
+            })?;
+
+        fetch_lock_events(self.provider.as_ref(), contract, from_block, to_block, self.log_batch_size).await
     }
 
     /// Unlock tokens on Ethereum
+    ///
+    /// Simulates `unlockTokens` first so a call the contract would revert is
+    /// reported with its reason, then signs and sends it priced by the
+    /// configured gas strategy. Returns the hash of the pending transaction.
     pub async fn unlock_tokens(
         &self,
         user: &str,
@@ -123,48 +170,65 @@ impl EthereumClient {
         polkadot_tx_hash: &str,
         signatures: Vec<Vec<u8>>,
     ) -> Result<H256> {
-        let wallet = self.wallet.as_ref()
+        let (wallet, nonces) = self.wallet.as_ref().zip(self.nonces.as_ref())
             .ok_or_else(|| RelayerError::Ethereum {
                 message: "Wallet not configured for transactions".to_string(),
             })?;
 
-        // Simplified implementation - would create contract instance here
-        let contract_address: Address = self.config.bridge_contract.parse()
+        let contract: Address = self.config.bridge_contract.parse()
             .map_err(|e| RelayerError::Ethereum {
                 message: format!("Invalid contract address: {}", e),
             })?;
 
-        // Parse parameters
-        let user_address: Address = user.parse()
-            .map_err(|e| RelayerError::Ethereum {
-                message: format!("Invalid user address: {}", e),
-            })?;
+        let calldata = unlock_calldata(user, token, amount, polkadot_tx_hash, &signatures)?;
 
-        let token_address: Address = token.parse()
-            .map_err(|e| RelayerError::Ethereum {
-                message: format!("Invalid token address: {}", e),
-            })?;
+        info!(
+            "Submitting unlock transaction for user {} token {} amount {} with {} signatures",
+            user, token, amount, signatures.len()
+        );
 
-        let amount_u256: U256 = amount.parse()
-            .map_err(|e| RelayerError::Ethereum {
-                message: format!("Invalid amount: {}", e),
-            })?;
+        let client = SignerMiddleware::new(self.provider.clone(), wallet.clone());
+        let tx_hash = send_unlock(
+            &client,
+            nonces,
+            contract,
+            calldata,
+            &self.gas_oracle,
+        ).await?;
+
+        info!("Unlock transaction sent: {:?}", tx_hash);
+        Ok(tx_hash)
+    }
 
-        let tx_hash: H256 = polkadot_tx_hash.parse()
+    /// Expected cost of an `unlockTokens` call carrying `signature_count`
+    /// signatures
+    ///
+    /// The call is priced the way [`unlock_tokens`](Self::unlock_tokens)
+    /// would price it, with placeholder signatures of the right length. It is
+    /// estimated from the relayer's own account, or the zero address when no
+    /// wallet is configured.
+    pub async fn estimate_unlock_fee(
+        &self,
+        token: &str,
+        amount: &str,
+        signature_count: usize,
+    ) -> Result<FeeEstimate> {
+        let contract: Address = self.config.bridge_contract.parse()
             .map_err(|e| RelayerError::Ethereum {
-                message: format!("Invalid transaction hash: {}", e),
+                message: format!("Invalid contract address: {}", e),
             })?;
 
-        // Simplified implementation - would submit actual transaction here
-        info!(
-            "Would submit unlock transaction for user {} token {} amount {} with {} signatures",
-            user, token, amount, signatures.len()
-        );
+        let from = self.wallet.as_ref().map(|wallet| wallet.address()).unwrap_or_default();
+        let signatures = vec![vec![0u8; 65]; signature_count];
+        let calldata = unlock_calldata(
+            &format!("{:?}", from),
+            token,
+            amount,
+            &format!("{:?}", H256::zero()),
+            &signatures,
+        )?;
 
-        // Return mock transaction hash
-        let mock_tx_hash = H256::from_slice(&[1u8; 32]);
-        info!("Mock unlock transaction hash: {:?}", mock_tx_hash);
-        Ok(mock_tx_hash)
+        Ok(estimate_unlock_fee(self.provider.as_ref(), from, contract, calldata, &self.gas_oracle).await)
     }
 
     /// Get the current block number
@@ -178,6 +242,22 @@ impl EthereumClient {
         Ok(block_number.as_u64())
     }
 
+    /// Blocks a lock must be buried under before it is relayed
+    pub fn confirmations(&self) -> u64 {
+        self.config.confirmations
+    }
+
+    /// Hash of the canonical block at `block_number`, if the node has it
+    pub async fn get_block_hash(&self, block_number: u64) -> Result<Option<H256>> {
+        let block = self.provider.get_block(block_number)
+            .await
+            .map_err(|e| RelayerError::Ethereum {
+                message: format!("Failed to get block {}: {}", block_number, e),
+            })?;
+
+        Ok(block.and_then(|block| block.hash))
+    }
+
     /// Check if a transaction is confirmed
     pub async fn is_transaction_confirmed(&self, tx_hash: H256, confirmations: u64) -> Result<bool> {
         let tx_receipt = self.provider.get_transaction_receipt(tx_hash)
@@ -198,8 +278,397 @@ impl EthereumClient {
     }
 }
 
+/// Selector of Solidity's `Error(string)` revert payload
+const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Encode a call to the bridge contract's `unlockTokens`
+pub fn unlock_calldata(
+    user: &str,
+    token: &str,
+    amount: &str,
+    polkadot_tx_hash: &str,
+    signatures: &[Vec<u8>],
+) -> Result<Bytes> {
+    let (user, token, amount, polkadot_tx_hash) = parse_unlock(user, token, amount, polkadot_tx_hash)?;
+
+    let abi = EthereumClient::get_bridge_abi();
+    let unlock = abi.function("unlockTokens")
+        .map_err(|e| RelayerError::Ethereum {
+            message: format!("Bridge ABI has no unlockTokens: {}", e),
+        })?;
+
+    let calldata = unlock.encode_input(&[
+        Token::Address(user),
+        Token::Address(token),
+        Token::Uint(amount),
+        Token::FixedBytes(polkadot_tx_hash.as_bytes().to_vec()),
+        Token::Array(signatures.iter().cloned().map(Token::Bytes).collect()),
+    ])
+    .map_err(|e| RelayerError::Ethereum {
+        message: format!("Failed to encode unlockTokens call: {}", e),
+    })?;
+
+    Ok(calldata.into())
+}
+
+/// Digest the bridge contract checks an unlock's signatures against
+///
+/// Matches `unlockTokens`: the Ethereum signed-message hash of
+/// `keccak256(abi.encodePacked(user, token, amount, polkadotTxHash, chainid))`.
+/// Validators sign this digest as is, and the contract recovers each
+/// validator's address from its 65-byte signature.
+pub fn unlock_message(
+    user: &str,
+    token: &str,
+    amount: &str,
+    polkadot_tx_hash: &str,
+    chain_id: u64,
+) -> Result<[u8; 32]> {
+    let (user, token, amount, polkadot_tx_hash) = parse_unlock(user, token, amount, polkadot_tx_hash)?;
+
+    // Packed by hand: `abi::encode_packed` trims uints to their minimal
+    // width, where Solidity packs a `uint256` as a full 32-byte word
+    let mut packed = Vec::with_capacity(20 + 20 + 32 + 32 + 32);
+    packed.extend_from_slice(user.as_bytes());
+    packed.extend_from_slice(token.as_bytes());
+    let mut word = [0u8; 32];
+    amount.to_big_endian(&mut word);
+    packed.extend_from_slice(&word);
+    packed.extend_from_slice(polkadot_tx_hash.as_bytes());
+    U256::from(chain_id).to_big_endian(&mut word);
+    packed.extend_from_slice(&word);
+
+    Ok(hash_message(keccak256(packed)).0)
+}
+
+/// Parse the arguments of an `unlockTokens` call
+fn parse_unlock(user: &str, token: &str, amount: &str, polkadot_tx_hash: &str) -> Result<(Address, Address, U256, H256)> {
+    let user: Address = user.parse()
+        .map_err(|e| RelayerError::Ethereum {
+            message: format!("Invalid user address: {}", e),
+        })?;
+
+    let token: Address = token.parse()
+        .map_err(|e| RelayerError::Ethereum {
+            message: format!("Invalid token address: {}", e),
+        })?;
+
+    let amount = U256::from_dec_str(amount)
+        .map_err(|e| RelayerError::Ethereum {
+            message: format!("Invalid amount: {}", e),
+        })?;
+
+    let polkadot_tx_hash: H256 = polkadot_tx_hash.parse()
+        .map_err(|e| RelayerError::Ethereum {
+            message: format!("Invalid transaction hash: {}", e),
+        })?;
+
+    Ok((user, token, amount, polkadot_tx_hash))
+}
+
+/// Reason given by a call the contract reverted with `Error(string)`
+fn revert_reason<E: MiddlewareError>(error: &E) -> Option<String> {
+    let data = error.as_error_response()?.as_revert_data()?;
+    let encoded = data.strip_prefix(&ERROR_STRING_SELECTOR)?;
+
+    match abi::decode(&[ParamType::String], encoded).ok()?.pop()? {
+        Token::String(reason) => Some(reason),
+        _ => None,
+    }
+}
+
+/// How a transaction pays for gas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasPricing {
+    /// A single legacy gas price
+    Legacy { gas_price: U256 },
+    /// EIP-1559 fee caps
+    Eip1559 {
+        max_fee_per_gas: U256,
+        max_priority_fee_per_gas: U256,
+    },
+}
+
+/// Gas limit and pricing chosen for one transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasParams {
+    pub gas_limit: U256,
+    pub pricing: GasPricing,
+}
+
+impl GasParams {
+    /// `request` with this gas limit and pricing applied
+    ///
+    /// EIP-1559 pricing turns it into a type-2 transaction.
+    pub fn transaction(self, request: TransactionRequest) -> TypedTransaction {
+        match self.pricing {
+            GasPricing::Legacy { gas_price } => request.gas(self.gas_limit).gas_price(gas_price).into(),
+            GasPricing::Eip1559 { max_fee_per_gas, max_priority_fee_per_gas } => Eip1559TransactionRequest {
+                from: request.from,
+                to: request.to,
+                gas: Some(self.gas_limit),
+                value: request.value,
+                data: request.data,
+                nonce: request.nonce,
+                chain_id: request.chain_id,
+                max_fee_per_gas: Some(max_fee_per_gas),
+                max_priority_fee_per_gas: Some(max_priority_fee_per_gas),
+                ..Default::default()
+            }
+            .into(),
+        }
+    }
+}
+
+/// Expected cost of one transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeEstimate {
+    pub gas_limit: U256,
+    /// Legacy gas price, or the EIP-1559 fee cap
+    pub gas_price: U256,
+    /// `gas_limit * gas_price`
+    pub fee_wei: U256,
+}
+
+impl From<GasParams> for FeeEstimate {
+    fn from(params: GasParams) -> Self {
+        let gas_price = match params.pricing {
+            GasPricing::Legacy { gas_price } => gas_price,
+            GasPricing::Eip1559 { max_fee_per_gas, .. } => max_fee_per_gas,
+        };
+
+        Self {
+            gas_limit: params.gas_limit,
+            gas_price,
+            fee_wei: params.gas_limit.saturating_mul(gas_price),
+        }
+    }
+}
+
+/// Chooses the gas limit and fees of outgoing transactions
+///
+/// The static strategy always uses the configured limit and price. The
+/// EIP-1559 strategy estimates the limit for each call and derives its fee
+/// caps from `eth_feeHistory`: twice the next block's base fee plus the median
+/// recent tip, which stays includable through several full blocks. Whatever
+/// the node cannot provide falls back to the configured values.
+#[derive(Debug, Clone, Copy)]
+pub struct GasOracle {
+    strategy: GasStrategy,
+    gas_limit: u64,
+    gas_price: u64,
+}
+
+impl GasOracle {
+    /// Price with `strategy`, falling back to `gas_limit` and `gas_price`
+    pub fn new(strategy: GasStrategy, gas_limit: u64, gas_price: u64) -> Self {
+        Self { strategy, gas_limit, gas_price }
+    }
+
+    /// Oracle for the configured gas strategy and static values
+    pub fn from_config(config: &EthereumConfig) -> Self {
+        Self::new(config.gas_strategy, config.gas_limit, config.gas_price)
+    }
+
+    /// Gas limit and pricing for sending `tx` through `client`
+    pub async fn gas_params<M: Middleware>(&self, client: &M, tx: &TypedTransaction) -> GasParams {
+        let fallback = GasParams {
+            gas_limit: self.gas_limit.into(),
+            pricing: GasPricing::Legacy { gas_price: self.gas_price.into() },
+        };
+        if self.strategy == GasStrategy::Static {
+            return fallback;
+        }
+
+        let gas_limit = match client.estimate_gas(tx, None).await {
+            Ok(estimate) => estimate + estimate * GAS_LIMIT_HEADROOM_PERCENT / 100,
+            Err(e) => {
+                warn!("Gas estimation failed, using the configured limit of {}: {}", self.gas_limit, e);
+                fallback.gas_limit
+            }
+        };
+
+        let pricing = match Self::eip1559_fees(client).await {
+            Ok(Some(pricing)) => pricing,
+            Ok(None) => {
+                warn!("Node reports no base fee, sending a legacy transaction");
+                fallback.pricing
+            }
+            Err(e) => {
+                warn!("Fee history unavailable, sending a legacy transaction: {}", e);
+                fallback.pricing
+            }
+        };
+
+        GasParams { gas_limit, pricing }
+    }
+
+    /// EIP-1559 fee caps from recent fee history, or `None` before London
+    async fn eip1559_fees<M: Middleware>(client: &M) -> Result<Option<GasPricing>> {
+        let history = client.fee_history(FEE_HISTORY_BLOCKS, BlockNumber::Latest, &[PRIORITY_FEE_PERCENTILE])
+            .await
+            .map_err(|e| RelayerError::Ethereum {
+                message: format!("Failed to get fee history: {}", e),
+            })?;
+
+        // The last entry is the base fee of the block after the newest one
+        let Some(base_fee) = history.base_fee_per_gas.last().copied().filter(|fee| !fee.is_zero()) else {
+            return Ok(None);
+        };
+
+        let mut tips: Vec<U256> = history.reward.iter().filter_map(|rewards| rewards.first().copied()).collect();
+        tips.sort();
+        let max_priority_fee_per_gas = match tips.get(tips.len() / 2) {
+            Some(tip) => *tip,
+            None => client.provider()
+                .request::<_, U256>("eth_maxPriorityFeePerGas", ())
+                .await
+                .map_err(|e| RelayerError::Ethereum {
+                    message: format!("Failed to get priority fee: {}", e),
+                })?,
+        };
+
+        Ok(Some(GasPricing::Eip1559 {
+            max_fee_per_gas: base_fee * U256::from(2) + max_priority_fee_per_gas,
+            max_priority_fee_per_gas,
+        }))
+    }
+}
+
+/// Send `unlockTokens` calldata from the account of `nonces` to the bridge
+/// `contract`
+///
+/// The transaction takes the next nonce from `nonces`, is priced by `gas`,
+/// and is simulated with `eth_call` before sending, so a revert fails here
+/// with the contract's reason instead of costing gas. The nonce is released
+/// for the next transaction when the simulation reverts or the send fails.
+pub async fn send_unlock<M: Middleware>(
+    client: &M,
+    nonces: &NonceManager,
+    contract: Address,
+    calldata: Bytes,
+    gas: &GasOracle,
+) -> Result<H256> {
+    let nonce = nonces.next_nonce(client).await?;
+
+    let result = send_unlock_with_nonce(client, nonces.address(), contract, calldata, nonce, gas).await;
+    if result.is_err() {
+        nonces.release(nonce).await;
+    }
+    result
+}
+
+async fn send_unlock_with_nonce<M: Middleware>(
+    client: &M,
+    from: Address,
+    contract: Address,
+    calldata: Bytes,
+    nonce: U256,
+    gas: &GasOracle,
+) -> Result<H256> {
+    let request = TransactionRequest::new()
+        .from(from)
+        .to(contract)
+        .data(calldata)
+        .nonce(nonce);
+    let gas_params = gas.gas_params(client, &request.clone().into()).await;
+    debug!("Pricing unlock with {:?}", gas_params);
+    let tx = gas_params.transaction(request);
+
+    client.call(&tx, None)
+        .await
+        .map_err(|e| RelayerError::Ethereum {
+            message: match revert_reason(&e) {
+                Some(reason) => format!("Unlock reverted: {}", reason),
+                None => format!("Unlock simulation failed: {}", e),
+            },
+        })?;
+
+    let pending = client.send_transaction(tx, None)
+        .await
+        .map_err(|e| RelayerError::Ethereum {
+            message: format!("Failed to send unlock transaction: {}", e),
+        })?;
+
+    Ok(pending.tx_hash())
+}
+
+/// Expected cost of sending `unlockTokens` calldata from `from` to the
+/// bridge `contract`
+///
+/// Priced by `gas` exactly as [`send_unlock`] would be. Under EIP-1559 the fee
+/// uses the fee cap, so it is an upper bound on what the unlock pays.
+pub async fn estimate_unlock_fee<M: Middleware>(
+    client: &M,
+    from: Address,
+    contract: Address,
+    calldata: Bytes,
+    gas: &GasOracle,
+) -> FeeEstimate {
+    let request = TransactionRequest::new()
+        .from(from)
+        .to(contract)
+        .data(calldata);
+
+    gas.gas_params(client, &request.into()).await.into()
+}
+
+/// Log filter for BridgeLock events emitted by `contract` in `[from_block, to_block]`
+pub fn lock_event_filter(contract: Address, from_block: u64, to_block: u64) -> Filter {
+    Filter::new()
+        .address(contract)
+        .event(&BridgeLockEvent::abi_signature())
+        .from_block(from_block)
+        .to_block(to_block)
+}
+
+/// Fetch and decode the BridgeLock events `contract` emitted in
+/// `[from_block, to_block]`, oldest first
+///
+/// The range is requested `batch_size` blocks at a time, so providers that
+/// cap the block span or result size of `eth_getLogs` can serve it. Logs
+/// removed by a reorg are skipped.
+pub async fn fetch_lock_events<M: Middleware>(
+    provider: &M,
+    contract: Address,
+    from_block: u64,
+    to_block: u64,
+    batch_size: u64,
+) -> Result<Vec<(BridgeLockEvent, LogMeta)>> {
+    let batch_size = batch_size.max(1);
+    let mut events = Vec::new();
+
+    let mut start = from_block;
+    while start <= to_block {
+        let end = start.saturating_add(batch_size - 1).min(to_block);
+        debug!("Fetching BridgeLock logs in blocks {} to {}", start, end);
+
+        let logs = provider.get_logs(&lock_event_filter(contract, start, end))
+            .await
+            .map_err(|e| RelayerError::Ethereum {
+                message: format!("Failed to get BridgeLock logs for blocks {} to {}: {}", start, end, e),
+            })?;
+
+        for log in logs.into_iter().filter(|log| log.removed != Some(true)) {
+            let meta = LogMeta::from(&log);
+            let event = parse_log::<BridgeLockEvent>(log).map_err(|e| RelayerError::Ethereum {
+                message: format!("Failed to decode BridgeLock log in {:?}: {}", meta.transaction_hash, e),
+            })?;
+            events.push((event, meta));
+        }
+
+        if end == u64::MAX {
+            break;
+        }
+        start = end + 1;
+    }
+
+    Ok(events)
+}
+
 /// BridgeLock event structure
-#[derive(Debug, Clone, EthEvent)]
+#[derive(Debug, Clone, PartialEq, Eq, EthEvent)]
+#[ethevent(name = "BridgeLock")]
 pub struct BridgeLockEvent {
     #[ethevent(indexed)]
     pub user: Address,
@@ -213,6 +682,7 @@ pub struct BridgeLockEvent {
 
 /// BridgeUnlock event structure
 #[derive(Debug, Clone, EthEvent)]
+#[ethevent(name = "BridgeUnlock")]
 pub struct BridgeUnlockEvent {
     #[ethevent(indexed)]
     pub user: Address,
@@ -1,23 +1,291 @@
 //! Event monitoring service for cross-chain events
 
 use crate::{
-    coordinator::BridgeEvent,
-    config::MonitoringConfig,
-    database::Database,
+    config::RelayDirection,
+    coordinator::{BridgeCoordinator, BridgeEvent},
+    database::{Database, SeenEvent},
     ethereum::EthereumClient,
+    metrics::{ChainReorgs, LatencyHistogram, RpcRateLimits},
     polkadot::PolkadotClient,
+    policy::RelayerPolicy,
     error::{RelayerError, Result},
 };
+use async_trait::async_trait;
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 use tracing::{info, debug, error, warn};
+use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::Instant;
+
+/// Blocks below the cursor whose relayed events are re-checked for reorgs
+pub const REORG_CHECK_DEPTH: u64 = 128;
+
+/// Blocks scanned per poll when no batch size is configured
+pub const DEFAULT_BATCH_SIZE: u64 = 1000;
+
+/// A chain the monitor can poll for bridge events
+#[async_trait]
+pub trait EventSource: Send + Sync {
+    /// Chain name used for cursors and logging
+    fn chain(&self) -> &'static str;
+
+    /// Fetch events from at most `max_blocks` blocks after `from_block`,
+    /// returning them with the new cursor
+    async fn poll(&self, from_block: u64, max_blocks: u64) -> Result<(Vec<BridgeEvent>, u64)>;
+
+    /// Hash of the canonical block at `block`, if the source can tell
+    ///
+    /// Sources that return `None` are not checked for reorgs.
+    async fn block_hash(&self, _block: u64) -> Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+/// Persistent storage for per-chain monitoring cursors
+#[async_trait]
+pub trait CursorStore: Send + Sync {
+    /// Last processed block for a chain, if any
+    async fn load_cursor(&self, chain: &str) -> Result<Option<u64>>;
+
+    /// Persist the last processed block for a chain
+    async fn save_cursor(&self, chain: &str, block: u64) -> Result<()>;
+}
+
+/// Storage for events too old to relay without an admin's release
+#[async_trait]
+pub trait StaleEventStore: Send + Sync {
+    /// Record `event` as stale instead of relaying it
+    async fn hold_stale_event(&self, event: &BridgeEvent) -> Result<()>;
+}
+
+/// Record of the blocks relayed events were found in, used to detect reorgs
+#[async_trait]
+pub trait SeenEventStore: Send + Sync {
+    /// Record (or move) a relayed event to the block it was found in
+    async fn record_seen_event(&self, chain: &str, event: &SeenEvent) -> Result<()>;
+
+    /// The recorded block of a relayed event, if it was relayed
+    async fn seen_event(&self, chain: &str, tx_hash: &str) -> Result<Option<SeenEvent>>;
+
+    /// Relayed events found at or above `from_block`, lowest block first
+    async fn seen_events_since(&self, chain: &str, from_block: u64) -> Result<Vec<SeenEvent>>;
+}
+
+#[async_trait]
+impl SeenEventStore for Database {
+    async fn record_seen_event(&self, chain: &str, event: &SeenEvent) -> Result<()> {
+        self.store_seen_event(chain, event).await
+    }
+
+    async fn seen_event(&self, chain: &str, tx_hash: &str) -> Result<Option<SeenEvent>> {
+        self.get_seen_event(chain, tx_hash).await
+    }
+
+    async fn seen_events_since(&self, chain: &str, from_block: u64) -> Result<Vec<SeenEvent>> {
+        Database::seen_events_since(self, chain, from_block).await
+    }
+}
+
+#[async_trait]
+impl StaleEventStore for Database {
+    async fn hold_stale_event(&self, event: &BridgeEvent) -> Result<()> {
+        BridgeCoordinator::validate_amount(event)?;
+
+        // A redelivered event was already handled when first stored
+        if self.store_event(event).await? {
+            self.mark_stale(event).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CursorStore for Database {
+    async fn load_cursor(&self, chain: &str) -> Result<Option<u64>> {
+        match chain {
+            "ethereum" => self.get_last_processed_ethereum_block().await,
+            "polkadot" => Ok(self.get_last_processed_polkadot_block().await?.map(u64::from)),
+            other => Err(RelayerError::Generic {
+                message: format!("Unknown chain: {}", other),
+            }),
+        }
+    }
+
+    async fn save_cursor(&self, chain: &str, block: u64) -> Result<()> {
+        match chain {
+            "ethereum" => self.set_last_processed_ethereum_block(block).await,
+            "polkadot" => {
+                let block = u32::try_from(block).map_err(|_| RelayerError::Polkadot {
+                    message: format!("Block number {} out of range", block),
+                })?;
+                self.set_last_processed_polkadot_block(block).await
+            }
+            other => Err(RelayerError::Generic {
+                message: format!("Unknown chain: {}", other),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl EventSource for EthereumClient {
+    fn chain(&self) -> &'static str {
+        "ethereum"
+    }
+
+    async fn poll(&self, from_block: u64, max_blocks: u64) -> Result<(Vec<BridgeEvent>, u64)> {
+        // Blocks shallower than the confirmation depth may still be reorged away
+        let current_block = self.get_block_number().await?
+            .saturating_sub(self.confirmations())
+            .min(from_block.saturating_add(max_blocks));
+
+        if current_block <= from_block {
+            return Ok((vec![], from_block));
+        }
+
+        debug!("Processing Ethereum blocks {} to {}", from_block + 1, current_block);
+
+        // Get past lock events
+        let lock_events = self.get_past_lock_events(from_block + 1, current_block).await?;
+
+        let events = lock_events
+            .into_iter()
+            .map(|(event, meta)| BridgeEvent::EthereumLock {
+                user: format!("{:?}", event.user),
+                token: format!("{:?}", event.token),
+                amount: event.amount.to_string(),
+                polkadot_address: format!("{:?}", event.polkadot_address),
+                tx_hash: format!("{:?}", meta.transaction_hash),
+                block_number: meta.block_number.as_u64(),
+            })
+            .collect();
+
+        Ok((events, current_block))
+    }
+
+    async fn block_hash(&self, block: u64) -> Result<Option<String>> {
+        Ok(self.get_block_hash(block).await?.map(|hash| format!("{:?}", hash)))
+    }
+}
+
+#[async_trait]
+impl EventSource for PolkadotClient {
+    fn chain(&self) -> &'static str {
+        "polkadot"
+    }
+
+    async fn poll(&self, from_block: u64, max_blocks: u64) -> Result<(Vec<BridgeEvent>, u64)> {
+        let from_block = u32::try_from(from_block).map_err(|_| RelayerError::Polkadot {
+            message: format!("Block number {} out of range", from_block),
+        })?;
+        let max_blocks = u32::try_from(max_blocks).unwrap_or(u32::MAX);
+        let current_block = self.get_block_number().await?
+            .saturating_sub(self.confirmations())
+            .min(from_block.saturating_add(max_blocks));
+
+        if current_block <= from_block {
+            return Ok((vec![], from_block.into()));
+        }
+
+        debug!("Processing Polkadot blocks {} to {}", from_block + 1, current_block);
+
+        // Get past burn events
+        let burn_events = self.get_past_burn_events(from_block + 1, current_block).await?;
+
+        let events = burn_events
+            .into_iter()
+            .map(|event| BridgeEvent::PolkadotBurn {
+                user: event.burner,
+                asset_id: event.asset_id,
+                amount: event.amount,
+                ethereum_recipient: event.ethereum_recipient,
+                tx_hash: event.tx_hash,
+                block_number: event.block_number,
+            })
+            .collect();
+
+        Ok((events, current_block.into()))
+    }
+
+    async fn block_hash(&self, block: u64) -> Result<Option<String>> {
+        match u32::try_from(block) {
+            Ok(block) => self.get_block_hash(block).await,
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+/// Event source recording how long each of its calls takes
+struct TimedSource {
+    inner: Arc<dyn EventSource>,
+    durations: Arc<LatencyHistogram>,
+}
+
+#[async_trait]
+impl EventSource for TimedSource {
+    fn chain(&self) -> &'static str {
+        self.inner.chain()
+    }
+
+    async fn poll(&self, from_block: u64, max_blocks: u64) -> Result<(Vec<BridgeEvent>, u64)> {
+        let started = Instant::now();
+        let poll = self.inner.poll(from_block, max_blocks).await;
+        self.durations.observe(&[("chain", self.chain()), ("call", "poll")], started.elapsed());
+        poll
+    }
+
+    async fn block_hash(&self, block: u64) -> Result<Option<String>> {
+        let started = Instant::now();
+        let hash = self.inner.block_hash(block).await;
+        self.durations.observe(&[("chain", self.chain()), ("call", "block_hash")], started.elapsed());
+        hash
+    }
+}
+
+/// Handle to running monitoring tasks
+pub struct MonitorHandle {
+    cancel: CancellationToken,
+    tasks: Vec<JoinHandle<Result<()>>>,
+}
+
+impl MonitorHandle {
+    /// Token that stops the monitoring tasks when cancelled
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancel
+    }
+
+    /// Stop monitoring and wait for every task to persist its cursor
+    pub async fn stop(self) -> Result<()> {
+        self.cancel.cancel();
+
+        for task in self.tasks {
+            task.await.map_err(|e| RelayerError::Generic {
+                message: format!("Monitoring task panicked: {}", e),
+            })??;
+        }
+
+        Ok(())
+    }
+}
+
+/// State shared by every chain's monitoring task
+#[derive(Clone)]
+struct MonitorContext {
+    cursors: Arc<dyn CursorStore>,
+    rate_limits: Arc<RpcRateLimits>,
+    stale_events: Option<(u64, Arc<dyn StaleEventStore>)>,
+    seen_events: Option<Arc<dyn SeenEventStore>>,
+    reorgs: Arc<ChainReorgs>,
+    rpc_calls: Arc<LatencyHistogram>,
+    batch_size: u64,
+}
 
 /// Event monitor that watches both chains for bridge events
 pub struct EventMonitor {
-    ethereum_client: Arc<EthereumClient>,
-    polkadot_client: Arc<PolkadotClient>,
-    database: Arc<Database>,
+    sources: Vec<Arc<dyn EventSource>>,
+    context: MonitorContext,
 }
 
 impl EventMonitor {
@@ -27,194 +295,346 @@ impl EventMonitor {
         polkadot_client: Arc<PolkadotClient>,
         database: Arc<Database>,
     ) -> Result<Self> {
-        Ok(Self {
-            ethereum_client,
-            polkadot_client,
+        Ok(Self::with_sources(
+            vec![ethereum_client, polkadot_client],
             database,
-        })
+        ))
+    }
+
+    /// Create an event monitor over arbitrary event sources
+    pub fn with_sources(sources: Vec<Arc<dyn EventSource>>, cursors: Arc<dyn CursorStore>) -> Self {
+        Self {
+            sources,
+            context: MonitorContext {
+                cursors,
+                rate_limits: Arc::new(RpcRateLimits::new()),
+                stale_events: None,
+                seen_events: None,
+                reorgs: Arc::new(ChainReorgs::new()),
+                rpc_calls: Arc::new(LatencyHistogram::rpc_calls()),
+                batch_size: DEFAULT_BATCH_SIZE,
+            },
+        }
+    }
+
+    /// Count rate-limited polls in `rate_limits`
+    pub fn with_rate_limits(mut self, rate_limits: Arc<RpcRateLimits>) -> Self {
+        self.context.rate_limits = rate_limits;
+        self
+    }
+
+    /// Scan at most `batch_size` blocks per poll, saving the cursor after each
+    pub fn with_batch_size(mut self, batch_size: u64) -> Self {
+        self.context.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Time every poll and block-hash lookup in `rpc_calls`
+    pub fn with_rpc_durations(mut self, rpc_calls: Arc<LatencyHistogram>) -> Self {
+        self.context.rpc_calls = rpc_calls;
+        self
     }
 
-    /// Start monitoring events on both chains
+    /// Record the block of every relayed event in `store` and roll back when
+    /// a reorg changes one of those blocks
+    pub fn with_reorg_detection(mut self, store: Arc<dyn SeenEventStore>) -> Self {
+        self.context.seen_events = Some(store);
+        self
+    }
+
+    /// Hold events more than `max_age_blocks` behind the polled block in
+    /// `store` instead of relaying them
+    pub fn with_max_event_age(mut self, max_age_blocks: u64, store: Arc<dyn StaleEventStore>) -> Self {
+        self.context.stale_events = Some((max_age_blocks, store));
+        self
+    }
+
+    /// Rate-limited polls per chain
+    pub fn rate_limits(&self) -> &Arc<RpcRateLimits> {
+        &self.context.rate_limits
+    }
+
+    /// Reorgs rolled back per chain
+    pub fn reorgs(&self) -> &Arc<ChainReorgs> {
+        &self.context.reorgs
+    }
+
+    /// Durations of the calls made to each chain's source
+    pub fn rpc_durations(&self) -> &Arc<LatencyHistogram> {
+        &self.context.rpc_calls
+    }
+
+    /// Drop the sources of chains whose transfers `direction` does not handle
+    pub fn for_direction(mut self, direction: RelayDirection) -> Self {
+        self.sources.retain(|source| direction.handles_chain(source.chain()));
+        self
+    }
+
+    /// Chains being monitored
+    pub fn chains(&self) -> Vec<&'static str> {
+        self.sources.iter().map(|source| source.chain()).collect()
+    }
+
+    /// Start monitoring events on all chains
+    ///
+    /// Each chain resumes from its persisted cursor. Cancelling `cancel` (or
+    /// calling [`MonitorHandle::stop`]) saves the cursor and ends the tasks, so
+    /// monitoring can be restarted with a fresh policy without re-scanning.
     pub async fn start_monitoring(
         &self,
         event_sender: mpsc::UnboundedSender<BridgeEvent>,
-        config: MonitoringConfig,
-    ) -> Result<()> {
+        policy: RelayerPolicy,
+        cancel: CancellationToken,
+    ) -> Result<MonitorHandle> {
         info!("Starting event monitoring");
 
-        // Start Ethereum monitoring
-        let ethereum_client = self.ethereum_client.clone();
-        let database = self.database.clone();
-        let event_sender_eth = event_sender.clone();
-        let poll_interval = config.poll_interval;
-
-        tokio::spawn(async move {
-            if let Err(e) = Self::monitor_ethereum_events(
-                ethereum_client,
-                database,
-                event_sender_eth,
-                poll_interval,
-            ).await {
-                error!("Ethereum event monitoring failed: {}", e);
-            }
-        });
-
-        // Start Polkadot monitoring
-        let polkadot_client = self.polkadot_client.clone();
-        let database = self.database.clone();
-        let event_sender_dot = event_sender;
-
-        tokio::spawn(async move {
-            if let Err(e) = Self::monitor_polkadot_events(
-                polkadot_client,
-                database,
-                event_sender_dot,
-                poll_interval,
-            ).await {
-                error!("Polkadot event monitoring failed: {}", e);
-            }
-        });
+        let tasks = self.sources
+            .iter()
+            .map(|source| {
+                let source: Arc<dyn EventSource> = Arc::new(TimedSource {
+                    inner: source.clone(),
+                    durations: self.context.rpc_calls.clone(),
+                });
+                let context = self.context.clone();
+                let event_sender = event_sender.clone();
+                let cancel = cancel.clone();
 
-        Ok(())
+                tokio::spawn(async move {
+                    let chain = source.chain();
+                    let result = Self::monitor_events(
+                        source,
+                        context,
+                        event_sender,
+                        policy,
+                        cancel,
+                    ).await;
+
+                    if let Err(e) = &result {
+                        error!("{} event monitoring failed: {}", chain, e);
+                    }
+                    result
+                })
+            })
+            .collect();
+
+        Ok(MonitorHandle { cancel, tasks })
     }
 
-    /// Monitor Ethereum events
-    async fn monitor_ethereum_events(
-        ethereum_client: Arc<EthereumClient>,
-        database: Arc<Database>,
+    /// Poll a single chain until cancelled
+    ///
+    /// Failed polls are retried with exponential backoff. A poll the provider
+    /// rate-limited is counted, and its backoff starts from the policy's
+    /// rate-limit cooldown instead of the retry delay. Once the retries are
+    /// exhausted the next poll keeps backing off rather than waiting the poll
+    /// interval, until one succeeds.
+    /// Events older than the maximum event age are held as stale rather than
+    /// sent on.
+    ///
+    /// A backlog is scanned `batch_size` blocks per poll, with the cursor
+    /// saved after each batch, until a poll comes back short of a full batch.
+    /// Event age is measured from the end of the batch the event was found in.
+    ///
+    /// With reorg detection enabled, each iteration first re-checks the blocks
+    /// of recently relayed events. If one changed hash, the cursor is rolled
+    /// back to just before it and the blocks re-scanned; events relayed before
+    /// the reorg are not sent again, wherever they land in the new chain.
+    async fn monitor_events(
+        source: Arc<dyn EventSource>,
+        context: MonitorContext,
         event_sender: mpsc::UnboundedSender<BridgeEvent>,
-        poll_interval: u64,
+        policy: RelayerPolicy,
+        cancel: CancellationToken,
     ) -> Result<()> {
-        info!("Starting Ethereum event monitoring");
+        let MonitorContext { cursors, rate_limits, stale_events, seen_events, reorgs, batch_size, .. } = context;
+        let chain = source.chain();
+        let mut last_processed_block = cursors.load_cursor(chain).await?.unwrap_or(0);
 
-        let mut last_processed_block = database.get_last_processed_ethereum_block().await?
-            .unwrap_or(0);
+        info!("Starting {} event monitoring from block {}", chain, last_processed_block);
 
+        let mut failed_polls = 0;
         loop {
-            match Self::process_ethereum_events(
-                &ethereum_client,
-                &database,
-                &event_sender,
-                last_processed_block,
-            ).await {
-                Ok(new_block) => {
-                    if new_block > last_processed_block {
-                        last_processed_block = new_block;
-                        database.set_last_processed_ethereum_block(new_block).await?;
+            if let Some(store) = &seen_events {
+                match Self::find_reorg(source.as_ref(), store.as_ref(), last_processed_block).await {
+                    Ok(Some((block, orphaned))) => {
+                        let tx_hashes: Vec<_> = orphaned.iter().map(|event| event.tx_hash.as_str()).collect();
+                        warn!(
+                            "{} reorg at block {}; rolling back from block {} (orphaned events: {})",
+                            chain, block, last_processed_block, tx_hashes.join(", ")
+                        );
+                        reorgs.record(chain);
+                        for event in &orphaned {
+                            store.record_seen_event(chain, event).await?;
+                        }
+                        last_processed_block = block.saturating_sub(1);
+                        cursors.save_cursor(chain, last_processed_block).await?;
                     }
-                }
-                Err(e) => {
-                    error!("Error processing Ethereum events: {}", e);
+                    Ok(None) => {}
+                    Err(e) => warn!("Failed to check {} for reorgs: {}", chain, e),
                 }
             }
 
-            tokio::time::sleep(Duration::from_secs(poll_interval)).await;
-        }
-    }
+            // Scan the backlog a batch at a time, so a restart mid-range
+            // resumes from the last completed batch
+            let mut next_poll = policy.timeouts.poll_interval;
+            loop {
+                let from_block = last_processed_block;
+                let poll = policy.retry.retry_with_backoff(
+                    &format!("{} event poll", chain),
+                    || async {
+                        let poll = source.poll(from_block, batch_size).await;
+                        if poll.as_ref().is_err_and(RelayerError::is_rate_limited) {
+                            rate_limits.record(chain);
+                        }
+                        poll
+                    },
+                ).await;
 
-    /// Process Ethereum events for a block range
-    async fn process_ethereum_events(
-        ethereum_client: &EthereumClient,
-        database: &Database,
-        event_sender: &mpsc::UnboundedSender<BridgeEvent>,
-        from_block: u64,
-    ) -> Result<u64> {
-        let current_block = ethereum_client.get_block_number().await?;
-        
-        if current_block <= from_block {
-            return Ok(from_block);
-        }
+                match poll {
+                    Ok((events, new_block)) => {
+                        failed_polls = 0;
+                        let mut block_hashes = HashMap::new();
+                        for event in events {
+                            if let Some(store) = &seen_events {
+                                match Self::record_seen(source.as_ref(), store.as_ref(), &mut block_hashes, &event).await {
+                                    Ok(true) => {
+                                        debug!("Skipping {} event {} already relayed before a reorg", chain, event.tx_hash());
+                                        continue;
+                                    }
+                                    Ok(false) => {}
+                                    Err(e) => error!("Failed to record block of {} event {}: {}", chain, event.tx_hash(), e),
+                                }
+                            }
 
-        debug!("Processing Ethereum blocks {} to {}", from_block + 1, current_block);
+                            if let Some((max_age, store)) = &stale_events {
+                                let age = new_block.saturating_sub(event.block_number());
+                                if age > *max_age {
+                                    warn!(
+                                        "Holding stale {} event {} ({} blocks old, max {})",
+                                        chain, event.tx_hash(), age, max_age
+                                    );
+                                    if let Err(e) = store.hold_stale_event(&event).await {
+                                        error!("Failed to hold stale {} event: {}", chain, e);
+                                    }
+                                    continue;
+                                }
+                            }
 
-        // Get past lock events
-        let lock_events = ethereum_client.get_past_lock_events(from_block + 1).await?;
+                            if let Err(e) = event_sender.send(event) {
+                                error!("Failed to send {} event: {}", chain, e);
+                            }
+                        }
 
-        for event in lock_events {
-            let bridge_event = BridgeEvent::EthereumLock {
-                user: format!("{:?}", event.user),
-                token: format!("{:?}", event.token),
-                amount: event.amount.to_string(),
-                polkadot_address: format!("{:?}", event.polkadot_address),
-                tx_hash: "mock_tx_hash".to_string(), // Would get from event metadata
-                block_number: current_block,
-            };
+                        if new_block > last_processed_block {
+                            last_processed_block = new_block;
+                            cursors.save_cursor(chain, new_block).await?;
+                        }
+
+                        // A chunk shorter than the batch means the scan reached the head
+                        if new_block < from_block.saturating_add(batch_size) || cancel.is_cancelled() {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        // Keep growing the backoff past the retry budget instead
+                        // of returning to the poll interval while the RPC is down
+                        failed_polls += 1;
+                        let backoff = policy.retry.backoff(&e, policy.retry.max_retries + failed_polls);
+                        error!("Error processing {} events, next poll in {:?}: {}", chain, backoff, e);
+                        next_poll = next_poll.max(backoff);
+                        break;
+                    }
+                }
 
-            if let Err(e) = event_sender.send(bridge_event) {
-                error!("Failed to send Ethereum event: {}", e);
             }
+
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = tokio::time::sleep(next_poll) => {}
+            }
+        }
+
+        if let Err(e) = cursors.save_cursor(chain, last_processed_block).await {
+            warn!("Failed to persist {} cursor on shutdown: {}", chain, e);
+            return Err(e);
         }
 
-        Ok(current_block)
+        info!("Stopped {} event monitoring at block {}", chain, last_processed_block);
+        Ok(())
     }
 
-    /// Monitor Polkadot events
-    async fn monitor_polkadot_events(
-        polkadot_client: Arc<PolkadotClient>,
-        database: Arc<Database>,
-        event_sender: mpsc::UnboundedSender<BridgeEvent>,
-        poll_interval: u64,
-    ) -> Result<()> {
-        info!("Starting Polkadot event monitoring");
+    /// The lowest recently relayed block whose hash changed, with the events
+    /// relayed from it and every block above
+    ///
+    /// The orphaned events come back carrying the current hash of their
+    /// block, so recording them acknowledges the reorg while still marking
+    /// them as relayed.
+    async fn find_reorg(
+        source: &dyn EventSource,
+        store: &dyn SeenEventStore,
+        last_processed_block: u64,
+    ) -> Result<Option<(u64, Vec<SeenEvent>)>> {
+        let chain = source.chain();
+        let from_block = last_processed_block.saturating_sub(REORG_CHECK_DEPTH);
+        let seen = store.seen_events_since(chain, from_block).await?;
 
-        let mut last_processed_block = database.get_last_processed_polkadot_block().await?
-            .unwrap_or(0);
+        let mut block_hashes = HashMap::new();
+        let mut reorg_block = None;
+        let mut orphaned = Vec::new();
+        for event in seen {
+            let hash = Self::cached_block_hash(source, &mut block_hashes, event.block_number).await?;
 
-        loop {
-            match Self::process_polkadot_events(
-                &polkadot_client,
-                &database,
-                &event_sender,
-                last_processed_block,
-            ).await {
-                Ok(new_block) => {
-                    if new_block > last_processed_block {
-                        last_processed_block = new_block;
-                        database.set_last_processed_polkadot_block(new_block).await?;
-                    }
-                }
-                Err(e) => {
-                    error!("Error processing Polkadot events: {}", e);
-                }
+            if reorg_block.is_none() && hash.as_ref().is_some_and(|hash| *hash != event.block_hash) {
+                reorg_block = Some(event.block_number);
             }
 
-            tokio::time::sleep(Duration::from_secs(poll_interval)).await;
+            if reorg_block.is_some() {
+                orphaned.push(SeenEvent {
+                    block_hash: hash.unwrap_or(event.block_hash),
+                    ..event
+                });
+            }
         }
+
+        Ok(reorg_block.map(|block| (block, orphaned)))
     }
 
-    /// Process Polkadot events for a block range
-    async fn process_polkadot_events(
-        polkadot_client: &PolkadotClient,
-        database: &Database,
-        event_sender: &mpsc::UnboundedSender<BridgeEvent>,
-        from_block: u32,
-    ) -> Result<u32> {
-        let current_block = polkadot_client.get_block_number().await?;
-        
-        if current_block <= from_block {
-            return Ok(from_block);
-        }
+    /// Record the block `event` was found in, returning whether it had
+    /// already been relayed
+    async fn record_seen(
+        source: &dyn EventSource,
+        store: &dyn SeenEventStore,
+        block_hashes: &mut HashMap<u64, Option<String>>,
+        event: &BridgeEvent,
+    ) -> Result<bool> {
+        let chain = source.chain();
+        let block_number = event.block_number();
+        let already_seen = store.seen_event(chain, event.tx_hash()).await?.is_some();
 
-        debug!("Processing Polkadot blocks {} to {}", from_block + 1, current_block);
+        let block_hash = Self::cached_block_hash(source, block_hashes, block_number).await?;
 
-        // Get past burn events
-        let burn_events = polkadot_client.get_past_burn_events(from_block + 1).await?;
+        if let Some(block_hash) = block_hash {
+            store.record_seen_event(chain, &SeenEvent {
+                tx_hash: event.tx_hash().to_string(),
+                block_number,
+                block_hash,
+            }).await?;
+        }
 
-        for event in burn_events {
-            let bridge_event = BridgeEvent::PolkadotBurn {
-                user: event.burner,
-                asset_id: event.asset_id,
-                amount: event.amount,
-                ethereum_recipient: event.ethereum_recipient,
-                tx_hash: event.tx_hash,
-                block_number: event.block_number,
-            };
+        Ok(already_seen)
+    }
 
-            if let Err(e) = event_sender.send(bridge_event) {
-                error!("Failed to send Polkadot event: {}", e);
-            }
+    /// Hash of `block` from `source`, asking it at most once per cache
+    async fn cached_block_hash(
+        source: &dyn EventSource,
+        block_hashes: &mut HashMap<u64, Option<String>>,
+        block: u64,
+    ) -> Result<Option<String>> {
+        if let Some(hash) = block_hashes.get(&block) {
+            return Ok(hash.clone());
         }
 
-        Ok(current_block)
+        let hash = source.block_hash(block).await?;
+        block_hashes.insert(block, hash.clone());
+        Ok(hash)
     }
 }
@@ -0,0 +1,183 @@
+//! Publishing of processed bridge events to external message queues
+//!
+//! Operators who already run a queue can consume the relayer's view of each
+//! transfer from it instead of polling the API. Without a configured sink,
+//! events are not published anywhere outside the process; in-process
+//! subscribers such as the API's WebSocket clients get them through a
+//! [`BroadcastEventSink`].
+
+use crate::{
+    config::EventSinkConfig,
+    coordinator::BridgeEvent,
+    error::{RelayerError, Result},
+};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing::warn;
+
+/// How handling a bridge event ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventStatus {
+    /// The event was handled without error
+    Processed,
+    /// Handling the event failed
+    Failed,
+}
+
+/// A processed bridge event as published to a sink
+#[derive(Debug, Clone, Serialize)]
+pub struct PublishedEvent {
+    pub chain: &'static str,
+    pub tx_hash: String,
+    pub block_number: u64,
+    pub status: EventStatus,
+    pub error: Option<String>,
+    /// Short code for why a failed event was rejected, such as
+    /// `invalid_destination`, when the failure has one
+    pub reason: Option<&'static str>,
+    pub event: BridgeEvent,
+}
+
+impl PublishedEvent {
+    /// Describe the outcome of handling `event`
+    pub fn new(event: &BridgeEvent, result: &Result<()>) -> Self {
+        let (status, error, reason) = match result {
+            Ok(()) => (EventStatus::Processed, None, None),
+            Err(e) => (EventStatus::Failed, Some(e.to_string()), e.failure_reason()),
+        };
+
+        Self {
+            chain: event.chain(),
+            tx_hash: event.tx_hash().to_string(),
+            block_number: event.block_number(),
+            status,
+            error,
+            reason,
+            event: event.clone(),
+        }
+    }
+}
+
+/// Destination for processed bridge events
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    /// Publish one processed event
+    async fn publish(&self, event: &PublishedEvent) -> Result<()>;
+}
+
+/// Sink that drops every event
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopEventSink;
+
+#[async_trait]
+impl EventSink for NoopEventSink {
+    async fn publish(&self, _event: &PublishedEvent) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Sink forwarding every event to in-process subscribers before publishing
+/// it to an inner sink
+///
+/// Subscribers that fall more than the channel capacity behind miss events
+/// rather than slowing down relaying.
+pub struct BroadcastEventSink {
+    inner: Arc<dyn EventSink>,
+    sender: broadcast::Sender<PublishedEvent>,
+}
+
+impl BroadcastEventSink {
+    /// Wrap `inner`, buffering up to `capacity` events per subscriber
+    pub fn new(inner: Arc<dyn EventSink>, capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self { inner, sender }
+    }
+
+    /// Channel the events are broadcast on; subscribe to it to receive them
+    pub fn sender(&self) -> &broadcast::Sender<PublishedEvent> {
+        &self.sender
+    }
+}
+
+#[async_trait]
+impl EventSink for BroadcastEventSink {
+    async fn publish(&self, event: &PublishedEvent) -> Result<()> {
+        // Having no subscribers is not an error
+        let _ = self.sender.send(event.clone());
+        self.inner.publish(event).await
+    }
+}
+
+/// Sink appending each event to a Redis stream as JSON in an `event` field
+#[cfg(feature = "redis-sink")]
+pub struct RedisStreamSink {
+    connection: redis::aio::ConnectionManager,
+    stream: String,
+}
+
+#[cfg(feature = "redis-sink")]
+impl RedisStreamSink {
+    /// Connect to the Redis server at `url`, publishing to `stream`
+    pub async fn connect(url: &str, stream: String) -> Result<Self> {
+        let client = redis::Client::open(url).map_err(|e| RelayerError::Config {
+            message: format!("Invalid Redis URL {}: {}", url, e),
+        })?;
+        let connection = client.get_connection_manager().await.map_err(|e| RelayerError::Network {
+            message: format!("Failed to connect to Redis at {}: {}", url, e),
+        })?;
+
+        Ok(Self { connection, stream })
+    }
+}
+
+#[cfg(feature = "redis-sink")]
+#[async_trait]
+impl EventSink for RedisStreamSink {
+    async fn publish(&self, event: &PublishedEvent) -> Result<()> {
+        let payload = serde_json::to_string(event).map_err(|e| RelayerError::Generic {
+            message: format!("Failed to encode event {}: {}", event.tx_hash, e),
+        })?;
+
+        let mut connection = self.connection.clone();
+        let _: String = redis::cmd("XADD")
+            .arg(&self.stream)
+            .arg("*")
+            .arg("event")
+            .arg(payload)
+            .query_async(&mut connection)
+            .await
+            .map_err(|e| RelayerError::Network {
+                message: format!("Failed to publish event {} to {}: {}", event.tx_hash, self.stream, e),
+            })?;
+
+        Ok(())
+    }
+}
+
+/// Build the configured event sink, falling back to [`NoopEventSink`]
+pub async fn build_event_sink(config: Option<&EventSinkConfig>) -> Result<Arc<dyn EventSink>> {
+    match config {
+        None => Ok(Arc::new(NoopEventSink)),
+        #[cfg(feature = "redis-sink")]
+        Some(EventSinkConfig::RedisStream { url, stream }) => {
+            Ok(Arc::new(RedisStreamSink::connect(url, stream.clone()).await?))
+        }
+        #[cfg(not(feature = "redis-sink"))]
+        Some(EventSinkConfig::RedisStream { .. }) => Err(RelayerError::Config {
+            message: "Redis event sink requires the relayer's redis-sink feature".to_string(),
+        }),
+    }
+}
+
+/// Publish the outcome of handling `event`
+///
+/// A sink failure is logged rather than returned: the event itself was
+/// already handled, and failing it would only cause it to be retried.
+pub async fn publish_outcome(sink: &dyn EventSink, event: &BridgeEvent, result: &Result<()>) {
+    if let Err(e) = sink.publish(&PublishedEvent::new(event, result)).await {
+        warn!("Failed to publish {} event {}: {}", event.chain(), event.tx_hash(), e);
+    }
+}
@@ -0,0 +1,70 @@
+//! This validator's threshold key share, loaded from an encrypted keystore
+//!
+//! The keystore file is written by the DKG ceremony with
+//! [`threshold::keystore::encrypt_key_share`]. It is decrypted on first use
+//! with the passphrase from [`KEYSTORE_PASSPHRASE_ENV`] and kept in memory for
+//! the life of the process; the decrypted share is zeroized when dropped.
+
+use crate::error::{RelayerError, Result};
+use std::path::PathBuf;
+use std::sync::Arc;
+use threshold::keystore::{decrypt_key_share, SecretKeyShare};
+use tokio::sync::OnceCell;
+use tracing::info;
+use zeroize::Zeroizing;
+
+/// Environment variable holding the keystore passphrase
+pub const KEYSTORE_PASSPHRASE_ENV: &str = "VALIDATOR_KEYSTORE_PASSPHRASE";
+
+/// Lazily decrypted, cached key share of this validator
+pub struct KeyStore {
+    path: PathBuf,
+    passphrase: Zeroizing<String>,
+    key_share: OnceCell<Arc<SecretKeyShare>>,
+}
+
+impl KeyStore {
+    /// Keystore at `path`, opened with `passphrase`
+    pub fn new(path: impl Into<PathBuf>, passphrase: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            passphrase: Zeroizing::new(passphrase.into()),
+            key_share: OnceCell::new(),
+        }
+    }
+
+    /// Keystore at `path`, opened with the passphrase from [`KEYSTORE_PASSPHRASE_ENV`]
+    pub fn from_env(path: impl Into<PathBuf>) -> Result<Self> {
+        let passphrase = std::env::var(KEYSTORE_PASSPHRASE_ENV).map_err(|_| RelayerError::Config {
+            message: format!("{} must be set to open the validator keystore", KEYSTORE_PASSPHRASE_ENV),
+        })?;
+
+        Ok(Self::new(path, passphrase))
+    }
+
+    /// Path of the keystore file
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// The decrypted key share, read from disk on the first call
+    pub async fn key_share(&self) -> Result<Arc<SecretKeyShare>> {
+        self.key_share
+            .get_or_try_init(|| async {
+                let sealed = tokio::fs::read(&self.path).await.map_err(|e| RelayerError::Config {
+                    message: format!("Failed to read keystore {}: {}", self.path.display(), e),
+                })?;
+                let key_share = decrypt_key_share(&sealed, &self.passphrase)?;
+
+                info!(
+                    "Loaded key share for {} (epoch {}) from {}",
+                    key_share.validator_id,
+                    key_share.key_epoch,
+                    self.path.display()
+                );
+                Ok(Arc::new(key_share))
+            })
+            .await
+            .cloned()
+    }
+}
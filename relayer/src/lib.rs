@@ -9,8 +9,19 @@ pub mod polkadot;
 pub mod coordinator;
 pub mod config;
 pub mod event_monitor;
+pub mod event_sink;
 pub mod signature_coordinator;
+pub mod keystore;
 pub mod database;
+pub mod metrics;
+pub mod policy;
+pub mod price_oracle;
+pub mod allowlist;
+pub mod mint_batcher;
+pub mod retry_queue;
+pub mod nonce_manager;
+pub mod checkpoint;
+pub mod p2p;
 
 pub use error::{RelayerError, Result};
 pub use coordinator::BridgeCoordinator;
@@ -0,0 +1,604 @@
+//! Relayer health metrics
+//!
+//! Every metric is a `prometheus` collector registered in the
+//! [`RelayerMetrics`] registry, which the monitoring port and the API's
+//! `/metrics` endpoint both render.
+
+use crate::{
+    database::{CompletionTimes, Database, TokenStats},
+    error::{RelayerError, Result},
+    signature_coordinator::SignatureCoordinator,
+};
+use http_body_util::Full;
+use hyper::{body::Bytes, header, server::conn::http1, service::service_fn, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use prometheus::{
+    core::Collector, GaugeVec, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts,
+    TextEncoder,
+};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use threshold::KeySet;
+use tokio::net::TcpListener;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+pub use prometheus::Registry;
+
+/// Chains tracked by the relayer
+pub const CHAINS: [&str; 2] = ["ethereum", "polkadot"];
+
+/// Content type of the Prometheus text exposition format
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Register `collector` in `registry`
+pub fn register(registry: &Registry, collector: impl Collector + 'static) -> Result<()> {
+    registry
+        .register(Box::new(collector))
+        .map_err(|e| RelayerError::Generic {
+            message: format!("Failed to register metric: {}", e),
+        })
+}
+
+/// Render every metric in `registry` in Prometheus text exposition format
+pub fn encode(registry: &Registry) -> Result<String> {
+    TextEncoder::new()
+        .encode_to_string(&registry.gather())
+        .map_err(|e| RelayerError::Generic {
+            message: format!("Failed to encode metrics: {}", e),
+        })
+}
+
+/// Integer gauge labelled by `chain`, with a zero sample for each of [`CHAINS`]
+fn chain_gauge(name: &str, help: &str) -> IntGaugeVec {
+    let gauge = IntGaugeVec::new(Opts::new(name, help), &["chain"]).expect("metric options are valid");
+    for chain in CHAINS {
+        gauge.with_label_values(&[chain]);
+    }
+    gauge
+}
+
+/// Counter labelled by `chain`, with a zero sample for each of [`CHAINS`]
+fn chain_counter(name: &str, help: &str) -> IntCounterVec {
+    let counter = IntCounterVec::new(Opts::new(name, help), &["chain"]).expect("metric options are valid");
+    for chain in CHAINS {
+        counter.with_label_values(&[chain]);
+    }
+    counter
+}
+
+/// Detected vs completed transfer counts for a single chain
+#[derive(Debug, Clone, Default)]
+pub struct ChainTransferHealth {
+    /// Source-chain events the relayer has recorded
+    pub detected: u64,
+    /// Transfers completed on the destination chain
+    pub completed: u64,
+    /// When the gap first exceeded the configured threshold
+    pub over_threshold_since: Option<Instant>,
+    /// Whether the gap has exceeded the threshold for longer than the window
+    pub degraded: bool,
+}
+
+impl ChainTransferHealth {
+    /// Transfers that were detected but have not completed
+    pub fn incomplete(&self) -> u64 {
+        self.detected.saturating_sub(self.completed)
+    }
+}
+
+/// Tracks the gap between detected and completed transfers per chain
+///
+/// A growing gap means events are being dropped somewhere between detection
+/// and submission. The tracker flips to degraded once the gap has stayed above
+/// `threshold` for longer than `window`.
+#[derive(Debug)]
+pub struct TransferHealth {
+    threshold: u64,
+    window: Duration,
+    chains: RwLock<HashMap<String, ChainTransferHealth>>,
+    incomplete: IntGaugeVec,
+    degraded: IntGaugeVec,
+}
+
+impl TransferHealth {
+    /// Create a new tracker
+    pub fn new(threshold: u64, window: Duration) -> Self {
+        Self {
+            threshold,
+            window,
+            chains: RwLock::new(HashMap::new()),
+            incomplete: chain_gauge(
+                "bridge_incomplete_transfers",
+                "Transfers detected but not yet completed",
+            ),
+            degraded: chain_gauge(
+                "bridge_transfers_degraded",
+                "Whether the incomplete-transfer gap exceeded its threshold for too long",
+            ),
+        }
+    }
+
+    /// Record the latest counts for a chain
+    pub async fn observe(&self, chain: &str, detected: u64, completed: u64) {
+        self.observe_at(chain, detected, completed, Instant::now()).await
+    }
+
+    /// Record the latest counts for a chain as of `now`
+    pub async fn observe_at(&self, chain: &str, detected: u64, completed: u64, now: Instant) {
+        let mut chains = self.chains.write().await;
+        let health = chains.entry(chain.to_string()).or_default();
+
+        health.detected = detected;
+        health.completed = completed;
+
+        if health.incomplete() > self.threshold {
+            let since = *health.over_threshold_since.get_or_insert(now);
+            health.degraded = now.saturating_duration_since(since) >= self.window;
+        } else {
+            health.over_threshold_since = None;
+            health.degraded = false;
+        }
+
+        self.incomplete.with_label_values(&[chain]).set(health.incomplete() as i64);
+        self.degraded.with_label_values(&[chain]).set(health.degraded as i64);
+    }
+
+    /// Number of incomplete transfers for a chain
+    pub async fn incomplete_transfers(&self, chain: &str) -> u64 {
+        self.chains
+            .read()
+            .await
+            .get(chain)
+            .map(ChainTransferHealth::incomplete)
+            .unwrap_or(0)
+    }
+
+    /// Whether any chain is currently degraded
+    pub async fn is_degraded(&self) -> bool {
+        self.chains.read().await.values().any(|health| health.degraded)
+    }
+
+    /// Snapshot of the per-chain state
+    pub async fn snapshot(&self) -> HashMap<String, ChainTransferHealth> {
+        self.chains.read().await.clone()
+    }
+
+    /// Register the incomplete-transfer and degraded gauges in `registry`
+    pub fn register(&self, registry: &Registry) -> Result<()> {
+        register(registry, self.incomplete.clone())?;
+        register(registry, self.degraded.clone())
+    }
+}
+
+/// Counts source-chain events that arrived after they were already stored
+///
+/// Duplicates are expected after a reorg or monitor restart re-scans a few
+/// blocks; a steadily climbing count points at overlapping monitors or a
+/// misconfigured cursor.
+#[derive(Debug, Clone)]
+pub struct DuplicateEvents {
+    counter: IntCounterVec,
+}
+
+impl Default for DuplicateEvents {
+    fn default() -> Self {
+        Self {
+            counter: chain_counter(
+                "bridge_duplicate_events_total",
+                "Source-chain events received after they were already stored",
+            ),
+        }
+    }
+}
+
+impl DuplicateEvents {
+    /// Create a new counter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a duplicate event on a chain
+    pub fn record(&self, chain: &str) {
+        self.counter.with_label_values(&[chain]).inc();
+    }
+
+    /// Number of duplicates seen on a chain
+    pub fn count(&self, chain: &str) -> u64 {
+        self.counter.with_label_values(&[chain]).get()
+    }
+
+    /// Register the counter in `registry`
+    pub fn register(&self, registry: &Registry) -> Result<()> {
+        register(registry, self.counter.clone())
+    }
+}
+
+/// Counts RPC requests each chain's provider rejected for rate limiting
+#[derive(Debug, Clone)]
+pub struct RpcRateLimits {
+    counter: IntCounterVec,
+}
+
+impl Default for RpcRateLimits {
+    fn default() -> Self {
+        Self {
+            counter: chain_counter(
+                "bridge_rpc_rate_limited_total",
+                "RPC requests rejected by the provider's rate limit",
+            ),
+        }
+    }
+}
+
+impl RpcRateLimits {
+    /// Create a new counter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a rate-limited request on a chain
+    pub fn record(&self, chain: &str) {
+        self.counter.with_label_values(&[chain]).inc();
+    }
+
+    /// Number of rate-limited requests seen on a chain
+    pub fn count(&self, chain: &str) -> u64 {
+        self.counter.with_label_values(&[chain]).get()
+    }
+
+    /// Register the counter in `registry`
+    pub fn register(&self, registry: &Registry) -> Result<()> {
+        register(registry, self.counter.clone())
+    }
+}
+
+/// Counts chain reorganizations the event monitor rolled back over
+///
+/// Each count is a rescan from the first block whose hash changed under an
+/// already-relayed event.
+#[derive(Debug, Clone)]
+pub struct ChainReorgs {
+    counter: IntCounterVec,
+}
+
+impl Default for ChainReorgs {
+    fn default() -> Self {
+        Self {
+            counter: chain_counter(
+                "bridge_chain_reorgs_total",
+                "Reorganizations that orphaned an already-relayed event",
+            ),
+        }
+    }
+}
+
+impl ChainReorgs {
+    /// Create a new counter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a reorg on a chain
+    pub fn record(&self, chain: &str) {
+        self.counter.with_label_values(&[chain]).inc();
+    }
+
+    /// Number of reorgs seen on a chain
+    pub fn count(&self, chain: &str) -> u64 {
+        self.counter.with_label_values(&[chain]).get()
+    }
+
+    /// Register the counter in `registry`
+    pub fn register(&self, registry: &Registry) -> Result<()> {
+        register(registry, self.counter.clone())
+    }
+}
+
+/// Counts transfers completed on their destination chain, by source chain
+#[derive(Debug, Clone)]
+pub struct ProcessedTransactions {
+    counter: IntCounterVec,
+}
+
+impl Default for ProcessedTransactions {
+    fn default() -> Self {
+        Self {
+            counter: chain_counter(
+                "bridge_processed_transactions_total",
+                "Transfers completed on their destination chain",
+            ),
+        }
+    }
+}
+
+impl ProcessedTransactions {
+    /// Create a new counter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a completed transfer from a chain
+    pub fn record(&self, chain: &str) {
+        self.counter.with_label_values(&[chain]).inc();
+    }
+
+    /// Number of transfers completed from a chain
+    pub fn count(&self, chain: &str) -> u64 {
+        self.counter.with_label_values(&[chain]).get()
+    }
+
+    /// Register the counter in `registry`
+    pub fn register(&self, registry: &Registry) -> Result<()> {
+        register(registry, self.counter.clone())
+    }
+}
+
+/// Upper bounds, in seconds, of the latency histogram buckets
+pub const LATENCY_BUCKETS: [f64; 12] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+/// Latency histogram with one series per label set
+#[derive(Debug, Clone)]
+pub struct LatencyHistogram {
+    histogram: HistogramVec,
+}
+
+impl LatencyHistogram {
+    /// Create an empty histogram exported as `name`, labelled by `labels`
+    pub fn new(name: &str, help: &str, labels: &[&str]) -> Self {
+        let opts = HistogramOpts::new(name, help).buckets(LATENCY_BUCKETS.to_vec());
+        Self {
+            histogram: HistogramVec::new(opts, labels).expect("metric options are valid"),
+        }
+    }
+
+    /// Histogram of the calls event sources make to chain RPC endpoints,
+    /// labelled by `chain` and `call`
+    pub fn rpc_calls() -> Self {
+        Self::new("bridge_rpc_call_duration_seconds", "Duration of chain RPC calls", &["chain", "call"])
+    }
+
+    /// Record a duration in the series for `labels`
+    ///
+    /// Label names the histogram was not created with are ignored, dropping
+    /// the observation.
+    pub fn observe(&self, labels: &[(&str, &str)], duration: Duration) {
+        let labels: HashMap<&str, &str> = labels.iter().copied().collect();
+        match self.histogram.get_metric_with(&labels) {
+            Ok(series) => series.observe(duration.as_secs_f64()),
+            Err(e) => debug!("Dropped latency observation: {}", e),
+        }
+    }
+
+    /// Number of durations recorded for `labels`
+    pub fn count(&self, labels: &[(&str, &str)]) -> u64 {
+        let labels: HashMap<&str, &str> = labels.iter().copied().collect();
+        self.histogram
+            .get_metric_with(&labels)
+            .map(|series| series.get_sample_count())
+            .unwrap_or(0)
+    }
+
+    /// Register the histogram in `registry`
+    pub fn register(&self, registry: &Registry) -> Result<()> {
+        register(registry, self.histogram.clone())
+    }
+}
+
+/// Relayer metrics and the registry they are gathered from
+///
+/// The relayer's own counters and histograms are registered on creation;
+/// collectors owned elsewhere, such as the event monitor's counters, are
+/// added with their `register` methods.
+#[derive(Debug)]
+pub struct RelayerMetrics {
+    registry: Registry,
+    /// Completed transfers per source chain
+    pub processed_transactions: ProcessedTransactions,
+    /// Time spent handling each source-chain event, labelled by `chain`
+    pub processing_latency: LatencyHistogram,
+    /// Chain RPC call durations, shared with the event monitor
+    pub rpc_calls: Arc<LatencyHistogram>,
+    pending_sessions: IntGauge,
+    active_validators: IntGauge,
+    key_epoch: IntGaugeVec,
+    completion_seconds: GaugeVec,
+}
+
+impl Default for RelayerMetrics {
+    fn default() -> Self {
+        let metrics = Self {
+            registry: Registry::new(),
+            processed_transactions: ProcessedTransactions::new(),
+            processing_latency: LatencyHistogram::new(
+                "bridge_event_processing_seconds",
+                "Time from receiving a source-chain event to finishing its processing",
+                &["chain"],
+            ),
+            rpc_calls: Arc::new(LatencyHistogram::rpc_calls()),
+            pending_sessions: IntGauge::new(
+                "bridge_pending_signature_sessions",
+                "Signature sessions waiting for partial signatures",
+            ).expect("metric options are valid"),
+            active_validators: IntGauge::new(
+                "bridge_active_validators",
+                "Validators currently counting toward the signing threshold",
+            ).expect("metric options are valid"),
+            // Unlabelled, so no sample is emitted until a key set is loaded
+            key_epoch: IntGaugeVec::new(
+                Opts::new("bridge_key_epoch", "Key-generation epoch of the active threshold key set"),
+                &[],
+            ).expect("metric options are valid"),
+            completion_seconds: GaugeVec::new(
+                Opts::new(
+                    "bridge_transfer_completion_seconds",
+                    "Seconds from detecting a transfer to completing it, over recent completions",
+                ),
+                &["stat"],
+            ).expect("metric options are valid"),
+        };
+
+        metrics.register_own().expect("relayer metrics have distinct names");
+        metrics
+    }
+}
+
+impl RelayerMetrics {
+    /// Create empty metrics
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn register_own(&self) -> Result<()> {
+        self.processed_transactions.register(&self.registry)?;
+        self.processing_latency.register(&self.registry)?;
+        self.rpc_calls.register(&self.registry)?;
+        register(&self.registry, self.pending_sessions.clone())?;
+        register(&self.registry, self.active_validators.clone())?;
+        register(&self.registry, self.key_epoch.clone())?;
+        register(&self.registry, self.completion_seconds.clone())
+    }
+
+    /// Registry every served metric is gathered from
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    /// Set the gauges read from `signatures` on each scrape: open signature
+    /// sessions, active validators and the active key epoch
+    pub async fn refresh(&self, signatures: &SignatureCoordinator) {
+        let pending_sessions = signatures.count_pending_signatures().await.unwrap_or_default();
+        let active_validators = signatures.count_active_validators().await.unwrap_or_default();
+
+        self.pending_sessions.set(pending_sessions as i64);
+        self.active_validators.set(active_validators as i64);
+        self.set_key_epoch(signatures.key_set().as_ref());
+    }
+
+    /// Report the epoch of `key_set`, or nothing until one is loaded
+    pub fn set_key_epoch(&self, key_set: Option<&KeySet>) {
+        match key_set {
+            Some(key_set) => self.key_epoch.with_label_values(&[]).set(key_set.key_epoch as i64),
+            None => self.key_epoch.reset(),
+        }
+    }
+
+    /// Report the average and 95th percentile of recent completion times
+    ///
+    /// No samples are emitted while no transfer completed within the window.
+    pub fn set_completion_times(&self, times: &CompletionTimes) {
+        for (stat, secs) in [("avg", times.average_secs), ("p95", times.p95_secs)] {
+            match secs {
+                Some(secs) => self.completion_seconds.with_label_values(&[stat]).set(secs),
+                None => {
+                    let _ = self.completion_seconds.remove_label_values(&[stat]);
+                }
+            }
+        }
+    }
+
+    /// Render every registered metric in Prometheus text exposition format
+    pub fn encode(&self) -> Result<String> {
+        encode(&self.registry)
+    }
+}
+
+/// Serve `metrics` at `/metrics` on `listener` until `shutdown` resolves
+///
+/// The gauges read from `signatures` are refreshed on each scrape.
+pub async fn serve(
+    listener: TcpListener,
+    metrics: Arc<RelayerMetrics>,
+    signatures: Arc<SignatureCoordinator>,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> Result<()> {
+    tokio::pin!(shutdown);
+
+    loop {
+        let stream = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    return Err(RelayerError::Network {
+                        message: format!("Metrics endpoint failed: {}", e),
+                    })
+                }
+            },
+            _ = &mut shutdown => return Ok(()),
+        };
+
+        let metrics = metrics.clone();
+        let signatures = signatures.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| scrape(request, metrics.clone(), signatures.clone()));
+            if let Err(e) = http1::Builder::new().serve_connection(TokioIo::new(stream), service).await {
+                debug!("Metrics connection closed: {}", e);
+            }
+        });
+    }
+}
+
+async fn scrape<B>(
+    request: Request<B>,
+    metrics: Arc<RelayerMetrics>,
+    signatures: Arc<SignatureCoordinator>,
+) -> std::result::Result<Response<Full<Bytes>>, Infallible> {
+    if request.uri().path() != "/metrics" {
+        return Ok(status(StatusCode::NOT_FOUND));
+    }
+
+    metrics.refresh(&signatures).await;
+    let response = match metrics.encode() {
+        Ok(body) => Response::builder()
+            .header(header::CONTENT_TYPE, CONTENT_TYPE)
+            .body(Full::new(Bytes::from(body)))
+            .expect("static headers are valid"),
+        Err(e) => {
+            debug!("{}", e);
+            status(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    };
+    Ok(response)
+}
+
+fn status(code: StatusCode) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::default());
+    *response.status_mut() = code;
+    response
+}
+
+/// Caches the per-token statistics for a fixed TTL
+///
+/// Aggregating every stored transfer is too heavy to repeat on each `/stats`
+/// request, so results are reused until they are `ttl` old.
+#[derive(Debug)]
+pub struct TokenStatsCache {
+    ttl: Duration,
+    cached: RwLock<Option<(HashMap<String, TokenStats>, Instant)>>,
+}
+
+impl TokenStatsCache {
+    /// Create a cache reusing each aggregation for `ttl`
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            cached: RwLock::new(None),
+        }
+    }
+
+    /// Per-token statistics, aggregated from `database` once the cache is stale
+    pub async fn get(&self, database: &Database) -> Result<HashMap<String, TokenStats>> {
+        if let Some((stats, computed_at)) = self.cached.read().await.as_ref() {
+            if computed_at.elapsed() < self.ttl {
+                return Ok(stats.clone());
+            }
+        }
+
+        let stats = database.token_stats().await?;
+        *self.cached.write().await = Some((stats.clone(), Instant::now()));
+        Ok(stats)
+    }
+}
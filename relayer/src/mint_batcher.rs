@@ -0,0 +1,181 @@
+//! Batching of signed mints into single Polkadot extrinsics
+
+use crate::{
+    error::RelayerError,
+    polkadot::{MintSubmitter, PendingMint},
+};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// A mint that went out, and the extrinsic that carried it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmittedMint {
+    /// Hash of the Ethereum lock the mint is for
+    pub ethereum_tx_hash: String,
+    /// Hash of the `mint_tokens` or `mint_tokens_batch` extrinsic
+    pub extrinsic_hash: String,
+}
+
+/// A mint that could not be submitted, and why
+#[derive(Debug)]
+pub struct FailedMint {
+    /// Hash of the Ethereum lock the mint is for
+    pub ethereum_tx_hash: String,
+    pub error: RelayerError,
+}
+
+/// What became of the mints in a submitted batch
+///
+/// Every mint handed to the submitter ends up in exactly one of the two
+/// lists, so the caller can record the minted locks and release its claims
+/// on the failed ones.
+#[derive(Debug, Default)]
+pub struct BatchOutcome {
+    pub minted: Vec<SubmittedMint>,
+    pub failed: Vec<FailedMint>,
+}
+
+impl BatchOutcome {
+    /// Whether no mint was submitted or attempted
+    pub fn is_empty(&self) -> bool {
+        self.minted.is_empty() && self.failed.is_empty()
+    }
+}
+
+/// Mints queued for the next batch
+#[derive(Default)]
+struct PendingBatch {
+    mints: Vec<PendingMint>,
+    opened_at: Option<Instant>,
+}
+
+/// Collects signed mints and submits them together
+///
+/// A batch is submitted once it holds `max_batch` mints or its window has
+/// elapsed. With `max_batch` of one, every mint is submitted immediately.
+pub struct MintBatcher {
+    submitter: Arc<dyn MintSubmitter>,
+    max_batch: usize,
+    window: Duration,
+    pending: Mutex<PendingBatch>,
+}
+
+impl MintBatcher {
+    /// Create a batcher submitting up to `max_batch` mints per extrinsic
+    pub fn new(submitter: Arc<dyn MintSubmitter>, max_batch: usize, window: Duration) -> Self {
+        Self {
+            submitter,
+            max_batch: max_batch.max(1),
+            window,
+            pending: Mutex::new(PendingBatch::default()),
+        }
+    }
+
+    /// Whether mints are held back to be combined
+    pub fn is_batching(&self) -> bool {
+        self.max_batch > 1
+    }
+
+    /// How long a batch may wait to fill
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Number of mints waiting to be submitted
+    pub async fn pending_count(&self) -> usize {
+        self.pending.lock().await.mints.len()
+    }
+
+    /// Queue a mint, submitting the batch once it is full
+    ///
+    /// Returns what became of the submitted mints, which is empty while the
+    /// batch is still filling.
+    pub async fn push(&self, mint: PendingMint) -> BatchOutcome {
+        let full = {
+            let mut pending = self.pending.lock().await;
+            pending.opened_at.get_or_insert_with(Instant::now);
+            pending.mints.push(mint);
+
+            if pending.mints.len() >= self.max_batch {
+                Some(std::mem::take(&mut *pending))
+            } else {
+                None
+            }
+        };
+
+        match full {
+            Some(batch) => self.submit(batch.mints).await,
+            None => BatchOutcome::default(),
+        }
+    }
+
+    /// Submit the queued batch if its window has elapsed
+    pub async fn flush_due(&self) -> BatchOutcome {
+        let due = {
+            let mut pending = self.pending.lock().await;
+            match pending.opened_at {
+                Some(opened_at) if opened_at.elapsed() >= self.window => {
+                    Some(std::mem::take(&mut *pending))
+                }
+                _ => None,
+            }
+        };
+
+        match due {
+            Some(batch) => self.submit(batch.mints).await,
+            None => BatchOutcome::default(),
+        }
+    }
+
+    /// Submit whatever is queued, regardless of the window
+    pub async fn flush(&self) -> BatchOutcome {
+        let batch = std::mem::take(&mut *self.pending.lock().await);
+        self.submit(batch.mints).await
+    }
+
+    /// Submit mints, reporting which were minted and which failed
+    ///
+    /// The pallet rejects a whole batch if any mint in it is invalid, so a
+    /// failed batch is retried one mint at a time to let the valid ones
+    /// through.
+    async fn submit(&self, mints: Vec<PendingMint>) -> BatchOutcome {
+        if mints.len() > 1 {
+            match self.submitter.submit_mint_batch(&mints).await {
+                Ok(hash) => {
+                    debug!("Submitted batch of {} mints in {}", mints.len(), hash);
+                    return BatchOutcome {
+                        minted: mints
+                            .into_iter()
+                            .map(|m| SubmittedMint {
+                                ethereum_tx_hash: m.ethereum_tx_hash,
+                                extrinsic_hash: hash.clone(),
+                            })
+                            .collect(),
+                        failed: vec![],
+                    };
+                }
+                Err(e) => {
+                    warn!("Mint batch of {} failed, submitting individually: {}", mints.len(), e);
+                }
+            }
+        }
+
+        let mut outcome = BatchOutcome::default();
+        for mint in mints {
+            match self.submitter.submit_mint(&mint).await {
+                Ok(extrinsic_hash) => outcome.minted.push(SubmittedMint {
+                    ethereum_tx_hash: mint.ethereum_tx_hash,
+                    extrinsic_hash,
+                }),
+                Err(error) => outcome.failed.push(FailedMint {
+                    ethereum_tx_hash: mint.ethereum_tx_hash,
+                    error,
+                }),
+            }
+        }
+
+        outcome
+    }
+}
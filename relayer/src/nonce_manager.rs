@@ -0,0 +1,178 @@
+//! Nonce assignment for transactions sent from the relayer's Ethereum account
+//!
+//! Nonces are handed out locally instead of being read from the node for
+//! every transaction, so concurrent unlocks never race for the same pending
+//! nonce. The starting point is the account's pending nonce on the chain, or
+//! the persisted next nonce if that is further ahead (transactions sent just
+//! before a restart may not be known to the node yet). A nonce whose
+//! transaction reverted or failed to send is released and handed out again,
+//! so the next transaction replaces it instead of leaving a gap that would
+//! stall every later transaction.
+
+use crate::{
+    database::Database,
+    error::{RelayerError, Result},
+};
+use async_trait::async_trait;
+use ethers::{
+    providers::Middleware,
+    types::{Address, BlockNumber, U256},
+};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// Persistent storage for the next nonce of an account
+#[async_trait]
+pub trait NonceStore: Send + Sync {
+    /// Next unused nonce recorded for `address`, if any
+    async fn load_nonce(&self, address: Address) -> Result<Option<u64>>;
+
+    /// Persist the next unused nonce for `address`
+    async fn save_nonce(&self, address: Address, nonce: u64) -> Result<()>;
+}
+
+#[async_trait]
+impl NonceStore for Database {
+    async fn load_nonce(&self, address: Address) -> Result<Option<u64>> {
+        self.get_ethereum_nonce(&format!("{:?}", address)).await
+    }
+
+    async fn save_nonce(&self, address: Address, nonce: u64) -> Result<()> {
+        self.set_ethereum_nonce(&format!("{:?}", address), nonce).await
+    }
+}
+
+/// Nonces handed out so far
+struct NonceState {
+    /// Lowest nonce never handed out
+    next: U256,
+    /// Nonces below `next` whose transactions were never sent
+    released: BTreeSet<U256>,
+}
+
+/// Hands out monotonically increasing nonces for one account
+pub struct NonceManager {
+    address: Address,
+    store: Option<Arc<dyn NonceStore>>,
+    state: Mutex<Option<NonceState>>,
+}
+
+impl NonceManager {
+    /// Manage the nonces of `address`, without persisting them
+    pub fn new(address: Address) -> Self {
+        Self {
+            address,
+            store: None,
+            state: Mutex::new(None),
+        }
+    }
+
+    /// Persist the next nonce to `store` and resume from it after a restart
+    pub fn with_store(mut self, store: Arc<dyn NonceStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Account whose nonces are managed
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Take the next nonce, reading the account's pending nonce from `client`
+    /// on first use
+    ///
+    /// Released nonces are reused lowest first. Every nonce returned must be
+    /// either sent or passed back to [`Self::release`].
+    pub async fn next_nonce<M: Middleware>(&self, client: &M) -> Result<U256> {
+        let mut state = self.state.lock().await;
+
+        if state.is_none() {
+            *state = Some(self.initial_state(client).await?);
+        }
+        let state = state.as_mut().expect("nonce state initialized above");
+
+        if let Some(nonce) = state.released.pop_first() {
+            debug!("Reusing released nonce {} of {:?}", nonce, self.address);
+            return Ok(nonce);
+        }
+
+        let nonce = state.next;
+        self.persist(nonce + 1).await?;
+        state.next = nonce + 1;
+
+        Ok(nonce)
+    }
+
+    /// Give back a nonce whose transaction reverted or was never accepted,
+    /// so the next transaction replaces it
+    pub async fn release(&self, nonce: U256) {
+        let mut state = self.state.lock().await;
+        let Some(state) = state.as_mut() else {
+            return;
+        };
+        if nonce >= state.next {
+            return;
+        }
+
+        debug!("Releasing nonce {} of {:?}", nonce, self.address);
+        if nonce + 1 == state.next {
+            state.next = nonce;
+            // Earlier releases directly below the new top collapse into it
+            while let Some(&last) = state.released.last() {
+                if last + 1 != state.next {
+                    break;
+                }
+                state.released.pop_last();
+                state.next = last;
+            }
+
+            if let Err(e) = self.persist(state.next).await {
+                warn!("Failed to persist nonce of {:?}: {}", self.address, e);
+            }
+        } else {
+            state.released.insert(nonce);
+        }
+    }
+
+    /// Start after the chain's pending nonce or the persisted one, whichever
+    /// is further ahead
+    async fn initial_state<M: Middleware>(&self, client: &M) -> Result<NonceState> {
+        let pending = client
+            .get_transaction_count(self.address, Some(BlockNumber::Pending.into()))
+            .await
+            .map_err(|e| RelayerError::Ethereum {
+                message: format!("Failed to get nonce of {:?}: {}", self.address, e),
+            })?;
+
+        let persisted = match &self.store {
+            Some(store) => store.load_nonce(self.address).await?.map(U256::from),
+            None => None,
+        };
+
+        let next = match persisted {
+            Some(persisted) if persisted > pending => {
+                warn!(
+                    "Resuming {:?} at persisted nonce {} ahead of the chain's pending nonce {}",
+                    self.address, persisted, pending
+                );
+                persisted
+            }
+            _ => pending,
+        };
+        debug!("Starting nonces of {:?} at {}", self.address, next);
+
+        Ok(NonceState {
+            next,
+            released: BTreeSet::new(),
+        })
+    }
+
+    async fn persist(&self, next: U256) -> Result<()> {
+        match &self.store {
+            Some(store) => store.save_nonce(self.address, next.as_u64()).await,
+            None => Ok(()),
+        }
+    }
+}
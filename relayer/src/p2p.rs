@@ -0,0 +1,384 @@
+//! Validator-to-validator exchange of partial signatures over gRPC
+//!
+//! Every validator serves a `Validator` service whose `SubmitPartial` call
+//! carries a partial signature in the [`threshold::wire`] format. Partials are
+//! accepted only from configured, active peers and only for sessions this
+//! validator has open; accepted partials go through
+//! [`SignatureCoordinator::add_partial_signature`], which verifies them against
+//! the claimed signer's registered public key share. The payload's validator
+//! ID is only trusted once that check passes, so partials from validators
+//! without a registered share are rejected. A rejected partial is not retried
+//! by the sender: the session's re-requests pick it up later.
+//!
+//! With a [`PeerTls`] identity configured the exchange runs over mutually
+//! authenticated TLS with every peer's certificate pinned, and a partial is
+//! only accepted from the connection of the validator it claims to be from.
+
+use crate::{
+    config::{ValidatorConfig, ValidatorPeer},
+    error::{RelayerError, Result},
+    signature_coordinator::SignatureCoordinator,
+};
+use futures::future::join_all;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use threshold::{wire::{self, PartialSignatureMessage}, PartialSignature};
+use tokio::net::TcpListener;
+use tonic::transport::{
+    server::TcpIncoming, Certificate, Channel, ClientTlsConfig, Endpoint, Identity, Server, ServerTlsConfig,
+};
+use tonic::{Request, Response, Status};
+use tracing::{debug, warn};
+
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/bridge.p2p.Validator.rs"));
+}
+
+pub use generated::validator_client::ValidatorClient;
+pub use generated::validator_server::{Validator, ValidatorServer};
+
+/// How long a peer gets to accept a partial before it is given up on
+const PEER_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A partial signature submitted by a peer
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PartialSignatureMsg {
+    /// Partial signature and its session in the `threshold::wire` format
+    #[prost(bytes = "vec", tag = "1")]
+    pub payload: Vec<u8>,
+}
+
+/// Acknowledgement of an accepted partial signature
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubmitPartialResponse {}
+
+/// A peer's certificate, pinned as the only one it may present
+#[derive(Clone)]
+struct PinnedCertificate {
+    pem: Vec<u8>,
+    der: Vec<u8>,
+}
+
+/// TLS identity of this validator and the certificates pinned for its peers
+///
+/// The server only completes handshakes with clients presenting a pinned
+/// certificate, and clients only accept a server whose certificate is the one
+/// pinned for the peer they dial. Peers are dialled by validator ID, which
+/// their certificates must name.
+#[derive(Clone)]
+pub struct PeerTls {
+    identity: Identity,
+    pinned: HashMap<String, PinnedCertificate>,
+}
+
+impl PeerTls {
+    /// TLS for `config`, or `None` if it has no TLS identity
+    ///
+    /// Fails if a certificate or key cannot be read, or an active peer has
+    /// no certificate to pin.
+    pub fn from_config(config: &ValidatorConfig) -> Result<Option<Self>> {
+        let Some(tls) = &config.tls else {
+            return Ok(None);
+        };
+
+        let identity = Identity::from_pem(read_pem(&tls.certificate_path)?, read_pem(&tls.private_key_path)?);
+        let pinned = config.peers
+            .iter()
+            .filter(|peer| peer.active)
+            .map(|peer| {
+                let path = peer.certificate_path.as_ref().ok_or_else(|| RelayerError::Config {
+                    message: format!("No TLS certificate pinned for validator {}", peer.id),
+                })?;
+                Ok((peer.id.clone(), PinnedCertificate::from_pem(read_pem(path)?)?))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Some(Self { identity, pinned }))
+    }
+
+    /// Server settings requiring clients to present a pinned certificate
+    fn server_config(&self) -> ServerTlsConfig {
+        let config = ServerTlsConfig::new().identity(self.identity.clone());
+        if self.pinned.is_empty() {
+            return config;
+        }
+
+        let roots: Vec<&[u8]> = self.pinned.values().map(|pinned| pinned.pem.as_slice()).collect();
+        config.client_ca_root(Certificate::from_pem(roots.join(&b'\n')))
+    }
+
+    /// Client settings accepting only the certificate pinned for `peer_id`
+    fn client_config(&self, peer_id: &str) -> Result<ClientTlsConfig> {
+        let pinned = self.pinned.get(peer_id).ok_or_else(|| RelayerError::Config {
+            message: format!("No TLS certificate pinned for validator {}", peer_id),
+        })?;
+
+        Ok(ClientTlsConfig::new()
+            .ca_certificate(Certificate::from_pem(&pinned.pem))
+            .identity(self.identity.clone())
+            .domain_name(peer_id))
+    }
+
+    /// Peer whose pinned certificate is the DER-encoded `certificate`
+    fn peer_presenting(&self, certificate: &[u8]) -> Option<&str> {
+        self.pinned
+            .iter()
+            .find(|(_, pinned)| pinned.der == certificate)
+            .map(|(peer_id, _)| peer_id.as_str())
+    }
+}
+
+impl PinnedCertificate {
+    /// Pin the single certificate in `pem`
+    fn from_pem(pem: Vec<u8>) -> Result<Self> {
+        let der = rustls_pemfile::certs(&mut pem.as_slice())
+            .next()
+            .transpose()
+            .map_err(|e| RelayerError::Config {
+                message: format!("Invalid TLS certificate: {}", e),
+            })?
+            .ok_or_else(|| RelayerError::Config {
+                message: "No certificate in PEM file".to_string(),
+            })?
+            .to_vec();
+
+        Ok(Self { pem, der })
+    }
+}
+
+/// Read a PEM file
+fn read_pem(path: &str) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| RelayerError::Config {
+        message: format!("Failed to read {}: {}", path, e),
+    })
+}
+
+/// gRPC service routing peers' partial signatures into signature sessions
+pub struct PartialSignatureService {
+    coordinator: Arc<SignatureCoordinator>,
+    peers: HashSet<String>,
+    tls: Option<PeerTls>,
+}
+
+impl PartialSignatureService {
+    /// Accept partials for `coordinator`'s sessions from the active `peers`
+    pub fn new(coordinator: Arc<SignatureCoordinator>, peers: &[ValidatorPeer]) -> Self {
+        Self {
+            coordinator,
+            peers: peers.iter().filter(|peer| peer.active).map(|peer| peer.id.clone()).collect(),
+            tls: None,
+        }
+    }
+
+    /// Serve over TLS, accepting each peer's partials only from the
+    /// connection presenting its pinned certificate
+    pub fn with_tls(mut self, tls: Option<PeerTls>) -> Self {
+        self.tls = tls;
+        self
+    }
+}
+
+#[tonic::async_trait]
+impl Validator for PartialSignatureService {
+    async fn submit_partial(
+        &self,
+        request: Request<PartialSignatureMsg>,
+    ) -> std::result::Result<Response<SubmitPartialResponse>, Status> {
+        let source = request
+            .remote_addr()
+            .map(|addr| addr.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let presented = match (&self.tls, request.peer_certs()) {
+            (Some(tls), Some(certificates)) => certificates
+                .first()
+                .and_then(|certificate| tls.peer_presenting(certificate.as_ref()))
+                .map(str::to_string),
+            _ => None,
+        };
+
+        let PartialSignatureMessage { session_id, partial_signature } = wire::decode(&request.into_inner().payload)
+            .map_err(|e| Status::invalid_argument(format!("Malformed partial signature: {}", e)))?;
+        let validator_id = partial_signature.validator_id.clone();
+
+        if !self.peers.contains(&validator_id) {
+            warn!("Rejecting partial signature from unknown validator {} ({})", validator_id, source);
+            return Err(Status::permission_denied(format!("{} is not a configured peer", validator_id)));
+        }
+
+        if self.tls.is_some() && presented.as_deref() != Some(validator_id.as_str()) {
+            warn!("Rejecting partial signature claiming to be from {} over another peer's connection ({})", validator_id, source);
+            return Err(Status::permission_denied(format!("Connection is not authenticated as {}", validator_id)));
+        }
+
+        if self.coordinator.session(&session_id).await.is_none() {
+            debug!("Rejecting partial signature from {} for unknown session {}", validator_id, session_id);
+            return Err(Status::not_found(format!("No signature session for {}", session_id)));
+        }
+
+        self.coordinator
+            .add_partial_signature(&session_id, &validator_id, &source, partial_signature)
+            .await
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        Ok(Response::new(SubmitPartialResponse {}))
+    }
+}
+
+/// Serve `service` on `listener` until `shutdown` resolves
+///
+/// Connections use TLS when the service has a [`PeerTls`] identity.
+pub async fn serve(
+    listener: TcpListener,
+    service: PartialSignatureService,
+    shutdown: impl Future<Output = ()>,
+) -> Result<()> {
+    let incoming = TcpIncoming::from_listener(listener, true, None)
+        .map_err(|e| RelayerError::Network {
+            message: format!("Failed to accept validator connections: {}", e),
+        })?;
+
+    let mut server = Server::builder();
+    if let Some(tls) = &service.tls {
+        server = server.tls_config(tls.server_config()).map_err(|e| RelayerError::Config {
+            message: format!("Invalid validator TLS identity: {}", e),
+        })?;
+    }
+
+    server
+        .add_service(ValidatorServer::new(service))
+        .serve_with_incoming_shutdown(incoming, shutdown)
+        .await
+        .map_err(|e| RelayerError::Network {
+            message: format!("Partial signature service failed: {}", e),
+        })
+}
+
+/// Lazily connected client for `peer`, over TLS if `tls` is given
+///
+/// Addresses without a scheme are taken to be `https://` endpoints under TLS
+/// and plain `http://` ones otherwise.
+fn peer_client(peer: &ValidatorPeer, tls: Option<&PeerTls>) -> Result<ValidatorClient<Channel>> {
+    let url = match (peer.address.contains("://"), tls) {
+        (true, _) => peer.address.clone(),
+        (false, Some(_)) => format!("https://{}", peer.address),
+        (false, None) => format!("http://{}", peer.address),
+    };
+
+    let mut endpoint = Endpoint::from_shared(url)
+        .map_err(|e| RelayerError::Config {
+            message: format!("Invalid peer address {}: {}", peer.address, e),
+        })?
+        .connect_timeout(PEER_TIMEOUT)
+        .timeout(PEER_TIMEOUT);
+    if let Some(tls) = tls {
+        endpoint = endpoint.tls_config(tls.client_config(&peer.id)?).map_err(|e| RelayerError::Config {
+            message: format!("Invalid TLS settings for validator {}: {}", peer.id, e),
+        })?;
+    }
+
+    Ok(ValidatorClient::new(endpoint.connect_lazy()))
+}
+
+/// Send an encoded partial signature through `client`
+async fn send_partial(client: &ValidatorClient<Channel>, payload: Vec<u8>) -> Result<()> {
+    client
+        .clone()
+        .submit_partial(PartialSignatureMsg { payload })
+        .await
+        .map(|_| ())
+        .map_err(|status| RelayerError::Network {
+            message: format!("Peer rejected partial signature: {}", status.message()),
+        })
+}
+
+/// Send a partial signature for `session_id` to `peer`, over TLS if `tls`
+/// is given
+pub async fn submit_partial(
+    peer: &ValidatorPeer,
+    tls: Option<&PeerTls>,
+    session_id: &str,
+    partial: &PartialSignature,
+) -> Result<()> {
+    let payload = encode_partial(session_id, partial)?;
+    send_partial(&peer_client(peer, tls)?, payload).await
+}
+
+/// Encode a partial signature for the wire
+fn encode_partial(session_id: &str, partial: &PartialSignature) -> Result<Vec<u8>> {
+    Ok(wire::encode(&PartialSignatureMessage {
+        session_id: session_id.to_string(),
+        partial_signature: partial.clone(),
+    })?)
+}
+
+/// Fans this validator's partial signatures out to its configured peers
+pub struct PeerBroadcaster {
+    peers: Vec<(String, ValidatorClient<Channel>)>,
+}
+
+impl PeerBroadcaster {
+    /// Broadcast to the active `peers`, over TLS if `tls` is given, skipping
+    /// any with an unusable address
+    ///
+    /// Connections are opened on first use.
+    pub fn new(peers: &[ValidatorPeer], tls: Option<&PeerTls>) -> Self {
+        let peers = peers
+            .iter()
+            .filter(|peer| peer.active)
+            .filter_map(|peer| match peer_client(peer, tls) {
+                Ok(client) => Some((peer.id.clone(), client)),
+                Err(e) => {
+                    warn!("Not broadcasting to validator {}: {}", peer.id, e);
+                    None
+                }
+            })
+            .collect();
+
+        Self { peers }
+    }
+
+    /// Number of peers partials are sent to
+    pub fn peer_count(&self) -> usize {
+        self.peers.len()
+    }
+
+    /// Send a partial signature for `session_id` to every peer, returning
+    /// how many accepted it
+    ///
+    /// Peers are contacted concurrently; failures are logged, not returned.
+    pub async fn broadcast(&self, session_id: &str, partial: &PartialSignature) -> Result<usize> {
+        self.send_to(session_id, partial, |_| true).await
+    }
+
+    /// Send a partial signature for `session_id` to the peers whose ID
+    /// `include` selects, returning how many accepted it
+    ///
+    /// Like [`Self::broadcast`], failures are logged, not returned.
+    pub async fn send_to(
+        &self,
+        session_id: &str,
+        partial: &PartialSignature,
+        include: impl Fn(&str) -> bool,
+    ) -> Result<usize> {
+        let payload = encode_partial(session_id, partial)?;
+
+        let peers = self.peers.iter().filter(|(peer_id, _)| include(peer_id));
+        let results = join_all(peers.map(|(peer_id, client)| {
+            let payload = payload.clone();
+            async move { (peer_id, send_partial(client, payload).await) }
+        }))
+        .await;
+
+        let mut delivered = 0;
+        for (peer_id, result) in results {
+            match result {
+                Ok(()) => delivered += 1,
+                Err(e) => warn!("Failed to send partial signature for {} to {}: {}", session_id, peer_id, e),
+            }
+        }
+
+        Ok(delivered)
+    }
+}
@@ -0,0 +1,200 @@
+//! Retry and timeout policy shared across the relayer
+//!
+//! Every component takes its retry counts and timeouts from a single
+//! [`RelayerPolicy`] built from the relayer configuration, instead of reading
+//! scattered config fields or hardcoding durations.
+
+use crate::{
+    config::RelayerConfig,
+    error::{RelayerError, Result},
+};
+use rand::Rng;
+use std::future::Future;
+use std::time::{Duration, SystemTime};
+use tracing::warn;
+
+/// Upper bound on how often expired signature sessions are swept
+const MAX_SIGNATURE_CLEANUP_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Upper bound on the first delay between partial signature re-requests
+const MAX_PARTIAL_REREQUEST_DELAY: Duration = Duration::from_secs(30);
+
+/// Upper bound on a single backoff delay, before jitter
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(600);
+
+/// How failed operations are retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt
+    pub max_retries: u32,
+    /// Delay between attempts
+    pub retry_delay: Duration,
+    /// Longer delay after an RPC provider rate-limits us
+    pub rate_limit_cooldown: Duration,
+}
+
+impl RetryPolicy {
+    /// How long to wait before retrying after `error`
+    pub fn delay_for(&self, error: &RelayerError) -> Duration {
+        if error.is_rate_limited() {
+            self.rate_limit_cooldown.max(self.retry_delay)
+        } else {
+            self.retry_delay
+        }
+    }
+
+    /// Delay before the `attempt`th retry after `error`
+    ///
+    /// Starts at [`delay_for`](Self::delay_for) and doubles with each attempt
+    /// up to ten minutes, plus up to a quarter more of random jitter so
+    /// relayers sharing a provider do not retry in lockstep.
+    pub fn backoff(&self, error: &RelayerError, attempt: u32) -> Duration {
+        let delay = self.delay_for(error)
+            .saturating_mul(1u32 << attempt.saturating_sub(1).min(16))
+            .min(MAX_RETRY_BACKOFF);
+        let jitter = rand::thread_rng().gen_range(0..=delay.as_millis() as u64 / 4);
+
+        delay + Duration::from_millis(jitter)
+    }
+
+    /// Run `operation`, retrying failures up to `max_retries` times with
+    /// exponentially growing [`backoff`](Self::backoff) delays
+    pub async fn retry_with_backoff<T, F, Fut>(&self, what: &str, mut operation: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempt = 0;
+
+        loop {
+            match operation().await {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt < self.max_retries => {
+                    attempt += 1;
+                    let delay = self.backoff(&e, attempt);
+                    warn!(
+                        "{} failed (attempt {}/{}), retrying in {:?}: {}",
+                        what, attempt, self.max_retries + 1, delay, e
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Run `operation`, retrying failures up to `max_retries` times
+    pub async fn retry<T, F, Fut>(&self, what: &str, mut operation: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempt = 0;
+
+        loop {
+            match operation().await {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt < self.max_retries => {
+                    attempt += 1;
+                    warn!("{} failed (attempt {}/{}): {}", what, attempt, self.max_retries + 1, e);
+                    tokio::time::sleep(self.delay_for(&e)).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Timeouts and intervals used by the relayer's background tasks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeouts {
+    /// Interval between chain event polls
+    pub poll_interval: Duration,
+    /// How long a signature session may collect partials before it expires
+    pub signature_timeout: Duration,
+    /// How often expired signature sessions are swept
+    pub signature_cleanup_interval: Duration,
+    /// How long past its timeout an incomplete session keeps re-requesting
+    /// partials before it is dead-lettered
+    pub signature_grace_period: Duration,
+    /// Delay before the second re-request, doubling after each one
+    pub partial_rerequest_delay: Duration,
+    /// How far a partial signature's timestamp may drift from local time
+    /// (zero disables the check)
+    pub max_clock_skew: Duration,
+}
+
+impl Timeouts {
+    /// Delay after the `attempt`th re-request before the next one
+    pub fn rerequest_backoff(&self, attempt: u32) -> Duration {
+        self.partial_rerequest_delay
+            .saturating_mul(1u32 << attempt.saturating_sub(1).min(16))
+    }
+
+    /// Reject a partial signature timestamped more than `max_clock_skew`
+    /// before or after `now`
+    pub fn check_clock_skew(&self, validator_id: &str, timestamp: SystemTime, now: SystemTime) -> Result<()> {
+        if self.max_clock_skew.is_zero() {
+            return Ok(());
+        }
+
+        let offset = match timestamp.duration_since(now) {
+            Ok(ahead) => ahead.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs() as i64),
+        };
+
+        if offset.unsigned_abs() > self.max_clock_skew.as_secs() {
+            return Err(RelayerError::ClockSkew {
+                validator_id: validator_id.to_string(),
+                offset_secs: offset,
+                tolerance_secs: self.max_clock_skew.as_secs(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Retry and timeout policy for the relayer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelayerPolicy {
+    pub retry: RetryPolicy,
+    pub timeouts: Timeouts,
+}
+
+impl RelayerPolicy {
+    /// Build the policy from relayer configuration
+    ///
+    /// Expired sessions are swept at a quarter of the signature timeout,
+    /// capped at five minutes, so a session never outlives its timeout by more
+    /// than that margin. With a grace period, re-requests start at an eighth
+    /// of it (at most 30 seconds) and sweeps run at least that often.
+    pub fn from_config(config: &RelayerConfig) -> Self {
+        let signature_timeout = Duration::from_secs(config.threshold.signature_timeout);
+        let signature_grace_period = Duration::from_secs(config.threshold.signature_grace_period);
+        let partial_rerequest_delay = (signature_grace_period / 8)
+            .clamp(Duration::from_secs(1), MAX_PARTIAL_REREQUEST_DELAY);
+
+        let mut signature_cleanup_interval = (signature_timeout / 4)
+            .clamp(Duration::from_secs(1), MAX_SIGNATURE_CLEANUP_INTERVAL);
+        if !signature_grace_period.is_zero() {
+            signature_cleanup_interval = signature_cleanup_interval.min(partial_rerequest_delay);
+        }
+
+        Self {
+            retry: RetryPolicy {
+                max_retries: config.monitoring.max_retries,
+                retry_delay: Duration::from_secs(config.monitoring.retry_delay),
+                rate_limit_cooldown: Duration::from_secs(config.monitoring.rate_limit_cooldown),
+            },
+            timeouts: Timeouts {
+                poll_interval: Duration::from_secs(config.monitoring.poll_interval),
+                signature_timeout,
+                signature_cleanup_interval,
+                signature_grace_period,
+                partial_rerequest_delay,
+                max_clock_skew: Duration::from_secs(config.threshold.max_clock_skew),
+            },
+        }
+    }
+}
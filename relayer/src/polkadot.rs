@@ -4,13 +4,128 @@ use crate::{
     config::PolkadotConfig,
     error::{RelayerError, Result},
 };
-use subxt::{OnlineClient, PolkadotConfig as SubxtConfig};
-use tracing::{info, debug, error};
+use async_trait::async_trait;
+use bridge_runtime_api::BurnRequest;
+use ethers::{types::{H160, H256}, utils::keccak256};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::str::FromStr;
+use std::time::Duration;
+use subxt::{
+    backend::{legacy::LegacyRpcMethods, rpc::RpcClient},
+    dynamic::Value,
+    error::DispatchError,
+    ext::codec::Encode,
+    tx::{DynamicPayload, Signer, SubmittableExtrinsic, TxPayload},
+    utils::AccountId32,
+    OnlineClient,
+    PolkadotConfig as SubxtConfig,
+};
+use subxt_signer::{sr25519::Keypair, SecretUri};
+use tokio::sync::Mutex;
+use tracing::{info, debug, warn};
+
+/// Submissions of one extrinsic before a retryable pool rejection is given up on
+const MAX_SUBMIT_ATTEMPTS: u32 = 3;
+
+/// Wait before resubmitting an extrinsic the pool holds as valid only in the future
+const FUTURE_TX_WAIT: Duration = Duration::from_secs(6);
+
+/// A burn request as stored by the bridge runtime, whose assets are `u32`
+/// IDs and balances `u128`
+pub type PalletBurnRequest = BurnRequest<AccountId32, u32, u128>;
+
+/// Privileged bridge pallet calls submitted with the configured sudo key
+#[async_trait]
+pub trait PalletAdmin: Send + Sync {
+    /// Pause or resume the bridge pallet, returning the extrinsic hash
+    async fn set_paused(&self, paused: bool) -> Result<String>;
+}
+
+/// Submission of signed mints to the bridge pallet
+#[async_trait]
+pub trait MintSubmitter: Send + Sync {
+    /// Submit a single `mint_tokens` extrinsic, returning its hash
+    async fn submit_mint(&self, mint: &PendingMint) -> Result<String>;
+
+    /// Submit one atomic `mint_tokens_batch` extrinsic, returning its hash
+    async fn submit_mint_batch(&self, mints: &[PendingMint]) -> Result<String>;
+}
+
+/// A mint with enough validator signatures, waiting to be submitted
+#[derive(Debug, Clone)]
+pub struct PendingMint {
+    pub recipient: String,
+    pub ethereum_address: String,
+    pub amount: String,
+    pub ethereum_tx_hash: String,
+    pub signatures: Vec<Vec<u8>>,
+}
+
+/// Lookup of an account's next nonce on chain
+#[async_trait]
+pub trait NonceSource: Send + Sync {
+    /// Next nonce of `account` as of the latest finalized block
+    async fn fetch_nonce(&self, account: &AccountId32) -> Result<u64>;
+}
+
+/// Local nonce bookkeeping for the relayer's signing accounts
+///
+/// The chain only learns of a nonce once its extrinsic is in a block, so
+/// fetching it per submission hands consecutive submissions the same nonce.
+/// The tracker fetches each account's nonce once and then counts up locally,
+/// going back to the chain only when told to resync or forget.
+#[derive(Debug, Default)]
+pub struct NonceTracker {
+    next: Mutex<BTreeMap<AccountId32, u64>>,
+}
+
+impl NonceTracker {
+    /// Create a tracker that knows no accounts yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fetch `account`'s nonce from `source` and track it from there
+    pub async fn sync(&self, source: &dyn NonceSource, account: &AccountId32) -> Result<u64> {
+        let nonce = source.fetch_nonce(account).await?;
+        self.next.lock().await.insert(account.clone(), nonce);
+        Ok(nonce)
+    }
+
+    /// Take the next nonce for `account`, fetching it first if untracked
+    pub async fn reserve(&self, source: &dyn NonceSource, account: &AccountId32) -> Result<u64> {
+        let mut next = self.next.lock().await;
+        let nonce = match next.get(account) {
+            Some(nonce) => *nonce,
+            None => source.fetch_nonce(account).await?,
+        };
+        next.insert(account.clone(), nonce + 1);
+        Ok(nonce)
+    }
+
+    /// Refetch `account`'s nonce after the pool rejected one as stale, and take it
+    pub async fn resync(&self, source: &dyn NonceSource, account: &AccountId32) -> Result<u64> {
+        let nonce = source.fetch_nonce(account).await?;
+        self.next.lock().await.insert(account.clone(), nonce + 1);
+        Ok(nonce)
+    }
+
+    /// Stop tracking `account`, so its next nonce is fetched again
+    ///
+    /// Used when a submission is abandoned: its nonce was never used, and
+    /// counting past it would leave every later extrinsic waiting on the gap.
+    pub async fn forget(&self, account: &AccountId32) {
+        self.next.lock().await.remove(account);
+    }
+}
 
 /// Polkadot client for bridge operations
 pub struct PolkadotClient {
     config: PolkadotConfig,
     client: OnlineClient<SubxtConfig>,
+    rpc: LegacyRpcMethods<SubxtConfig>,
+    nonces: NonceTracker,
 }
 
 impl PolkadotClient {
@@ -18,19 +133,38 @@ impl PolkadotClient {
     pub async fn new(config: &PolkadotConfig) -> Result<Self> {
         info!("Connecting to Polkadot at {}", config.ws_url);
 
-        let client = OnlineClient::<SubxtConfig>::from_url(&config.ws_url)
+        let rpc_client = RpcClient::from_url(&config.ws_url)
+            .await
+            .map_err(|e| RelayerError::Polkadot {
+                message: format!("Failed to connect to Polkadot: {}", e),
+            })?;
+        let client = OnlineClient::<SubxtConfig>::from_rpc_client(rpc_client.clone())
             .await
             .map_err(|e| RelayerError::Polkadot {
                 message: format!("Failed to connect to Polkadot: {}", e),
             })?;
 
-        Ok(Self {
+        let polkadot = Self {
             config: config.clone(),
             client,
-        })
+            rpc: LegacyRpcMethods::new(rpc_client),
+            nonces: NonceTracker::new(),
+        };
+
+        for seed in [&config.account_seed, &config.admin_seed].into_iter().flatten() {
+            let account_id = <Keypair as Signer<SubxtConfig>>::account_id(&signer_from_seed(seed)?);
+            let nonce = polkadot.nonces.sync(&polkadot, &account_id).await?;
+            debug!("Polkadot account {} starts at nonce {}", account_id, nonce);
+        }
+
+        Ok(polkadot)
     }
 
     /// Mint tokens on Polkadot
+    ///
+    /// Signs `mint_tokens` with the configured account key and waits for it
+    /// to be finalized, returning the extrinsic hash. A mint the pallet
+    /// rejects is reported through [`mint_failure`].
     pub async fn mint_tokens(
         &self,
         recipient: &str,
@@ -44,18 +178,98 @@ impl PolkadotClient {
             recipient, amount, ethereum_tx_hash
         );
 
-        // This is a simplified implementation
-        // In a real implementation, you would:
-        // 1. Parse the recipient address
-        // 2. Create the mint extrinsic
-        // 3. Sign and submit the transaction
-        // 4. Wait for confirmation
+        let call = mint_call(
+            &self.config.pallet_name,
+            recipient,
+            ethereum_address,
+            amount,
+            ethereum_tx_hash,
+            &signatures,
+        )?;
+        let signer = self.account_signer()?;
+
+        let progress = self
+            .sign_and_submit("mint", &call, &signer, |extrinsic| async move {
+                extrinsic.submit_and_watch().await
+            })
+            .await?;
+
+        let events = progress
+            .wait_for_finalized_success()
+            .await
+            .map_err(|e| finalization_failure(recipient, e))?;
+
+        let tx_hash = format!("{:?}", events.extrinsic_hash());
+        debug!("Mint transaction finalized: {}", tx_hash);
+        Ok(tx_hash)
+    }
 
-        // For now, return a mock transaction hash
-        let mock_tx_hash = format!("polkadot_mint_{}", ethereum_tx_hash);
-        
-        debug!("Mock mint transaction submitted: {}", mock_tx_hash);
-        Ok(mock_tx_hash)
+    /// Partial fee of a `mint_tokens` extrinsic carrying `signature_count`
+    /// signatures, in the chain's smallest unit
+    ///
+    /// The mint is built and signed by the configured account, minting to
+    /// itself with placeholder signatures and lock hash, and priced with
+    /// `payment_queryInfo`. It is never submitted, so the nonce is irrelevant.
+    pub async fn estimate_mint_fee(
+        &self,
+        ethereum_address: &str,
+        amount: &str,
+        signature_count: usize,
+    ) -> Result<u128> {
+        let signer = self.account_signer()?;
+        let account_id = <Keypair as Signer<SubxtConfig>>::account_id(&signer);
+        let signatures = vec![vec![0u8; 65]; signature_count];
+
+        let call = mint_call(
+            &self.config.pallet_name,
+            &format!("0x{}", hex::encode(account_id.0)),
+            ethereum_address,
+            amount,
+            &format!("{:?}", H256::zero()),
+            &signatures,
+        )?;
+
+        let extrinsic = self.client.tx()
+            .create_signed_with_nonce(&call, &signer, 0, Default::default())
+            .map_err(|e| RelayerError::Polkadot {
+                message: format!("Failed to build mint for fee estimation: {}", e),
+            })?;
+
+        extrinsic.partial_fee_estimate()
+            .await
+            .map_err(|e| RelayerError::Polkadot {
+                message: format!("Failed to estimate mint fee: {}", e),
+            })
+    }
+
+    /// Mint tokens for several Ethereum locks in one extrinsic
+    ///
+    /// The pallet applies the batch atomically: if any mint is invalid, none
+    /// of them are minted. Signs `mint_tokens_batch` with the configured
+    /// account key and waits for it to be finalized, returning the extrinsic
+    /// hash.
+    pub async fn mint_tokens_batch(&self, mints: &[PendingMint]) -> Result<String> {
+        info!("Minting batch of {} on Polkadot", mints.len());
+
+        let call = mint_batch_call(&self.config.pallet_name, mints)?;
+        let signer = self.account_signer()?;
+
+        let progress = self
+            .sign_and_submit("mint batch", &call, &signer, |extrinsic| async move {
+                extrinsic.submit_and_watch().await
+            })
+            .await?;
+
+        let events = progress
+            .wait_for_finalized_success()
+            .await
+            .map_err(|e| RelayerError::Polkadot {
+                message: format!("Mint batch of {} failed: {}", mints.len(), e),
+            })?;
+
+        let tx_hash = format!("{:?}", events.extrinsic_hash());
+        debug!("Mint batch transaction finalized: {}", tx_hash);
+        Ok(tx_hash)
     }
 
     /// Listen for burn events
@@ -74,9 +288,9 @@ impl PolkadotClient {
         Ok(())
     }
 
-    /// Get past burn events from a specific block
-    pub async fn get_past_burn_events(&self, from_block: u32) -> Result<Vec<PolkadotBurnEvent>> {
-        info!("Fetching past burn events from block {}", from_block);
+    /// Get past burn events between two blocks, inclusive
+    pub async fn get_past_burn_events(&self, from_block: u32, to_block: u32) -> Result<Vec<PolkadotBurnEvent>> {
+        info!("Fetching past burn events from block {} to {}", from_block, to_block);
 
         // This is a simplified implementation
         // In a real implementation, you would:
@@ -98,8 +312,172 @@ impl PolkadotClient {
         Ok(header.number())
     }
 
+    /// Blocks a burn must be buried under before it is relayed
+    pub fn confirmations(&self) -> u32 {
+        self.config.confirmations
+    }
+
+    /// Hash of the canonical block at `block_number`, if the node has it
+    pub async fn get_block_hash(&self, block_number: u32) -> Result<Option<String>> {
+        let hash = self.rpc.chain_get_block_hash(Some(block_number.into()))
+            .await
+            .map_err(|e| RelayerError::Polkadot {
+                message: format!("Failed to get hash of block {}: {}", block_number, e),
+            })?;
+
+        Ok(hash.map(|hash| format!("{:?}", hash)))
+    }
+
+    /// Ethereum addresses of the bridge's active validators
+    ///
+    /// Read through the pallet's `CrossChainBridgeApi::validator_eth_addresses`
+    /// runtime API at the latest block.
+    pub async fn get_validator_eth_addresses(&self) -> Result<Vec<String>> {
+        let runtime_api = self.client.runtime_api().at_latest()
+            .await
+            .map_err(|e| RelayerError::Polkadot {
+                message: format!("Failed to get latest block: {}", e),
+            })?;
+
+        let addresses: Vec<[u8; 20]> = runtime_api
+            .call_raw("CrossChainBridgeApi_validator_eth_addresses", None)
+            .await
+            .map_err(|e| RelayerError::Polkadot {
+                message: format!("Failed to query validator Ethereum addresses: {}", e),
+            })?;
+
+        Ok(addresses
+            .iter()
+            .map(|address| format!("0x{}", hex::encode(address)))
+            .collect())
+    }
+
+    /// Burn requests not yet completed, with their IDs, in request order
+    ///
+    /// Read through the pallet's `BridgeApi::pending_burn_requests` runtime
+    /// API at the latest block.
+    pub async fn pending_burn_requests(&self) -> Result<Vec<(u64, PalletBurnRequest)>> {
+        let runtime_api = self.client.runtime_api().at_latest()
+            .await
+            .map_err(|e| RelayerError::Polkadot {
+                message: format!("Failed to get latest block: {}", e),
+            })?;
+
+        runtime_api
+            .call_raw("BridgeApi_pending_burn_requests", None)
+            .await
+            .map_err(|e| RelayerError::Polkadot {
+                message: format!("Failed to query pending burn requests: {}", e),
+            })
+    }
+
+    /// The burn request `id`, processed or not
+    ///
+    /// Read through the pallet's `BridgeApi::burn_request` runtime API at the
+    /// latest block.
+    pub async fn burn_request(&self, id: u64) -> Result<Option<PalletBurnRequest>> {
+        let runtime_api = self.client.runtime_api().at_latest()
+            .await
+            .map_err(|e| RelayerError::Polkadot {
+                message: format!("Failed to get latest block: {}", e),
+            })?;
+
+        // A SCALE-encoded u64 is its little-endian bytes
+        runtime_api
+            .call_raw("BridgeApi_burn_request", Some(&id.to_le_bytes()))
+            .await
+            .map_err(|e| RelayerError::Polkadot {
+                message: format!("Failed to query burn request {}: {}", id, e),
+            })
+    }
+
+    /// Whether a sudo key is configured for privileged calls
+    pub fn has_admin_key(&self) -> bool {
+        self.config.admin_seed.is_some()
+    }
+
+    /// Load the configured sudo key
+    fn admin_signer(&self) -> Result<Keypair> {
+        let seed = self.config.admin_seed.as_deref().ok_or_else(|| RelayerError::Config {
+            message: "No Polkadot admin key configured".to_string(),
+        })?;
+
+        signer_from_seed(seed)
+    }
+
+    /// Load the configured key that signs mints
+    fn account_signer(&self) -> Result<Keypair> {
+        let seed = self.config.account_seed.as_deref().ok_or_else(|| RelayerError::Config {
+            message: "No Polkadot account key configured".to_string(),
+        })?;
+
+        signer_from_seed(seed)
+    }
+
+    /// Sign and submit `call`, returning its hash once in the pool
+    async fn submit_extrinsic<Call: TxPayload>(&self, what: &str, call: &Call, signer: &Keypair) -> Result<String> {
+        self.sign_and_submit(what, call, signer, |extrinsic| async move {
+            extrinsic.submit().await.map(|hash| format!("{:?}", hash))
+        })
+        .await
+    }
+
+    /// Sign `call` and hand it to `submit`, handling transaction pool
+    /// rejections by class
+    ///
+    /// The nonce is taken from the client's [`NonceTracker`]. A stale nonce is
+    /// resynced from the chain and a future one waited out before
+    /// resubmitting, up to [`MAX_SUBMIT_ATTEMPTS`] submissions. Terminal
+    /// rejections such as a bad signature fail at once, as do errors that are
+    /// not pool rejections.
+    async fn sign_and_submit<Call, T, F, Fut>(&self, what: &str, call: &Call, signer: &Keypair, submit: F) -> Result<T>
+    where
+        Call: TxPayload,
+        F: Fn(SubmittableExtrinsic<SubxtConfig, OnlineClient<SubxtConfig>>) -> Fut,
+        Fut: Future<Output = std::result::Result<T, subxt::Error>>,
+    {
+        let account_id = <Keypair as Signer<SubxtConfig>>::account_id(signer);
+        let mut nonce = self.nonces.reserve(self, &account_id).await?;
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let result = match self.client.tx().create_signed_with_nonce(call, signer, nonce, Default::default()) {
+                Ok(extrinsic) => submit(extrinsic).await,
+                Err(e) => Err(e),
+            };
+            let error = match result {
+                Ok(submitted) => return Ok(submitted),
+                Err(e) => e.to_string(),
+            };
+
+            match classify_pool_error(&error) {
+                PoolErrorAction::ResyncNonce if attempt < MAX_SUBMIT_ATTEMPTS => {
+                    warn!("{} rejected with a stale nonce {}, resyncing: {}", what, nonce, error);
+                    nonce = self.nonces.resync(self, &account_id).await?;
+                }
+                PoolErrorAction::Wait if attempt < MAX_SUBMIT_ATTEMPTS => {
+                    warn!("{} with nonce {} is not yet valid, waiting {:?}: {}", what, nonce, FUTURE_TX_WAIT, error);
+                    tokio::time::sleep(FUTURE_TX_WAIT).await;
+                }
+                PoolErrorAction::Terminal => {
+                    self.nonces.forget(&account_id).await;
+                    return Err(RelayerError::Polkadot {
+                        message: format!("{} rejected by the transaction pool, not retrying: {}", what, error),
+                    });
+                }
+                _ => {
+                    self.nonces.forget(&account_id).await;
+                    return Err(RelayerError::Polkadot {
+                        message: format!("Failed to submit {}: {}", what, error),
+                    });
+                }
+            }
+        }
+    }
+
     /// Check if a transaction is confirmed
-    pub async fn is_transaction_confirmed(&self, tx_hash: &str, confirmations: u32) -> Result<bool> {
+    pub async fn is_transaction_confirmed(&self, tx_hash: &str, _confirmations: u32) -> Result<bool> {
         debug!("Checking confirmation for transaction: {}", tx_hash);
 
         // This is a simplified implementation
@@ -113,6 +491,261 @@ impl PolkadotClient {
     }
 }
 
+#[async_trait]
+impl NonceSource for PolkadotClient {
+    async fn fetch_nonce(&self, account: &AccountId32) -> Result<u64> {
+        self.client.tx().account_nonce(account).await.map_err(|e| RelayerError::Polkadot {
+            message: format!("Failed to get account nonce: {}", e),
+        })
+    }
+}
+
+#[async_trait]
+impl PalletAdmin for PolkadotClient {
+    async fn set_paused(&self, paused: bool) -> Result<String> {
+        let signer = self.admin_signer()?;
+
+        let call_name = if paused { "pause" } else { "unpause" };
+        info!("Submitting {}::{} via sudo", self.config.pallet_name, call_name);
+
+        let call = subxt::dynamic::tx(
+            self.config.pallet_name.as_str(),
+            call_name,
+            Vec::<Value>::new(),
+        );
+        let sudo = subxt::dynamic::tx("Sudo", "sudo", vec![call.into_value()]);
+
+        self.submit_extrinsic(call_name, &sudo, &signer).await
+    }
+}
+
+#[async_trait]
+impl MintSubmitter for PolkadotClient {
+    async fn submit_mint(&self, mint: &PendingMint) -> Result<String> {
+        self.mint_tokens(
+            &mint.recipient,
+            &mint.ethereum_address,
+            &mint.amount,
+            &mint.ethereum_tx_hash,
+            mint.signatures.clone(),
+        ).await
+    }
+
+    async fn submit_mint_batch(&self, mints: &[PendingMint]) -> Result<String> {
+        self.mint_tokens_batch(mints).await
+    }
+}
+
+/// Decode the Polkadot account an Ethereum lock mints to
+///
+/// The bridge contract emits the destination as a 0x-prefixed 32-byte public
+/// key; SS58 addresses are also accepted, with their checksum verified. An
+/// all-zero key decodes but no one holds its secret, so tokens minted to it
+/// could never be spent.
+pub fn decode_destination(tx_hash: &str, destination: &str) -> Result<AccountId32> {
+    let invalid = |reason: String| RelayerError::InvalidDestination {
+        tx_hash: tx_hash.to_string(),
+        destination: destination.to_string(),
+        reason,
+    };
+
+    let account = match destination.strip_prefix("0x") {
+        Some(hex_key) => {
+            let bytes = hex::decode(hex_key).map_err(|e| invalid(format!("not hex: {}", e)))?;
+            let key: [u8; 32] = bytes
+                .try_into()
+                .map_err(|bytes: Vec<u8>| invalid(format!("expected 32 bytes, got {}", bytes.len())))?;
+            AccountId32::from(key)
+        }
+        None => AccountId32::from_str(destination).map_err(|e| invalid(format!("not an SS58 address: {}", e)))?,
+    };
+
+    if account.0 == [0u8; 32] {
+        return Err(invalid("all-zero public key".to_string()));
+    }
+    Ok(account)
+}
+
+/// Build the bridge pallet's `mint_tokens` call for an Ethereum lock
+///
+/// The recipient is decoded as by [`decode_destination`]; the Ethereum token
+/// address, amount and lock transaction hash are checked to fit the call's
+/// `H160`, `u128` balance and `H256` arguments.
+pub fn mint_call(
+    pallet_name: &str,
+    recipient: &str,
+    ethereum_address: &str,
+    amount: &str,
+    ethereum_tx_hash: &str,
+    signatures: &[Vec<u8>],
+) -> Result<DynamicPayload> {
+    Ok(subxt::dynamic::tx(
+        pallet_name,
+        "mint_tokens",
+        mint_args(recipient, ethereum_address, amount, ethereum_tx_hash, signatures)?,
+    ))
+}
+
+/// Build the bridge pallet's `mint_tokens_batch` call for several locks
+///
+/// Each mint becomes one `(recipient, token, amount, tx_hash, signatures)`
+/// tuple, checked as by [`mint_call`]. One undecodable mint fails the whole
+/// call, as the pallet would.
+pub fn mint_batch_call(pallet_name: &str, mints: &[PendingMint]) -> Result<DynamicPayload> {
+    let mints = mints
+        .iter()
+        .map(|mint| {
+            mint_args(
+                &mint.recipient,
+                &mint.ethereum_address,
+                &mint.amount,
+                &mint.ethereum_tx_hash,
+                &mint.signatures,
+            )
+            .map(Value::unnamed_composite)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(subxt::dynamic::tx(
+        pallet_name,
+        "mint_tokens_batch",
+        vec![Value::unnamed_composite(mints)],
+    ))
+}
+
+/// The fields of one mint, parsed into the types `mint_tokens` takes
+struct MintFields {
+    recipient: AccountId32,
+    token: H160,
+    amount: u128,
+    lock_tx_hash: H256,
+}
+
+impl MintFields {
+    fn parse(recipient: &str, ethereum_address: &str, amount: &str, ethereum_tx_hash: &str) -> Result<Self> {
+        let invalid = |what: &str, value: &str, e: &dyn std::fmt::Display| RelayerError::Polkadot {
+            message: format!("Invalid {} '{}' for mint of {}: {}", what, value, ethereum_tx_hash, e),
+        };
+
+        Ok(Self {
+            recipient: decode_destination(ethereum_tx_hash, recipient)?,
+            token: H160::from_str(ethereum_address).map_err(|e| invalid("token address", ethereum_address, &e))?,
+            amount: u128::from_str(amount).map_err(|e| invalid("amount", amount, &e))?,
+            lock_tx_hash: H256::from_str(ethereum_tx_hash)
+                .map_err(|e| invalid("transaction hash", ethereum_tx_hash, &e))?,
+        })
+    }
+}
+
+/// Arguments of one mint, in the order `mint_tokens` takes them
+fn mint_args(
+    recipient: &str,
+    ethereum_address: &str,
+    amount: &str,
+    ethereum_tx_hash: &str,
+    signatures: &[Vec<u8>],
+) -> Result<Vec<Value>> {
+    let fields = MintFields::parse(recipient, ethereum_address, amount, ethereum_tx_hash)?;
+
+    Ok(vec![
+        Value::from_bytes(fields.recipient.0),
+        Value::from_bytes(fields.token.as_bytes()),
+        Value::u128(fields.amount),
+        Value::from_bytes(fields.lock_tx_hash.as_bytes()),
+        Value::unnamed_composite(signatures.iter().map(Value::from_bytes)),
+    ])
+}
+
+/// Digest the bridge pallet checks a mint's signatures against
+///
+/// Matches the pallet's `mint_message`: keccak-256 of the SCALE encoding of
+/// `(b"mint", recipient, token, amount, lock transaction hash)`, which is
+/// the raw `mint` bytes, the 32-byte account, the 20-byte token address, the
+/// amount as a little-endian `u128` and the 32-byte hash. Validators sign the
+/// digest itself, without an Ethereum message prefix.
+pub fn mint_message(recipient: &str, ethereum_address: &str, amount: &str, ethereum_tx_hash: &str) -> Result<[u8; 32]> {
+    let fields = MintFields::parse(recipient, ethereum_address, amount, ethereum_tx_hash)?;
+    let encoded = (b"mint", fields.recipient.0, fields.token.0, fields.amount, fields.lock_tx_hash.0).encode();
+    Ok(keccak256(encoded))
+}
+
+/// Map a `mint_tokens` extrinsic that failed to finalize to a relayer error,
+/// through [`mint_failure`] if the pallet rejected it
+fn finalization_failure(recipient: &str, error: subxt::Error) -> RelayerError {
+    if let subxt::Error::Runtime(DispatchError::Module(module)) = &error {
+        if let Ok(details) = module.details() {
+            return mint_failure(recipient, &details.variant.name);
+        }
+    }
+    RelayerError::Polkadot {
+        message: format!("Mint to {} failed: {}", recipient, error),
+    }
+}
+
+/// Load an sr25519 key from a secret URI such as `//Alice`
+fn signer_from_seed(seed: &str) -> Result<Keypair> {
+    let uri = SecretUri::from_str(seed).map_err(|e| RelayerError::Config {
+        message: format!("Invalid Polkadot key: {}", e),
+    })?;
+
+    Keypair::from_uri(&uri).map_err(|e| RelayerError::Config {
+        message: format!("Invalid Polkadot key: {}", e),
+    })
+}
+
+/// Map the bridge pallet error that rejected a mint to a relayer error
+///
+/// Recipient problems get their own variants so operators see why a mint
+/// failed instead of a generic dispatch error.
+pub fn mint_failure(recipient: &str, pallet_error: &str) -> RelayerError {
+    let recipient = recipient.to_string();
+
+    match pallet_error {
+        "RecipientNotAllowed" => RelayerError::RecipientNotAllowed { recipient },
+        "RecipientBelowMinBalance" => RelayerError::RecipientBelowMinBalance { recipient },
+        "RecipientCannotHoldAsset" => RelayerError::RecipientCannotHoldAsset { recipient },
+        other => RelayerError::Polkadot {
+            message: format!("Mint to {} rejected by the bridge pallet: {}", recipient, other),
+        },
+    }
+}
+
+/// How to handle an extrinsic the transaction pool rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolErrorAction {
+    /// The nonce was already used or outbid: fetch the account nonce again
+    /// and resubmit
+    ResyncNonce,
+    /// The nonce is ahead of the account's, or the transaction is briefly
+    /// banned: wait, then resubmit unchanged
+    Wait,
+    /// Resubmitting cannot succeed, for example a bad signature or fees the
+    /// account cannot pay
+    Terminal,
+    /// Not a transaction pool rejection
+    Other,
+}
+
+/// Classify a submission error by the pool's rejection reason
+///
+/// `subxt` passes pool rejections through as RPC error text, so the message
+/// is matched for the reasons Substrate nodes report, by description or by
+/// `InvalidTransaction` variant name.
+pub fn classify_pool_error(message: &str) -> PoolErrorAction {
+    let message = message.to_lowercase();
+    let mentions = |needles: &[&str]| needles.iter().any(|needle| message.contains(needle));
+
+    if mentions(&["bad signature", "badproof", "inability to pay", "payment", "call is not expected"]) {
+        PoolErrorAction::Terminal
+    } else if mentions(&["outdated", "stale", "priority is too low"]) {
+        PoolErrorAction::ResyncNonce
+    } else if mentions(&["valid in the future", "future", "temporarily banned"]) {
+        PoolErrorAction::Wait
+    } else {
+        PoolErrorAction::Other
+    }
+}
+
 /// Polkadot burn event structure
 #[derive(Debug, Clone)]
 pub struct PolkadotBurnEvent {
@@ -0,0 +1,183 @@
+//! Token price oracles for normalizing amounts to USD
+//!
+//! Fee and volume-limit logic can express thresholds in USD when an oracle is
+//! configured; without one, amounts stay in native token units.
+
+use crate::{
+    config::PriceOracleConfig,
+    error::{RelayerError, Result},
+};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+
+/// Source of token prices in USD
+#[async_trait]
+pub trait PriceOracle: Send + Sync {
+    /// USD price of one whole unit of `token`
+    async fn price(&self, token: &str) -> Result<Decimal>;
+}
+
+/// Prices fixed in configuration
+#[derive(Debug, Clone, Default)]
+pub struct StaticPriceOracle {
+    prices: HashMap<String, Decimal>,
+}
+
+impl StaticPriceOracle {
+    /// Create an oracle from a token → USD price map
+    pub fn new(prices: HashMap<String, Decimal>) -> Self {
+        let prices = prices
+            .into_iter()
+            .map(|(token, price)| (token.to_lowercase(), price))
+            .collect();
+
+        Self { prices }
+    }
+}
+
+#[async_trait]
+impl PriceOracle for StaticPriceOracle {
+    async fn price(&self, token: &str) -> Result<Decimal> {
+        self.prices
+            .get(&token.to_lowercase())
+            .copied()
+            .ok_or_else(|| RelayerError::PriceUnavailable {
+                token: token.to_string(),
+            })
+    }
+}
+
+/// CoinGecko-style HTTP price feed with an optional static fallback
+///
+/// Queries `{url}?contract_addresses={token}&vs_currencies=usd` and expects a
+/// response of the form `{"<token>": {"usd": <price>}}`.
+pub struct HttpPriceOracle {
+    client: reqwest::Client,
+    url: String,
+    fallback: Option<StaticPriceOracle>,
+}
+
+impl HttpPriceOracle {
+    /// Create a new HTTP oracle
+    pub fn new(url: String, fallback: Option<StaticPriceOracle>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url,
+            fallback,
+        }
+    }
+
+    async fn fetch(&self, token: &str) -> Result<Decimal> {
+        let token = token.to_lowercase();
+
+        let body: serde_json::Value = self.client
+            .get(&self.url)
+            .query(&[("contract_addresses", token.as_str()), ("vs_currencies", "usd")])
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| RelayerError::Network {
+                message: format!("Price request for {} failed: {}", token, e),
+            })?
+            .json()
+            .await
+            .map_err(|e| RelayerError::Network {
+                message: format!("Invalid price response for {}: {}", token, e),
+            })?;
+
+        body.get(&token)
+            .and_then(|entry| entry.get("usd"))
+            .and_then(|usd| Decimal::from_str(&usd.to_string()).ok())
+            .ok_or(RelayerError::PriceUnavailable { token })
+    }
+}
+
+#[async_trait]
+impl PriceOracle for HttpPriceOracle {
+    async fn price(&self, token: &str) -> Result<Decimal> {
+        match self.fetch(token).await {
+            Ok(price) => Ok(price),
+            Err(e) => match &self.fallback {
+                Some(fallback) => {
+                    warn!("Price feed unavailable for {}, using static price: {}", token, e);
+                    fallback.price(token).await
+                }
+                None => Err(e),
+            },
+        }
+    }
+}
+
+/// Caches another oracle's prices for a fixed TTL
+pub struct CachedPriceOracle<O> {
+    inner: O,
+    ttl: Duration,
+    cache: RwLock<HashMap<String, (Decimal, Instant)>>,
+}
+
+impl<O: PriceOracle> CachedPriceOracle<O> {
+    /// Wrap `inner`, reusing each price for `ttl`
+    pub fn new(inner: O, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl<O: PriceOracle> PriceOracle for CachedPriceOracle<O> {
+    async fn price(&self, token: &str) -> Result<Decimal> {
+        let key = token.to_lowercase();
+
+        if let Some((price, fetched_at)) = self.cache.read().await.get(&key) {
+            if fetched_at.elapsed() < self.ttl {
+                return Ok(*price);
+            }
+        }
+
+        let price = self.inner.price(token).await?;
+        debug!("Fetched price for {}: {} USD", token, price);
+        self.cache.write().await.insert(key, (price, Instant::now()));
+
+        Ok(price)
+    }
+}
+
+/// Build the configured oracle, if any
+pub fn build_price_oracle(config: Option<&PriceOracleConfig>) -> Option<Arc<dyn PriceOracle>> {
+    let config = config?;
+    let static_oracle = (!config.static_prices.is_empty())
+        .then(|| StaticPriceOracle::new(config.static_prices.clone()));
+    let ttl = Duration::from_secs(config.cache_ttl);
+
+    match &config.url {
+        Some(url) => Some(Arc::new(CachedPriceOracle::new(
+            HttpPriceOracle::new(url.clone(), static_oracle),
+            ttl,
+        ))),
+        None => static_oracle.map(|oracle| Arc::new(oracle) as Arc<dyn PriceOracle>),
+    }
+}
+
+/// Convert a whole-unit token amount to USD
+///
+/// Returns `None` when no oracle is configured, so callers can fall back to
+/// comparing native amounts.
+pub async fn usd_value(
+    oracle: Option<&dyn PriceOracle>,
+    token: &str,
+    amount: Decimal,
+) -> Result<Option<Decimal>> {
+    match oracle {
+        Some(oracle) => Ok(Some(oracle.price(token).await? * amount)),
+        None => Ok(None),
+    }
+}
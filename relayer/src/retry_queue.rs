@@ -0,0 +1,131 @@
+//! Retries of bridge events that failed to process
+//!
+//! An event whose handling fails is consumed from the event channel, so
+//! without a retry it would never be relayed. The [`RetryQueue`] holds such
+//! events in memory and hands them back once their backoff elapses; events
+//! that exhaust the retry budget are dead-lettered in the `failed_events`
+//! table for an operator to inspect and retry.
+
+use crate::{
+    coordinator::BridgeEvent,
+    database::Database,
+    error::{RelayerError, Result},
+    policy::RetryPolicy,
+};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::Mutex;
+use tracing::{error, warn};
+
+/// An event waiting for its next attempt
+#[derive(Debug, Clone)]
+pub struct PendingRetry {
+    pub event: BridgeEvent,
+    /// Attempts made so far
+    pub attempts: u32,
+    /// When the next attempt is due
+    pub due: Instant,
+}
+
+/// Re-runs failed events with backoff, dead-lettering them once retries
+/// run out
+///
+/// Each event gets `max_retries` retries after its first attempt, spaced by
+/// [`RetryPolicy::backoff`]. Transfers rejected for a
+/// [`failure_reason`](RelayerError::failure_reason), such as an invalid
+/// destination, would fail the same way again and are not retried.
+pub struct RetryQueue {
+    database: Arc<Database>,
+    policy: RetryPolicy,
+    pending: Mutex<Vec<PendingRetry>>,
+}
+
+impl RetryQueue {
+    /// Create a queue retrying as `policy` allows and dead-lettering into
+    /// `database`
+    pub fn new(database: Arc<Database>, policy: RetryPolicy) -> Self {
+        Self {
+            database,
+            policy,
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Number of events waiting to be retried
+    pub async fn pending_count(&self) -> usize {
+        self.pending.lock().await.len()
+    }
+
+    /// When the earliest waiting retry is due
+    pub async fn next_due(&self) -> Option<Instant> {
+        self.pending.lock().await.iter().map(|retry| retry.due).min()
+    }
+
+    /// Run `process` on `event`, which has already been tried `attempts`
+    /// times, scheduling a retry or dead-lettering it if it fails
+    ///
+    /// Only failing to dead-letter is returned as an error; processing
+    /// failures are logged and handled here.
+    pub async fn attempt<F, Fut>(&self, event: BridgeEvent, attempts: u32, process: F) -> Result<()>
+    where
+        F: FnOnce(BridgeEvent) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        let attempts = attempts + 1;
+        match process(event.clone()).await {
+            Ok(()) => Ok(()),
+            Err(e) => self.record_failure(event, attempts, &e).await,
+        }
+    }
+
+    /// Run `process` on every event whose retry is due
+    pub async fn retry_due<F, Fut>(&self, process: F) -> Result<()>
+    where
+        F: Fn(BridgeEvent) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        let now = Instant::now();
+        let due: Vec<PendingRetry> = {
+            let mut pending = self.pending.lock().await;
+            let (due, waiting) = std::mem::take(&mut *pending).into_iter().partition(|retry| retry.due <= now);
+            *pending = waiting;
+            due
+        };
+
+        for retry in due {
+            self.attempt(retry.event, retry.attempts, &process).await?;
+        }
+        Ok(())
+    }
+
+    /// Schedule another attempt of `event` after its `attempts`th failed
+    /// with `error`, or dead-letter it once retries are exhausted
+    async fn record_failure(&self, event: BridgeEvent, attempts: u32, error: &RelayerError) -> Result<()> {
+        if error.failure_reason().is_some() {
+            error!("Rejected {} event {}, not retrying: {}", event.chain(), event.tx_hash(), error);
+            return Ok(());
+        }
+
+        if attempts > self.policy.max_retries {
+            error!(
+                "Dead-lettering {} event {} after {} attempts: {}",
+                event.chain(), event.tx_hash(), attempts, error
+            );
+            self.database.add_dead_letter(&event, attempts, &error.to_string()).await?;
+            return Ok(());
+        }
+
+        let delay = self.policy.backoff(error, attempts);
+        warn!(
+            "Failed to handle {} event {} (attempt {}/{}), retrying in {:?}: {}",
+            event.chain(), event.tx_hash(), attempts, self.policy.max_retries + 1, delay, error
+        );
+        self.pending.lock().await.push(PendingRetry {
+            event,
+            attempts,
+            due: Instant::now() + delay,
+        });
+        Ok(())
+    }
+}
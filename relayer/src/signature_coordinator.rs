@@ -1,22 +1,184 @@
 //! Signature coordination service for threshold signatures
 
 use crate::{
-    config::ValidatorConfig,
-    database::Database,
+    checkpoint::BridgeCheckpoint,
+    config::{ThresholdConfig, ValidatorConfig},
+    database::{Database, StoredPartialSignature, StoredSignatureSession},
     error::{RelayerError, Result},
+    keystore::KeyStore,
+    metrics::{self, Registry},
+    p2p::{PeerBroadcaster, PeerTls},
+    ethereum,
+    policy::Timeouts,
+    polkadot,
 };
-use threshold::{SimpleThresholdManager, PartialSignature};
-use tracing::{info, debug, warn};
-use std::sync::Arc;
-use std::collections::HashMap;
+use ethers::{signers::LocalWallet, types::{Signature, H256}};
+use prometheus::IntCounter;
+use threshold::{keystore::SecretKeyShare, AggregatedSignature, KeySet, KeyShare, PartialSignature, PublicKeyShare, SimpleThresholdManager, ThresholdError};
+use tracing::{info, debug, error, warn};
+use std::sync::{Arc, Weak};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, SystemTime};
 use tokio::sync::RwLock;
 
+/// Most transactions for which partials may be buffered before their session exists
+const MAX_EARLY_PARTIAL_SESSIONS: usize = 1024;
+
+/// Source recorded for partials created by this validator
+pub const LOCAL_SOURCE: &str = "local";
+
+/// Default cap on a session's message size
+///
+/// Canonical mint, unlock and checkpoint messages are a few hundred bytes at
+/// most, so anything near this size points at an encoding bug or hostile input.
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 1024;
+
 /// Signature coordinator for managing threshold signatures
 pub struct SignatureCoordinator {
     config: ValidatorConfig,
     threshold_manager: Arc<SimpleThresholdManager>,
+    /// Where sessions and their partials are persisted across restarts
     database: Arc<Database>,
     pending_signatures: Arc<RwLock<HashMap<String, SignatureSession>>>,
+    early_partials: Arc<RwLock<HashMap<String, EarlyPartials>>>,
+    grace: Arc<RwLock<HashMap<String, GraceState>>>,
+    /// Aggregated signatures of ready sessions, dropped when their partials change
+    aggregated: Arc<RwLock<HashMap<String, Vec<Vec<u8>>>>>,
+    /// Transactions already submitted, kept for a while to recognise late partials
+    completed: Arc<RwLock<HashMap<String, SystemTime>>>,
+    aggregations: AtomicU64,
+    late_partials: IntCounter,
+    threshold: RwLock<u32>,
+    removed_validators: RwLock<HashSet<String>>,
+    /// Signer weights replacing the signer count, if configured
+    weighted: Option<WeightedThreshold>,
+    /// Public key share of each validator whose partials can be verified
+    public_key_shares: HashMap<String, Vec<u8>>,
+    max_message_size: usize,
+    timeouts: Timeouts,
+    /// Sends our partials to the configured peers
+    broadcaster: PeerBroadcaster,
+    /// This validator's key share from the DKG, when a keystore is configured
+    keystore: Option<KeyStore>,
+}
+
+/// Readiness by total signer weight rather than signer count
+///
+/// Fits deployments where validators hold differing stake: a few heavily
+/// staked validators can complete a session that would otherwise need more
+/// equal-weight signers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WeightedThreshold {
+    /// Weight of each validator; validators not listed weigh 1
+    pub weights: HashMap<String, u64>,
+    /// Total weight the signers must reach
+    pub threshold: u64,
+}
+
+impl WeightedThreshold {
+    /// The weighted threshold configured for the relayer, if any
+    pub fn from_config(config: &ThresholdConfig) -> Option<Self> {
+        config.weighted_threshold.map(|threshold| Self {
+            weights: config.validator_weights.clone(),
+            threshold,
+        })
+    }
+
+    /// Signing weight of a validator
+    pub fn weight(&self, validator_id: &str) -> u64 {
+        self.weights.get(validator_id).copied().unwrap_or(1)
+    }
+
+    /// Whether `signers` together reach the threshold
+    pub fn is_met<'a>(&self, signers: impl IntoIterator<Item = &'a String>) -> bool {
+        let weight = signers
+            .into_iter()
+            .fold(0u64, |total, signer| total.saturating_add(self.weight(signer)));
+        weight >= self.threshold
+    }
+}
+
+/// Partials received from fast peers before the local session was created
+#[derive(Debug, Clone)]
+struct EarlyPartials {
+    partials: HashMap<String, PartialSignature>,
+    sources: HashMap<String, String>,
+    received_at: SystemTime,
+}
+
+/// Re-request schedule for a session past its timeout
+#[derive(Debug, Clone, Copy)]
+struct GraceState {
+    attempts: u32,
+    next_rerequest: SystemTime,
+}
+
+/// What a sweep of the signature sessions did
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionSweep {
+    /// Completed sessions removed after their timeout
+    pub expired: Vec<String>,
+    /// Incomplete sessions whose partials were re-requested
+    pub rerequested: Vec<String>,
+    /// Incomplete sessions abandoned after their grace period
+    pub dead_lettered: Vec<String>,
+}
+
+/// A member of the signing set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorInfo {
+    /// Validator ID
+    pub id: String,
+    /// Network address partials are exchanged on, if known
+    pub address: Option<String>,
+    /// Whether the validator currently counts toward the threshold
+    pub active: bool,
+}
+
+/// The signing set and the signatures it must produce
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorSet {
+    /// This validator first, then its configured peers
+    pub validators: Vec<ValidatorInfo>,
+    /// Signatures required for new sessions
+    pub threshold: u32,
+}
+
+/// How the partials of a session are produced and verified
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SignatureFormat {
+    /// Threshold-scheme partials over the message under the session ID
+    #[default]
+    Threshold,
+    /// 65-byte recoverable ECDSA signatures of the 32-byte message itself,
+    /// as the bridge pallet checks mints and the bridge contract unlocks
+    /// against validators' Ethereum addresses
+    Recoverable,
+}
+
+impl SignatureFormat {
+    /// Name the format is persisted under
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Threshold => "threshold",
+            Self::Recoverable => "recoverable",
+        }
+    }
+}
+
+impl std::str::FromStr for SignatureFormat {
+    type Err = RelayerError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "threshold" => Ok(Self::Threshold),
+            "recoverable" => Ok(Self::Recoverable),
+            other => Err(RelayerError::Generic {
+                message: format!("Unknown signature format '{}'", other),
+            }),
+        }
+    }
 }
 
 /// A signature session for a specific transaction
@@ -24,40 +186,247 @@ pub struct SignatureCoordinator {
 pub struct SignatureSession {
     pub tx_hash: String,
     pub message: Vec<u8>,
+    /// How partials for the message are made and checked
+    pub format: SignatureFormat,
     pub partial_signatures: HashMap<String, PartialSignature>,
+    /// Connection each validator's partial arrived from
+    pub sources: HashMap<String, String>,
+    /// Public key shares partials are verified against; partials from
+    /// validators without one are rejected, except our own
+    pub public_key_shares: HashMap<String, Vec<u8>>,
     pub required_signatures: u32,
     pub created_at: std::time::SystemTime,
 }
 
+impl SignatureSession {
+    /// Whether enough partials have arrived to aggregate
+    ///
+    /// With `weighted`, the signers' combined weight must reach its
+    /// threshold; otherwise `required_signatures` partials are needed.
+    pub fn is_ready(&self, weighted: Option<&WeightedThreshold>) -> bool {
+        match weighted {
+            Some(weighted) => weighted.is_met(self.partial_signatures.keys()),
+            None => self.partial_signatures.len() >= self.required_signatures as usize,
+        }
+    }
+
+    /// Number of distinct connections the partials arrived from
+    pub fn distinct_sources(&self) -> usize {
+        self.partial_signatures
+            .keys()
+            .filter_map(|validator_id| self.sources.get(validator_id))
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Check that the partials came from enough distinct sources
+    ///
+    /// A single machine posing as several validators shows up as fewer
+    /// sources than required signatures. With `require_distinct_sources` this
+    /// is an error; otherwise it is only logged. A weighted session may be
+    /// ready with fewer partials than `required_signatures`, in which case
+    /// each partial must come from its own source.
+    pub fn check_source_diversity(&self, require_distinct_sources: bool) -> Result<()> {
+        let sources = self.distinct_sources();
+        let required = self.required_signatures.min(self.partial_signatures.len() as u32);
+        if sources >= required as usize {
+            return Ok(());
+        }
+
+        if require_distinct_sources {
+            return Err(RelayerError::InsufficientSourceDiversity {
+                tx_hash: self.tx_hash.clone(),
+                sources,
+                required,
+            });
+        }
+
+        warn!(
+            "Partials for tx {} came from only {} distinct source(s), {} required",
+            self.tx_hash, sources, required
+        );
+        Ok(())
+    }
+
+    /// The session as it is persisted
+    pub fn to_stored(&self) -> StoredSignatureSession {
+        StoredSignatureSession {
+            tx_hash: self.tx_hash.clone(),
+            message: self.message.clone(),
+            format: self.format,
+            required_signatures: self.required_signatures,
+            created_at: self.created_at,
+            partials: self.partial_signatures
+                .iter()
+                .map(|(validator_id, signature)| StoredPartialSignature {
+                    validator_id: validator_id.clone(),
+                    source: self.sources.get(validator_id).cloned().unwrap_or_default(),
+                    signature: signature.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Rebuild a persisted session, verifying later partials against
+    /// `public_key_shares`
+    pub fn from_stored(stored: StoredSignatureSession, public_key_shares: HashMap<String, Vec<u8>>) -> Self {
+        let mut partial_signatures = HashMap::new();
+        let mut sources = HashMap::new();
+        for partial in stored.partials {
+            sources.insert(partial.validator_id.clone(), partial.source);
+            partial_signatures.insert(partial.validator_id, partial.signature);
+        }
+
+        Self {
+            tx_hash: stored.tx_hash,
+            message: stored.message,
+            format: stored.format,
+            partial_signatures,
+            sources,
+            public_key_shares,
+            required_signatures: stored.required_signatures,
+            created_at: stored.created_at,
+        }
+    }
+}
+
 impl SignatureCoordinator {
     /// Create a new signature coordinator
     pub async fn new(
         config: ValidatorConfig,
         threshold_manager: Arc<SimpleThresholdManager>,
         database: Arc<Database>,
+        timeouts: Timeouts,
     ) -> Result<Self> {
+        let threshold = threshold_manager.config().threshold;
+        let broadcaster = PeerBroadcaster::new(&config.peers, PeerTls::from_config(&config)?.as_ref());
+        let keystore = config.keystore_path.as_ref().map(KeyStore::from_env).transpose()?;
+
         Ok(Self {
             config,
             threshold_manager,
             database,
             pending_signatures: Arc::new(RwLock::new(HashMap::new())),
+            early_partials: Arc::new(RwLock::new(HashMap::new())),
+            grace: Arc::new(RwLock::new(HashMap::new())),
+            aggregated: Arc::new(RwLock::new(HashMap::new())),
+            completed: Arc::new(RwLock::new(HashMap::new())),
+            aggregations: AtomicU64::new(0),
+            late_partials: IntCounter::new(
+                "bridge_late_partial_signatures_total",
+                "Partial signatures discarded because their transfer was already submitted",
+            ).expect("metric options are valid"),
+            threshold: RwLock::new(threshold),
+            removed_validators: RwLock::new(HashSet::new()),
+            weighted: None,
+            public_key_shares: HashMap::new(),
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+            timeouts,
+            broadcaster,
+            keystore,
         })
     }
 
+    /// Decide readiness by signer weight instead of signer count
+    pub fn with_weighted_threshold(mut self, weighted: Option<WeightedThreshold>) -> Self {
+        self.weighted = weighted;
+        self
+    }
+
+    /// Verify partials from the validators in `public_key_shares` before
+    /// adding them to a session; peers without a share here are rejected
+    pub fn with_public_key_shares(mut self, public_key_shares: &[PublicKeyShare]) -> Self {
+        self.public_key_shares = public_key_shares
+            .iter()
+            .map(|share| (share.validator_id.clone(), share.public_share.clone()))
+            .collect();
+        self
+    }
+
+    /// Refuse to open sessions for messages longer than `max_message_size` bytes
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
     /// Start the signature coordinator
-    pub async fn start(&self) -> Result<()> {
+    ///
+    /// Sessions persisted before a restart are restored first, then expired
+    /// sessions are swept periodically until the coordinator is dropped.
+    pub async fn start(self: &Arc<Self>) -> Result<()> {
         info!("Starting signature coordinator");
 
+        let restored = self.restore_sessions().await?;
+        if restored > 0 {
+            info!("Restored {} persisted signature sessions", restored);
+        }
+
         // Start signature cleanup task
-        let pending_signatures = self.pending_signatures.clone();
+        let coordinator = Arc::downgrade(self);
+        let cleanup_interval = self.timeouts.signature_cleanup_interval;
         tokio::spawn(async move {
-            Self::cleanup_expired_signatures(pending_signatures).await;
+            Self::cleanup_expired_signatures(coordinator, cleanup_interval).await;
         });
 
         Ok(())
     }
 
+    /// Load the persisted signature sessions into memory, returning how many
+    /// were restored
+    ///
+    /// Sessions already in memory and transfers already submitted are left
+    /// alone.
+    pub async fn restore_sessions(&self) -> Result<usize> {
+        let stored = self.database.load_signature_sessions().await?;
+
+        // Lock order (sessions, then completed) matches `mark_completed`
+        let mut pending = self.pending_signatures.write().await;
+        let completed = self.completed.read().await;
+        let mut restored = 0;
+        for session in stored {
+            if completed.contains_key(&session.tx_hash) || pending.contains_key(&session.tx_hash) {
+                continue;
+            }
+            debug!("Restoring signature session for tx {} with {} partials", session.tx_hash, session.partials.len());
+            pending.insert(
+                session.tx_hash.clone(),
+                SignatureSession::from_stored(session, self.public_key_shares.clone()),
+            );
+            restored += 1;
+        }
+
+        Ok(restored)
+    }
+
+    /// Load a single persisted session into memory if it is not there yet
+    async fn restore_session(&self, tx_hash: &str) -> Result<()> {
+        if self.pending_signatures.read().await.contains_key(tx_hash) || self.is_completed(tx_hash).await {
+            return Ok(());
+        }
+
+        if let Some(stored) = self.database.load_signature_session(tx_hash).await? {
+            debug!("Restoring signature session for tx {} from the database", tx_hash);
+            self.pending_signatures
+                .write()
+                .await
+                .entry(tx_hash.to_string())
+                .or_insert_with(|| SignatureSession::from_stored(stored, self.public_key_shares.clone()));
+        }
+        Ok(())
+    }
+
+    /// Persist a session, logging rather than failing if the database is
+    /// unavailable so signing carries on in memory
+    async fn persist_session(&self, session: &StoredSignatureSession) {
+        if let Err(e) = self.database.save_signature_session(session).await {
+            error!("Failed to persist signature session for tx {}: {}", session.tx_hash, e);
+        }
+    }
+
     /// Request a mint signature for an Ethereum lock event
+    ///
+    /// Validators sign the pallet's mint digest directly with their key
+    /// shares, so each partial is a signature `mint_tokens` accepts as is.
     pub async fn request_mint_signature(
         &self,
         recipient: &str,
@@ -69,36 +438,23 @@ impl SignatureCoordinator {
             return Ok(());
         }
 
-        info!("Requesting mint signature for tx {}", ethereum_tx_hash);
-
-        // Create message to sign
-        let message = self.create_mint_message(recipient, token, amount, ethereum_tx_hash)?;
+        if self.is_completed(ethereum_tx_hash).await {
+            debug!("Transfer {} already submitted, not signing again", ethereum_tx_hash);
+            return Ok(());
+        }
 
-        // Create signature session
-        let session = SignatureSession {
-            tx_hash: ethereum_tx_hash.to_string(),
-            message: message.clone(),
-            partial_signatures: HashMap::new(),
-            required_signatures: self.threshold_manager.config().threshold,
-            created_at: std::time::SystemTime::now(),
-        };
+        info!("Requesting mint signature for tx {}", ethereum_tx_hash);
 
-        // Store session
-        {
-            let mut pending = self.pending_signatures.write().await;
-            pending.insert(ethereum_tx_hash.to_string(), session);
-        }
+        // The pallet recovers each signer from a signature of this digest
+        let message = polkadot::mint_message(recipient, token, amount, ethereum_tx_hash)?;
+        self.open_session(ethereum_tx_hash, message.to_vec(), SignatureFormat::Recoverable).await?;
 
         // Generate our partial signature
-        if let Some(private_key) = &self.config.private_key {
-            let key_share = self.get_validator_key_share(private_key).await?;
-            let partial_sig = self.threshold_manager
-                .create_partial_signature(&key_share, &message, ethereum_tx_hash)
-                .await
-                .map_err(|e| RelayerError::ThresholdSignature(e))?;
+        if let Some(key_share) = self.validator_key_share().await? {
+            let partial_sig = sign_recoverable(&key_share, &message)?;
 
             // Store our signature
-            self.add_partial_signature(ethereum_tx_hash, &self.config.validator_id, partial_sig.clone()).await?;
+            self.add_partial_signature(ethereum_tx_hash, &self.config.validator_id, LOCAL_SOURCE, partial_sig.clone()).await?;
 
             // Broadcast to other validators (simplified)
             self.broadcast_partial_signature(ethereum_tx_hash, &partial_sig).await?;
@@ -108,156 +464,601 @@ impl SignatureCoordinator {
     }
 
     /// Request an unlock signature for a Polkadot burn event
+    ///
+    /// Validators sign the bridge contract's unlock digest for `chain_id`
+    /// directly with their key shares, so each partial is a signature
+    /// `unlockTokens` accepts as is.
     pub async fn request_unlock_signature(
         &self,
         recipient: &str,
-        asset_id: u32,
+        token: &str,
         amount: &str,
         polkadot_tx_hash: &str,
+        chain_id: u64,
     ) -> Result<()> {
         if !self.config.enabled {
             return Ok(());
         }
 
+        if self.is_completed(polkadot_tx_hash).await {
+            debug!("Transfer {} already submitted, not signing again", polkadot_tx_hash);
+            return Ok(());
+        }
+
         info!("Requesting unlock signature for tx {}", polkadot_tx_hash);
 
-        // Create message to sign
-        let message = self.create_unlock_message(recipient, asset_id, amount, polkadot_tx_hash)?;
+        // The contract recovers each signer from a signature of this digest
+        let message = ethereum::unlock_message(recipient, token, amount, polkadot_tx_hash, chain_id)?;
+        self.open_session(polkadot_tx_hash, message.to_vec(), SignatureFormat::Recoverable).await?;
 
-        // Create signature session
-        let session = SignatureSession {
-            tx_hash: polkadot_tx_hash.to_string(),
-            message: message.clone(),
-            partial_signatures: HashMap::new(),
-            required_signatures: self.threshold_manager.config().threshold,
-            created_at: std::time::SystemTime::now(),
-        };
+        // Generate our partial signature
+        if let Some(key_share) = self.validator_key_share().await? {
+            let partial_sig = sign_recoverable(&key_share, &message)?;
 
-        // Store session
-        {
-            let mut pending = self.pending_signatures.write().await;
-            pending.insert(polkadot_tx_hash.to_string(), session);
+            // Store our signature
+            self.add_partial_signature(polkadot_tx_hash, &self.config.validator_id, LOCAL_SOURCE, partial_sig.clone()).await?;
+
+            // Broadcast to other validators (simplified)
+            self.broadcast_partial_signature(polkadot_tx_hash, &partial_sig).await?;
         }
 
-        // Generate our partial signature
-        if let Some(private_key) = &self.config.private_key {
-            let key_share = self.get_validator_key_share(private_key).await?;
+        Ok(())
+    }
+
+    /// Request signatures over a bridge state checkpoint
+    pub async fn request_checkpoint_signature(&self, checkpoint: &BridgeCheckpoint) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let session_id = checkpoint.session_id();
+        info!("Requesting checkpoint signature for {}", session_id);
+
+        let message = checkpoint.message();
+        self.open_session(&session_id, message.clone(), SignatureFormat::Threshold).await?;
+
+        if let Some(key_share) = self.validator_key_share().await? {
             let partial_sig = self.threshold_manager
-                .create_partial_signature(&key_share, &message, polkadot_tx_hash)
+                .create_partial_signature(&key_share, &message, &session_id)
                 .await
-                .map_err(|e| RelayerError::ThresholdSignature(e))?;
+                .map_err(RelayerError::ThresholdSignature)?;
 
-            // Store our signature
-            self.add_partial_signature(polkadot_tx_hash, &self.config.validator_id, partial_sig.clone()).await?;
+            self.add_partial_signature(&session_id, &self.config.validator_id, LOCAL_SOURCE, partial_sig.clone()).await?;
+            self.broadcast_partial_signature(&session_id, &partial_sig).await?;
+        }
 
-            // Broadcast to other validators (simplified)
-            self.broadcast_partial_signature(polkadot_tx_hash, &partial_sig).await?;
+        Ok(())
+    }
+
+    /// Create the signature session for a transaction, merging in any
+    /// partials that arrived before it
+    ///
+    /// Fails without opening the session if its message is over the size cap
+    /// or too few validators are active to ever reach the threshold.
+    async fn open_session(&self, tx_hash: &str, message: Vec<u8>, format: SignatureFormat) -> Result<()> {
+        if message.len() > self.max_message_size {
+            return Err(RelayerError::MessageTooLarge {
+                tx_hash: tx_hash.to_string(),
+                size: message.len(),
+                max: self.max_message_size,
+            });
         }
 
+        self.ensure_threshold_reachable(tx_hash).await?;
+
+        // Lock order (early partials, then sessions) matches `add_partial_signature`
+        let mut early_partials = self.early_partials.write().await;
+        let mut pending = self.pending_signatures.write().await;
+
+        let mut session = SignatureSession {
+            tx_hash: tx_hash.to_string(),
+            message,
+            format,
+            partial_signatures: HashMap::new(),
+            sources: HashMap::new(),
+            public_key_shares: self.public_key_shares.clone(),
+            required_signatures: *self.threshold.read().await,
+            created_at: SystemTime::now(),
+        };
+
+        // Early partials arrived before the message was known, so they are
+        // only verified now
+        if let Some(early) = early_partials.remove(tx_hash) {
+            debug!("Merging {} early partial signatures for tx {}", early.partials.len(), tx_hash);
+            for (validator_id, partial_sig) in early.partials {
+                let source = early.sources.get(&validator_id).cloned().unwrap_or_default();
+                if let Err(e) = self.verify_partial(&session, &validator_id, &source, &partial_sig).await {
+                    warn!("Dropping early partial signature from {}: {}", validator_id, e);
+                    continue;
+                }
+                session.sources.insert(validator_id.clone(), source);
+                session.partial_signatures.insert(validator_id, partial_sig);
+            }
+        }
+
+        let stored = session.to_stored();
+        pending.insert(tx_hash.to_string(), session);
+        drop(pending);
+        drop(early_partials);
+
+        self.persist_session(&stored).await;
         Ok(())
     }
 
+    /// Check that the active validators can meet the threshold for `tx_hash`
+    ///
+    /// Without configured peers the validator set is unknown and the check is
+    /// skipped. Under a weighted threshold the active validators' combined
+    /// weight is compared instead of their count.
+    async fn ensure_threshold_reachable(&self, tx_hash: &str) -> Result<()> {
+        if self.config.peers.is_empty() {
+            return Ok(());
+        }
+
+        let active_ids = self.active_validator_ids().await;
+        let (active, required) = match &self.weighted {
+            Some(weighted) => (
+                active_ids
+                    .iter()
+                    .fold(0u64, |total, id| total.saturating_add(weighted.weight(id))),
+                weighted.threshold,
+            ),
+            None => (active_ids.len() as u64, u64::from(*self.threshold.read().await)),
+        };
+        if active >= required {
+            return Ok(());
+        }
+
+        error!(
+            "Refusing signature session for tx {}: active validators provide {} of the {} required",
+            tx_hash, active, required
+        );
+        Err(RelayerError::InsufficientActiveValidators {
+            tx_hash: tx_hash.to_string(),
+            active,
+            required,
+        })
+    }
+
+    /// IDs of this validator and its active, non-removed peers
+    async fn active_validator_ids(&self) -> Vec<String> {
+        let removed = self.removed_validators.read().await;
+        std::iter::once(self.config.validator_id.clone())
+            .chain(
+                self.config.peers
+                    .iter()
+                    .filter(|p| p.active && !removed.contains(&p.id))
+                    .map(|p| p.id.clone()),
+            )
+            .collect()
+    }
+
     /// Add a partial signature to a session
+    ///
+    /// `source` identifies the connection (peer address) the partial arrived
+    /// on, or [`LOCAL_SOURCE`] for our own. Partials for a transaction without
+    /// a session yet are buffered and merged in when the session is created.
+    /// Partials must verify over the session message against their
+    /// validator's public key share, or are rejected (or dropped, if
+    /// buffered); partials from validators without a registered share are
+    /// rejected unless they are our own.
+    /// Partials timestamped outside the clock-skew tolerance are rejected,
+    /// since their timestamps would throw off session expiry. Partials for a
+    /// transfer that was already submitted are counted and discarded.
     pub async fn add_partial_signature(
         &self,
         tx_hash: &str,
         validator_id: &str,
+        source: &str,
         partial_sig: PartialSignature,
     ) -> Result<()> {
+        if self.removed_validators.read().await.contains(validator_id) {
+            return Err(RelayerError::Generic {
+                message: format!("Validator {} has been removed", validator_id),
+            });
+        }
+
+        if let Err(e) = self.timeouts.check_clock_skew(validator_id, partial_sig.timestamp, SystemTime::now()) {
+            warn!("Suspected clock skew on validator {} ({}): {}", validator_id, source, e);
+            return Err(e);
+        }
+
+        // Hold the buffer lock throughout so a concurrent `open_session`
+        // cannot miss a partial buffered here
+        let mut early_partials = self.early_partials.write().await;
         let mut pending = self.pending_signatures.write().await;
-        
+
+        if self.completed.read().await.contains_key(tx_hash) {
+            self.late_partials.inc();
+            debug!("Discarding late partial signature from {} ({}) for submitted tx {}", validator_id, source, tx_hash);
+            return Ok(());
+        }
+
         if let Some(session) = pending.get_mut(tx_hash) {
-            session.partial_signatures.insert(validator_id.to_string(), partial_sig);
-            debug!("Added partial signature from {} for tx {}", validator_id, tx_hash);
+            if let Err(e) = self.verify_partial(session, validator_id, source, &partial_sig).await {
+                warn!("Rejecting partial signature from {} ({}) for tx {}: {}", validator_id, source, tx_hash, e);
+                return Err(e);
+            }
+
+            let replaced = session.partial_signatures.insert(validator_id.to_string(), partial_sig.clone());
+            if replaced.is_none_or(|old| old.signature != partial_sig.signature) {
+                self.aggregated.write().await.remove(tx_hash);
+            }
+            session.sources.insert(validator_id.to_string(), source.to_string());
+            debug!("Added partial signature from {} ({}) for tx {}", validator_id, source, tx_hash);
+            drop(pending);
+            drop(early_partials);
+
+            let stored = StoredPartialSignature {
+                validator_id: validator_id.to_string(),
+                source: source.to_string(),
+                signature: partial_sig,
+            };
+            if let Err(e) = self.database.save_partial_signature(tx_hash, &stored).await {
+                error!("Failed to persist partial signature from {} for tx {}: {}", validator_id, tx_hash, e);
+            }
+            return Ok(());
         }
+        drop(pending);
+
+        if !early_partials.contains_key(tx_hash) && early_partials.len() >= MAX_EARLY_PARTIAL_SESSIONS {
+            let oldest = early_partials
+                .iter()
+                .min_by_key(|(_, early)| early.received_at)
+                .map(|(tx_hash, _)| tx_hash.clone());
+            if let Some(oldest) = oldest {
+                warn!("Early partial signature buffer full, dropping partials for tx {}", oldest);
+                early_partials.remove(&oldest);
+            }
+        }
+
+        let early = early_partials
+            .entry(tx_hash.to_string())
+            .or_insert_with(|| EarlyPartials {
+                partials: HashMap::new(),
+                sources: HashMap::new(),
+                received_at: SystemTime::now(),
+            });
+        early.partials.insert(validator_id.to_string(), partial_sig);
+        early.sources.insert(validator_id.to_string(), source.to_string());
+        debug!("Buffered early partial signature from {} for tx {}", validator_id, tx_hash);
 
         Ok(())
     }
 
-    /// Get aggregated signatures for mint operation if ready
-    pub async fn get_mint_signatures(&self, tx_hash: &str) -> Result<Option<Vec<Vec<u8>>>> {
-        let pending = self.pending_signatures.read().await;
-        
-        if let Some(session) = pending.get(tx_hash) {
-            if session.partial_signatures.len() >= session.required_signatures as usize {
-                // We have enough signatures, aggregate them
-                let partial_sigs: Vec<PartialSignature> = session.partial_signatures.values().cloned().collect();
-                
-                // For simplified implementation, just return the signature bytes
-                let signatures: Vec<Vec<u8>> = partial_sigs.iter()
-                    .map(|sig| sig.signature.clone())
-                    .collect();
-
-                return Ok(Some(signatures));
+    /// Check a partial against its signer's public key share
+    ///
+    /// A peer's claimed validator ID is only trusted once its partial verifies
+    /// against that validator's registered share, so partials from validators
+    /// without one are rejected. Our own partials, created locally, are the
+    /// exception.
+    async fn verify_partial(
+        &self,
+        session: &SignatureSession,
+        validator_id: &str,
+        source: &str,
+        partial_sig: &PartialSignature,
+    ) -> Result<()> {
+        let Some(public_share) = session.public_key_shares.get(validator_id) else {
+            if source == LOCAL_SOURCE && validator_id == self.config.validator_id {
+                return Ok(());
+            }
+            return Err(RelayerError::ThresholdSignature(ThresholdError::InvalidSignature {
+                reason: format!("No public key share registered for validator {}", validator_id),
+            }));
+        };
+
+        let valid = match session.format {
+            SignatureFormat::Threshold => self.threshold_manager
+                .verify_partial_signature(public_share, &session.message, &session.tx_hash, partial_sig)
+                .await
+                .map_err(RelayerError::ThresholdSignature)?,
+            SignatureFormat::Recoverable => recovers_to(public_share, &session.message, &partial_sig.signature)?,
+        };
+        if !valid {
+            return Err(RelayerError::ThresholdSignature(ThresholdError::InvalidSignature {
+                reason: format!("Partial signature from {} for tx {} does not verify", validator_id, session.tx_hash),
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Close the session of a transfer that has been submitted
+    ///
+    /// The session and its cached aggregate are dropped so nothing can
+    /// aggregate or submit it again. The transfer is remembered for the
+    /// signature timeout so partials still in flight are discarded rather than
+    /// opening a new buffer, and so signing is not requested again.
+    pub async fn mark_completed(&self, tx_hash: &str) {
+        // Lock order (early partials, then sessions) matches `add_partial_signature`
+        let mut early_partials = self.early_partials.write().await;
+        let mut pending = self.pending_signatures.write().await;
+
+        early_partials.remove(tx_hash);
+        pending.remove(tx_hash);
+        self.grace.write().await.remove(tx_hash);
+        self.aggregated.write().await.remove(tx_hash);
+        self.completed.write().await.insert(tx_hash.to_string(), SystemTime::now());
+        drop(pending);
+        drop(early_partials);
+        debug!("Closed signature session for submitted tx {}", tx_hash);
+
+        if let Err(e) = self.database.delete_signature_session(tx_hash).await {
+            error!("Failed to delete persisted signature session for tx {}: {}", tx_hash, e);
+        }
+    }
+
+    /// Whether the transfer was submitted within the signature timeout
+    pub async fn is_completed(&self, tx_hash: &str) -> bool {
+        self.completed.read().await.contains_key(tx_hash)
+    }
+
+    /// Number of partials discarded because their transfer was already submitted
+    pub fn late_partial_count(&self) -> u64 {
+        self.late_partials.get()
+    }
+
+    /// Register the late partial counter in `registry`
+    ///
+    /// Late partials arrive by gossip after their transfer was submitted; a
+    /// steady trickle is normal for validators slower than the threshold.
+    pub fn register_metrics(&self, registry: &Registry) -> Result<()> {
+        metrics::register(registry, self.late_partials.clone())
+    }
+
+    /// The threshold key set currently signed with, if one is loaded
+    pub fn key_set(&self) -> Option<KeySet> {
+        self.threshold_manager.key_set()
+    }
+
+    /// Remove a validator from the signing set and adopt a new threshold
+    ///
+    /// Sessions that already hold enough partials are left alone so they can
+    /// still complete. Incomplete sessions drop the removed validator's
+    /// partial and require the new threshold, as do all later sessions.
+    pub async fn remove_validator(&self, validator_id: &str, threshold: u32) -> Result<()> {
+        if threshold == 0 {
+            return Err(RelayerError::Generic {
+                message: "Threshold must be at least 1".to_string(),
+            });
+        }
+
+        info!("Removing validator {}, threshold is now {}", validator_id, threshold);
+
+        self.removed_validators.write().await.insert(validator_id.to_string());
+        *self.threshold.write().await = threshold;
+
+        // Lock order (early partials, then sessions) matches `add_partial_signature`
+        let mut early_partials = self.early_partials.write().await;
+        let mut pending = self.pending_signatures.write().await;
+
+        for early in early_partials.values_mut() {
+            early.partials.remove(validator_id);
+            early.sources.remove(validator_id);
+        }
+
+        let mut aggregated = self.aggregated.write().await;
+        let mut changed = Vec::new();
+        for session in pending.values_mut() {
+            if session.is_ready(self.weighted.as_ref()) {
+                continue;
             }
+            aggregated.remove(&session.tx_hash);
+            session.partial_signatures.remove(validator_id);
+            session.sources.remove(validator_id);
+            session.required_signatures = threshold;
+            changed.push(session.to_stored());
+        }
+        drop(aggregated);
+        drop(pending);
+        drop(early_partials);
+
+        for session in &changed {
+            self.persist_session(session).await;
+        }
+
+        Ok(())
+    }
+
+    /// Signatures required for new sessions
+    pub async fn threshold(&self) -> u32 {
+        *self.threshold.read().await
+    }
+
+    /// This validator and its peers, with removed and inactive peers marked
+    /// inactive, and the current threshold
+    pub async fn validator_set(&self) -> ValidatorSet {
+        let removed = self.removed_validators.read().await;
+        let local = ValidatorInfo {
+            id: self.config.validator_id.clone(),
+            address: self.config.listen_address.clone(),
+            active: true,
+        };
+        let peers = self.config.peers.iter().map(|p| ValidatorInfo {
+            id: p.id.clone(),
+            address: Some(p.address.clone()),
+            active: p.active && !removed.contains(&p.id),
+        });
+        let validators = std::iter::once(local).chain(peers).collect();
+        drop(removed);
+
+        ValidatorSet {
+            validators,
+            threshold: self.threshold().await,
         }
+    }
 
-        Ok(None)
+    /// Get aggregated signatures for mint operation if ready
+    ///
+    /// These are the validators' 65-byte recoverable signatures
+    /// of [`polkadot::mint_message`], ready to pass to `mint_tokens`.
+    ///
+    /// The aggregate is cached per session, so repeated ready-checks only
+    /// aggregate again after the session's partials change. A session not in
+    /// memory is read back from the database, so partials collected before a
+    /// restart still count.
+    pub async fn get_mint_signatures(&self, tx_hash: &str) -> Result<Option<Vec<Vec<u8>>>> {
+        self.recoverable_signatures(tx_hash).await
     }
 
     /// Get aggregated signatures for unlock operation if ready
+    ///
+    /// These are the validators' 65-byte recoverable signatures of
+    /// [`ethereum::unlock_message`], ready to pass to `unlockTokens`, and are
+    /// cached and restored as [`Self::get_mint_signatures`] describes.
     pub async fn get_unlock_signatures(&self, tx_hash: &str) -> Result<Option<Vec<Vec<u8>>>> {
-        // Same logic as mint signatures for now
-        self.get_mint_signatures(tx_hash).await
+        self.recoverable_signatures(tx_hash).await
     }
 
-    /// Create message for mint operation
-    fn create_mint_message(
-        &self,
-        recipient: &str,
-        token: &str,
-        amount: &str,
-        ethereum_tx_hash: &str,
-    ) -> Result<Vec<u8>> {
-        let message = format!("mint:{}:{}:{}:{}", recipient, token, amount, ethereum_tx_hash);
-        Ok(message.into_bytes())
+    /// Recoverable signatures of a ready session
+    ///
+    /// A session restored from before transfers were signed this way holds
+    /// threshold partials no chain can recover a signer from, and is refused.
+    async fn recoverable_signatures(&self, tx_hash: &str) -> Result<Option<Vec<Vec<u8>>>> {
+        self.restore_session(tx_hash).await?;
+        let pending = self.pending_signatures.read().await;
+
+        let Some(session) = pending.get(tx_hash) else {
+            return Ok(None);
+        };
+        if session.format != SignatureFormat::Recoverable {
+            return Err(RelayerError::Generic {
+                message: format!(
+                    "Session for tx {} holds {} partials, not recoverable signatures",
+                    tx_hash,
+                    session.format.as_str()
+                ),
+            });
+        }
+        if !session.is_ready(self.weighted.as_ref()) {
+            return Ok(None);
+        }
+
+        session.check_source_diversity(self.config.require_distinct_sources)?;
+
+        let mut aggregated = self.aggregated.write().await;
+        if let Some(signatures) = aggregated.get(tx_hash) {
+            return Ok(Some(signatures.clone()));
+        }
+
+        let signatures = self.aggregate(session);
+        aggregated.insert(tx_hash.to_string(), signatures.clone());
+        Ok(Some(signatures))
     }
 
-    /// Create message for unlock operation
-    fn create_unlock_message(
+    /// Threshold aggregate of a ready session, verifiable against
+    /// `public_key_shares`
+    ///
+    /// Unlike [`Self::get_mint_signatures`], which hands the partials to the
+    /// pallet as they are, this combines them into a single
+    /// [`AggregatedSignature`] under the session ID.
+    pub async fn get_aggregated_signature(
         &self,
-        recipient: &str,
-        asset_id: u32,
-        amount: &str,
-        polkadot_tx_hash: &str,
-    ) -> Result<Vec<u8>> {
-        let message = format!("unlock:{}:{}:{}:{}", recipient, asset_id, amount, polkadot_tx_hash);
-        Ok(message.into_bytes())
+        session_id: &str,
+        public_key_shares: &[PublicKeyShare],
+    ) -> Result<Option<AggregatedSignature>> {
+        let pending = self.pending_signatures.read().await;
+
+        let Some(session) = pending.get(session_id) else {
+            return Ok(None);
+        };
+        if !session.is_ready(self.weighted.as_ref()) {
+            return Ok(None);
+        }
+
+        session.check_source_diversity(self.config.require_distinct_sources)?;
+
+        let partials: Vec<PartialSignature> = session.partial_signatures.values().cloned().collect();
+        self.aggregations.fetch_add(1, Ordering::Relaxed);
+        let signature = self.threshold_manager
+            .aggregate_signatures(&partials, public_key_shares, &session.message, session_id)
+            .await
+            .map_err(RelayerError::ThresholdSignature)?;
+
+        Ok(Some(signature))
+    }
+
+    /// Number of times a ready session's partials have been aggregated
+    pub fn aggregation_count(&self) -> u64 {
+        self.aggregations.load(Ordering::Relaxed)
+    }
+
+    /// Aggregate the partials of a ready session
+    ///
+    /// The pallet takes a mint's signatures as they are, and the bridge
+    /// contract an unlock's, one 65-byte recoverable signature per validator,
+    /// so the partials are handed over unchanged.
+    fn aggregate(&self, session: &SignatureSession) -> Vec<Vec<u8>> {
+        self.aggregations.fetch_add(1, Ordering::Relaxed);
+        debug!("Aggregating {} partial signatures for tx {}", session.partial_signatures.len(), session.tx_hash);
+
+        session.partial_signatures
+            .values()
+            .map(|sig| sig.signature.clone())
+            .collect()
     }
 
-    /// Get validator key share (simplified)
-    async fn get_validator_key_share(&self, private_key: &str) -> Result<threshold::KeyShare> {
-        // This is a simplified implementation
-        // In a real system, key shares would be generated through DKG
-        use threshold::types::{KeyShare, ThresholdConfig};
+    /// This validator's key share, or `None` if it does not sign
+    ///
+    /// The share comes from the keystore when one is configured. Without one,
+    /// a share is built from the raw `private_key` for development setups;
+    /// peers only accept its partials if they register the matching public
+    /// key for this validator.
+    async fn validator_key_share(&self) -> Result<Option<Arc<SecretKeyShare>>> {
+        if let Some(keystore) = &self.keystore {
+            let key_share = keystore.key_share().await?;
+            if key_share.validator_id != self.config.validator_id {
+                return Err(RelayerError::Config {
+                    message: format!(
+                        "Keystore {} holds the share of {}, not {}",
+                        keystore.path().display(),
+                        key_share.validator_id,
+                        self.config.validator_id
+                    ),
+                });
+            }
+            return Ok(Some(key_share));
+        }
 
-        let config = ThresholdConfig::new(2, 3, 256)
-            .map_err(|e| RelayerError::ThresholdSignature(e))?;
+        let Some(private_key) = &self.config.private_key else {
+            return Ok(None);
+        };
 
-        Ok(KeyShare {
+        let private_share = hex::decode(private_key.trim_start_matches("0x"))
+            .map_err(|e| RelayerError::Config {
+                message: format!("Invalid private key hex: {}", e),
+            })?;
+
+        Ok(Some(Arc::new(SecretKeyShare::new(KeyShare {
             validator_id: self.config.validator_id.clone(),
-            private_share: hex::decode(private_key)
-                .map_err(|e| RelayerError::Config {
-                    message: format!("Invalid private key hex: {}", e),
-                })?,
-            public_share: vec![0u8; 65], // Mock public key
+            private_share,
+            public_share: vec![],
             coefficients: vec![],
-            config,
-        })
+            config: self.threshold_manager.config().clone(),
+            key_epoch: 0,
+        }))))
     }
 
-    /// Broadcast partial signature to other validators (simplified)
+    /// Broadcast our partial signature to the other validators
+    ///
+    /// Peers that are unreachable or reject the partial are only logged; the
+    /// session's re-requests give them another chance.
     async fn broadcast_partial_signature(
         &self,
         tx_hash: &str,
         partial_sig: &PartialSignature,
     ) -> Result<()> {
+        if self.broadcaster.peer_count() == 0 {
+            return Ok(());
+        }
+
         debug!("Broadcasting partial signature for tx {}", tx_hash);
-        
-        // In a real implementation, this would:
-        // 1. Send the signature to other validators via network
-        // 2. Handle network failures and retries
-        // 3. Verify signatures from other validators
+        let delivered = self.broadcaster.broadcast(tx_hash, partial_sig).await?;
+        debug!(
+            "Partial signature for tx {} reached {}/{} peers",
+            tx_hash, delivered, self.broadcaster.peer_count()
+        );
 
         Ok(())
     }
@@ -268,35 +1069,192 @@ impl SignatureCoordinator {
         Ok(pending.len() as u64)
     }
 
+    /// In-flight signature session for a transaction, if any
+    pub async fn session(&self, tx_hash: &str) -> Option<SignatureSession> {
+        self.pending_signatures.read().await.get(tx_hash).cloned()
+    }
+
     /// Count active validators
     pub async fn count_active_validators(&self) -> Result<u64> {
-        Ok(self.config.peers.iter().filter(|p| p.active).count() as u64 + 1) // +1 for self
+        Ok(self.active_validator_ids().await.len() as u64)
     }
 
-    /// Cleanup expired signature sessions
-    async fn cleanup_expired_signatures(
-        pending_signatures: Arc<RwLock<HashMap<String, SignatureSession>>>,
-    ) {
-        let cleanup_interval = Duration::from_secs(300); // 5 minutes
-        let session_timeout = Duration::from_secs(3600); // 1 hour
+    /// Sweep signature sessions as of `now`
+    ///
+    /// Completed sessions past the signature timeout are removed. Incomplete
+    /// ones get the grace period to catch up, during which their partials are
+    /// re-requested with exponential backoff; only then are they
+    /// dead-lettered. Submitted transfers are forgotten after the signature
+    /// timeout.
+    pub async fn sweep_sessions(&self, now: SystemTime) -> SessionSweep {
+        let sweep = Self::sweep(
+            &self.pending_signatures,
+            &self.grace,
+            &self.aggregated,
+            &self.completed,
+            self.weighted.as_ref(),
+            self.timeouts,
+            now,
+        ).await;
+
+        for tx_hash in sweep.expired.iter().chain(&sweep.dead_lettered) {
+            if let Err(e) = self.database.delete_signature_session(tx_hash).await {
+                error!("Failed to delete persisted signature session for tx {}: {}", tx_hash, e);
+            }
+        }
+        for tx_hash in &sweep.rerequested {
+            self.rerequest_partial_signatures(tx_hash).await;
+        }
+        sweep
+    }
 
+    /// Cleanup expired signature sessions every `cleanup_interval` until the
+    /// coordinator is dropped
+    async fn cleanup_expired_signatures(coordinator: Weak<Self>, cleanup_interval: Duration) {
         loop {
             tokio::time::sleep(cleanup_interval).await;
 
-            let mut pending = pending_signatures.write().await;
-            let now = std::time::SystemTime::now();
+            let Some(coordinator) = coordinator.upgrade() else {
+                break;
+            };
 
-            pending.retain(|tx_hash, session| {
-                if let Ok(elapsed) = now.duration_since(session.created_at) {
-                    if elapsed > session_timeout {
-                        warn!("Cleaning up expired signature session for tx {}", tx_hash);
-                        return false;
-                    }
+            let now = SystemTime::now();
+            coordinator.sweep_sessions(now).await;
+
+            // Partials whose session never appeared are as stale as an expired session
+            let signature_timeout = coordinator.timeouts.signature_timeout;
+            coordinator.early_partials.write().await.retain(|tx_hash, early| {
+                let expired = now
+                    .duration_since(early.received_at)
+                    .is_ok_and(|elapsed| elapsed > signature_timeout);
+                if expired {
+                    warn!("Dropping early partial signatures for tx {} with no session", tx_hash);
                 }
-                true
+                !expired
+            });
+        }
+    }
+
+    /// Sweep the sessions in `pending_signatures`, tracking re-requests in
+    /// `grace`, dropping cached aggregates of removed sessions and forgetting
+    /// `completed` transfers past the signature timeout
+    async fn sweep(
+        pending_signatures: &RwLock<HashMap<String, SignatureSession>>,
+        grace: &RwLock<HashMap<String, GraceState>>,
+        aggregated: &RwLock<HashMap<String, Vec<Vec<u8>>>>,
+        completed: &RwLock<HashMap<String, SystemTime>>,
+        weighted: Option<&WeightedThreshold>,
+        timeouts: Timeouts,
+        now: SystemTime,
+    ) -> SessionSweep {
+        let mut pending = pending_signatures.write().await;
+        let mut grace = grace.write().await;
+        let mut sweep = SessionSweep::default();
+
+        pending.retain(|tx_hash, session| {
+            let elapsed = now.duration_since(session.created_at).unwrap_or_default();
+            if elapsed <= timeouts.signature_timeout {
+                return true;
+            }
+
+            if session.is_ready(weighted) {
+                debug!("Cleaning up expired signature session for tx {}", tx_hash);
+                sweep.expired.push(tx_hash.clone());
+                return false;
+            }
+
+            if elapsed > timeouts.signature_timeout + timeouts.signature_grace_period {
+                error!(
+                    "Dead-lettering signature session for tx {} with {}/{} partials",
+                    tx_hash,
+                    session.partial_signatures.len(),
+                    session.required_signatures
+                );
+                sweep.dead_lettered.push(tx_hash.clone());
+                return false;
+            }
+
+            let state = grace.entry(tx_hash.clone()).or_insert(GraceState {
+                attempts: 0,
+                next_rerequest: now,
             });
+            if now >= state.next_rerequest {
+                state.attempts += 1;
+                state.next_rerequest = now + timeouts.rerequest_backoff(state.attempts);
+                warn!(
+                    "Re-requesting partial signatures for tx {} (attempt {}, {}/{} received)",
+                    tx_hash,
+                    state.attempts,
+                    session.partial_signatures.len(),
+                    session.required_signatures
+                );
+                sweep.rerequested.push(tx_hash.clone());
+            }
+            true
+        });
+
+        grace.retain(|tx_hash, _| pending.contains_key(tx_hash));
+        aggregated.write().await.retain(|tx_hash, _| pending.contains_key(tx_hash));
+        completed.write().await.retain(|_, completed_at| {
+            !now.duration_since(*completed_at)
+                .is_ok_and(|elapsed| elapsed > timeouts.signature_timeout)
+        });
+        sweep
+    }
+
+    /// Ask the peers missing from `tx_hash`'s session for their partials
+    ///
+    /// Our own partial is sent to each of them again, and they do the same
+    /// from their sweeps, so a partial lost while a peer was down reaches it
+    /// once both ends are back.
+    async fn rerequest_partial_signatures(&self, tx_hash: &str) {
+        let Some(session) = self.session(tx_hash).await else {
+            return;
+        };
+        let Some(partial) = session.partial_signatures.get(&self.config.validator_id) else {
+            debug!("No partial of our own to re-send for tx {}", tx_hash);
+            return;
+        };
+
+        let missing = |peer_id: &str| !session.partial_signatures.contains_key(peer_id);
+        match self.broadcaster.send_to(tx_hash, partial, missing).await {
+            Ok(delivered) => debug!("Re-sent partial signature for tx {} to {} peers", tx_hash, delivered),
+            Err(e) => warn!("Failed to re-send partial signature for tx {}: {}", tx_hash, e),
         }
     }
 }
 
-use std::time::Duration;
+/// Sign the 32-byte `digest` with `key_share` as a 65-byte recoverable
+/// signature (`r || s || v`, `v` = 27 or 28)
+fn sign_recoverable(key_share: &KeyShare, digest: &[u8; 32]) -> Result<PartialSignature> {
+    let wallet = LocalWallet::from_bytes(&key_share.private_share).map_err(|e| RelayerError::Config {
+        message: format!("Invalid key share for {}: {}", key_share.validator_id, e),
+    })?;
+    let signature = wallet.sign_hash(H256::from(*digest)).map_err(|e| RelayerError::Generic {
+        message: format!("Failed to sign as {}: {}", key_share.validator_id, e),
+    })?;
+
+    Ok(PartialSignature {
+        validator_id: key_share.validator_id.clone(),
+        signature: signature.to_vec(),
+        commitment: None,
+        timestamp: SystemTime::now(),
+        key_epoch: key_share.key_epoch,
+    })
+}
+
+/// Whether `signature` is a recoverable signature of the 32-byte `digest`
+/// by the holder of `public_share`
+fn recovers_to(public_share: &[u8], digest: &[u8], signature: &[u8]) -> Result<bool> {
+    let address = threshold::utils::ethereum_address(public_share).map_err(RelayerError::ThresholdSignature)?;
+    if digest.len() != 32 {
+        return Ok(false);
+    }
+    let Ok(signature) = Signature::try_from(signature) else {
+        return Ok(false);
+    };
+
+    Ok(signature
+        .recover(H256::from_slice(digest))
+        .is_ok_and(|signer| signer.as_bytes() == address))
+}
@@ -1,14 +1,10 @@
 //! API integration tests
 
 use crate::common::{
-    setup::{setup_mock_coordinator, init_test_logging},
+    setup::init_test_logging,
     assertions::{assert_valid_bridge_stats, assert_json_contains},
     TestResult, with_timeout,
 };
-use api::{ApiServer, server::ApiConfig};
-use reqwest::Client;
-use std::sync::Arc;
-use tokio::time::{sleep, Duration};
 
 #[tokio::test]
 async fn test_api_server_startup() -> TestResult<()> {
@@ -28,40 +24,102 @@ async fn test_api_server_startup() -> TestResult<()> {
             cors_origins: vec![],
             enable_metrics: true,
             metrics_path: "/metrics".to_string(),
+            admin_api_key: None,
+            api_keys: vec![],
+            rate_limit_per_minute: 100,
+            rate_limit_burst: 200,
         };
 
         Ok(())
     }).await
 }
 
+/// Chain probe that answers, fails, or never answers
+struct StubChain {
+    chain: &'static str,
+    reachable: Option<bool>,
+}
+
+#[async_trait::async_trait]
+impl api::handlers::health::ChainProbe for StubChain {
+    fn chain(&self) -> &'static str {
+        self.chain
+    }
+
+    async fn latest_block(&self) -> relayer::Result<u64> {
+        match self.reachable {
+            Some(true) => Ok(100),
+            Some(false) => Err(relayer::RelayerError::Network {
+                message: "connection refused".to_string(),
+            }),
+            None => std::future::pending().await,
+        }
+    }
+}
+
+/// Get `/health` with the given chains
+async fn get_health(chains: Vec<StubChain>) -> TestResult<(axum::http::StatusCode, serde_json::Value)> {
+    use api::server::HealthState;
+    use axum::Extension;
+    use relayer::metrics::TransferHealth;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    let state = HealthState {
+        stats: Arc::new(FixedStats),
+        chains: chains.into_iter().map(|chain| Arc::new(chain) as _).collect(),
+        transfer_health: Arc::new(TransferHealth::new(10, Duration::from_secs(60))),
+        started_at: Instant::now() - Duration::from_secs(90),
+        probe_timeout: Duration::from_millis(100),
+    };
+
+    get_router(api::routes::create_api_routes().layer(Extension(state)), "/health").await
+}
+
 #[tokio::test]
 async fn test_health_endpoint() -> TestResult<()> {
     init_test_logging();
-    
+
     with_timeout(async {
-        // In a real test, this would:
-        // 1. Start the API server
-        // 2. Make HTTP request to /health
-        // 3. Verify response structure and content
-        
-        // For now, test the response structure
-        let mock_health_response = serde_json::json!({
-            "status": "healthy",
-            "version": "0.1.0",
-            "uptime": 3600,
-            "bridge_stats": {
-                "ethereum_processed_txs": 100,
-                "polkadot_processed_txs": 95,
-                "pending_signatures": 2,
-                "active_validators": 3
-            }
-        });
-        
-        let required_fields = ["status", "version", "uptime", "bridge_stats"];
-        assert_json_contains(&mock_health_response, &required_fields)?;
-        
-        assert_valid_bridge_stats(&mock_health_response["bridge_stats"])?;
-        
+        let (status, body) = get_health(vec![
+            StubChain { chain: "ethereum", reachable: Some(true) },
+            StubChain { chain: "polkadot", reachable: Some(true) },
+        ]).await?;
+
+        assert_eq!(status, axum::http::StatusCode::OK);
+        assert_json_contains(&body, &["status", "version", "uptime", "bridge_stats", "unavailable"])?;
+        assert_eq!(body["status"], "healthy");
+        assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+        assert!(body["uptime"].as_u64().unwrap() >= 90);
+        assert_eq!(body["unavailable"], serde_json::json!([]));
+        assert_valid_bridge_stats(&body["bridge_stats"])?;
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_health_degraded_when_chain_down() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        // One chain refuses the connection, the other never answers
+        let (status, body) = get_health(vec![
+            StubChain { chain: "ethereum", reachable: Some(false) },
+            StubChain { chain: "polkadot", reachable: Some(true) },
+        ]).await?;
+        assert_eq!(status, axum::http::StatusCode::OK);
+        assert_eq!(body["status"], "degraded");
+        assert_eq!(body["unavailable"], serde_json::json!(["ethereum"]));
+
+        let (status, body) = get_health(vec![
+            StubChain { chain: "ethereum", reachable: Some(true) },
+            StubChain { chain: "polkadot", reachable: None },
+        ]).await?;
+        assert_eq!(status, axum::http::StatusCode::OK);
+        assert_eq!(body["status"], "degraded");
+        assert_eq!(body["unavailable"], serde_json::json!(["polkadot"]));
+
         Ok(())
     }).await
 }
@@ -119,20 +177,26 @@ async fn test_transactions_endpoint() -> TestResult<()> {
     
     with_timeout(async {
         // Mock transactions response
-        let mock_transactions_response = serde_json::json!([
-            {
-                "tx_hash": "0x1234567890abcdef",
-                "chain": "ethereum",
-                "status": "confirmed",
-                "amount": "1000",
-                "token": "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8",
-                "user": "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
-                "block_number": 12345,
-                "timestamp": "2024-01-01T12:00:00Z"
-            }
-        ]);
+        let mock_transactions_response = serde_json::json!({
+            "transactions": [
+                {
+                    "tx_hash": "0x1234567890abcdef",
+                    "chain": "ethereum",
+                    "status": "confirmed",
+                    "amount": "1000",
+                    "token": "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8",
+                    "user": "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+                    "block_number": 12345,
+                    "timestamp": "2024-01-01T12:00:00Z"
+                }
+            ],
+            "total": 1,
+            "page": 1,
+            "limit": 20
+        });
+        assert_json_contains(&mock_transactions_response, &["transactions", "total", "page", "limit"])?;
         
-        let transactions = mock_transactions_response.as_array().unwrap();
+        let transactions = mock_transactions_response["transactions"].as_array().unwrap();
         assert!(!transactions.is_empty(), "Should have transactions");
         
         let transaction_fields = ["tx_hash", "chain", "status", "amount", "token", "user", "block_number", "timestamp"];
@@ -142,93 +206,986 @@ async fn test_transactions_endpoint() -> TestResult<()> {
     }).await
 }
 
+/// Refreshes relayer metrics as the coordinator does
+struct RelayerMetricsSource {
+    metrics: std::sync::Arc<relayer::metrics::RelayerMetrics>,
+    signatures: std::sync::Arc<relayer::signature_coordinator::SignatureCoordinator>,
+}
+
+#[async_trait::async_trait]
+impl api::handlers::metrics::MetricsSource for RelayerMetricsSource {
+    async fn refresh(&self) -> relayer::Result<()> {
+        self.metrics.refresh(&self.signatures).await;
+        Ok(())
+    }
+}
+
 #[tokio::test]
 async fn test_metrics_endpoint() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use api::server::MetricsState;
+    use axum::{body::{to_bytes, Body}, http::{header, Request, StatusCode}, Extension};
+    use relayer::{
+        database::Database, metrics::{DuplicateEvents, RelayerMetrics}, policy::RelayerPolicy,
+        signature_coordinator::SignatureCoordinator,
+    };
+    use std::sync::Arc;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+    use tower::ServiceExt;
+
     init_test_logging();
-    
+
     with_timeout(async {
-        // In a real test, this would:
-        // 1. Start the API server
-        // 2. Make HTTP request to /metrics
-        // 3. Verify Prometheus format response
-        // 4. Verify metric values are reasonable
-        
-        // For now, test the metrics format
-        let mock_metrics = r#"
-# HELP bridge_processed_transactions_total Total number of processed transactions
-# TYPE bridge_processed_transactions_total counter
-bridge_processed_transactions_total{chain="ethereum"} 100
-bridge_processed_transactions_total{chain="polkadot"} 95
-
-# HELP bridge_active_validators Number of active validators
-# TYPE bridge_active_validators gauge
-bridge_active_validators 3
-"#;
-        
-        assert!(mock_metrics.contains("bridge_processed_transactions_total"));
-        assert!(mock_metrics.contains("bridge_active_validators"));
-        assert!(mock_metrics.contains("# HELP"));
-        assert!(mock_metrics.contains("# TYPE"));
-        
+        let config = create_test_relayer_config();
+        let db = Arc::new(Database::new(&config.database).await?);
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(1, 1, 256)?)?);
+        let signatures = Arc::new(SignatureCoordinator::new(
+            config.validator.clone(),
+            manager,
+            db,
+            RelayerPolicy::from_config(&config).timeouts,
+        ).await?);
+
+        let metrics = Arc::new(RelayerMetrics::new());
+        metrics.processed_transactions.record("ethereum");
+        metrics.processed_transactions.record("ethereum");
+        metrics.processed_transactions.record("polkadot");
+        // Collectors registered by other components are served too
+        let duplicates = DuplicateEvents::new();
+        duplicates.register(metrics.registry())?;
+        duplicates.record("ethereum");
+
+        let router = api::routes::create_metrics_routes().layer(Extension(MetricsState {
+            registry: metrics.registry().clone(),
+            source: Arc::new(RelayerMetricsSource { metrics, signatures }),
+        }));
+        let response = router
+            .oneshot(Request::get("/metrics").body(Body::empty())?)
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/plain; version=0.0.4; charset=utf-8",
+        );
+        let body = to_bytes(response.into_body(), usize::MAX).await?;
+        let scrape = String::from_utf8(body.to_vec())?;
+
+        let samples: Vec<(&str, &str)> = scrape
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| line.rsplit_once(' '))
+            .collect();
+        assert!(samples.contains(&("bridge_processed_transactions_total{chain=\"ethereum\"}", "2")));
+        assert!(samples.contains(&("bridge_processed_transactions_total{chain=\"polkadot\"}", "1")));
+        // Only this validator is configured, and it always counts
+        assert!(samples.contains(&("bridge_active_validators", "1")));
+        assert!(samples.contains(&("bridge_duplicate_events_total{chain=\"ethereum\"}", "1")));
+
+        for (family, kind) in [("bridge_processed_transactions_total", "counter"), ("bridge_active_validators", "gauge")] {
+            assert_eq!(scrape.matches(&format!("# TYPE {} {}\n", family, kind)).count(), 1);
+            assert_eq!(scrape.matches(&format!("# HELP {} ", family)).count(), 1);
+        }
+
         Ok(())
     }).await
 }
 
+/// Statistics with fixed counts
+struct FixedStats;
+
+#[async_trait::async_trait]
+impl api::websocket::StatsSource for FixedStats {
+    async fn bridge_stats(&self) -> relayer::Result<relayer::coordinator::BridgeStats> {
+        Ok(relayer::coordinator::BridgeStats {
+            ethereum_processed_txs: 100,
+            polkadot_processed_txs: 95,
+            pending_signatures: 2,
+            active_validators: 3,
+            tokens: Default::default(),
+            completion_times: Default::default(),
+        })
+    }
+}
+
+/// Next text frame from a WebSocket client, parsed as JSON
+async fn next_json<S>(ws: &mut S) -> TestResult<serde_json::Value>
+where
+    S: futures::Stream<Item = Result<tokio_tungstenite::tungstenite::Message, tokio_tungstenite::tungstenite::Error>>
+        + Unpin,
+{
+    use futures::StreamExt;
+
+    let frame = ws.next().await.ok_or("WebSocket closed")??;
+    Ok(serde_json::from_str(&frame.into_text()?)?)
+}
+
 #[tokio::test]
 async fn test_websocket_connection() -> TestResult<()> {
+    use api::websocket::EventStreamState;
+    use axum::extract::Extension;
+    use futures::SinkExt;
+    use relayer::{coordinator::BridgeEvent, event_sink::PublishedEvent};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::{net::TcpListener, sync::broadcast};
+    use tokio_tungstenite::{connect_async, tungstenite::Message};
+
     init_test_logging();
-    
+
     with_timeout(async {
-        // In a real test, this would:
-        // 1. Start the API server
-        // 2. Connect to WebSocket endpoint
-        // 3. Subscribe to events
-        // 4. Verify real-time updates
-        // 5. Test connection handling
-        
-        // For now, test WebSocket message structure
-        let mock_ws_message = serde_json::json!({
-            "type": "bridge_event",
-            "event_type": "ethereum_lock",
-            "data": {
-                "user": "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
-                "token": "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8",
-                "amount": "1000",
-                "tx_hash": "0x1234567890abcdef"
-            }
-        });
-        
-        let required_fields = ["type", "event_type", "data"];
-        assert_json_contains(&mock_ws_message, &required_fields)?;
-        
+        let (events, _) = broadcast::channel(16);
+        let state = EventStreamState::new(events.clone(), Arc::new(FixedStats))
+            .with_stats_interval(Duration::from_secs(3600));
+        let app = api::routes::create_websocket_routes().layer(Extension(state));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}/ws", listener.local_addr()?);
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let (mut ws, _) = connect_async(&url).await?;
+
+        // Statistics are sent as soon as the client connects
+        let stats = next_json(&mut ws).await?;
+        assert_eq!(stats["type"], "stats_update");
+        assert_valid_bridge_stats(&stats["stats"])?;
+
+        let lock = BridgeEvent::EthereumLock {
+            user: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
+            token: "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8".to_string(),
+            amount: "1000".to_string(),
+            polkadot_address: "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".to_string(),
+            tx_hash: "0x1234567890abcdef".to_string(),
+            block_number: 100,
+        };
+        events.send(PublishedEvent::new(&lock, &Ok(())))?;
+
+        let message = next_json(&mut ws).await?;
+        let required_fields = ["type", "transfer_id", "status", "event_type", "data"];
+        assert_json_contains(&message, &required_fields)?;
+        assert_eq!(message["type"], "bridge_event");
+        assert_eq!(message["transfer_id"], "0x1234567890abcdef");
+        assert_eq!(message["status"], "pending");
+        assert_eq!(message["event_type"], "ethereum_lock");
+        assert_eq!(message["data"]["user"], "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        assert_eq!(message["data"]["token"], "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8");
+        assert_eq!(message["data"]["amount"], "1000");
+        assert_eq!(message["data"]["tx_hash"], "0x1234567890abcdef");
+
+        ws.send(Message::Text(r#"{"type":"ping"}"#.to_string())).await?;
+        let pong = next_json(&mut ws).await?;
+        assert_eq!(pong, serde_json::json!({"type": "pong"}));
+
+        ws.close(None).await?;
         Ok(())
     }).await
 }
 
 #[tokio::test]
-async fn test_api_error_handling() -> TestResult<()> {
+async fn test_server_shuts_down_gracefully() -> TestResult<()> {
+    use api::{server::serve, websocket::EventStreamState};
+    use axum::extract::Extension;
+    use futures::StreamExt;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::{net::TcpListener, sync::{broadcast, oneshot}};
+    use tokio_tungstenite::{connect_async, tungstenite::{protocol::frame::coding::CloseCode, Message}};
+
     init_test_logging();
-    
+
     with_timeout(async {
-        // In a real test, this would:
-        // 1. Test 404 responses for invalid endpoints
-        // 2. Test 400 responses for invalid parameters
-        // 3. Test 500 responses for server errors
-        // 4. Test rate limiting
-        // 5. Test authentication failures
-        
-        // For now, test error response structure
-        let mock_error_response = serde_json::json!({
-            "error": "Not Found",
-            "message": "Resource not found: /invalid/endpoint",
-            "code": 404
-        });
-        
-        let required_fields = ["error", "message", "code"];
-        assert_json_contains(&mock_error_response, &required_fields)?;
-        
-        assert_eq!(mock_error_response["code"].as_u64().unwrap(), 404);
-        
+        let (events, _) = broadcast::channel(16);
+        let state = EventStreamState::new(events, Arc::new(FixedStats))
+            .with_stats_interval(Duration::from_secs(3600));
+        let app = api::routes::create_websocket_routes().layer(Extension(state.clone()));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}/ws", listener.local_addr()?);
+        let (trigger, signal) = oneshot::channel::<()>();
+        let server = tokio::spawn(serve(listener, app, state, async move {
+            let _ = signal.await;
+        }));
+
+        let (mut ws, _) = connect_async(&url).await?;
+        assert_eq!(next_json(&mut ws).await?["type"], "stats_update");
+
+        trigger.send(()).map_err(|_| "server already stopped")?;
+
+        // Open streams are told the server is going away
+        let Some(Ok(Message::Close(Some(frame)))) = ws.next().await else {
+            panic!("expected a close frame");
+        };
+        assert_eq!(frame.code, CloseCode::Away);
+
+        tokio::time::timeout(Duration::from_secs(5), server).await???;
+
         Ok(())
     }).await
 }
+
+#[tokio::test]
+async fn test_websocket_messages_share_transfer_id() -> TestResult<()> {
+    use api::server::{BridgeStatsResponse, TransactionResponse, WebSocketMessage};
+
+    init_test_logging();
+
+    let tx_hash = "0x1234567890abcdef";
+    let detected = WebSocketMessage::BridgeEvent {
+        transfer_id: tx_hash.to_string(),
+        status: "pending".to_string(),
+        event_type: "ethereum_lock".to_string(),
+        data: serde_json::json!({ "amount": "1000", "tx_hash": tx_hash }),
+    };
+    let confirmed = WebSocketMessage::transfer_update(&TransactionResponse {
+        tx_hash: tx_hash.to_string(),
+        chain: "ethereum".to_string(),
+        status: "confirmed".to_string(),
+        amount: "1000".to_string(),
+        token: "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8".to_string(),
+        user: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
+        block_number: 100,
+        timestamp: "2024-01-01T12:00:00Z".to_string(),
+    });
+    let stats = WebSocketMessage::StatsUpdate {
+        transfer_id: Some(tx_hash.to_string()),
+        stats: BridgeStatsResponse {
+            ethereum_processed_txs: 1,
+            polkadot_processed_txs: 0,
+            pending_signatures: 0,
+            active_validators: 3,
+            tokens: Default::default(),
+            average_completion_secs: None,
+            p95_completion_secs: None,
+        },
+    };
+
+    let detected = serde_json::to_value(&detected)?;
+    let confirmed = serde_json::to_value(&confirmed)?;
+    let stats = serde_json::to_value(&stats)?;
+
+    assert_json_contains(&detected, &["type", "transfer_id", "status", "event_type", "data"])?;
+    assert_eq!(detected["type"], "bridge_event");
+    assert_eq!(detected["status"], "pending");
+    assert_eq!(confirmed["type"], "transfer_update");
+    assert_eq!(confirmed["status"], "confirmed");
+    assert_eq!(stats["type"], "stats_update");
+
+    for message in [&detected, &confirmed, &stats] {
+        assert_eq!(message["transfer_id"], tx_hash);
+        let parsed: WebSocketMessage = serde_json::from_value(message.clone())?;
+        assert_eq!(parsed.transfer_id(), Some(tx_hash));
+    }
+
+    // Periodic stats not triggered by a transfer carry no transfer_id
+    let periodic = serde_json::to_value(WebSocketMessage::StatsUpdate {
+        transfer_id: None,
+        stats: serde_json::from_value(stats["stats"].clone())?,
+    })?;
+    assert!(periodic.get("transfer_id").is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_api_error_handling() -> TestResult<()> {
+    use api::{server::ErrorResponse, ApiError};
+    use axum::{body::to_bytes, http::{header, StatusCode}, response::IntoResponse};
+
+    init_test_logging();
+
+    let response = ApiError::NotFound { resource: "transaction 0xabc".to_string() }.into_response();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body: serde_json::Value = serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await?)?;
+    assert_json_contains(&body, &["error", "message", "code"])?;
+    assert_eq!(body["code"], 404);
+    assert_eq!(body["error"], "Not Found");
+    assert!(body["message"].as_str().unwrap().contains("0xabc"));
+
+    // Every variant embeds the status it is sent with
+    let errors = [
+        ApiError::Validation { message: "bad".to_string() },
+        ApiError::Unauthorized { message: "no key".to_string() },
+        ApiError::Unavailable { message: "draining".to_string() },
+        ApiError::RateLimited { retry_after_secs: 3 },
+        ApiError::Internal { message: "boom".to_string() },
+    ];
+    for error in errors {
+        let response = error.into_response();
+        let status = response.status();
+        let retry_after = response.headers().get(header::RETRY_AFTER).cloned();
+        let body: ErrorResponse = serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await?)?;
+        assert_eq!(body.code, status.as_u16());
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            assert_eq!(retry_after.unwrap(), "3");
+        }
+    }
+
+    // Routed requests get the same shape
+    let (status, body) = get_api("/transactions/0xnothex").await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], 400);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_time_window_parsing() -> TestResult<()> {
+    use api::server::parse_time_window;
+
+    let (since, until) = parse_time_window(Some("2024-01-01T00:00:00Z"), Some("2024-01-02T00:00:00+02:00"))?;
+    assert_eq!(since.unwrap().to_rfc3339(), "2024-01-01T00:00:00+00:00");
+    assert_eq!(until.unwrap().to_rfc3339(), "2024-01-01T22:00:00+00:00");
+
+    assert_eq!(parse_time_window(None, None)?, (None, None));
+    assert!(parse_time_window(Some("yesterday"), None).is_err());
+    assert!(parse_time_window(Some("2024-01-02T00:00:00Z"), Some("2024-01-01T00:00:00Z")).is_err());
+
+    Ok(())
+}
+
+/// Pallet admin stub recording submitted `set_paused` calls
+struct StubPalletAdmin {
+    calls: std::sync::Mutex<Vec<bool>>,
+}
+
+#[async_trait::async_trait]
+impl relayer::polkadot::PalletAdmin for StubPalletAdmin {
+    async fn set_paused(&self, paused: bool) -> relayer::Result<String> {
+        self.calls.lock().unwrap().push(paused);
+        Ok(format!("0xpaused_{}", paused))
+    }
+}
+
+#[tokio::test]
+async fn test_admin_pause_and_resume_submit_set_paused() -> TestResult<()> {
+    use api::{handlers::admin, server::AdminState, ApiError};
+    use axum::{extract::Extension, http::{header, HeaderMap}};
+    use std::sync::Arc;
+
+    let stub = Arc::new(StubPalletAdmin { calls: std::sync::Mutex::new(vec![]) });
+    let state = AdminState {
+        pallet: Some(stub.clone()),
+        api_key: Some("secret".to_string()),
+        threshold_manager: Arc::new(threshold::SimpleThresholdManager::new(
+            threshold::ThresholdConfig::new(2, 3, 256)?,
+        )?),
+    };
+    let mut headers = HeaderMap::new();
+    headers.insert(header::AUTHORIZATION, "Bearer secret".parse()?);
+
+    let response = admin::pause(Extension(state.clone()), headers.clone()).await?;
+    assert!(response.paused);
+    assert_eq!(response.extrinsic_hash, "0xpaused_true");
+
+    let response = admin::resume(Extension(state.clone()), headers.clone()).await?;
+    assert!(!response.paused);
+    assert_eq!(*stub.calls.lock().unwrap(), vec![true, false]);
+
+    // Wrong credentials never reach the pallet
+    let result = admin::pause(Extension(state.clone()), HeaderMap::new()).await;
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+    assert_eq!(stub.calls.lock().unwrap().len(), 2);
+
+    // Without a configured admin key the call is rejected
+    let no_key = AdminState { pallet: None, ..state };
+    let result = admin::pause(Extension(no_key), headers).await;
+    assert!(matches!(result, Err(ApiError::Config { .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cors_allows_only_configured_origins() -> TestResult<()> {
+    use api::{server::cors_layer, ApiError};
+    use axum::{body::Body, http::{header, Request}, routing::get, Router};
+    use tower::ServiceExt;
+
+    let router = Router::new()
+        .route("/health", get(|| async { "ok" }))
+        .layer(cors_layer(&["https://bridge.example.com".to_string()])?);
+    let from = |origin: &str| Request::get("/health").header(header::ORIGIN, origin).body(Body::empty());
+
+    let response = router.clone().oneshot(from("https://bridge.example.com")?).await?;
+    assert_eq!(response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN], "https://bridge.example.com");
+
+    let response = router.oneshot(from("https://evil.example.com")?).await?;
+    assert!(response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+
+    for invalid in ["bridge.example.com", "https://bridge.example.com/app", "https://bad\norigin"] {
+        let result = cors_layer(&[invalid.to_string()]);
+        assert!(matches!(result, Err(ApiError::Config { .. })), "{} was accepted", invalid);
+    }
+
+    Ok(())
+}
+
+/// Send a GET request through the API router and return the status and body
+async fn get_api(uri: &str) -> TestResult<(axum::http::StatusCode, serde_json::Value)> {
+    get_router(api::routes::create_api_routes(), uri).await
+}
+
+/// Send a GET request through `router` and return the status and body
+async fn get_router(router: axum::Router, uri: &str) -> TestResult<(axum::http::StatusCode, serde_json::Value)> {
+    use axum::{body::{to_bytes, Body}, http::Request};
+    use tower::ServiceExt;
+
+    let response = router
+        .oneshot(Request::get(uri).body(Body::empty())?)
+        .await?;
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await?;
+
+    Ok((status, serde_json::from_slice(&body)?))
+}
+
+/// Signing set of two validators, the second removed
+struct TwoValidators;
+
+#[async_trait::async_trait]
+impl api::handlers::validators::ValidatorSource for TwoValidators {
+    async fn validator_set(&self) -> relayer::signature_coordinator::ValidatorSet {
+        use relayer::signature_coordinator::{ValidatorInfo, ValidatorSet};
+
+        ValidatorSet {
+            validators: vec![
+                ValidatorInfo {
+                    id: "validator_0".to_string(),
+                    address: Some("127.0.0.1:50051".to_string()),
+                    active: true,
+                },
+                ValidatorInfo {
+                    id: "validator_1".to_string(),
+                    address: Some("127.0.0.1:50052".to_string()),
+                    active: false,
+                },
+            ],
+            threshold: 1,
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_validators_reflect_signing_set() -> TestResult<()> {
+    use api::server::ValidatorState;
+    use axum::{http::StatusCode, Extension};
+    use std::sync::Arc;
+
+    let router = || {
+        api::routes::create_api_routes().layer(Extension(ValidatorState {
+            source: Arc::new(TwoValidators),
+        }))
+    };
+
+    let (status, body) = get_router(router(), "/validators").await?;
+    assert_eq!(status, StatusCode::OK);
+    let validators = body.as_array().expect("validator list");
+    assert_eq!(validators.len(), 2);
+    assert_eq!(validators[0]["id"], "validator_0");
+    assert_eq!(validators[1]["id"], "validator_1");
+    assert_eq!(validators[1]["active"], false);
+    // Nothing tracks stake or uptime, so none is invented
+    assert!(validators[0]["stake"].is_null());
+    assert!(validators[0]["uptime"].is_null());
+
+    let (status, body) = get_router(router(), "/validators/validator_1").await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["address"], "127.0.0.1:50052");
+
+    let (status, _) = get_router(router(), "/validators/validator_9").await?;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transactions_rejects_unknown_chain() -> TestResult<()> {
+    let (status, body) = get_api("/transactions?chain=solana").await?;
+
+    assert_eq!(status, axum::http::StatusCode::BAD_REQUEST);
+    assert!(body["message"].as_str().unwrap().contains("chain"));
+
+    let (status, _) = get_api("/events?chain=solana").await?;
+    assert_eq!(status, axum::http::StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transactions_rejects_inverted_block_range() -> TestResult<()> {
+    let (status, body) = get_api("/transactions?from_block=200&to_block=100").await?;
+
+    assert_eq!(status, axum::http::StatusCode::BAD_REQUEST);
+    assert!(body["message"].as_str().unwrap().contains("from_block"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_export_rejects_unknown_format() -> TestResult<()> {
+    let (status, body) = get_api("/transactions/export?format=xml").await?;
+
+    assert_eq!(status, axum::http::StatusCode::BAD_REQUEST);
+    assert!(body["message"].as_str().unwrap().contains("format"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transactions_rejects_malformed_address() -> TestResult<()> {
+    let (status, body) = get_api("/transactions?user=0x1234").await?;
+    assert_eq!(status, axum::http::StatusCode::BAD_REQUEST);
+    assert!(body["message"].as_str().unwrap().contains("user"));
+
+    // An SS58 account is not a valid Ethereum sender
+    let (status, _) = get_api(
+        "/transactions?chain=ethereum&user=5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+    ).await?;
+    assert_eq!(status, axum::http::StatusCode::BAD_REQUEST);
+
+    // Overlong values are rejected before any format checks
+    let long_token = format!("/transactions?token={}", "1".repeat(200));
+    let (status, body) = get_api(&long_token).await?;
+    assert_eq!(status, axum::http::StatusCode::BAD_REQUEST);
+    assert!(body["message"].as_str().unwrap().contains("at most"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_filter_validation_accepts_known_values() -> TestResult<()> {
+    use api::{server::TransactionFilters, validation::Validate};
+
+    let filters: TransactionFilters = serde_json::from_value(serde_json::json!({
+        "chain": "polkadot",
+        "status": "confirmed",
+        "user": "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+        "token": "1",
+    }))?;
+    filters.validate()?;
+
+    let filters: TransactionFilters = serde_json::from_value(serde_json::json!({
+        "status": "pending",
+        "user": "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+    }))?;
+    filters.validate()?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_rejects_malformed_hash() -> TestResult<()> {
+    let (status, body) = get_api("/transactions/0xnothex").await?;
+
+    assert_eq!(status, axum::http::StatusCode::BAD_REQUEST);
+    assert!(body["message"].as_str().unwrap().contains("0xnothex"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fee_estimate_rejects_bad_parameters() -> TestResult<()> {
+    let token = "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8";
+
+    for uri in [
+        "/bridge/estimate-fee?chain=ethereum&token=0x1234&amount=1".to_string(),
+        format!("/bridge/estimate-fee?chain=ethereum&token={}&amount=0", token),
+        format!("/bridge/estimate-fee?chain=ethereum&token={}&amount=-5", token),
+        format!("/bridge/estimate-fee?chain=kusama&token={}&amount=1", token),
+        format!("/bridge/estimate-fee?chain=polkadot&token={}", token),
+    ] {
+        let (status, body) = get_api(&uri).await?;
+        assert_eq!(status, axum::http::StatusCode::BAD_REQUEST, "{}", uri);
+        assert_eq!(body["error"], "Validation Error", "{}", uri);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_lookup_finds_locks_and_burns() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use api::{handlers::transactions::resolve_transaction, ApiError};
+    use relayer::database::Database;
+
+    let db = Database::new(&create_test_relayer_config().database).await?;
+    let lock_hash = format!("0x{}", "a".repeat(64));
+    let burn_hash = format!("0x{}", "b".repeat(64));
+    let unknown_hash = format!("0x{}", "c".repeat(64));
+
+    db.store_ethereum_lock("0xuser", "0xtoken", "1000", "5Grw", &lock_hash, 42).await?;
+    db.store_polkadot_burn("5Grw", 7, "2500", "0xrecipient", &burn_hash, 43).await?;
+
+    let lock = db.get_transaction_by_hash(&lock_hash).await?;
+    let response = resolve_transaction(&lock_hash, lock, None)?;
+    assert_eq!(response.chain, "ethereum");
+    assert_eq!(response.status, "pending");
+    assert_eq!(response.amount, "1000");
+    assert_eq!(response.block_number, 42);
+
+    let burn = db.get_transaction_by_hash(&burn_hash).await?;
+    let response = resolve_transaction(&burn_hash, burn, None)?;
+    assert_eq!(response.chain, "polkadot");
+    assert_eq!(response.token, "7");
+    assert_eq!(response.amount, "2500");
+    assert_eq!(response.block_number, 43);
+
+    let unknown = db.get_transaction_by_hash(&unknown_hash).await?;
+    assert!(matches!(
+        resolve_transaction(&unknown_hash, unknown, None),
+        Err(ApiError::NotFound { .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tokens_come_from_token_mappings() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use api::{handlers::tokens::token_by_address, server::TokenResponse, ApiError};
+    use relayer::database::Database;
+
+    let db = Database::new(&create_test_relayer_config().database).await?;
+    let dot = "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8";
+    let usdc = "0x3C44CdDdB6a900fa2b585dd299e03d12FA4293BC";
+    db.add_token_mapping(usdc, 2).await?;
+    db.add_token_mapping(dot, 1).await?;
+
+    let tokens: Vec<TokenResponse> = db.list_tokens().await?.into_iter().map(TokenResponse::from).collect();
+    assert_eq!(tokens.len(), 2);
+    assert_eq!((tokens[0].ethereum_address.as_str(), tokens[0].polkadot_asset_id), (dot, 1));
+    assert_eq!((tokens[1].ethereum_address.as_str(), tokens[1].polkadot_asset_id), (usdc, 2));
+    assert!(tokens[0].symbol.is_none());
+
+    let token = token_by_address(&db, &usdc.to_lowercase()).await?;
+    assert_eq!(token.ethereum_address, usdc);
+    assert_eq!(token.polkadot_asset_id, 2);
+
+    let unknown = token_by_address(&db, "0x90F79bf6EB2c4f870365E785982E1f101E93b906").await;
+    assert!(matches!(unknown, Err(ApiError::NotFound { .. })));
+
+    let (status, _) = get_api("/tokens/not-an-address").await?;
+    assert_eq!(status, axum::http::StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unknown_transaction_is_not_found() -> TestResult<()> {
+    use api::{handlers::transactions::resolve_transaction, ApiError};
+    use axum::{http::StatusCode, response::IntoResponse};
+
+    let Err(error) = resolve_transaction("0xunknown", None, None) else {
+        panic!("unknown transaction resolved");
+    };
+    assert!(matches!(error, ApiError::NotFound { .. }));
+    assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_in_flight_transaction_is_signing() -> TestResult<()> {
+    use api::handlers::transactions::resolve_transaction;
+    use relayer::{database::TransactionRecord, signature_coordinator::{SignatureFormat, SignatureSession}};
+    use std::collections::HashMap;
+
+    let session = SignatureSession {
+        tx_hash: "0xabc".to_string(),
+        message: vec![0u8; 32],
+        format: SignatureFormat::Recoverable,
+        partial_signatures: HashMap::new(),
+        sources: HashMap::new(),
+        public_key_shares: HashMap::new(),
+        required_signatures: 2,
+        created_at: std::time::SystemTime::now(),
+    };
+    let record = TransactionRecord {
+        tx_hash: "0xabc".to_string(),
+        chain: "ethereum".to_string(),
+        status: "pending".to_string(),
+        user: "0xuser".to_string(),
+        token: "0xtoken".to_string(),
+        amount: "1000".to_string(),
+        recipient: "5GrwvaEF".to_string(),
+        block_number: 7,
+        created_at: chrono::Utc::now(),
+    };
+
+    // Session without a stored record is still known, though a transfer
+    // digest does not say where it started or what it moves
+    let response = resolve_transaction("0xabc", None, Some(&session))?;
+    assert_eq!(response.status, "signing");
+    assert_eq!(response.chain, "");
+    assert_eq!(response.token, "");
+    assert_eq!(response.amount, "");
+
+    // Pending record with an in-flight session is being signed
+    let response = resolve_transaction("0xabc", Some(record.clone()), Some(&session))?;
+    assert_eq!(response.status, "signing");
+    assert_eq!(response.block_number, 7);
+
+    // Pending record with no session is waiting for signers
+    let response = resolve_transaction("0xabc", Some(record), None)?;
+    assert_eq!(response.status, "pending");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_keyset_reports_epoch_and_members() -> TestResult<()> {
+    use api::{handlers::admin, server::AdminState, ApiError};
+    use axum::{extract::Extension, http::{header, HeaderMap}};
+    use std::sync::Arc;
+    use threshold::{PublicKeyShare, SimpleThresholdManager, ThresholdConfig};
+
+    let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(2, 3, 256)?)?);
+    let state = AdminState {
+        pallet: None,
+        api_key: Some("secret".to_string()),
+        threshold_manager: manager.clone(),
+    };
+    let mut headers = HeaderMap::new();
+    headers.insert(header::AUTHORIZATION, "Bearer secret".parse()?);
+
+    // Nothing to report before a key set is loaded
+    let result = admin::keyset(Extension(state.clone()), headers.clone()).await;
+    assert!(matches!(result, Err(ApiError::NotFound { .. })));
+
+    let shares: Vec<PublicKeyShare> = ["validator_2", "validator_0", "validator_1"]
+        .into_iter()
+        .map(|validator_id| PublicKeyShare {
+            validator_id: validator_id.to_string(),
+            public_share: vec![],
+            verification_key: vec![],
+            key_epoch: 7,
+        })
+        .collect();
+    manager.load_key_set(&shares, vec![0x02, 0xab])?;
+
+    let response = admin::keyset(Extension(state.clone()), headers).await?;
+    assert_eq!(response.key_epoch, 7);
+    assert_eq!(response.members, vec!["validator_0", "validator_1", "validator_2"]);
+    assert_eq!(response.group_public_key, "0x02ab");
+
+    // The key set is only disclosed to admins
+    let result = admin::keyset(Extension(state), HeaderMap::new()).await;
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    let metrics = relayer::metrics::RelayerMetrics::new();
+    metrics.set_key_epoch(manager.key_set().as_ref());
+    assert!(metrics.encode()?.contains("bridge_key_epoch 7\n"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_drain_fails_readiness_and_rejects_new_writes() -> TestResult<()> {
+    use api::{
+        middleware::drain::{DrainLayer, DrainState},
+        server::AdminState,
+    };
+    use axum::{
+        body::{to_bytes, Body},
+        extract::Extension,
+        http::{header, Method, Request, StatusCode},
+    };
+    use std::sync::Arc;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+    use tower::ServiceExt;
+
+    let drain = DrainState::new();
+    let admin = AdminState {
+        pallet: None,
+        api_key: Some("secret".to_string()),
+        threshold_manager: Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(2, 3, 256)?)?),
+    };
+    let app = api::routes::create_api_routes()
+        .merge(api::routes::create_websocket_routes())
+        .layer(DrainLayer::new(drain.clone()))
+        .layer(Extension(drain.clone()))
+        .layer(Extension(admin));
+
+    let send = |method: Method, uri: &str, auth: bool| {
+        let mut request = Request::builder().method(method).uri(uri);
+        if auth {
+            request = request.header(header::AUTHORIZATION, "Bearer secret");
+        }
+        let request = request.body(Body::empty());
+        let app = app.clone();
+        async move { TestResult::Ok(app.oneshot(request?).await?) }
+    };
+
+    let response = send(Method::GET, "/readyz", false).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Only admins can drain
+    let response = send(Method::POST, "/admin/drain", false).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert!(!drain.is_draining());
+
+    let response = send(Method::POST, "/admin/drain", true).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(drain.is_draining());
+
+    let response = send(Method::GET, "/readyz", false).await?;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let body: serde_json::Value = serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await?)?;
+    assert_eq!(body["status"], "draining");
+
+    // New writes and subscriptions are refused
+    for uri in ["/bridge/lock", "/bridge/mint"] {
+        let response = send(Method::POST, uri, false).await?;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+    let response = send(Method::GET, "/ws/events", false).await?;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bridge_writes_require_api_key() -> TestResult<()> {
+    use api::middleware::auth::AuthLayer;
+    use axum::{
+        body::{to_bytes, Body},
+        http::{header, Method, Request, StatusCode},
+        routing::{get, post},
+        Router,
+    };
+    use tower::ServiceExt;
+
+    let routes = Router::new()
+        .route("/health", get(|| async { "healthy" }))
+        .route("/bridge/lock", post(|| async { "locked" }));
+    let app = routes.clone().layer(AuthLayer::new(vec!["secret".to_string()]));
+
+    let send = |app: Router, method: Method, uri: &str, credentials: Option<(&str, &str)>| {
+        let mut request = Request::builder().method(method).uri(uri);
+        if let Some((name, value)) = credentials {
+            request = request.header(name, value);
+        }
+        let request = request.body(Body::empty());
+        async move { TestResult::Ok(app.oneshot(request?).await?) }
+    };
+
+    let response = send(app.clone(), Method::POST, "/bridge/lock", None).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await?)?;
+    assert_eq!(body["message"], "Missing API key");
+
+    let wrong = Some((header::AUTHORIZATION.as_str(), "Bearer guess"));
+    let response = send(app.clone(), Method::POST, "/bridge/lock", wrong).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Either header carries the key
+    let bearer = Some((header::AUTHORIZATION.as_str(), "Bearer secret"));
+    let response = send(app.clone(), Method::POST, "/bridge/lock", bearer).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(&to_bytes(response.into_body(), usize::MAX).await?[..], b"locked");
+
+    let api_key = Some(("x-api-key", "secret"));
+    let response = send(app.clone(), Method::POST, "/bridge/lock", api_key).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Reads stay public
+    let response = send(app, Method::GET, "/health", None).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Without configured keys writes are refused outright
+    let locked_down = routes.layer(AuthLayer::new(vec![]));
+    let response = send(locked_down, Method::POST, "/bridge/lock", bearer).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // The real lock route is behind the same check
+    let response = send(
+        api::routes::create_api_routes().layer(AuthLayer::new(vec!["secret".to_string()])),
+        Method::POST,
+        "/bridge/lock",
+        None,
+    ).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limit_rejects_requests_over_burst() -> TestResult<()> {
+    use api::middleware::rate_limit::{RateLimitLayer, RateLimiter};
+    use axum::{
+        body::Body,
+        extract::ConnectInfo,
+        http::{header, Request, StatusCode},
+        routing::get,
+        Router,
+    };
+    use std::net::SocketAddr;
+    use tower::ServiceExt;
+
+    const BURST: u32 = 5;
+
+    let limiter = RateLimiter::new(60, BURST).with_api_keys(vec!["secret".to_string()]);
+    let app = Router::new()
+        .route("/health", get(|| async { "healthy" }))
+        .layer(RateLimitLayer::new(limiter));
+
+    let send = |ip: &str, api_key: Option<&str>| {
+        let mut request = Request::get("/health");
+        if let Some(api_key) = api_key {
+            request = request.header("x-api-key", api_key);
+        }
+        let request = request.body(Body::empty()).map(|mut request| {
+            request.extensions_mut().insert(ConnectInfo(SocketAddr::new(ip.parse().unwrap(), 4000)));
+            request
+        });
+        let app = app.clone();
+        async move { TestResult::Ok(app.oneshot(request?).await?) }
+    };
+
+    for _ in 0..BURST {
+        assert_eq!(send("10.0.0.1", None).await?.status(), StatusCode::OK);
+    }
+
+    let response = send("10.0.0.1", None).await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: u64 = response.headers()[header::RETRY_AFTER].to_str()?.parse()?;
+    assert!((1..=60).contains(&retry_after), "{}", retry_after);
+
+    // A made-up key does not buy a fresh bucket
+    assert_eq!(send("10.0.0.1", Some("guess")).await?.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    // Other clients have their own buckets
+    assert_eq!(send("10.0.0.2", None).await?.status(), StatusCode::OK);
+    assert_eq!(send("10.0.0.1", Some("secret")).await?.status(), StatusCode::OK);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_request_id_is_echoed_or_generated() -> TestResult<()> {
+    use api::middleware::request_id::{RequestId, RequestIdLayer, REQUEST_ID_HEADER};
+    use axum::{
+        body::{to_bytes, Body},
+        extract::Extension,
+        http::Request,
+        routing::get,
+        Router,
+    };
+    use tower::ServiceExt;
+
+    // The handler answers with the id it finds in request extensions
+    let app = Router::new()
+        .route("/health", get(|Extension(id): Extension<RequestId>| async move { id.0 }))
+        .layer(RequestIdLayer::new());
+
+    let request = Request::get("/health").header(REQUEST_ID_HEADER, "client-trace-42").body(Body::empty())?;
+    let response = app.clone().oneshot(request).await?;
+    assert_eq!(response.headers()[REQUEST_ID_HEADER], "client-trace-42");
+    assert_eq!(&to_bytes(response.into_body(), usize::MAX).await?[..], b"client-trace-42");
+
+    let response = app.oneshot(Request::get("/health").body(Body::empty())?).await?;
+    let generated = response.headers()[REQUEST_ID_HEADER].to_str()?.to_string();
+    assert!(uuid::Uuid::parse_str(&generated).is_ok(), "{} is not a UUID", generated);
+    assert_eq!(&to_bytes(response.into_body(), usize::MAX).await?[..], generated.as_bytes());
+
+    Ok(())
+}
@@ -1,12 +1,11 @@
 //! End-to-end bridge integration tests
 
 use crate::common::{
-    setup::{setup_test_coordinator, setup_mock_coordinator, init_test_logging, wait_for_services_ready},
+    setup::{setup_mock_coordinator, init_test_logging, wait_for_services_ready},
     mock_data::{mock_ethereum_lock_event, mock_polkadot_burn_event, mock_validators},
     assertions::{assert_valid_bridge_stats, assert_valid_tx_hash},
-    TestResult, with_timeout, wait_for_condition,
+    test_tx_hash, TestResult, with_timeout, TEST_RECIPIENT, TEST_TOKEN,
 };
-use std::time::Duration;
 
 #[tokio::test]
 async fn test_bridge_coordinator_initialization() -> TestResult<()> {
@@ -238,3 +237,533 @@ async fn test_bridge_performance() -> TestResult<()> {
         Ok(())
     }).await
 }
+
+#[tokio::test]
+async fn test_incomplete_transfer_gauge_and_degraded_flag() -> TestResult<()> {
+    use relayer::metrics::{self, Registry, TransferHealth};
+    use std::time::{Duration, Instant};
+
+    let health = TransferHealth::new(0, Duration::from_secs(60));
+    let start = Instant::now();
+
+    // A lock was detected but its mint never completed
+    health.observe_at("ethereum", 1, 0, start).await;
+    assert_eq!(health.incomplete_transfers("ethereum").await, 1);
+    assert_eq!(health.incomplete_transfers("polkadot").await, 0);
+    assert!(!health.is_degraded().await);
+
+    let registry = Registry::new();
+    health.register(&registry)?;
+    let metrics = metrics::encode(&registry)?;
+    assert!(metrics.contains("bridge_incomplete_transfers{chain=\"ethereum\"} 1"));
+    assert!(metrics.contains("bridge_incomplete_transfers{chain=\"polkadot\"} 0"));
+
+    // The gap persisting past the window flips the degraded flag
+    health.observe_at("ethereum", 1, 0, start + Duration::from_secs(61)).await;
+    assert!(health.is_degraded().await);
+
+    // Completing the transfer clears it
+    health.observe_at("ethereum", 1, 1, start + Duration::from_secs(62)).await;
+    assert_eq!(health.incomplete_transfers("ethereum").await, 0);
+    assert!(!health.is_degraded().await);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_signature_cleanup_honors_configured_timeout() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::policy::RelayerPolicy;
+    use std::time::Duration;
+
+    let mut config = create_test_relayer_config();
+    config.threshold.signature_timeout = 30;
+    let policy = RelayerPolicy::from_config(&config);
+    assert_eq!(policy.timeouts.signature_timeout, Duration::from_secs(30));
+    assert!(policy.timeouts.signature_cleanup_interval <= policy.timeouts.signature_timeout);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_recipient_allowlist() -> TestResult<()> {
+    use relayer::{allowlist::RecipientAllowlist, RelayerError};
+
+    let alice = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+    let bob = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
+
+    // An empty allowlist allows everyone
+    let open = RecipientAllowlist::new(vec![]);
+    assert!(!open.is_enabled());
+    open.check(bob)?;
+
+    let allowlist = RecipientAllowlist::new(vec![alice.to_string(), "5Dfis*".to_string()]);
+    allowlist.check(alice)?;
+    allowlist.check("5DfisYourAccountHere")?;
+
+    let result = allowlist.check(bob);
+    assert!(matches!(result, Err(RelayerError::RecipientNotAllowed { ref recipient }) if recipient == bob));
+
+    Ok(())
+}
+
+/// Mint submitter recording each extrinsic and rejecting batches on request
+#[derive(Default)]
+struct StubMintSubmitter {
+    singles: std::sync::Mutex<Vec<String>>,
+    batches: std::sync::Mutex<Vec<Vec<String>>>,
+    reject_batches: bool,
+    reject: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl relayer::polkadot::MintSubmitter for StubMintSubmitter {
+    async fn submit_mint(&self, mint: &relayer::polkadot::PendingMint) -> relayer::Result<String> {
+        if self.reject.as_deref() == Some(mint.ethereum_tx_hash.as_str()) {
+            return Err(relayer::RelayerError::Polkadot { message: "invalid mint".to_string() });
+        }
+        self.singles.lock().unwrap().push(mint.ethereum_tx_hash.clone());
+        Ok(format!("0xmint_{}", mint.ethereum_tx_hash))
+    }
+
+    async fn submit_mint_batch(&self, mints: &[relayer::polkadot::PendingMint]) -> relayer::Result<String> {
+        if self.reject_batches {
+            return Err(relayer::RelayerError::Polkadot { message: "batch rejected".to_string() });
+        }
+        self.batches.lock().unwrap().push(mints.iter().map(|m| m.ethereum_tx_hash.clone()).collect());
+        Ok("0xbatch".to_string())
+    }
+}
+
+/// Ethereum lock hashes of submitted mints
+fn lock_hashes(outcome: &relayer::mint_batcher::BatchOutcome) -> Vec<String> {
+    outcome.minted.iter().map(|mint| mint.ethereum_tx_hash.clone()).collect()
+}
+
+fn pending_mint(tx_hash: &str) -> relayer::polkadot::PendingMint {
+    relayer::polkadot::PendingMint {
+        recipient: "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".to_string(),
+        ethereum_address: "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8".to_string(),
+        amount: "1000".to_string(),
+        ethereum_tx_hash: tx_hash.to_string(),
+        signatures: vec![vec![1u8; 65], vec![2u8; 65]],
+    }
+}
+
+#[tokio::test]
+async fn test_mint_batcher_submits_full_and_due_batches() -> TestResult<()> {
+    use relayer::mint_batcher::MintBatcher;
+    use std::{sync::Arc, time::Duration};
+
+    let stub = Arc::new(StubMintSubmitter::default());
+    let batcher = MintBatcher::new(stub.clone(), 3, Duration::from_millis(50));
+    assert!(batcher.is_batching());
+
+    // Nothing is submitted until the batch is full
+    assert!(batcher.push(pending_mint("0x1")).await.is_empty());
+    assert!(batcher.push(pending_mint("0x2")).await.is_empty());
+    assert!(batcher.flush_due().await.is_empty());
+    assert_eq!(lock_hashes(&batcher.push(pending_mint("0x3")).await), vec!["0x1", "0x2", "0x3"]);
+    assert_eq!(*stub.batches.lock().unwrap(), vec![vec!["0x1", "0x2", "0x3"]]);
+
+    // A partial batch goes out once its window has elapsed
+    assert!(batcher.push(pending_mint("0x4")).await.is_empty());
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(lock_hashes(&batcher.flush_due().await), vec!["0x4"]);
+    assert_eq!(batcher.pending_count().await, 0);
+
+    // A lone mint uses the single-mint call
+    assert_eq!(*stub.singles.lock().unwrap(), vec!["0x4"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_batcher_retries_rejected_batch_individually() -> TestResult<()> {
+    use relayer::mint_batcher::MintBatcher;
+    use std::{sync::Arc, time::Duration};
+
+    let stub = Arc::new(StubMintSubmitter {
+        reject_batches: true,
+        reject: Some("0x2".to_string()),
+        ..Default::default()
+    });
+    let batcher = MintBatcher::new(stub.clone(), 3, Duration::from_secs(60));
+
+    batcher.push(pending_mint("0x1")).await;
+    batcher.push(pending_mint("0x2")).await;
+    let outcome = batcher.push(pending_mint("0x3")).await;
+
+    // Only the valid mints are reported as minted, each with its own extrinsic
+    assert_eq!(lock_hashes(&outcome), vec!["0x1", "0x3"]);
+    assert_eq!(outcome.minted[1].extrinsic_hash, "0xmint_0x3");
+    assert!(stub.batches.lock().unwrap().is_empty());
+
+    // The invalid one is reported back rather than dropped
+    assert_eq!(outcome.failed.len(), 1);
+    assert_eq!(outcome.failed[0].ethereum_tx_hash, "0x2");
+
+    // Without batching, mints are submitted as they arrive
+    let unbatched = MintBatcher::new(stub.clone(), 1, Duration::from_secs(60));
+    assert!(!unbatched.is_batching());
+    assert_eq!(lock_hashes(&unbatched.push(pending_mint("0x5")).await), vec!["0x5"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_partials_from_one_source_fail_diversity_check() -> TestResult<()> {
+    use relayer::{signature_coordinator::{SignatureFormat, SignatureSession}, RelayerError};
+    use std::collections::HashMap;
+    use threshold::PartialSignature;
+
+    let partial = |validator_id: &str| PartialSignature {
+        validator_id: validator_id.to_string(),
+        signature: vec![1u8; 64],
+        commitment: None,
+        timestamp: std::time::SystemTime::now(),
+        key_epoch: 0,
+    };
+
+    // Three "validators" whose partials all arrived over one connection
+    let mut session = SignatureSession {
+        tx_hash: "0xabc".to_string(),
+        message: vec![],
+        format: SignatureFormat::Threshold,
+        partial_signatures: HashMap::new(),
+        sources: HashMap::new(),
+        public_key_shares: HashMap::new(),
+        required_signatures: 3,
+        created_at: std::time::SystemTime::now(),
+    };
+    for validator_id in ["validator_1", "validator_2", "validator_3"] {
+        session.partial_signatures.insert(validator_id.to_string(), partial(validator_id));
+        session.sources.insert(validator_id.to_string(), "10.0.0.1:30333".to_string());
+    }
+    assert_eq!(session.distinct_sources(), 1);
+
+    let result = session.check_source_diversity(true);
+    assert!(matches!(
+        result,
+        Err(RelayerError::InsufficientSourceDiversity { sources: 1, required: 3, .. })
+    ));
+
+    // With the policy off the same session is only warned about
+    session.check_source_diversity(false)?;
+
+    // Partials from distinct peers pass
+    session.sources.insert("validator_2".to_string(), "10.0.0.2:30333".to_string());
+    session.sources.insert("validator_3".to_string(), "10.0.0.3:30333".to_string());
+    session.check_source_diversity(true)?;
+
+    Ok(())
+}
+
+fn failing_lock(tx_hash: &str) -> relayer::coordinator::BridgeEvent {
+    relayer::coordinator::BridgeEvent::EthereumLock {
+        user: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
+        token: "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8".to_string(),
+        amount: "1000".to_string(),
+        polkadot_address: "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".to_string(),
+        tx_hash: tx_hash.to_string(),
+        block_number: 12345,
+    }
+}
+
+/// Retry every due event until the queue is empty
+async fn drain_retries<F, Fut>(queue: &relayer::retry_queue::RetryQueue, process: F) -> TestResult<()>
+where
+    F: Fn(relayer::coordinator::BridgeEvent) -> Fut,
+    Fut: std::future::Future<Output = relayer::Result<()>>,
+{
+    while let Some(due) = queue.next_due().await {
+        tokio::time::sleep_until(due.into()).await;
+        queue.retry_due(&process).await?;
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_failed_event_is_retried_until_processed() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::{coordinator::BridgeEvent, database::Database, policy::RetryPolicy, retry_queue::RetryQueue, RelayerError};
+    use std::sync::{atomic::{AtomicU32, Ordering}, Arc};
+    use std::time::Duration;
+
+    let database = Arc::new(Database::new(&create_test_relayer_config().database).await?);
+    let queue = RetryQueue::new(database.clone(), RetryPolicy {
+        max_retries: 3,
+        retry_delay: Duration::from_millis(10),
+        rate_limit_cooldown: Duration::from_millis(10),
+    });
+
+    // The Polkadot node is down for the first two attempts
+    let attempts = AtomicU32::new(0);
+    let process = |_event: BridgeEvent| {
+        let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+        async move {
+            if attempt <= 2 {
+                Err(RelayerError::Polkadot { message: "connection refused".to_string() })
+            } else {
+                Ok(())
+            }
+        }
+    };
+
+    with_timeout(async {
+        queue.attempt(failing_lock("0x01"), 0, &process).await?;
+        assert_eq!(queue.pending_count().await, 1);
+
+        drain_retries(&queue, &process).await?;
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert_eq!(queue.pending_count().await, 0);
+        assert!(database.list_dead_letters().await?.is_empty());
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_permanently_failing_event_is_dead_lettered() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::{coordinator::BridgeEvent, database::Database, policy::RetryPolicy, retry_queue::RetryQueue, RelayerError};
+    use std::sync::{atomic::{AtomicU32, Ordering}, Arc};
+    use std::time::Duration;
+
+    let database = Arc::new(Database::new(&create_test_relayer_config().database).await?);
+    let queue = RetryQueue::new(database.clone(), RetryPolicy {
+        max_retries: 2,
+        retry_delay: Duration::from_millis(10),
+        rate_limit_cooldown: Duration::from_millis(10),
+    });
+
+    let attempts = AtomicU32::new(0);
+    let process = |_event: BridgeEvent| {
+        attempts.fetch_add(1, Ordering::SeqCst);
+        async { Err::<(), _>(RelayerError::Polkadot { message: "connection refused".to_string() }) }
+    };
+
+    with_timeout(async {
+        queue.attempt(failing_lock("0x02"), 0, &process).await?;
+        drain_retries(&queue, &process).await?;
+
+        // The first attempt and both retries failed
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        let dead_letters = database.list_dead_letters().await?;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].event.tx_hash(), "0x02");
+        assert_eq!(dead_letters[0].attempts, 3);
+        assert_eq!(dead_letters[0].last_error, "Polkadot error: connection refused");
+
+        // Taking a dead letter for an operator retry removes it
+        let taken = database.take_dead_letter(dead_letters[0].id).await?.expect("dead letter");
+        assert_eq!(taken.event.tx_hash(), "0x02");
+        assert!(database.list_dead_letters().await?.is_empty());
+        assert!(database.take_dead_letter(dead_letters[0].id).await?.is_none());
+
+        // A rejected transfer would fail the same way again, so it is dropped
+        let rejected = |_event: BridgeEvent| async {
+            Err::<(), _>(RelayerError::InvalidAmount { tx_hash: "0x03".to_string(), amount: "0".to_string() })
+        };
+        queue.attempt(failing_lock("0x03"), 0, rejected).await?;
+        assert_eq!(queue.pending_count().await, 0);
+        assert!(database.list_dead_letters().await?.is_empty());
+
+        Ok(())
+    }).await
+}
+
+/// Mint submitter that records each mint and, while `stalled`, never returns
+/// from submitting it, as if the relayer died right after sending
+#[derive(Default)]
+struct StallingSubmitter {
+    mints: std::sync::Mutex<Vec<String>>,
+    stalled: std::sync::atomic::AtomicBool,
+}
+
+#[async_trait::async_trait]
+impl relayer::polkadot::MintSubmitter for StallingSubmitter {
+    async fn submit_mint(&self, mint: &relayer::polkadot::PendingMint) -> relayer::Result<String> {
+        self.mints.lock().unwrap().push(mint.ethereum_tx_hash.clone());
+        if self.stalled.load(std::sync::atomic::Ordering::SeqCst) {
+            std::future::pending::<()>().await;
+        }
+        Ok(format!("0xmint_{}", mint.ethereum_tx_hash))
+    }
+
+    async fn submit_mint_batch(&self, _mints: &[relayer::polkadot::PendingMint]) -> relayer::Result<String> {
+        Ok("0xbatch".to_string())
+    }
+}
+
+#[tokio::test]
+async fn test_mint_submitted_before_a_crash_is_not_repeated() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::{
+        allowlist::RecipientAllowlist,
+        coordinator::{BridgeEvent, MintPipeline},
+        database::Database,
+        event_sink::NoopEventSink,
+        metrics::{DuplicateEvents, RelayerMetrics},
+        mint_batcher::MintBatcher,
+        policy::RelayerPolicy,
+        signature_coordinator::SignatureCoordinator,
+    };
+    use std::sync::{atomic::Ordering, Arc};
+    use std::time::Duration;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let config = create_test_relayer_config();
+        let db = Arc::new(Database::new(&config.database).await?);
+
+        // A 1-of-1 validator submits as soon as it has signed
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(1, 1, 256)?)?);
+        let signatures = SignatureCoordinator::new(
+            config.validator.clone(),
+            manager,
+            db.clone(),
+            RelayerPolicy::from_config(&config).timeouts,
+        ).await?;
+        let submitter = Arc::new(StallingSubmitter::default());
+        let batcher = MintBatcher::new(submitter.clone(), 1, Duration::from_secs(60));
+        let duplicates = DuplicateEvents::new();
+        let metrics = RelayerMetrics::new();
+        let allowlist = RecipientAllowlist::new(vec![]);
+
+        let pipeline = MintPipeline {
+            database: &db,
+            duplicate_events: &duplicates,
+            metrics: &metrics,
+            recipient_allowlist: &allowlist,
+            signature_coordinator: &signatures,
+            mint_batcher: &batcher,
+            event_sink: &NoopEventSink,
+            validator_enabled: config.validator.enabled,
+        };
+        let event = |tx_hash: &str| BridgeEvent::EthereumLock {
+            user: "0xuser".to_string(),
+            token: TEST_TOKEN.to_string(),
+            amount: "1000".to_string(),
+            polkadot_address: TEST_RECIPIENT.to_string(),
+            tx_hash: tx_hash.to_string(),
+            block_number: 100,
+        };
+
+        let (crash, clean) = (test_tx_hash("crash"), test_tx_hash("clean"));
+
+        // The relayer goes down after sending the mint but before recording it
+        submitter.stalled.store(true, Ordering::SeqCst);
+        let crashed = tokio::time::timeout(Duration::from_millis(200), pipeline.relay(&event(&crash))).await;
+        assert!(crashed.is_err(), "the stalled submission should not finish");
+        assert_eq!(*submitter.mints.lock().unwrap(), vec![crash.clone()]);
+        assert!(!db.is_ethereum_tx_processed(&crash).await?);
+        assert!(db.is_submission_pending("ethereum", &crash).await?);
+
+        // Reprocessing after the restart leaves the lock alone
+        submitter.stalled.store(false, Ordering::SeqCst);
+        pipeline.process(&event(&crash)).await?;
+        assert_eq!(*submitter.mints.lock().unwrap(), vec![crash.clone()]);
+
+        // A lock that completes is recorded with the extrinsic that minted it
+        pipeline.relay(&event(&clean)).await?;
+        assert_eq!(*submitter.mints.lock().unwrap(), vec![crash.clone(), clean.clone()]);
+        assert!(db.is_ethereum_tx_processed(&clean).await?);
+        assert!(!db.is_submission_pending("ethereum", &clean).await?);
+        assert_eq!(db.destination_tx_hash("ethereum", &clean).await?.as_deref(), Some(format!("0xmint_{}", clean).as_str()));
+
+        // Neither a processed nor a claimed lock can be claimed again
+        assert!(!db.claim_submission("ethereum", &clean).await?);
+        assert!(!db.claim_submission("ethereum", &crash).await?);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_failed_mints_release_their_claims() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::{
+        allowlist::RecipientAllowlist,
+        coordinator::{BridgeEvent, MintPipeline},
+        database::Database,
+        event_sink::NoopEventSink,
+        metrics::{DuplicateEvents, RelayerMetrics},
+        mint_batcher::MintBatcher,
+        policy::RelayerPolicy,
+        signature_coordinator::SignatureCoordinator,
+    };
+    use std::sync::Arc;
+    use std::time::Duration;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let config = create_test_relayer_config();
+        let db = Arc::new(Database::new(&config.database).await?);
+
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(1, 1, 256)?)?);
+        let signatures = SignatureCoordinator::new(
+            config.validator.clone(),
+            manager,
+            db.clone(),
+            RelayerPolicy::from_config(&config).timeouts,
+        ).await?;
+        let (queued, fills) = (test_tx_hash("queued"), test_tx_hash("fills"));
+
+        // Batches are rejected, and so is the queued lock on its own
+        let submitter = Arc::new(StubMintSubmitter {
+            reject_batches: true,
+            reject: Some(queued.clone()),
+            ..Default::default()
+        });
+        let batcher = MintBatcher::new(submitter.clone(), 2, Duration::from_secs(60));
+        let duplicates = DuplicateEvents::new();
+        let metrics = RelayerMetrics::new();
+        let allowlist = RecipientAllowlist::new(vec![]);
+
+        let pipeline = MintPipeline {
+            database: &db,
+            duplicate_events: &duplicates,
+            metrics: &metrics,
+            recipient_allowlist: &allowlist,
+            signature_coordinator: &signatures,
+            mint_batcher: &batcher,
+            event_sink: &NoopEventSink,
+            validator_enabled: config.validator.enabled,
+        };
+        let event = |tx_hash: &str| BridgeEvent::EthereumLock {
+            user: "0xuser".to_string(),
+            token: TEST_TOKEN.to_string(),
+            amount: "1000".to_string(),
+            polkadot_address: TEST_RECIPIENT.to_string(),
+            tx_hash: tx_hash.to_string(),
+            block_number: 100,
+        };
+
+        // The first lock waits in the batch, claimed
+        pipeline.relay(&event(&queued)).await?;
+        assert!(db.is_submission_pending("ethereum", &queued).await?);
+
+        // The second fills it; only the second mint goes through
+        pipeline.relay(&event(&fills)).await?;
+        assert!(db.is_ethereum_tx_processed(&fills).await?);
+        assert_eq!(*submitter.singles.lock().unwrap(), vec![fills.clone()]);
+
+        // The queued lock that failed along the way can be minted again
+        assert!(!db.is_ethereum_tx_processed(&queued).await?);
+        assert!(!db.is_submission_pending("ethereum", &queued).await?);
+
+        // A lock whose own mint fails reports the error and releases its claim
+        let batcher = MintBatcher::new(submitter.clone(), 1, Duration::from_secs(60));
+        let pipeline = MintPipeline { mint_batcher: &batcher, ..pipeline };
+        let result = pipeline.process(&event(&queued)).await;
+        assert!(matches!(result, Err(relayer::RelayerError::Polkadot { .. })), "{:?}", result);
+        assert!(!db.is_submission_pending("ethereum", &queued).await?);
+
+        Ok(())
+    }).await
+}
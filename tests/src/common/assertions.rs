@@ -18,7 +18,7 @@ where
 /// Assert that a JSON value contains expected fields
 pub fn assert_json_contains(actual: &Value, expected_fields: &[&str]) -> TestResult<()> {
     for field in expected_fields {
-        if !actual.get(field).is_some() {
+        if actual.get(field).is_none() {
             return Err(format!("Missing field: {}", field).into());
         }
     }
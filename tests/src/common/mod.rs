@@ -64,6 +64,18 @@ pub fn generate_test_id() -> String {
     format!("test_{}", rng.gen::<u32>())
 }
 
+/// Polkadot account mints in tests are signed for
+pub const TEST_RECIPIENT: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+
+/// Ethereum token mints in tests are signed for
+pub const TEST_TOKEN: &str = "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8";
+
+/// Well-formed transaction hash derived from `label`, distinct per label
+pub fn test_tx_hash(label: &str) -> String {
+    use ethers::{types::H256, utils::keccak256};
+    format!("{:?}", H256::from(keccak256(label)))
+}
+
 /// Test environment setup and cleanup
 pub struct TestEnvironment {
     pub config: TestConfig,
@@ -97,6 +109,12 @@ impl TestEnvironment {
     }
 }
 
+impl Default for TestEnvironment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Drop for TestEnvironment {
     fn drop(&mut self) {
         // Ensure cleanup happens even if test panics
@@ -1,8 +1,15 @@
 //! Test setup utilities
 
 use super::{TestConfig, TestResult};
-use relayer::{BridgeCoordinator, config::RelayerConfig};
-use threshold::{SimpleThresholdManager, ThresholdConfig};
+use ethers::{signers::LocalWallet, types::H256};
+use relayer::{
+    BridgeCoordinator,
+    config::RelayerConfig,
+    database::Database,
+    signature_coordinator::{SignatureCoordinator, SignatureFormat},
+};
+use threshold::{utils, KeyShare, PartialSignature, PublicKeyShare, SimpleThresholdManager, ThresholdConfig};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Setup a test bridge coordinator (mock version for testing without database)
@@ -44,6 +51,7 @@ pub fn create_test_relayer_config() -> RelayerConfig {
             confirmations: 1, // Fast confirmations for testing
             gas_limit: 300000,
             gas_price: 20000000000,
+            gas_strategy: relayer::config::GasStrategy::Static,
             private_key: Some("0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80".to_string()),
             start_block: Some(0),
         },
@@ -53,6 +61,10 @@ pub fn create_test_relayer_config() -> RelayerConfig {
             confirmations: 1, // Fast confirmations for testing
             account_seed: Some("//Alice".to_string()),
             start_block: Some(0),
+            admin_seed: None,
+            allowed_recipients: vec![],
+            mint_batch_size: 1,
+            mint_batch_window: 1,
         },
         threshold: relayer::config::ThresholdConfig {
             scheme: "ecdsa".to_string(),
@@ -60,6 +72,11 @@ pub fn create_test_relayer_config() -> RelayerConfig {
             total_validators: 3,
             key_size: 256,
             signature_timeout: 60, // Shorter timeout for testing
+            signature_grace_period: 30,
+            max_clock_skew: 30,
+            max_message_size: 1024,
+            validator_weights: std::collections::HashMap::new(),
+            weighted_threshold: None,
         },
         database: relayer::config::DatabaseConfig {
             url: "sqlite::memory:".to_string(), // Use in-memory SQLite for testing
@@ -75,16 +92,46 @@ pub fn create_test_relayer_config() -> RelayerConfig {
             batch_size: 10,
             metrics_port: 9002,
             log_level: "debug".to_string(),
+            incomplete_transfer_threshold: 0,
+            incomplete_transfer_window: 0,
+            stats_cache_ttl: 0,
+            completion_time_window: 3600,
+            rate_limit_cooldown: 5,
+            max_event_age_blocks: None,
+            event_sink: None,
+            checkpoint_interval: None,
         },
         validator: relayer::config::ValidatorConfig {
             validator_id: "test_validator".to_string(),
             private_key: Some("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef".to_string()),
             peers: vec![],
             enabled: true,
+            require_distinct_sources: false,
+            listen_address: None,
+            keystore_path: None,
+            tls: None,
         },
+        price_oracle: None,
+        direction: relayer::config::RelayDirection::Both,
     }
 }
 
+/// Connect to the test database, honouring `DATABASE_URL` when set
+pub async fn setup_test_database() -> TestResult<Database> {
+    let url = std::env::var("DATABASE_URL")
+        .unwrap_or_else(|_| TestConfig::default().database_url);
+
+    let config = relayer::config::DatabaseConfig {
+        url,
+        max_connections: 5,
+        min_connections: 1,
+        connect_timeout: 10,
+        query_timeout: 30,
+    };
+
+    Ok(Database::new(&config).await?)
+}
+
 /// Setup test threshold manager
 pub async fn setup_test_threshold_manager() -> TestResult<Arc<SimpleThresholdManager>> {
     let config = ThresholdConfig::new(2, 3, 256)?;
@@ -92,6 +139,67 @@ pub async fn setup_test_threshold_manager() -> TestResult<Arc<SimpleThresholdMan
     Ok(Arc::new(manager))
 }
 
+/// Key shares of a set of test validators, for signing partials a
+/// coordinator holding their public key shares accepts
+pub struct TestSigners {
+    manager: SimpleThresholdManager,
+    key_shares: HashMap<String, KeyShare>,
+}
+
+impl TestSigners {
+    /// Generate a key share for each of `validator_ids`
+    pub async fn new(validator_ids: &[&str]) -> TestResult<Self> {
+        let manager = SimpleThresholdManager::new(ThresholdConfig::new(1, validator_ids.len() as u32, 256)?)?;
+        let validator_ids: Vec<String> = validator_ids.iter().map(|id| id.to_string()).collect();
+        let key_shares = manager.generate_key_shares(&validator_ids).await?;
+        Ok(Self { manager, key_shares })
+    }
+
+    /// Public key shares to register with a coordinator
+    pub fn public_key_shares(&self) -> TestResult<Vec<PublicKeyShare>> {
+        Ok(utils::extract_public_key_shares(&self.key_shares)?)
+    }
+
+    /// `validator_id`'s key share
+    pub fn key_share(&self, validator_id: &str) -> &KeyShare {
+        &self.key_shares[validator_id]
+    }
+
+    /// `validator_id`'s partial over `message` in session `session_id`
+    pub async fn sign(&self, validator_id: &str, session_id: &str, message: &[u8]) -> TestResult<PartialSignature> {
+        Ok(self.manager.create_partial_signature(self.key_share(validator_id), message, session_id).await?)
+    }
+
+    /// `validator_id`'s recoverable signature of the 32-byte `digest`, as
+    /// mints and unlocks are signed
+    pub fn sign_recoverable(&self, validator_id: &str, digest: &[u8]) -> TestResult<PartialSignature> {
+        let key_share = self.key_share(validator_id);
+        let wallet = LocalWallet::from_bytes(&key_share.private_share)?;
+        let signature = wallet.sign_hash(H256::from_slice(digest))?;
+        Ok(PartialSignature {
+            validator_id: validator_id.to_string(),
+            signature: signature.to_vec(),
+            commitment: None,
+            timestamp: std::time::SystemTime::now(),
+            key_epoch: key_share.key_epoch,
+        })
+    }
+
+    /// `validator_id`'s partial for the session `coordinator` has open for `tx_hash`
+    pub async fn partial(
+        &self,
+        coordinator: &SignatureCoordinator,
+        tx_hash: &str,
+        validator_id: &str,
+    ) -> TestResult<PartialSignature> {
+        let session = coordinator.session(tx_hash).await.ok_or("no signature session open")?;
+        match session.format {
+            SignatureFormat::Threshold => self.sign(validator_id, tx_hash, &session.message).await,
+            SignatureFormat::Recoverable => self.sign_recoverable(validator_id, &session.message),
+        }
+    }
+}
+
 /// Setup test validators
 pub async fn setup_test_validators() -> TestResult<Vec<String>> {
     Ok(vec![
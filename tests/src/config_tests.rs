@@ -0,0 +1,52 @@
+//! Relayer configuration tests
+
+use crate::common::{setup::init_test_logging, TestResult};
+use relayer::config::RelayerConfig;
+
+/// Fresh path in the temp directory for a generated config
+fn temp_config_path() -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("relayer-config-{}.toml", rand::random::<u64>()))
+}
+
+#[test]
+fn test_generated_config_round_trips() -> TestResult<()> {
+    init_test_logging();
+
+    let path = temp_config_path();
+    RelayerConfig::write_template(&path, false)?;
+
+    let contents = std::fs::read_to_string(&path)?;
+    assert!(contents.contains("# RPC endpoint URL\nrpc_url = "));
+    assert!(contents.contains("# checkpoint_interval = 3600"));
+    assert!(contents.contains("# [price_oracle]"));
+
+    let loaded = RelayerConfig::from_file(&path)?;
+    let template = RelayerConfig::template();
+    assert_eq!(loaded.ethereum.rpc_url, template.ethereum.rpc_url);
+    assert_eq!(loaded.polkadot.mint_batch_size, template.polkadot.mint_batch_size);
+    assert_eq!(loaded.threshold.threshold, template.threshold.threshold);
+    assert_eq!(loaded.database.url, template.database.url);
+    assert_eq!(loaded.monitoring.checkpoint_interval, None);
+    assert!(loaded.price_oracle.is_none());
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_generate_config_refuses_to_overwrite_without_force() -> TestResult<()> {
+    init_test_logging();
+
+    let path = temp_config_path();
+    std::fs::write(&path, "# hand-written\n")?;
+
+    let err = RelayerConfig::write_template(&path, false).unwrap_err();
+    assert!(err.to_string().contains("--force"));
+    assert_eq!(std::fs::read_to_string(&path)?, "# hand-written\n");
+
+    RelayerConfig::write_template(&path, true)?;
+    RelayerConfig::from_file(&path)?;
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
@@ -0,0 +1,1716 @@
+//! Relayer database integration tests (require a running PostgreSQL)
+
+use crate::common::{
+    setup::{setup_test_database, init_test_logging},
+    generate_test_id, test_tx_hash, TestConfig, TestResult, with_timeout, TEST_RECIPIENT, TEST_TOKEN,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use relayer::database::TransactionQuery;
+use sqlx::PgPool;
+
+/// Records every mint submitted to the pallet
+#[derive(Default)]
+struct RecordingSubmitter {
+    mints: std::sync::Mutex<Vec<String>>,
+}
+
+#[async_trait::async_trait]
+impl relayer::polkadot::MintSubmitter for RecordingSubmitter {
+    async fn submit_mint(&self, mint: &relayer::polkadot::PendingMint) -> relayer::Result<String> {
+        self.mints.lock().unwrap().push(mint.ethereum_tx_hash.clone());
+        Ok(format!("0xmint_{}", mint.ethereum_tx_hash))
+    }
+
+    async fn submit_mint_batch(&self, mints: &[relayer::polkadot::PendingMint]) -> relayer::Result<String> {
+        for mint in mints {
+            self.submit_mint(mint).await?;
+        }
+        Ok("0xbatch".to_string())
+    }
+}
+
+/// Backdate a stored transaction so time-window filters can be exercised
+async fn set_created_at(pool: &PgPool, table: &str, tx_hash: &str, at: DateTime<Utc>) -> TestResult<()> {
+    sqlx::query(&format!("UPDATE {} SET created_at = $1 WHERE tx_hash = $2", table))
+        .bind(at.naive_utc())
+        .bind(tx_hash)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Set when a stored transaction was marked processed
+async fn set_processed_at(pool: &PgPool, tx_hash: &str, at: DateTime<Utc>) -> TestResult<()> {
+    sqlx::query("UPDATE processed_transactions SET processed_at = $1 WHERE tx_hash = $2")
+        .bind(at.naive_utc())
+        .bind(tx_hash)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_transactions_time_window() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        let db = setup_test_database().await?;
+        let url = std::env::var("DATABASE_URL")
+            .unwrap_or_else(|_| TestConfig::default().database_url);
+        let pool = PgPool::connect(&url).await?;
+
+        // Seed a lock and a burn on each side of and inside the window
+        let id = generate_test_id();
+        // Sent by a user of this test's own, so other tests' rows in the
+        // shared database cannot crowd ours past the query limit
+        let user = format!("0x{}_user", id);
+        let seeded = [
+            ("ethereum_locks", format!("0x{}_lock_early", id), Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap()),
+            ("ethereum_locks", format!("0x{}_lock_inside", id), Utc.with_ymd_and_hms(2020, 1, 2, 12, 0, 0).unwrap()),
+            ("polkadot_burns", format!("0x{}_burn_inside", id), Utc.with_ymd_and_hms(2020, 1, 3, 0, 0, 0).unwrap()),
+            ("polkadot_burns", format!("0x{}_burn_late", id), Utc.with_ymd_and_hms(2020, 1, 5, 0, 0, 0).unwrap()),
+        ];
+
+        for (table, tx_hash, at) in &seeded {
+            if *table == "ethereum_locks" {
+                db.store_ethereum_lock(&user, "0xtoken", "1000", "5Grw", tx_hash, 100).await?;
+            } else {
+                db.store_polkadot_burn(&user, 1, "1000", "0xrecipient", tx_hash, 100).await?;
+            }
+            set_created_at(&pool, table, tx_hash, *at).await?;
+        }
+
+        let ours = |records: Vec<relayer::database::TransactionRecord>| -> Vec<String> {
+            records.into_iter()
+                .map(|r| r.tx_hash)
+                .filter(|h| h.contains(&id))
+                .collect()
+        };
+
+        let window = TransactionQuery {
+            since: Some(Utc.with_ymd_and_hms(2020, 1, 2, 0, 0, 0).unwrap()),
+            until: Some(Utc.with_ymd_and_hms(2020, 1, 4, 0, 0, 0).unwrap()),
+            user: Some(user.clone()),
+            limit: 100,
+            ..Default::default()
+        };
+        let found = ours(db.query_transactions(&window).await?);
+        assert_eq!(found, vec![seeded[2].1.clone(), seeded[1].1.clone()]);
+
+        // Time bounds combine with the chain filter
+        let ethereum_only = TransactionQuery {
+            chain: Some("ethereum".to_string()),
+            ..window.clone()
+        };
+        let found = ours(db.query_transactions(&ethereum_only).await?);
+        assert_eq!(found, vec![seeded[1].1.clone()]);
+
+        // An open-ended lower bound includes everything from `since` onwards
+        let since_only = TransactionQuery {
+            since: Some(Utc.with_ymd_and_hms(2020, 1, 3, 0, 0, 0).unwrap()),
+            user: Some(user.clone()),
+            limit: 100,
+            ..Default::default()
+        };
+        let found = ours(db.query_transactions(&since_only).await?);
+        assert_eq!(found, vec![seeded[3].1.clone(), seeded[2].1.clone()]);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_transaction_page_filters_and_counts() -> TestResult<()> {
+    use api::{
+        handlers::transactions::{transaction_page, transaction_query},
+        server::{PaginationParams, TransactionFilters},
+    };
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = setup_test_database().await?;
+
+        // Three locks and two burns from accounts unique to this run
+        let id = generate_test_id();
+        let sender = format!("0xabcdef{:0>34}", id.trim_start_matches("test_"));
+        let account = format!("5Page{}", id);
+        for block in [100, 101, 102] {
+            let tx_hash = format!("0x{}_lock_{}", id, block);
+            db.store_ethereum_lock(&sender, "0xtoken", "1000", "5Grw", &tx_hash, block).await?;
+        }
+        for block in [200, 201] {
+            let tx_hash = format!("0x{}_burn_{}", id, block);
+            db.store_polkadot_burn(&account, 1, "1000", "0xrecipient", &tx_hash, block).await?;
+        }
+
+        let page = |filters: serde_json::Value, page: u32, limit: u32| {
+            let db = &db;
+            async move {
+                let filters: TransactionFilters = serde_json::from_value(filters)?;
+                let pagination = PaginationParams { page: Some(page), limit: Some(limit) };
+                let query = transaction_query(filters, pagination.limit(), pagination.offset())?;
+                TestResult::Ok(transaction_page(db, &query, &pagination).await?)
+            }
+        };
+
+        // Filter by user, matched case-insensitively
+        let by_user = page(serde_json::json!({ "user": sender.replace("abcdef", "ABCDEF") }), 1, 20).await?;
+        assert_eq!(by_user.total, 3);
+        assert!(by_user.transactions.iter().all(|tx| tx.chain == "ethereum" && tx.user == sender));
+
+        // Filter by chain, combined with the user
+        let by_chain = page(serde_json::json!({ "chain": "polkadot", "user": &account }), 1, 20).await?;
+        assert_eq!(by_chain.total, 2);
+        assert!(by_chain.transactions.iter().all(|tx| tx.chain == "polkadot"));
+        let none = page(serde_json::json!({ "chain": "ethereum", "user": &sender, "from_block": 200 }), 1, 20).await?;
+        assert_eq!(none.total, 0);
+        assert!(none.transactions.is_empty());
+
+        // Block bounds are inclusive
+        let blocks = page(serde_json::json!({ "user": &sender, "from_block": 101, "to_block": 102 }), 1, 20).await?;
+        assert_eq!(blocks.total, 2);
+        assert!(blocks.transactions.iter().all(|tx| (101..=102).contains(&tx.block_number)));
+
+        // Pages split the matches without overlap; the total counts them all
+        let first = page(serde_json::json!({ "user": &sender }), 1, 2).await?;
+        let second = page(serde_json::json!({ "user": &sender }), 2, 2).await?;
+        let past_end = page(serde_json::json!({ "user": &sender }), 3, 2).await?;
+        assert_eq!((first.transactions.len(), second.transactions.len(), past_end.transactions.len()), (2, 1, 0));
+        assert_eq!((first.total, second.total, past_end.total), (3, 3, 3));
+        assert_eq!((second.page, second.limit), (2, 2));
+
+        let mut hashes: Vec<String> = first.transactions.into_iter()
+            .chain(second.transactions)
+            .map(|tx| tx.tx_hash)
+            .collect();
+        hashes.sort();
+        hashes.dedup();
+        assert_eq!(hashes.len(), 3);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_query_transactions_pages_are_stable_within_a_block() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        let db = setup_test_database().await?;
+        let url = std::env::var("DATABASE_URL")
+            .unwrap_or_else(|_| TestConfig::default().database_url);
+        let pool = PgPool::connect(&url).await?;
+
+        // Five transfers in the same block, recorded at the same instant
+        let id = generate_test_id();
+        let offset_secs = id.trim_start_matches("test_").parse::<i64>()? % 86_400;
+        let at = Utc.with_ymd_and_hms(2001, 1, 1, 0, 0, 0).unwrap() + chrono::Duration::seconds(offset_secs);
+
+        let mut hashes = Vec::new();
+        for suffix in ["c", "a", "e", "b", "d"] {
+            let tx_hash = format!("0x{}_{}", id, suffix);
+            db.store_ethereum_lock("0xuser", "0xtoken", "1000", "5Grw", &tx_hash, 777).await?;
+            set_created_at(&pool, "ethereum_locks", &tx_hash, at).await?;
+            hashes.push(tx_hash);
+        }
+
+        let page = |offset| TransactionQuery {
+            since: Some(at),
+            until: Some(at),
+            limit: 3,
+            offset,
+            ..Default::default()
+        };
+        let ours = |records: Vec<relayer::database::TransactionRecord>| -> Vec<String> {
+            records.into_iter()
+                .map(|r| r.tx_hash)
+                .filter(|h| h.contains(&id))
+                .collect()
+        };
+
+        // Two fetches of each page return identical rows
+        let first = ours(db.query_transactions(&page(0)).await?);
+        let second = ours(db.query_transactions(&page(3)).await?);
+        assert_eq!(first, ours(db.query_transactions(&page(0)).await?));
+        assert_eq!(second, ours(db.query_transactions(&page(3)).await?));
+
+        // Together the pages cover every row exactly once, in tx_hash order
+        hashes.sort();
+        let combined: Vec<String> = first.into_iter().chain(second).collect();
+        assert_eq!(combined, hashes);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_duplicate_event_is_counted_and_not_reprocessed() -> TestResult<()> {
+    use relayer::{
+        coordinator::{BridgeCoordinator, BridgeEvent},
+        metrics::{self, DuplicateEvents, Registry},
+    };
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = setup_test_database().await?;
+        let duplicates = DuplicateEvents::new();
+
+        let event = BridgeEvent::EthereumLock {
+            user: "0xuser".to_string(),
+            token: TEST_TOKEN.to_string(),
+            amount: "1000".to_string(),
+            polkadot_address: TEST_RECIPIENT.to_string(),
+            tx_hash: test_tx_hash(&format!("{}_duplicate", generate_test_id())),
+            block_number: 100,
+        };
+        let locks_before = db.count_ethereum_locks().await?;
+
+        // First delivery is new and goes on to be processed
+        assert!(BridgeCoordinator::store_event(&db, &duplicates, &event).await?);
+        assert_eq!(duplicates.count("ethereum"), 0);
+
+        // Redelivery is suppressed and counted
+        assert!(!BridgeCoordinator::store_event(&db, &duplicates, &event).await?);
+        assert_eq!(duplicates.count("ethereum"), 1);
+        assert_eq!(duplicates.count("polkadot"), 0);
+        assert_eq!(db.count_ethereum_locks().await?, locks_before + 1);
+
+        let registry = Registry::new();
+        duplicates.register(&registry)?;
+        let metrics = metrics::encode(&registry)?;
+        assert!(metrics.contains("bridge_duplicate_events_total{chain=\"ethereum\"} 1"));
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_partial_signature_before_session_is_counted() -> TestResult<()> {
+    use crate::common::setup::{create_test_relayer_config, TestSigners};
+    use relayer::{policy::RelayerPolicy, signature_coordinator::SignatureCoordinator};
+    use std::sync::Arc;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let mut config = create_test_relayer_config();
+        config.validator.private_key = None;
+
+        let signers = TestSigners::new(&["validator_1", "validator_2", "validator_3"]).await?;
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(2, 3, 256)?)?);
+        let coordinator = SignatureCoordinator::new(
+            config.validator,
+            manager,
+            db,
+            RelayerPolicy::from_config(&create_test_relayer_config()).timeouts,
+        ).await?
+        .with_public_key_shares(&signers.public_key_shares()?);
+
+        let tx_hash = test_tx_hash(&format!("{}_early", generate_test_id()));
+        let message = relayer::polkadot::mint_message(TEST_RECIPIENT, TEST_TOKEN, "1000", &tx_hash)?;
+
+        // A fast peer's partial arrives before the local session exists, as
+        // does one from a validator without a registered key share
+        let early = signers.sign_recoverable("validator_1", &message)?;
+        coordinator.add_partial_signature(&tx_hash, "validator_1", "10.0.0.1:30333", early).await?;
+        let mut unregistered = signers.sign_recoverable("validator_1", &message)?;
+        unregistered.validator_id = "validator_9".to_string();
+        coordinator.add_partial_signature(&tx_hash, "validator_9", "10.0.0.9:30333", unregistered).await?;
+        assert_eq!(coordinator.count_pending_signatures().await?, 0);
+
+        coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &tx_hash).await?;
+        assert_eq!(coordinator.count_pending_signatures().await?, 1);
+        assert!(coordinator.get_mint_signatures(&tx_hash).await?.is_none());
+        let session = coordinator.session(&tx_hash).await.expect("session opened");
+        assert!(!session.partial_signatures.contains_key("validator_9"));
+
+        // The buffered partial counts towards the threshold once the session exists
+        let partial = signers.partial(&coordinator, &tx_hash, "validator_2").await?;
+        coordinator.add_partial_signature(&tx_hash, "validator_2", "10.0.0.2:30333", partial).await?;
+        let signatures = coordinator.get_mint_signatures(&tx_hash).await?;
+        assert_eq!(signatures.map(|s| s.len()), Some(2));
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_signature_sessions_survive_a_coordinator_restart() -> TestResult<()> {
+    use crate::common::setup::{create_test_relayer_config, TestSigners};
+    use relayer::{database::Database, policy::RelayerPolicy, signature_coordinator::SignatureCoordinator};
+    use std::sync::Arc;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let mut config = create_test_relayer_config();
+        config.validator.private_key = None;
+        let db = Arc::new(Database::new(&config.database).await?);
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(2, 3, 256)?)?);
+        let timeouts = RelayerPolicy::from_config(&config).timeouts;
+        let signers = TestSigners::new(&["validator_1", "validator_2", "validator_3"]).await?;
+        let public_key_shares = signers.public_key_shares()?;
+
+        let tx_hash = test_tx_hash(&format!("{}_restart", generate_test_id()));
+        let coordinator = SignatureCoordinator::new(config.validator.clone(), manager.clone(), db.clone(), timeouts)
+            .await?
+            .with_public_key_shares(&public_key_shares);
+        coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &tx_hash).await?;
+        let partial = signers.partial(&coordinator, &tx_hash, "validator_1").await?;
+        coordinator.add_partial_signature(&tx_hash, "validator_1", "10.0.0.1:30333", partial.clone()).await?;
+        let message = coordinator.session(&tx_hash).await.expect("session opened").message;
+        drop(coordinator);
+
+        // A fresh coordinator starts empty and recovers the session from the database
+        let coordinator = SignatureCoordinator::new(config.validator, manager, db.clone(), timeouts)
+            .await?
+            .with_public_key_shares(&public_key_shares);
+        assert_eq!(coordinator.count_pending_signatures().await?, 0);
+        assert_eq!(coordinator.restore_sessions().await?, 1);
+
+        let session = coordinator.session(&tx_hash).await.expect("session restored");
+        assert_eq!(session.message, message);
+        assert_eq!(session.required_signatures, 2);
+        assert_eq!(session.partial_signatures["validator_1"].signature, partial.signature);
+        assert_eq!(session.sources["validator_1"], "10.0.0.1:30333");
+
+        // The recovered partial counts towards the threshold
+        let partial = signers.partial(&coordinator, &tx_hash, "validator_2").await?;
+        coordinator.add_partial_signature(&tx_hash, "validator_2", "10.0.0.2:30333", partial).await?;
+        let signatures = coordinator.get_mint_signatures(&tx_hash).await?;
+        assert_eq!(signatures.map(|s| s.len()), Some(2));
+
+        // Submitting the transfer forgets the persisted session
+        coordinator.mark_completed(&tx_hash).await;
+        assert!(db.load_signature_session(&tx_hash).await?.is_none());
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_invalid_amount_is_rejected_before_storage() -> TestResult<()> {
+    use relayer::{coordinator::{BridgeCoordinator, BridgeEvent}, metrics::DuplicateEvents, RelayerError};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = setup_test_database().await?;
+        let duplicates = DuplicateEvents::new();
+        let id = generate_test_id();
+
+        let lock = |amount: &str, tx_hash: &str| BridgeEvent::EthereumLock {
+            user: "0xuser".to_string(),
+            token: "0xtoken".to_string(),
+            amount: amount.to_string(),
+            polkadot_address: "5Grw".to_string(),
+            tx_hash: tx_hash.to_string(),
+            block_number: 100,
+        };
+        let burn = |amount: &str, tx_hash: &str| BridgeEvent::PolkadotBurn {
+            user: "5Grw".to_string(),
+            asset_id: 1,
+            amount: amount.to_string(),
+            ethereum_recipient: "0xrecipient".to_string(),
+            tx_hash: tx_hash.to_string(),
+            block_number: 100,
+        };
+
+        for (name, event) in [
+            ("non_numeric", lock("abc", &format!("0x{}_non_numeric", id))),
+            ("zero", lock("0", &format!("0x{}_zero", id))),
+            ("negative", burn("-5", &format!("0x{}_negative", id))),
+            ("overflow", burn("340282366920938463463374607431768211456", &format!("0x{}_overflow", id))),
+        ] {
+            let tx_hash = format!("0x{}_{}", id, name);
+            match BridgeCoordinator::store_event(&db, &duplicates, &event).await {
+                Err(RelayerError::InvalidAmount { tx_hash: rejected, .. }) => assert_eq!(rejected, tx_hash),
+                other => panic!("expected {} amount to be rejected, got {:?}", name, other),
+            }
+
+            // Nothing was stored, so the event never reaches the signing stage
+            assert!(db.get_transaction_by_hash(&tx_hash).await?.is_none());
+        }
+
+        // Amounts beyond u128 are still valid on Ethereum
+        let large = format!("0x{}_large", id);
+        assert!(BridgeCoordinator::store_event(
+            &db,
+            &duplicates,
+            &lock("340282366920938463463374607431768211456", &large),
+        ).await?);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_validator_removal_mid_transfer() -> TestResult<()> {
+    use crate::common::setup::{create_test_relayer_config, TestSigners};
+    use relayer::{policy::RelayerPolicy, signature_coordinator::SignatureCoordinator};
+    use std::sync::Arc;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let mut config = create_test_relayer_config();
+        config.validator.private_key = None;
+
+        let signers = TestSigners::new(&["validator_1", "validator_2", "validator_3", "validator_4"]).await?;
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(3, 4, 256)?)?);
+        let coordinator = SignatureCoordinator::new(
+            config.validator,
+            manager,
+            db,
+            RelayerPolicy::from_config(&create_test_relayer_config()).timeouts,
+        ).await?
+        .with_public_key_shares(&signers.public_key_shares()?);
+
+        let id = generate_test_id();
+        let signed = test_tx_hash(&format!("{}_signed", id));
+        let in_flight = test_tx_hash(&format!("{}_in_flight", id));
+        let later = test_tx_hash(&format!("{}_later", id));
+        let peer = |validator_id: &str| format!("10.0.0.{}:30333", validator_id.trim_start_matches("validator_"));
+
+        // One session has reached the 3-of-4 threshold, another is still collecting
+        coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &signed).await?;
+        coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &in_flight).await?;
+        for (tx_hash, validator_ids) in [
+            (&signed, &["validator_1", "validator_2", "validator_4"][..]),
+            (&in_flight, &["validator_1", "validator_4"][..]),
+        ] {
+            for validator_id in validator_ids {
+                let partial = signers.partial(&coordinator, tx_hash, validator_id).await?;
+                coordinator.add_partial_signature(tx_hash, validator_id, &peer(validator_id), partial).await?;
+            }
+        }
+
+        coordinator.remove_validator("validator_4", 2).await?;
+        assert_eq!(coordinator.threshold().await, 2);
+
+        // The already-signable session still completes with its original partials
+        let signatures = coordinator.get_mint_signatures(&signed).await?;
+        assert_eq!(signatures.map(|s| s.len()), Some(3));
+
+        // The in-flight session lost the removed validator's partial and needs
+        // a remaining validator to reach the new threshold
+        let session = coordinator.session(&in_flight).await.expect("session is still open");
+        assert_eq!(session.required_signatures, 2);
+        assert!(!session.partial_signatures.contains_key("validator_4"));
+        assert!(coordinator.get_mint_signatures(&in_flight).await?.is_none());
+
+        // The removed validator can no longer contribute
+        let partial = signers.partial(&coordinator, &in_flight, "validator_4").await?;
+        assert!(coordinator.add_partial_signature(&in_flight, "validator_4", "10.0.0.4:30333", partial).await.is_err());
+
+        let partial = signers.partial(&coordinator, &in_flight, "validator_2").await?;
+        coordinator.add_partial_signature(&in_flight, "validator_2", "10.0.0.2:30333", partial).await?;
+        let signatures = coordinator.get_mint_signatures(&in_flight).await?;
+        assert_eq!(signatures.map(|s| s.len()), Some(2));
+
+        // Subsequent sessions require the new threshold
+        coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &later).await?;
+        assert_eq!(coordinator.session(&later).await.map(|s| s.required_signatures), Some(2));
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_session_refused_when_too_few_validators_are_active() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::{config::ValidatorPeer, policy::RelayerPolicy, signature_coordinator::SignatureCoordinator, RelayerError};
+    use std::sync::Arc;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let mut config = create_test_relayer_config();
+        config.validator.private_key = None;
+        let peer = |id: &str, active: bool| ValidatorPeer {
+            id: id.to_string(),
+            public_key: String::new(),
+            address: format!("10.0.0.{}:30333", id.trim_start_matches("validator_")),
+            active,
+            certificate_path: None,
+        };
+        config.validator.peers = vec![peer("validator_2", true), peer("validator_3", false)];
+
+        // 3-of-3, but only this validator and one peer are active
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(3, 3, 256)?)?);
+        let coordinator = SignatureCoordinator::new(
+            config.validator,
+            manager,
+            db,
+            RelayerPolicy::from_config(&create_test_relayer_config()).timeouts,
+        ).await?;
+        assert_eq!(coordinator.count_active_validators().await?, 2);
+
+        let tx_hash = test_tx_hash(&format!("{}_unreachable", generate_test_id()));
+        let result = coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &tx_hash).await;
+        assert!(matches!(
+            result,
+            Err(RelayerError::InsufficientActiveValidators { active: 2, required: 3, .. })
+        ));
+        assert!(coordinator.session(&tx_hash).await.is_none());
+
+        // Once the threshold is reachable again, sessions open
+        coordinator.remove_validator("validator_3", 2).await?;
+        coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &tx_hash).await?;
+        assert!(coordinator.session(&tx_hash).await.is_some());
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_validator_catches_up_during_grace_period() -> TestResult<()> {
+    use crate::common::setup::{create_test_relayer_config, TestSigners};
+    use relayer::{policy::RelayerPolicy, signature_coordinator::SignatureCoordinator};
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime};
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let mut config = create_test_relayer_config();
+        config.validator.private_key = None;
+        let timeouts = RelayerPolicy::from_config(&config).timeouts;
+
+        let signers = TestSigners::new(&["validator_1", "validator_2", "validator_3"]).await?;
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(2, 3, 256)?)?);
+        let coordinator = SignatureCoordinator::new(config.validator, manager, db, timeouts)
+            .await?
+            .with_public_key_shares(&signers.public_key_shares()?);
+
+        let id = generate_test_id();
+        let recovered = test_tx_hash(&format!("{}_recovered", id));
+        let abandoned = test_tx_hash(&format!("{}_abandoned", id));
+
+        // Each transfer has one partial; the second validator is offline
+        for tx_hash in [&recovered, &abandoned] {
+            coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", tx_hash).await?;
+            let partial = signers.partial(&coordinator, tx_hash, "validator_1").await?;
+            coordinator.add_partial_signature(tx_hash, "validator_1", "10.0.0.1:30333", partial).await?;
+        }
+
+        let start = SystemTime::now();
+        let past_timeout = start + timeouts.signature_timeout + Duration::from_secs(1);
+
+        // Past the timeout both are re-requested rather than dead-lettered
+        let sweep = coordinator.sweep_sessions(past_timeout).await;
+        assert!(sweep.dead_lettered.is_empty());
+        assert!(sweep.rerequested.contains(&recovered));
+        assert!(sweep.rerequested.contains(&abandoned));
+
+        // Re-requests back off: nothing is re-sent before the first delay
+        // elapses, and the second delay is twice the first
+        let sweep = coordinator.sweep_sessions(past_timeout + timeouts.partial_rerequest_delay / 2).await;
+        assert!(sweep.rerequested.is_empty());
+        let second = past_timeout + timeouts.partial_rerequest_delay;
+        assert_eq!(coordinator.sweep_sessions(second).await.rerequested.len(), 2);
+        let sweep = coordinator.sweep_sessions(second + timeouts.partial_rerequest_delay).await;
+        assert!(sweep.rerequested.is_empty());
+
+        // The validator comes back within the grace period and the transfer completes
+        let partial = signers.partial(&coordinator, &recovered, "validator_2").await?;
+        coordinator.add_partial_signature(&recovered, "validator_2", "10.0.0.2:30333", partial).await?;
+        let signatures = coordinator.get_mint_signatures(&recovered).await?;
+        assert_eq!(signatures.map(|s| s.len()), Some(2));
+
+        // Only once the grace period is over is the other transfer dead-lettered
+        let past_grace = past_timeout + timeouts.signature_grace_period;
+        let sweep = coordinator.sweep_sessions(past_grace).await;
+        assert_eq!(sweep.dead_lettered, vec![abandoned.clone()]);
+        assert_eq!(sweep.expired, vec![recovered.clone()]);
+        assert!(coordinator.session(&abandoned).await.is_none());
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_ready_checks_reuse_cached_aggregate() -> TestResult<()> {
+    use crate::common::setup::{create_test_relayer_config, TestSigners};
+    use relayer::{policy::RelayerPolicy, signature_coordinator::SignatureCoordinator};
+    use std::sync::Arc;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let mut config = create_test_relayer_config();
+        config.validator.private_key = None;
+        let timeouts = RelayerPolicy::from_config(&config).timeouts;
+
+        let signers = TestSigners::new(&["validator_1", "validator_2", "validator_3"]).await?;
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(2, 3, 256)?)?);
+        let coordinator = SignatureCoordinator::new(config.validator, manager, db, timeouts)
+            .await?
+            .with_public_key_shares(&signers.public_key_shares()?);
+
+        let tx_hash = test_tx_hash(&format!("{}_cached", generate_test_id()));
+        coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &tx_hash).await?;
+        let mut partials = std::collections::HashMap::new();
+        for validator_id in ["validator_1", "validator_2", "validator_3"] {
+            partials.insert(validator_id, signers.partial(&coordinator, &tx_hash, validator_id).await?);
+        }
+        for validator_id in ["validator_1", "validator_2"] {
+            let source = format!("10.0.0.{}:30333", validator_id.trim_start_matches("validator_"));
+            coordinator.add_partial_signature(&tx_hash, validator_id, &source, partials[validator_id].clone()).await?;
+        }
+
+        // Two consecutive ready-checks aggregate once
+        let first = coordinator.get_mint_signatures(&tx_hash).await?;
+        let second = coordinator.get_mint_signatures(&tx_hash).await?;
+        assert_eq!(first, second);
+        assert_eq!(first.map(|s| s.len()), Some(2));
+        assert_eq!(coordinator.aggregation_count(), 1);
+
+        // A resent identical partial leaves the cached aggregate in place
+        coordinator.add_partial_signature(&tx_hash, "validator_2", "10.0.0.2:30333", partials["validator_2"].clone()).await?;
+        coordinator.get_mint_signatures(&tx_hash).await?;
+        assert_eq!(coordinator.aggregation_count(), 1);
+
+        // A partial that changes membership invalidates it
+        coordinator.add_partial_signature(&tx_hash, "validator_3", "10.0.0.3:30333", partials["validator_3"].clone()).await?;
+        let third = coordinator.get_mint_signatures(&tx_hash).await?;
+        assert_eq!(third.map(|s| s.len()), Some(3));
+        assert_eq!(coordinator.aggregation_count(), 2);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_find_transaction_distinguishes_unknown_from_pending() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        let db = setup_test_database().await?;
+        let tx_hash = format!("0x{}_find", generate_test_id());
+
+        assert!(db.get_transaction_by_hash(&tx_hash).await?.is_none());
+
+        db.store_polkadot_burn("5Grw", 1, "1000", "0xrecipient", &tx_hash, 100).await?;
+        let record = db.get_transaction_by_hash(&tx_hash).await?.expect("stored burn is found");
+        assert_eq!(record.chain, "polkadot");
+        assert_eq!(record.status, "pending");
+
+        db.mark_polkadot_tx_processed(&tx_hash).await?;
+        let record = db.get_transaction_by_hash(&tx_hash).await?.expect("processed burn is found");
+        assert_eq!(record.status, "confirmed");
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_csv_export_streams_filtered_transactions() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        let db = std::sync::Arc::new(setup_test_database().await?);
+        let id = generate_test_id();
+        let user = format!("5Export{}", id);
+
+        for i in 0..3 {
+            let tx_hash = format!("0x{}_export_{}", id, i);
+            db.store_polkadot_burn(&user, 1, "1000", "0xrecipient,quoted", &tx_hash, 100 + i).await?;
+        }
+        db.store_polkadot_burn("5Other", 1, "1000", "0xrecipient", &format!("0x{}_other", id), 100).await?;
+
+        // A small page size forces the export across several queries
+        let query = TransactionQuery {
+            user: Some(user.clone()),
+            limit: 2,
+            ..Default::default()
+        };
+        let body = api::handlers::transactions::export_csv(db, query);
+        let bytes = axum::body::to_bytes(body, usize::MAX).await?;
+        let csv = String::from_utf8(bytes.to_vec())?;
+
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("tx_hash,chain,status,user,token,amount,recipient,block_number,created_at"),
+        );
+
+        let rows: Vec<&str> = lines.collect();
+        assert_eq!(rows.len(), 3);
+        for row in rows {
+            assert!(row.starts_with(&format!("0x{}_export_", id)));
+            assert!(row.contains(&format!(",polkadot,pending,{},1,1000,", user)));
+            assert!(row.contains(",\"0xrecipient,quoted\","));
+        }
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_token_stats_aggregate_per_token() -> TestResult<()> {
+    use relayer::{database::TokenStats, metrics::TokenStatsCache};
+    use std::time::Duration;
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = setup_test_database().await?;
+        let url = std::env::var("DATABASE_URL")
+            .unwrap_or_else(|_| TestConfig::default().database_url);
+        let pool = PgPool::connect(&url).await?;
+
+        let id = generate_test_id();
+        let n: u32 = id.trim_start_matches("test_").parse()?;
+        let token_a = format!("0xA{:0>39}", n);
+        let token_b = format!("0xB{:0>39}", n);
+        let asset_a = n % 1_000_000_000;
+
+        // Burns of the mapped asset count as transfers out of token A
+        sqlx::query("INSERT INTO token_mappings (ethereum_address, polkadot_asset_id) VALUES ($1, $2)")
+            .bind(&token_a)
+            .bind(asset_a as i32)
+            .execute(&pool)
+            .await?;
+
+        db.store_ethereum_lock("0xuser", &token_a, "1000", "5Grw", &format!("0x{}_a1", id), 100).await?;
+        db.store_ethereum_lock("0xuser", &token_a, "2500", "5Grw", &format!("0x{}_a2", id), 101).await?;
+        db.store_polkadot_burn("5Grw", asset_a, "500", "0xrecipient", &format!("0x{}_a3", id), 102).await?;
+        db.store_ethereum_lock("0xuser", &token_b, "7", "5Grw", &format!("0x{}_b1", id), 103).await?;
+
+        let cache = TokenStatsCache::new(Duration::from_secs(3600));
+        let stats = cache.get(&db).await?;
+
+        assert_eq!(
+            stats.get(&token_a.to_lowercase()),
+            Some(&TokenStats { transfers_in: 2, transfers_out: 1, volume: "4000".to_string() }),
+        );
+        assert_eq!(
+            stats.get(&token_b.to_lowercase()),
+            Some(&TokenStats { transfers_in: 1, transfers_out: 0, volume: "7".to_string() }),
+        );
+
+        // Transfers stored within the TTL are not reflected until it expires
+        db.store_ethereum_lock("0xuser", &token_b, "3", "5Grw", &format!("0x{}_b2", id), 104).await?;
+        assert_eq!(cache.get(&db).await?[&token_b.to_lowercase()].transfers_in, 1);
+        assert_eq!(db.token_stats().await?[&token_b.to_lowercase()].transfers_in, 2);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_completion_times_average_and_p95() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        let db = setup_test_database().await?;
+        let url = std::env::var("DATABASE_URL")
+            .unwrap_or_else(|_| TestConfig::default().database_url);
+        let pool = PgPool::connect(&url).await?;
+
+        // A window of its own, away from other tests' transfers
+        let id = generate_test_id();
+        let offset_secs = id.trim_start_matches("test_").parse::<i64>()? % 86_400;
+        let start = Utc.with_ymd_and_hms(2002, 1, 1, 0, 0, 0).unwrap() + chrono::Duration::seconds(offset_secs * 100);
+        let window_end = start + chrono::Duration::hours(1);
+
+        // Twenty transfers taking 1..=20 seconds, the last one a burn
+        for secs in 1..=20i64 {
+            let tx_hash = format!("0x{}_{}", id, secs);
+            let table = if secs == 20 {
+                db.store_polkadot_burn("5Grw", 1, "1000", "0xrecipient", &tx_hash, 100).await?;
+                db.mark_polkadot_tx_processed(&tx_hash).await?;
+                "polkadot_burns"
+            } else {
+                db.store_ethereum_lock("0xuser", "0xtoken", "1000", "5Grw", &tx_hash, 100).await?;
+                db.mark_ethereum_tx_processed(&tx_hash).await?;
+                "ethereum_locks"
+            };
+            set_created_at(&pool, table, &tx_hash, start).await?;
+            set_processed_at(&pool, &tx_hash, start + chrono::Duration::seconds(secs)).await?;
+        }
+
+        // Neither an unfinished transfer nor one completed after the window counts
+        db.store_ethereum_lock("0xuser", "0xtoken", "1000", "5Grw", &format!("0x{}_open", id), 100).await?;
+        set_created_at(&pool, "ethereum_locks", &format!("0x{}_open", id), start).await?;
+        let late = test_tx_hash(&format!("{}_late", id));
+        db.store_ethereum_lock("0xuser", "0xtoken", "1000", "5Grw", &late, 100).await?;
+        db.mark_ethereum_tx_processed(&late).await?;
+        set_created_at(&pool, "ethereum_locks", &late, start).await?;
+        set_processed_at(&pool, &late, window_end + chrono::Duration::seconds(1)).await?;
+
+        let times = db.completion_times(start, window_end).await?;
+        assert_eq!(times.completed, 20);
+        assert_eq!(times.average_secs, Some(10.5));
+        assert_eq!(times.p95_secs, Some(19.0));
+
+        // An empty window has no times rather than zeros
+        let empty = db.completion_times(start - chrono::Duration::hours(2), start - chrono::Duration::hours(1)).await?;
+        assert_eq!(empty, relayer::database::CompletionTimes::default());
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_far_future_partial_is_rejected_as_clock_skew() -> TestResult<()> {
+    use crate::common::setup::{create_test_relayer_config, TestSigners};
+    use relayer::{policy::RelayerPolicy, signature_coordinator::SignatureCoordinator, RelayerError};
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime};
+    use threshold::{PartialSignature, SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let mut config = create_test_relayer_config();
+        config.validator.private_key = None;
+        let timeouts = RelayerPolicy::from_config(&config).timeouts;
+
+        let signers = TestSigners::new(&["validator_1", "validator_2", "validator_3"]).await?;
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(2, 3, 256)?)?);
+        let coordinator = SignatureCoordinator::new(config.validator, manager, db, timeouts)
+            .await?
+            .with_public_key_shares(&signers.public_key_shares()?);
+
+        let tx_hash = test_tx_hash(&format!("{}_skew", generate_test_id()));
+        coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &tx_hash).await?;
+
+        let signed = signers.partial(&coordinator, &tx_hash, "validator_1").await?;
+        let partial = |timestamp: SystemTime| PartialSignature { timestamp, ..signed.clone() };
+
+        let far_future = SystemTime::now() + Duration::from_secs(3600);
+        let result = coordinator
+            .add_partial_signature(&tx_hash, "validator_1", "10.0.0.1:30333", partial(far_future))
+            .await;
+        match result {
+            Err(RelayerError::ClockSkew { validator_id, offset_secs, tolerance_secs }) => {
+                assert_eq!(validator_id, "validator_1");
+                assert!(offset_secs > 3500);
+                assert_eq!(tolerance_secs, timeouts.max_clock_skew.as_secs());
+            }
+            other => panic!("expected a clock skew error, got {:?}", other),
+        }
+
+        // A partial within the tolerance is accepted
+        let slightly_behind = SystemTime::now() - Duration::from_secs(5);
+        coordinator
+            .add_partial_signature(&tx_hash, "validator_1", "10.0.0.1:30333", partial(slightly_behind))
+            .await?;
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_oversized_session_message_is_rejected() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::{checkpoint::BridgeCheckpoint, policy::RelayerPolicy, signature_coordinator::SignatureCoordinator, RelayerError};
+    use std::sync::Arc;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let mut config = create_test_relayer_config();
+        config.validator.private_key = None;
+        let timeouts = RelayerPolicy::from_config(&config).timeouts;
+
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(2, 3, 256)?)?);
+        let coordinator = SignatureCoordinator::new(config.validator, manager, db, timeouts)
+            .await?
+            .with_max_message_size(256);
+
+        // A state root no real checkpoint has pushes its message over the cap
+        let checkpoint = BridgeCheckpoint {
+            last_ethereum_block: 100,
+            last_polkadot_block: 200,
+            ethereum_processed: 1,
+            polkadot_processed: 1,
+            state_root: "f".repeat(300),
+        };
+        let result = coordinator.request_checkpoint_signature(&checkpoint).await;
+        match result {
+            Err(RelayerError::MessageTooLarge { tx_hash: rejected, size, max }) => {
+                assert_eq!(rejected, checkpoint.session_id());
+                assert!(size > 300);
+                assert_eq!(max, 256);
+            }
+            other => panic!("expected an oversized message error, got {:?}", other),
+        }
+        assert!(coordinator.session(&checkpoint.session_id()).await.is_none());
+
+        // Transfers still open their sessions; their digests are always small
+        let tx_hash = test_tx_hash(&format!("{}_unlock", generate_test_id()));
+        coordinator.request_unlock_signature("0x742d35Cc6634C0532925a3b844Bc454e4438f44e", TEST_TOKEN, "1000", &tx_hash, 1337).await?;
+        assert_eq!(coordinator.session(&tx_hash).await.map(|s| s.message.len()), Some(32));
+        let tx_hash = test_tx_hash(&format!("{}_mint", generate_test_id()));
+        coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &tx_hash).await?;
+        assert_eq!(coordinator.session(&tx_hash).await.map(|s| s.message.len()), Some(32));
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_partials_are_verified_before_joining_a_session() -> TestResult<()> {
+    use crate::common::setup::{create_test_relayer_config, TestSigners};
+    use relayer::{polkadot, policy::RelayerPolicy, signature_coordinator::SignatureCoordinator, RelayerError};
+    use std::sync::Arc;
+    use threshold::{utils, SimpleThresholdManager, ThresholdConfig, ThresholdError};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let mut config = create_test_relayer_config();
+        config.validator.private_key = None;
+        let timeouts = RelayerPolicy::from_config(&config).timeouts;
+
+        let signers = TestSigners::new(&["validator_1", "validator_2", "validator_3"]).await?;
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(2, 3, 256)?)?);
+        let coordinator = SignatureCoordinator::new(config.validator, manager, db, timeouts)
+            .await?
+            .with_public_key_shares(&signers.public_key_shares()?);
+
+        let tx_hash = test_tx_hash(&format!("{}_verified", generate_test_id()));
+        coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &tx_hash).await?;
+        let message = polkadot::mint_message(TEST_RECIPIENT, TEST_TOKEN, "1000", &tx_hash)?;
+
+        // Signed over the wrong amount, by another validator, or as a
+        // threshold partial the pallet cannot recover a signer from
+        let wrong_message = polkadot::mint_message(TEST_RECIPIENT, TEST_TOKEN, "9999", &tx_hash)?;
+        let wrong_message = signers.sign_recoverable("validator_1", &wrong_message)?;
+        let mut wrong_signer = signers.sign_recoverable("validator_2", &message)?;
+        wrong_signer.validator_id = "validator_1".to_string();
+        let wrong_format = signers.sign("validator_1", &tx_hash, &message).await?;
+        for partial in [wrong_message, wrong_signer, wrong_format] {
+            let result = coordinator.add_partial_signature(&tx_hash, "validator_1", "10.0.0.1:30333", partial).await;
+            assert!(matches!(
+                result,
+                Err(RelayerError::ThresholdSignature(ThresholdError::InvalidSignature { .. }))
+            ));
+        }
+        assert!(coordinator.session(&tx_hash).await.unwrap().partial_signatures.is_empty());
+
+        // The accepted partial is what the pallet checks: a 65-byte
+        // signature recovering to the validator's Ethereum address
+        let partial = signers.sign_recoverable("validator_1", &message)?;
+        coordinator.add_partial_signature(&tx_hash, "validator_1", "10.0.0.1:30333", partial).await?;
+        let session = coordinator.session(&tx_hash).await.unwrap();
+        let signature = ethers::types::Signature::try_from(session.partial_signatures["validator_1"].signature.as_slice())?;
+        let signer = signature.recover(ethers::types::H256::from(message))?;
+        assert_eq!(signer.as_bytes(), utils::ethereum_address(&signers.key_share("validator_1").public_share)?);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_unlock_calldata_signatures_recover_to_validators() -> TestResult<()> {
+    use crate::common::setup::{create_test_relayer_config, TestSigners};
+    use ethers::{
+        abi::{self, ParamType, Token},
+        types::{Address, Signature, H256},
+    };
+    use relayer::{ethereum, policy::RelayerPolicy, signature_coordinator::SignatureCoordinator};
+    use std::{collections::HashSet, sync::Arc};
+    use threshold::{utils, SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let mut config = create_test_relayer_config();
+        config.validator.private_key = None;
+        let timeouts = RelayerPolicy::from_config(&config).timeouts;
+
+        let signers = TestSigners::new(&["validator_1", "validator_2", "validator_3"]).await?;
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(2, 3, 256)?)?);
+        let coordinator = SignatureCoordinator::new(config.validator, manager, db, timeouts)
+            .await?
+            .with_public_key_shares(&signers.public_key_shares()?);
+
+        let user = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
+        let tx_hash = test_tx_hash(&format!("{}_unlock", generate_test_id()));
+        coordinator.request_unlock_signature(user, TEST_TOKEN, "1000", &tx_hash, 1337).await?;
+        assert!(coordinator.get_unlock_signatures(&tx_hash).await?.is_none());
+
+        // Partials are signed over the contract's digest for this chain
+        let digest = ethereum::unlock_message(user, TEST_TOKEN, "1000", &tx_hash, 1337)?;
+        assert_eq!(coordinator.session(&tx_hash).await.unwrap().message, digest.to_vec());
+        for validator_id in ["validator_1", "validator_3"] {
+            let partial = signers.partial(&coordinator, &tx_hash, validator_id).await?;
+            coordinator.add_partial_signature(&tx_hash, validator_id, validator_id, partial).await?;
+        }
+
+        // The calldata carries what `unlockTokens` recovers: one 65-byte
+        // signature per validator, each recovering to its Ethereum address
+        let signatures = coordinator.get_unlock_signatures(&tx_hash).await?.expect("threshold reached");
+        let calldata = ethereum::unlock_calldata(user, TEST_TOKEN, "1000", &tx_hash, &signatures)?;
+        let args = abi::decode(
+            &[
+                ParamType::Address,
+                ParamType::Address,
+                ParamType::Uint(256),
+                ParamType::FixedBytes(32),
+                ParamType::Array(Box::new(ParamType::Bytes)),
+            ],
+            &calldata[4..],
+        )?;
+        let Some(Token::Array(encoded)) = args.last() else {
+            panic!("unlockTokens takes its signatures last");
+        };
+
+        let mut recovered = HashSet::new();
+        for signature in encoded {
+            let Token::Bytes(signature) = signature else {
+                panic!("signatures are bytes");
+            };
+            assert_eq!(signature.len(), 65);
+            recovered.insert(Signature::try_from(signature.as_slice())?.recover(H256::from(digest))?);
+        }
+        let validators: HashSet<Address> = ["validator_1", "validator_3"]
+            .into_iter()
+            .map(|id| Ok(Address::from_slice(&utils::ethereum_address(&signers.key_share(id).public_share)?)))
+            .collect::<TestResult<_>>()?;
+        assert_eq!(recovered, validators);
+
+        // The same signatures do not authorize the unlock on another chain
+        let elsewhere = ethereum::unlock_message(user, TEST_TOKEN, "1000", &tx_hash, 1)?;
+        for signature in &signatures {
+            let signer = Signature::try_from(signature.as_slice())?.recover(H256::from(elsewhere))?;
+            assert!(!validators.contains(&signer));
+        }
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_reconcile_lists_only_unmatched_transfers() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        let db = setup_test_database().await?;
+        let id = generate_test_id();
+
+        let matched_lock = format!("0x{}_lock_matched", id);
+        let unmatched_lock = format!("0x{}_lock_unmatched", id);
+        let matched_burn = format!("0x{}_burn_matched", id);
+        let unmatched_burn = format!("0x{}_burn_unmatched", id);
+
+        db.store_ethereum_lock("0xuser", "0xtoken", "1000", "5Grw", &matched_lock, 100).await?;
+        db.store_ethereum_lock("0xuser", "0xtoken", "1000", "5Grw", &unmatched_lock, 101).await?;
+        db.store_polkadot_burn("5Grw", 1, "1000", "0xrecipient", &matched_burn, 100).await?;
+        db.store_polkadot_burn("5Grw", 1, "1000", "0xrecipient", &unmatched_burn, 101).await?;
+
+        db.mark_ethereum_tx_processed(&matched_lock).await?;
+        db.mark_polkadot_tx_processed(&matched_burn).await?;
+
+        let ours = |records: Vec<relayer::database::TransactionRecord>| -> Vec<String> {
+            records.into_iter()
+                .map(|r| r.tx_hash)
+                .filter(|h| h.contains(&id))
+                .collect()
+        };
+
+        assert_eq!(ours(db.unmatched_locks().await?), vec![unmatched_lock]);
+        assert_eq!(ours(db.unmatched_burns().await?), vec![unmatched_burn]);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_two_heavy_validators_meet_weighted_threshold() -> TestResult<()> {
+    use crate::common::setup::{create_test_relayer_config, TestSigners};
+    use relayer::{
+        policy::RelayerPolicy,
+        signature_coordinator::{SignatureCoordinator, WeightedThreshold},
+    };
+    use std::sync::Arc;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let mut config = create_test_relayer_config();
+        config.validator.private_key = None;
+        config.threshold.validator_weights = relayer::config::parse_validator_weights("validator_1=2,validator_2=2")?;
+        config.threshold.weighted_threshold = Some(3);
+        let timeouts = RelayerPolicy::from_config(&config).timeouts;
+        let weighted = WeightedThreshold::from_config(&config.threshold);
+
+        let signers = TestSigners::new(&["validator_1", "validator_2", "validator_3", "validator_4"]).await?;
+        let public_key_shares = signers.public_key_shares()?;
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(3, 4, 256)?)?);
+        let unweighted = SignatureCoordinator::new(config.validator.clone(), manager.clone(), db.clone(), timeouts)
+            .await?
+            .with_public_key_shares(&public_key_shares);
+        let weighted = SignatureCoordinator::new(config.validator, manager, db, timeouts)
+            .await?
+            .with_weighted_threshold(weighted)
+            .with_public_key_shares(&public_key_shares);
+
+        let id = generate_test_id();
+        for (coordinator, tx_hash) in [(&unweighted, test_tx_hash(&format!("{}_equal", id))), (&weighted, test_tx_hash(&format!("{}_weighted", id)))] {
+            coordinator.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &tx_hash).await?;
+            for (validator_id, source) in [("validator_1", "10.0.0.1:30333"), ("validator_2", "10.0.0.2:30333")] {
+                let partial = signers.partial(coordinator, &tx_hash, validator_id).await?;
+                coordinator.add_partial_signature(&tx_hash, validator_id, source, partial).await?;
+            }
+        }
+
+        // Two equal-weight partials fall short of 3-of-4 ...
+        let equal = test_tx_hash(&format!("{}_equal", id));
+        assert!(unweighted.get_mint_signatures(&equal).await?.is_none());
+        let partial = signers.partial(&unweighted, &equal, "validator_3").await?;
+        unweighted.add_partial_signature(&equal, "validator_3", "10.0.0.3:30333", partial).await?;
+        assert_eq!(unweighted.get_mint_signatures(&equal).await?.map(|s| s.len()), Some(3));
+
+        // ... but two validators of weight 2 together reach a weighted threshold of 3
+        let heavy = test_tx_hash(&format!("{}_weighted", id));
+        assert_eq!(weighted.get_mint_signatures(&heavy).await?.map(|s| s.len()), Some(2));
+
+        // A heavy and a default-weight validator do not
+        let mixed = test_tx_hash(&format!("{}_mixed", id));
+        weighted.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", &mixed).await?;
+        let partial = signers.partial(&weighted, &mixed, "validator_1").await?;
+        weighted.add_partial_signature(&mixed, "validator_1", "10.0.0.1:30333", partial).await?;
+        assert!(weighted.get_mint_signatures(&mixed).await?.is_none());
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_replayed_lock_is_minted_once() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::{
+        allowlist::RecipientAllowlist,
+        coordinator::{BridgeEvent, MintPipeline},
+        metrics::{DuplicateEvents, RelayerMetrics},
+        mint_batcher::MintBatcher,
+        policy::RelayerPolicy,
+        event_sink::NoopEventSink,
+        signature_coordinator::SignatureCoordinator,
+    };
+    use std::sync::Arc;
+    use std::time::Duration;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let config = create_test_relayer_config();
+        let timeouts = RelayerPolicy::from_config(&config).timeouts;
+
+        // A 1-of-1 validator signs each lock on its own
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(1, 1, 256)?)?);
+        let signatures = SignatureCoordinator::new(config.validator.clone(), manager, db.clone(), timeouts).await?;
+        let submitter = Arc::new(RecordingSubmitter::default());
+        let batcher = MintBatcher::new(submitter.clone(), 1, Duration::from_secs(60));
+        let duplicates = DuplicateEvents::new();
+        let metrics = RelayerMetrics::new();
+        let allowlist = RecipientAllowlist::new(vec![]);
+
+        let pipeline = MintPipeline {
+            database: &db,
+            duplicate_events: &duplicates,
+            metrics: &metrics,
+            recipient_allowlist: &allowlist,
+            signature_coordinator: &signatures,
+            mint_batcher: &batcher,
+            event_sink: &NoopEventSink,
+            validator_enabled: config.validator.enabled,
+        };
+
+        let tx_hash = test_tx_hash(&format!("{}_replay", generate_test_id()));
+        let event = BridgeEvent::EthereumLock {
+            user: "0xuser".to_string(),
+            token: TEST_TOKEN.to_string(),
+            amount: "1000".to_string(),
+            polkadot_address: TEST_RECIPIENT.to_string(),
+            tx_hash: tx_hash.clone(),
+            block_number: 100,
+        };
+
+        pipeline.relay(&event).await?;
+        pipeline.relay(&event).await?;
+
+        assert_eq!(*submitter.mints.lock().unwrap(), vec![tx_hash.clone()]);
+        assert_eq!(duplicates.count("ethereum"), 1);
+        assert!(db.is_ethereum_tx_processed(&tx_hash).await?);
+
+        let url = std::env::var("DATABASE_URL")
+            .unwrap_or_else(|_| TestConfig::default().database_url);
+        let pool = PgPool::connect(&url).await?;
+        let processed: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM processed_transactions WHERE tx_hash = $1 AND chain = 'ethereum'",
+        )
+        .bind(&tx_hash)
+        .fetch_one(&pool)
+        .await?;
+        assert_eq!(processed, 1);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_stale_event_is_held_until_released() -> TestResult<()> {
+    use relayer::{coordinator::BridgeEvent, event_monitor::StaleEventStore};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = setup_test_database().await?;
+        let tx_hash = test_tx_hash(&format!("{}_stale", generate_test_id()));
+        let event = BridgeEvent::PolkadotBurn {
+            user: "5Grw".to_string(),
+            asset_id: 7,
+            amount: "1000".to_string(),
+            ethereum_recipient: "0xrecipient".to_string(),
+            tx_hash: tx_hash.clone(),
+            block_number: 3,
+        };
+
+        db.hold_stale_event(&event).await?;
+        let record = db.get_transaction_by_hash(&tx_hash).await?.expect("held event stored");
+        assert_eq!(record.status, "skipped_stale");
+        assert!(!db.is_polkadot_tx_processed(&tx_hash).await?);
+
+        // Redelivery of the held event does not flag it again once released
+        let released = db.release_stale_event(&tx_hash).await?.expect("event was held");
+        assert_eq!(released.tx_hash(), tx_hash);
+        assert_eq!(released.block_number(), 3);
+        db.hold_stale_event(&event).await?;
+
+        let record = db.get_transaction_by_hash(&tx_hash).await?.expect("event stored");
+        assert_eq!(record.status, "pending");
+        assert!(db.release_stale_event(&tx_hash).await?.is_none());
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_each_processed_event_is_published_once() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::{
+        allowlist::RecipientAllowlist,
+        coordinator::{BridgeEvent, MintPipeline},
+        event_sink::{EventSink, EventStatus, PublishedEvent},
+        metrics::{DuplicateEvents, RelayerMetrics},
+        mint_batcher::MintBatcher,
+        policy::RelayerPolicy,
+        signature_coordinator::SignatureCoordinator,
+    };
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    /// Keeps every published event in memory
+    #[derive(Default)]
+    struct MemorySink {
+        published: Mutex<Vec<PublishedEvent>>,
+    }
+
+    #[async_trait::async_trait]
+    impl EventSink for MemorySink {
+        async fn publish(&self, event: &PublishedEvent) -> relayer::Result<()> {
+            self.published.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let config = create_test_relayer_config();
+        let timeouts = RelayerPolicy::from_config(&config).timeouts;
+
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(1, 1, 256)?)?);
+        let signatures = SignatureCoordinator::new(config.validator.clone(), manager, db.clone(), timeouts).await?;
+        let batcher = MintBatcher::new(Arc::new(RecordingSubmitter::default()), 1, Duration::from_secs(60));
+        let duplicates = DuplicateEvents::new();
+        let metrics = RelayerMetrics::new();
+        let allowlist = RecipientAllowlist::new(vec![]);
+        let sink = MemorySink::default();
+
+        let pipeline = MintPipeline {
+            database: &db,
+            duplicate_events: &duplicates,
+            metrics: &metrics,
+            recipient_allowlist: &allowlist,
+            signature_coordinator: &signatures,
+            mint_batcher: &batcher,
+            event_sink: &sink,
+            validator_enabled: config.validator.enabled,
+        };
+
+        let test_id = generate_test_id();
+        let tx_hashes: Vec<String> = (0..2).map(|i| test_tx_hash(&format!("{}_sink_{}", test_id, i))).collect();
+        for tx_hash in &tx_hashes {
+            let event = BridgeEvent::EthereumLock {
+                user: "0xuser".to_string(),
+                token: TEST_TOKEN.to_string(),
+                amount: "1000".to_string(),
+                polkadot_address: TEST_RECIPIENT.to_string(),
+                tx_hash: tx_hash.clone(),
+                block_number: 100,
+            };
+
+            // Redelivery is suppressed before it reaches the sink
+            pipeline.relay(&event).await?;
+            pipeline.relay(&event).await?;
+        }
+
+        let published = sink.published.lock().unwrap();
+        let published_hashes: Vec<&str> = published.iter().map(|event| event.tx_hash.as_str()).collect();
+        assert_eq!(published_hashes, tx_hashes.iter().map(String::as_str).collect::<Vec<_>>());
+        assert!(published.iter().all(|event| event.status == EventStatus::Processed && event.chain == "ethereum"));
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_lock_to_all_zero_destination_fails_without_minting() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::{
+        allowlist::RecipientAllowlist,
+        coordinator::{BridgeEvent, MintPipeline},
+        event_sink::{EventSink, EventStatus, PublishedEvent},
+        metrics::{DuplicateEvents, RelayerMetrics},
+        mint_batcher::MintBatcher,
+        policy::RelayerPolicy,
+        signature_coordinator::SignatureCoordinator,
+        RelayerError,
+    };
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use threshold::{SimpleThresholdManager, ThresholdConfig};
+
+    /// Keeps every published event in memory
+    #[derive(Default)]
+    struct MemorySink {
+        published: Mutex<Vec<PublishedEvent>>,
+    }
+
+    #[async_trait::async_trait]
+    impl EventSink for MemorySink {
+        async fn publish(&self, event: &PublishedEvent) -> relayer::Result<()> {
+            self.published.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let config = create_test_relayer_config();
+        let timeouts = RelayerPolicy::from_config(&config).timeouts;
+
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(1, 1, 256)?)?);
+        let signatures = SignatureCoordinator::new(config.validator.clone(), manager, db.clone(), timeouts).await?;
+        let submitter = Arc::new(RecordingSubmitter::default());
+        let batcher = MintBatcher::new(submitter.clone(), 1, Duration::from_secs(60));
+        let duplicates = DuplicateEvents::new();
+        let metrics = RelayerMetrics::new();
+        let allowlist = RecipientAllowlist::new(vec![]);
+        let sink = MemorySink::default();
+
+        let pipeline = MintPipeline {
+            database: &db,
+            duplicate_events: &duplicates,
+            metrics: &metrics,
+            recipient_allowlist: &allowlist,
+            signature_coordinator: &signatures,
+            mint_batcher: &batcher,
+            event_sink: &sink,
+            validator_enabled: config.validator.enabled,
+        };
+
+        let tx_hash = test_tx_hash(&format!("{}_zero_destination", generate_test_id()));
+        let event = BridgeEvent::EthereumLock {
+            user: "0xuser".to_string(),
+            token: TEST_TOKEN.to_string(),
+            amount: "1000".to_string(),
+            polkadot_address: format!("0x{}", "00".repeat(32)),
+            tx_hash: tx_hash.clone(),
+            block_number: 100,
+        };
+
+        let err = pipeline.relay(&event).await.unwrap_err();
+        assert!(matches!(err, RelayerError::InvalidDestination { .. }));
+        assert!(submitter.mints.lock().unwrap().is_empty());
+        assert!(signatures.session(&tx_hash).await.is_none());
+
+        let published = sink.published.lock().unwrap();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].status, EventStatus::Failed);
+        assert_eq!(published[0].reason, Some("invalid_destination"));
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_checkpoint_is_signed_by_threshold_and_served() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::{checkpoint::Checkpointer, policy::RelayerPolicy, signature_coordinator::SignatureCoordinator};
+    use std::sync::Arc;
+    use threshold::{utils, SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let mut config = create_test_relayer_config();
+        config.validator.private_key = None;
+
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(2, 3, 256)?)?);
+        let validator_ids = utils::generate_test_validator_ids(3);
+        let key_shares = manager.generate_key_shares(&validator_ids).await?;
+        let public_key_shares = utils::extract_public_key_shares(&key_shares)?;
+
+        let signatures = Arc::new(SignatureCoordinator::new(
+            config.validator,
+            manager.clone(),
+            db.clone(),
+            RelayerPolicy::from_config(&create_test_relayer_config()).timeouts,
+        ).await?
+        .with_public_key_shares(&public_key_shares));
+        let checkpointer = Checkpointer::new(db.clone(), signatures.clone(), public_key_shares.clone());
+
+        let tx_hash = test_tx_hash(&format!("{}_checkpointed", generate_test_id()));
+        db.mark_ethereum_tx_processed(&tx_hash).await?;
+
+        // The first tick takes a checkpoint and waits for signatures
+        assert!(checkpointer.tick().await?.is_none());
+        let checkpoint = checkpointer.pending().await.expect("checkpoint awaiting signatures");
+        assert!(checkpoint.ethereum_processed >= 1);
+        assert_eq!(checkpoint.state_root.len(), 66);
+
+        let session_id = checkpoint.session_id();
+        for validator_id in validator_ids.iter().take(2) {
+            let partial = manager
+                .create_partial_signature(&key_shares[validator_id], &checkpoint.message(), &session_id)
+                .await?;
+            signatures.add_partial_signature(&session_id, validator_id, validator_id, partial).await?;
+        }
+
+        // Once the threshold has signed, the next tick stores it
+        let signed = checkpointer.tick().await?.expect("signed checkpoint");
+        assert_eq!(signed.checkpoint, checkpoint);
+        assert!(checkpointer.pending().await.is_none());
+        assert!(signed.verify(&manager, &public_key_shares).await?);
+
+        // A checkpoint altered after signing no longer verifies
+        let mut altered = signed.clone();
+        altered.checkpoint.ethereum_processed += 1;
+        assert!(!altered.verify(&manager, &public_key_shares).await?);
+
+        // The stored checkpoint is served as the latest
+        let latest = db.latest_checkpoint().await?.expect("latest checkpoint");
+        assert_eq!(latest.id, signed.id);
+        assert!(latest.verify(&manager, &public_key_shares).await?);
+        let body = serde_json::to_value(&latest)?;
+        assert_eq!(body["checkpoint"]["state_root"], checkpoint.state_root);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_migrations_apply_once_and_detect_version_mismatch() -> TestResult<()> {
+    use relayer::{config::DatabaseConfig, database::{Database, MIGRATIONS}};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let url = std::env::var("DATABASE_URL")
+            .unwrap_or_else(|_| TestConfig::default().database_url);
+        let admin = PgPool::connect(&url).await?;
+
+        // A schema of its own, so editing schema_migrations cannot affect other tests
+        let schema = format!("migrations_{}", generate_test_id());
+        sqlx::query(&format!("CREATE SCHEMA {}", schema)).execute(&admin).await?;
+        let separator = if url.contains('?') { '&' } else { '?' };
+        let schema_url = format!("{}{}options[search_path]={}", url, separator, schema);
+
+        let db = Database::new(&DatabaseConfig {
+            url: schema_url.clone(),
+            max_connections: 2,
+            min_connections: 1,
+            connect_timeout: 10,
+            query_timeout: 30,
+        })
+        .await?;
+        let all_versions: Vec<i64> = MIGRATIONS.iter().map(|m| m.version).collect();
+        assert_eq!(db.applied_migrations().await?, all_versions);
+
+        // Running again changes nothing
+        db.migrate().await?;
+        assert_eq!(db.applied_migrations().await?, all_versions);
+
+        // An older binary refuses a database migrated past what it knows
+        let err = db.apply_migrations(&MIGRATIONS[..MIGRATIONS.len() - 1]).await.unwrap_err();
+        assert!(err.to_string().contains("newer version"), "{}", err);
+
+        // A step missing below the latest applied one is reported, not applied out of order
+        let schema_pool = PgPool::connect(&schema_url).await?;
+        sqlx::query("DELETE FROM schema_migrations WHERE version = 2").execute(&schema_pool).await?;
+        let err = db.migrate().await.unwrap_err();
+        assert!(err.to_string().contains("Migration 2"), "{}", err);
+        assert_eq!(db.applied_migrations().await?, vec![1, 3, 4, 5, 6, 7, 8]);
+
+        schema_pool.close().await;
+        db.close().await?;
+        sqlx::query(&format!("DROP SCHEMA {} CASCADE", schema)).execute(&admin).await?;
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_sqlite_in_memory_database_stores_and_reads_back_a_lock() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::database::{Backend, Database, MIGRATIONS};
+
+    init_test_logging();
+
+    with_timeout(async {
+        // The test relayer config points at in-memory SQLite; no server needed
+        let db = Database::new(&create_test_relayer_config().database).await?;
+        assert_eq!(db.backend(), Backend::Sqlite);
+        let all_versions: Vec<i64> = MIGRATIONS.iter().map(|m| m.version).collect();
+        assert_eq!(db.applied_migrations().await?, all_versions);
+
+        assert!(db.store_ethereum_lock("0xUser", "0xToken", "1000", "5Grw", "0xsqlite_lock", 42).await?);
+        assert!(!db.store_ethereum_lock("0xUser", "0xToken", "1000", "5Grw", "0xsqlite_lock", 42).await?);
+        assert!(db.store_ethereum_lock("0xUser", "0xToken", "2500", "5Grw", "0xsqlite_lock_2", 43).await?);
+
+        let lock = db.get_transaction_by_hash("0xsqlite_lock").await?.expect("stored lock");
+        assert_eq!(lock.chain, "ethereum");
+        assert_eq!(lock.status, "pending");
+        assert_eq!(lock.user, "0xUser");
+        assert_eq!(lock.amount, "1000");
+        assert_eq!(lock.recipient, "5Grw");
+        assert_eq!(lock.block_number, 42);
+
+        db.mark_ethereum_tx_processed("0xsqlite_lock").await?;
+        assert!(db.is_ethereum_tx_processed("0xsqlite_lock").await?);
+        assert_eq!(db.get_transaction_by_hash("0xsqlite_lock").await?.expect("stored lock").status, "confirmed");
+        assert_eq!(db.unmatched_locks().await?.len(), 1);
+
+        // Volumes are summed exactly even though SQLite cannot do it in SQL
+        let stats = db.token_stats().await?;
+        assert_eq!(stats["0xtoken"].transfers_in, 2);
+        assert_eq!(stats["0xtoken"].volume, "3500");
+
+        db.close().await?;
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_late_partial_after_submission_is_discarded() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use relayer::{
+        allowlist::RecipientAllowlist,
+        coordinator::{BridgeEvent, MintPipeline},
+        event_sink::NoopEventSink,
+        metrics::{DuplicateEvents, RelayerMetrics},
+        mint_batcher::MintBatcher,
+        policy::RelayerPolicy,
+        signature_coordinator::SignatureCoordinator,
+    };
+    use std::sync::Arc;
+    use std::time::Duration;
+    use threshold::{PartialSignature, SimpleThresholdManager, ThresholdConfig};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(setup_test_database().await?);
+        let config = create_test_relayer_config();
+        let timeouts = RelayerPolicy::from_config(&config).timeouts;
+
+        // A 1-of-1 validator submits as soon as it has signed
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(1, 1, 256)?)?);
+        let signatures = SignatureCoordinator::new(config.validator.clone(), manager, db.clone(), timeouts).await?;
+        let submitter = Arc::new(RecordingSubmitter::default());
+        let batcher = MintBatcher::new(submitter.clone(), 1, Duration::from_secs(60));
+        let duplicates = DuplicateEvents::new();
+        let metrics = RelayerMetrics::new();
+        let allowlist = RecipientAllowlist::new(vec![]);
+
+        let pipeline = MintPipeline {
+            database: &db,
+            duplicate_events: &duplicates,
+            metrics: &metrics,
+            recipient_allowlist: &allowlist,
+            signature_coordinator: &signatures,
+            mint_batcher: &batcher,
+            event_sink: &NoopEventSink,
+            validator_enabled: config.validator.enabled,
+        };
+
+        let tx_hash = test_tx_hash(&format!("{}_late", generate_test_id()));
+        let event = BridgeEvent::EthereumLock {
+            user: "0xuser".to_string(),
+            token: TEST_TOKEN.to_string(),
+            amount: "1000".to_string(),
+            polkadot_address: TEST_RECIPIENT.to_string(),
+            tx_hash: tx_hash.clone(),
+            block_number: 100,
+        };
+
+        pipeline.relay(&event).await?;
+        assert!(signatures.is_completed(&tx_hash).await);
+        assert!(signatures.session(&tx_hash).await.is_none());
+
+        // A slower validator's partial arrives by gossip after the mint went out
+        let late = PartialSignature {
+            validator_id: "validator_2".to_string(),
+            signature: vec![2u8; 64],
+            commitment: None,
+            timestamp: std::time::SystemTime::now(),
+            key_epoch: 0,
+        };
+        signatures.add_partial_signature(&tx_hash, "validator_2", "10.0.0.2:30333", late).await?;
+
+        assert_eq!(signatures.late_partial_count(), 1);
+        assert!(signatures.session(&tx_hash).await.is_none());
+        assert!(signatures.get_mint_signatures(&tx_hash).await?.is_none());
+
+        // Processing the lock again neither re-signs nor re-submits it
+        pipeline.process(&event).await?;
+        assert_eq!(*submitter.mints.lock().unwrap(), vec![tx_hash.clone()]);
+        assert_eq!(signatures.aggregation_count(), 1);
+
+        Ok(())
+    }).await
+}
@@ -73,3 +73,403 @@ async fn test_ethereum_contract_interaction() -> TestResult<()> {
         Ok(())
     }).await
 }
+
+#[tokio::test]
+async fn test_past_lock_events_decode_logs_in_batches() -> TestResult<()> {
+    use ethers::{
+        abi::{self, Token},
+        contract::EthEvent,
+        providers::Provider,
+        types::{Address, Log, H256, U256, U64},
+    };
+    use relayer::ethereum::{fetch_lock_events, lock_event_filter, BridgeLockEvent};
+
+    init_test_logging();
+
+    with_timeout(async {
+        let contract: Address = "0x1234567890123456789012345678901234567890".parse()?;
+        let user: Address = "0x00000000000000000000000000000000000000aa".parse()?;
+        let token: Address = "0x00000000000000000000000000000000000000bb".parse()?;
+        let polkadot_address = H256::repeat_byte(0x42);
+        let tx_hash = H256::repeat_byte(0x11);
+
+        let lock_log = |removed: bool| Log {
+            address: contract,
+            topics: vec![
+                BridgeLockEvent::signature(),
+                H256::from(user),
+                H256::from(token),
+                polkadot_address,
+            ],
+            data: abi::encode(&[Token::Uint(U256::from(1_000u64)), Token::Uint(U256::from(7u64))]).into(),
+            block_hash: Some(H256::repeat_byte(0x22)),
+            block_number: Some(U64::from(150)),
+            transaction_hash: Some(tx_hash),
+            transaction_index: Some(U64::from(0)),
+            log_index: Some(U256::from(3)),
+            removed: Some(removed),
+            ..Default::default()
+        };
+
+        // Blocks 100..=349 in batches of 100 take three requests; one returns
+        // the lock alongside a copy a reorg removed
+        let (provider, mock) = Provider::mocked();
+        mock.push::<Vec<Log>, _>(vec![])?;
+        mock.push::<Vec<Log>, _>(vec![lock_log(false), lock_log(true)])?;
+        mock.push::<Vec<Log>, _>(vec![])?;
+
+        let events = fetch_lock_events(&provider, contract, 100, 349, 100).await?;
+
+        assert_eq!(events.len(), 1);
+        let (event, meta) = &events[0];
+        assert_eq!(event.user, user);
+        assert_eq!(event.token, token);
+        assert_eq!(event.amount, U256::from(1_000u64));
+        assert_eq!(event.polkadot_address, polkadot_address);
+        assert_eq!(event.nonce, U256::from(7u64));
+        assert_eq!(meta.transaction_hash, tx_hash);
+        assert_eq!(meta.block_number, U64::from(150));
+
+        mock.assert_request("eth_getLogs", [lock_event_filter(contract, 100, 199)])?;
+        mock.assert_request("eth_getLogs", [lock_event_filter(contract, 200, 299)])?;
+        mock.assert_request("eth_getLogs", [lock_event_filter(contract, 300, 349)])?;
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_unlock_encodes_call_and_reports_reverts() -> TestResult<()> {
+    use ethers::{
+        abi::{self, ParamType, Token},
+        middleware::SignerMiddleware,
+        providers::{JsonRpcError, MockResponse, Provider},
+        signers::{LocalWallet, Signer},
+        types::{Address, Bytes, H256, U256},
+        utils::id,
+    };
+    use relayer::{
+        config::GasStrategy,
+        ethereum::{send_unlock, unlock_calldata, GasOracle},
+        nonce_manager::NonceManager,
+    };
+
+    init_test_logging();
+
+    with_timeout(async {
+        let user = "0x00000000000000000000000000000000000000aa";
+        let token = "0x00000000000000000000000000000000000000bb";
+        let polkadot_tx_hash = format!("0x{}", "33".repeat(32));
+        let signatures = vec![vec![1u8; 65], vec![2u8; 65]];
+
+        let calldata = unlock_calldata(user, token, "1000", &polkadot_tx_hash, &signatures)?;
+
+        assert_eq!(calldata[..4], id("unlockTokens(address,address,uint256,bytes32,bytes[])"));
+        let args = abi::decode(
+            &[
+                ParamType::Address,
+                ParamType::Address,
+                ParamType::Uint(256),
+                ParamType::FixedBytes(32),
+                ParamType::Array(Box::new(ParamType::Bytes)),
+            ],
+            &calldata[4..],
+        )?;
+        assert_eq!(args, vec![
+            Token::Address(user.parse()?),
+            Token::Address(token.parse()?),
+            Token::Uint(U256::from(1000)),
+            Token::FixedBytes(vec![0x33; 32]),
+            Token::Array(vec![Token::Bytes(vec![1u8; 65]), Token::Bytes(vec![2u8; 65])]),
+        ]);
+
+        let wallet: LocalWallet = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+            .parse::<LocalWallet>()?
+            .with_chain_id(1337u64);
+        let contract: Address = "0x1234567890123456789012345678901234567890".parse()?;
+        let static_gas = GasOracle::new(GasStrategy::Static, 300_000, 20_000_000_000);
+
+        // The mock answers newest-first: nonce, then the simulation, then the send
+        let (provider, mock) = Provider::mocked();
+        let sent = H256::repeat_byte(0x44);
+        mock.push::<H256, _>(sent)?;
+        mock.push::<Bytes, _>(Bytes::default())?;
+        mock.push::<U256, _>(U256::from(5))?;
+
+        let client = SignerMiddleware::new(provider, wallet.clone());
+        let nonces = NonceManager::new(wallet.address());
+        let tx_hash = send_unlock(&client, &nonces, contract, calldata.clone(), &static_gas).await?;
+        assert_eq!(tx_hash, sent);
+
+        // A revert is reported with the contract's reason and nothing is sent
+        let (provider, mock) = Provider::mocked();
+        let mut revert_data = id("Error(string)").to_vec();
+        revert_data.extend(abi::encode(&[Token::String("Token not supported".to_string())]));
+        mock.push_response(MockResponse::Error(JsonRpcError {
+            code: 3,
+            message: "execution reverted".to_string(),
+            data: Some(serde_json::Value::String(format!("{}", Bytes::from(revert_data)))),
+        }));
+        mock.push::<U256, _>(U256::from(5))?;
+
+        let client = SignerMiddleware::new(provider, wallet.clone());
+        let nonces = NonceManager::new(wallet.address());
+        let error = send_unlock(&client, &nonces, contract, calldata, &static_gas)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("Unlock reverted: Token not supported"), "{}", error);
+
+        Ok(())
+    }).await
+}
+
+#[test]
+fn test_unlock_message_matches_contract_encoding() {
+    use ethers::utils::{hex, keccak256};
+    use relayer::ethereum::unlock_message;
+
+    let user = "0x00000000000000000000000000000000000000aa";
+    let token = "0x00000000000000000000000000000000000000bb";
+    let polkadot_tx_hash = format!("0x{}", "33".repeat(32));
+
+    // abi.encodePacked(user, token, amount, polkadotTxHash, block.chainid):
+    // both addresses unpadded, the uints as 32-byte big-endian words
+    let mut packed = hex::decode(&user[2..]).unwrap();
+    packed.extend_from_slice(&hex::decode(&token[2..]).unwrap());
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&1000u64.to_be_bytes());
+    packed.extend_from_slice(&word);
+    packed.extend_from_slice(&[0x33; 32]);
+    word[24..].copy_from_slice(&1337u64.to_be_bytes());
+    packed.extend_from_slice(&word);
+    assert_eq!(packed.len(), 20 + 20 + 32 + 32 + 32);
+
+    // Signed as toEthSignedMessageHash, with the 32-byte message prefix
+    let mut prefixed = b"\x19Ethereum Signed Message:\n32".to_vec();
+    prefixed.extend_from_slice(&keccak256(&packed));
+
+    let digest = unlock_message(user, token, "1000", &polkadot_tx_hash, 1337).unwrap();
+    assert_eq!(digest, keccak256(&prefixed));
+    assert_ne!(digest, unlock_message(user, token, "1000", &polkadot_tx_hash, 1).unwrap());
+    assert_ne!(digest, unlock_message(user, token, "1001", &polkadot_tx_hash, 1337).unwrap());
+
+    // Unlocks that could not be submitted cannot be signed either
+    assert!(unlock_message("5Grw", token, "1000", &polkadot_tx_hash, 1337).is_err());
+    assert!(unlock_message(user, token, "1000", "0xburn", 1337).is_err());
+}
+
+#[tokio::test]
+async fn test_eip1559_gas_is_priced_from_fee_history() -> TestResult<()> {
+    use ethers::{
+        providers::{JsonRpcError, MockResponse, Provider},
+        types::{transaction::eip2718::TypedTransaction, Address, FeeHistory, TransactionRequest, U256},
+        utils::parse_units,
+    };
+    use relayer::{
+        config::GasStrategy,
+        ethereum::{GasOracle, GasParams, GasPricing},
+    };
+
+    init_test_logging();
+
+    with_timeout(async {
+        let gwei = |amount: u64| -> U256 { parse_units(amount, "gwei").unwrap().into() };
+        let request = TransactionRequest::new()
+            .from("0x00000000000000000000000000000000000000aa".parse::<Address>()?)
+            .to("0x1234567890123456789012345678901234567890".parse::<Address>()?)
+            .nonce(5);
+        let oracle = GasOracle::new(GasStrategy::Eip1559, 300_000, 20_000_000_000);
+
+        // The mock answers newest-first: the gas estimate, then the fee history
+        let (provider, mock) = Provider::mocked();
+        mock.push::<FeeHistory, _>(FeeHistory {
+            base_fee_per_gas: vec![gwei(20), gwei(25), gwei(30)],
+            gas_used_ratio: vec![0.9, 0.9],
+            oldest_block: U256::from(100),
+            reward: vec![vec![gwei(3)], vec![gwei(1)], vec![gwei(2)]],
+        })?;
+        mock.push::<U256, _>(U256::from(100_000))?;
+
+        let params = oracle.gas_params(&provider, &request.clone().into()).await;
+
+        // 20% over the estimate; twice the next base fee plus the median tip
+        assert_eq!(params, GasParams {
+            gas_limit: U256::from(120_000),
+            pricing: GasPricing::Eip1559 {
+                max_fee_per_gas: gwei(62),
+                max_priority_fee_per_gas: gwei(2),
+            },
+        });
+
+        let TypedTransaction::Eip1559(tx) = params.transaction(request.clone()) else {
+            panic!("expected a type-2 transaction");
+        };
+        assert_eq!(tx.max_fee_per_gas, Some(gwei(62)));
+        assert_eq!(tx.max_priority_fee_per_gas, Some(gwei(2)));
+        assert_eq!(tx.gas, Some(U256::from(120_000)));
+        assert_eq!(tx.nonce, Some(U256::from(5)));
+
+        // A node without fee history gets a legacy transaction at the static price
+        let (provider, mock) = Provider::mocked();
+        let unsupported = || MockResponse::Error(JsonRpcError {
+            code: -32601,
+            message: "the method eth_feeHistory does not exist".to_string(),
+            data: None,
+        });
+        mock.push_response(unsupported());
+        mock.push_response(unsupported());
+        mock.push::<U256, _>(U256::from(100_000))?;
+
+        let params = oracle.gas_params(&provider, &request.clone().into()).await;
+        assert_eq!(params.pricing, GasPricing::Legacy { gas_price: U256::from(20_000_000_000u64) });
+
+        let TypedTransaction::Legacy(tx) = params.transaction(request) else {
+            panic!("expected a legacy transaction");
+        };
+        assert_eq!(tx.gas_price, Some(U256::from(20_000_000_000u64)));
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_unlock_fee_is_gas_limit_times_price() -> TestResult<()> {
+    use ethers::{
+        providers::Provider,
+        types::{Address, Bytes, FeeHistory, U256},
+        utils::parse_units,
+    };
+    use relayer::{
+        config::GasStrategy,
+        ethereum::{estimate_unlock_fee, FeeEstimate, GasOracle},
+    };
+
+    init_test_logging();
+
+    with_timeout(async {
+        let gwei = |amount: u64| -> U256 { parse_units(amount, "gwei").unwrap().into() };
+        let from: Address = "0x00000000000000000000000000000000000000aa".parse()?;
+        let contract: Address = "0x1234567890123456789012345678901234567890".parse()?;
+        let calldata = Bytes::from(vec![0xab; 4]);
+
+        // The mock answers newest-first: the gas estimate, then the fee history
+        let (provider, mock) = Provider::mocked();
+        mock.push::<FeeHistory, _>(FeeHistory {
+            base_fee_per_gas: vec![gwei(10), gwei(15)],
+            gas_used_ratio: vec![0.5],
+            oldest_block: U256::from(100),
+            reward: vec![vec![gwei(2)]],
+        })?;
+        mock.push::<U256, _>(U256::from(50_000))?;
+
+        let oracle = GasOracle::new(GasStrategy::Eip1559, 300_000, 20_000_000_000);
+        let estimate = estimate_unlock_fee(&provider, from, contract, calldata.clone(), &oracle).await;
+
+        // 20% over the estimate, at the fee cap of twice the base fee plus the tip
+        assert_eq!(estimate, FeeEstimate {
+            gas_limit: U256::from(60_000),
+            gas_price: gwei(32),
+            fee_wei: U256::from(60_000) * gwei(32),
+        });
+
+        // The static strategy prices the configured limit without asking the node
+        let (provider, _mock) = Provider::mocked();
+        let oracle = GasOracle::new(GasStrategy::Static, 300_000, 20_000_000_000);
+        let estimate = estimate_unlock_fee(&provider, from, contract, calldata, &oracle).await;
+        assert_eq!(estimate.fee_wei, U256::from(6_000_000_000_000_000u64));
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_concurrent_unlocks_take_sequential_nonces() -> TestResult<()> {
+    use crate::common::setup::create_test_relayer_config;
+    use ethers::{
+        middleware::SignerMiddleware,
+        providers::{JsonRpcError, MockResponse, Provider},
+        signers::{LocalWallet, Signer},
+        types::{Address, Bytes, TransactionRequest, H256, U256},
+    };
+    use relayer::{
+        config::GasStrategy,
+        database::Database,
+        ethereum::{send_unlock, GasOracle, GasParams, GasPricing},
+        nonce_manager::NonceManager,
+    };
+    use std::sync::Arc;
+
+    init_test_logging();
+
+    with_timeout(async {
+        let db = Arc::new(Database::new(&create_test_relayer_config().database).await?);
+        let wallet: LocalWallet = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+            .parse::<LocalWallet>()?
+            .with_chain_id(1337u64);
+        let address = format!("{:?}", wallet.address());
+        let contract: Address = "0x1234567890123456789012345678901234567890".parse()?;
+        let calldata = Bytes::from(vec![0xab; 4]);
+        let static_gas = GasOracle::new(GasStrategy::Static, 300_000, 20_000_000_000);
+        let unlock_tx = |nonce: u64| {
+            GasParams {
+                gas_limit: U256::from(300_000),
+                pricing: GasPricing::Legacy { gas_price: U256::from(20_000_000_000u64) },
+            }
+            .transaction(
+                TransactionRequest::new()
+                    .from(wallet.address())
+                    .to(contract)
+                    .data(calldata.clone())
+                    .nonce(nonce),
+            )
+        };
+
+        let nonces = NonceManager::new(wallet.address()).with_store(db.clone());
+
+        // The pending nonce is read once; every simulation and send is answered
+        // with a hash, which also decodes as call output
+        let (provider, mock) = Provider::mocked();
+        for _ in 0..4 {
+            mock.push::<H256, _>(H256::repeat_byte(0x44))?;
+        }
+        mock.push::<U256, _>(U256::from(5))?;
+
+        let client = SignerMiddleware::new(provider, wallet.clone());
+        let (first, second) = tokio::join!(
+            send_unlock(&client, &nonces, contract, calldata.clone(), &static_gas),
+            send_unlock(&client, &nonces, contract, calldata.clone(), &static_gas),
+        );
+        first?;
+        second?;
+        mock.assert_request("eth_getTransactionCount", (wallet.address(), "pending"))?;
+        assert_eq!(db.get_ethereum_nonce(&address).await?, Some(7));
+
+        // A reverted unlock gives its nonce back to the next one
+        let (provider, mock) = Provider::mocked();
+        mock.push_response(MockResponse::Error(JsonRpcError {
+            code: 3,
+            message: "execution reverted".to_string(),
+            data: None,
+        }));
+        let client = SignerMiddleware::new(provider, wallet.clone());
+        assert!(send_unlock(&client, &nonces, contract, calldata.clone(), &static_gas).await.is_err());
+        mock.assert_request("eth_call", (unlock_tx(7), "latest"))?;
+        assert_eq!(db.get_ethereum_nonce(&address).await?, Some(7));
+
+        let (provider, mock) = Provider::mocked();
+        mock.push::<H256, _>(H256::repeat_byte(0x55))?;
+        mock.push::<Bytes, _>(Bytes::default())?;
+        let client = SignerMiddleware::new(provider, wallet.clone());
+        send_unlock(&client, &nonces, contract, calldata.clone(), &static_gas).await?;
+        mock.assert_request("eth_call", (unlock_tx(7), "latest"))?;
+        assert_eq!(db.get_ethereum_nonce(&address).await?, Some(8));
+
+        // After a restart the persisted nonce wins over a lagging node
+        let restarted = NonceManager::new(wallet.address()).with_store(db.clone());
+        let (provider, mock) = Provider::mocked();
+        mock.push::<U256, _>(U256::from(5))?;
+        assert_eq!(restarted.next_nonce(&provider).await?, U256::from(8));
+
+        Ok(())
+    }).await
+}
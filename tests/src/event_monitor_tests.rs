@@ -0,0 +1,505 @@
+//! Event monitor lifecycle tests
+
+use crate::common::{setup::create_test_relayer_config, wait_for_condition, TestResult, with_timeout};
+use async_trait::async_trait;
+use relayer::{
+    config::RelayDirection,
+    coordinator::BridgeEvent,
+    database::SeenEvent,
+    event_monitor::{CursorStore, EventMonitor, EventSource, SeenEventStore, StaleEventStore},
+    metrics::{self, Registry},
+    policy::RelayerPolicy,
+};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+/// Source whose head is always five blocks past the requested cursor
+struct MockSource {
+    polled_from: Arc<Mutex<Vec<u64>>>,
+}
+
+#[async_trait]
+impl EventSource for MockSource {
+    fn chain(&self) -> &'static str {
+        "ethereum"
+    }
+
+    async fn poll(&self, from_block: u64, _max_blocks: u64) -> relayer::Result<(Vec<BridgeEvent>, u64)> {
+        self.polled_from.lock().unwrap().push(from_block);
+        Ok((vec![], from_block + 5))
+    }
+}
+
+/// Source for a named chain that records every poll
+struct ChainSource {
+    chain: &'static str,
+    polls: Arc<Mutex<Vec<&'static str>>>,
+}
+
+#[async_trait]
+impl EventSource for ChainSource {
+    fn chain(&self) -> &'static str {
+        self.chain
+    }
+
+    async fn poll(&self, from_block: u64, _max_blocks: u64) -> relayer::Result<(Vec<BridgeEvent>, u64)> {
+        self.polls.lock().unwrap().push(self.chain);
+        Ok((vec![], from_block + 1))
+    }
+}
+
+/// Source whose provider rate-limits the first poll
+struct RateLimitedSource {
+    polled_at: Arc<Mutex<Vec<tokio::time::Instant>>>,
+}
+
+#[async_trait]
+impl EventSource for RateLimitedSource {
+    fn chain(&self) -> &'static str {
+        "ethereum"
+    }
+
+    async fn poll(&self, from_block: u64, _max_blocks: u64) -> relayer::Result<(Vec<BridgeEvent>, u64)> {
+        let mut polled_at = self.polled_at.lock().unwrap();
+        polled_at.push(tokio::time::Instant::now());
+
+        if polled_at.len() == 1 {
+            return Err(relayer::RelayerError::Ethereum {
+                message: "HTTP error 429 Too Many Requests".to_string(),
+            });
+        }
+        Ok((vec![], from_block + 1))
+    }
+}
+
+/// Source whose RPC fails the first `failures` polls
+struct FlakySource {
+    failures: usize,
+    polled_at: Arc<Mutex<Vec<tokio::time::Instant>>>,
+}
+
+#[async_trait]
+impl EventSource for FlakySource {
+    fn chain(&self) -> &'static str {
+        "ethereum"
+    }
+
+    async fn poll(&self, from_block: u64, _max_blocks: u64) -> relayer::Result<(Vec<BridgeEvent>, u64)> {
+        let mut polled_at = self.polled_at.lock().unwrap();
+        polled_at.push(tokio::time::Instant::now());
+
+        if polled_at.len() <= self.failures {
+            return Err(relayer::RelayerError::Ethereum {
+                message: "Failed to get block number: connection reset".to_string(),
+            });
+        }
+        Ok((vec![], from_block + 1))
+    }
+}
+
+/// Source that reports one event from block 1 and one from the head, block 100
+struct BacklogSource {
+    polled: Arc<Mutex<bool>>,
+}
+
+#[async_trait]
+impl EventSource for BacklogSource {
+    fn chain(&self) -> &'static str {
+        "ethereum"
+    }
+
+    async fn poll(&self, from_block: u64, _max_blocks: u64) -> relayer::Result<(Vec<BridgeEvent>, u64)> {
+        let mut polled = self.polled.lock().unwrap();
+        if *polled {
+            return Ok((vec![], from_block));
+        }
+        *polled = true;
+
+        let lock = |tx_hash: &str, block_number| BridgeEvent::EthereumLock {
+            user: "0x742d35Cc6634C0532925a3b8D4C9db96590c6C87".to_string(),
+            token: "0xA0b86a33E6441b8C4505E2c4B8b5b8e8E8E8E8E8".to_string(),
+            amount: "1000".to_string(),
+            polkadot_address: "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".to_string(),
+            tx_hash: tx_hash.to_string(),
+            block_number,
+        };
+        Ok((vec![lock("0xold", 1), lock("0xfresh", 100)], 100))
+    }
+}
+
+/// Source whose lock moves from block 10 to block 11 once `reorged` is set
+struct ReorgSource {
+    reorged: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl EventSource for ReorgSource {
+    fn chain(&self) -> &'static str {
+        "ethereum"
+    }
+
+    async fn poll(&self, from_block: u64, _max_blocks: u64) -> relayer::Result<(Vec<BridgeEvent>, u64)> {
+        let lock_block = if self.reorged.load(Ordering::SeqCst) { 11 } else { 10 };
+        if from_block >= lock_block {
+            return Ok((vec![], from_block.max(12)));
+        }
+
+        let lock = BridgeEvent::EthereumLock {
+            user: "0x742d35Cc6634C0532925a3b8D4C9db96590c6C87".to_string(),
+            token: "0xA0b86a33E6441b8C4505E2c4B8b5b8e8E8E8E8E8".to_string(),
+            amount: "1000".to_string(),
+            polkadot_address: "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".to_string(),
+            tx_hash: "0xlock".to_string(),
+            block_number: lock_block,
+        };
+        Ok((vec![lock], 12))
+    }
+
+    async fn block_hash(&self, block: u64) -> relayer::Result<Option<String>> {
+        let hash = match (block, self.reorged.load(Ordering::SeqCst)) {
+            (10, true) => "0xreorged".to_string(),
+            (block, _) => format!("0x{:x}", block),
+        };
+        Ok(Some(hash))
+    }
+}
+
+/// Source whose head is block 1000, recording every block range it scans
+struct ChunkedSource {
+    scans: Arc<Mutex<Vec<(u64, u64)>>>,
+}
+
+#[async_trait]
+impl EventSource for ChunkedSource {
+    fn chain(&self) -> &'static str {
+        "ethereum"
+    }
+
+    async fn poll(&self, from_block: u64, max_blocks: u64) -> relayer::Result<(Vec<BridgeEvent>, u64)> {
+        let to_block = (from_block + max_blocks).min(1000);
+        if to_block <= from_block {
+            return Ok((vec![], from_block));
+        }
+
+        self.scans.lock().unwrap().push((from_block + 1, to_block));
+        Ok((vec![], to_block))
+    }
+}
+
+#[derive(Default)]
+struct MemorySeenEvents {
+    events: Mutex<HashMap<String, SeenEvent>>,
+}
+
+#[async_trait]
+impl SeenEventStore for MemorySeenEvents {
+    async fn record_seen_event(&self, _chain: &str, event: &SeenEvent) -> relayer::Result<()> {
+        self.events.lock().unwrap().insert(event.tx_hash.clone(), event.clone());
+        Ok(())
+    }
+
+    async fn seen_event(&self, _chain: &str, tx_hash: &str) -> relayer::Result<Option<SeenEvent>> {
+        Ok(self.events.lock().unwrap().get(tx_hash).cloned())
+    }
+
+    async fn seen_events_since(&self, _chain: &str, from_block: u64) -> relayer::Result<Vec<SeenEvent>> {
+        let mut events: Vec<_> = self.events
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|event| event.block_number >= from_block)
+            .cloned()
+            .collect();
+        events.sort_by_key(|event| event.block_number);
+        Ok(events)
+    }
+}
+
+#[derive(Default)]
+struct MemoryStaleEvents {
+    held: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl StaleEventStore for MemoryStaleEvents {
+    async fn hold_stale_event(&self, event: &BridgeEvent) -> relayer::Result<()> {
+        self.held.lock().unwrap().push(event.tx_hash().to_string());
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct MemoryCursors {
+    cursors: Mutex<HashMap<String, u64>>,
+    saved: Mutex<Vec<u64>>,
+}
+
+#[async_trait]
+impl CursorStore for MemoryCursors {
+    async fn load_cursor(&self, chain: &str) -> relayer::Result<Option<u64>> {
+        Ok(self.cursors.lock().unwrap().get(chain).copied())
+    }
+
+    async fn save_cursor(&self, chain: &str, block: u64) -> relayer::Result<()> {
+        self.cursors.lock().unwrap().insert(chain.to_string(), block);
+        self.saved.lock().unwrap().push(block);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_monitor_restart_resumes_from_saved_cursor() -> TestResult<()> {
+    with_timeout(async {
+        let polled_from = Arc::new(Mutex::new(Vec::new()));
+        let cursors = Arc::new(MemoryCursors::default());
+        let monitor = EventMonitor::with_sources(
+            vec![Arc::new(MockSource { polled_from: polled_from.clone() })],
+            cursors.clone(),
+        );
+        let (sender, _receiver) = mpsc::unbounded_channel();
+        let mut policy = RelayerPolicy::from_config(&create_test_relayer_config());
+        policy.timeouts.poll_interval = Duration::from_secs(60);
+
+        // First run polls once from genesis, then waits for the next interval
+        let handle = monitor.start_monitoring(sender.clone(), policy, CancellationToken::new()).await?;
+        let seen = polled_from.clone();
+        wait_for_condition(move || seen.lock().unwrap().len() == 1, Duration::from_secs(5)).await?;
+        handle.stop().await?;
+
+        assert_eq!(cursors.load_cursor("ethereum").await?, Some(5));
+
+        // Restarting with a new policy continues from the persisted block
+        policy.timeouts.poll_interval = Duration::from_secs(30);
+        let handle = monitor.start_monitoring(sender, policy, CancellationToken::new()).await?;
+        let seen = polled_from.clone();
+        wait_for_condition(move || seen.lock().unwrap().len() == 2, Duration::from_secs(5)).await?;
+        handle.stop().await?;
+
+        assert_eq!(*polled_from.lock().unwrap(), vec![0, 5]);
+        assert_eq!(cursors.load_cursor("ethereum").await?, Some(10));
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_monitor_only_polls_chains_for_its_direction() -> TestResult<()> {
+    with_timeout(async {
+        for (direction, monitored, skipped) in [
+            (RelayDirection::MintOnly, "ethereum", "polkadot"),
+            (RelayDirection::UnlockOnly, "polkadot", "ethereum"),
+        ] {
+            let polls = Arc::new(Mutex::new(Vec::new()));
+            let sources: Vec<Arc<dyn EventSource>> = ["ethereum", "polkadot"]
+                .into_iter()
+                .map(|chain| Arc::new(ChainSource { chain, polls: polls.clone() }) as Arc<dyn EventSource>)
+                .collect();
+
+            let monitor = EventMonitor::with_sources(sources, Arc::new(MemoryCursors::default()))
+                .for_direction(direction);
+            assert_eq!(monitor.chains(), vec![monitored]);
+
+            let (sender, _receiver) = mpsc::unbounded_channel();
+            let mut policy = RelayerPolicy::from_config(&create_test_relayer_config());
+            policy.timeouts.poll_interval = Duration::from_millis(10);
+
+            let handle = monitor.start_monitoring(sender, policy, CancellationToken::new()).await?;
+            let seen = polls.clone();
+            wait_for_condition(move || seen.lock().unwrap().len() >= 3, Duration::from_secs(5)).await?;
+            handle.stop().await?;
+
+            let polls = polls.lock().unwrap();
+            assert!(polls.iter().all(|chain| *chain == monitored));
+            assert!(!polls.contains(&skipped));
+        }
+
+        // Directions parse from configuration; disabling both is not an option
+        assert_eq!("mint_only".parse::<RelayDirection>()?, RelayDirection::MintOnly);
+        assert_eq!("both".parse::<RelayDirection>()?, RelayDirection::Both);
+        assert!("none".parse::<RelayDirection>().is_err());
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limited_poll_is_counted_and_backs_off_longer() -> TestResult<()> {
+    with_timeout(async {
+        let rate_limited = relayer::RelayerError::Ethereum {
+            message: "(code: -32005) rate limit exceeded".to_string(),
+        };
+        let other = relayer::RelayerError::Ethereum {
+            message: "connection reset".to_string(),
+        };
+        assert!(rate_limited.is_rate_limited());
+        assert!(!other.is_rate_limited());
+
+        let policy = RelayerPolicy::from_config(&create_test_relayer_config());
+        assert!(policy.retry.rate_limit_cooldown > policy.retry.retry_delay);
+        assert_eq!(policy.retry.delay_for(&rate_limited), policy.retry.rate_limit_cooldown);
+        assert_eq!(policy.retry.delay_for(&other), policy.retry.retry_delay);
+
+        let polled_at = Arc::new(Mutex::new(Vec::new()));
+        let monitor = EventMonitor::with_sources(
+            vec![Arc::new(RateLimitedSource { polled_at: polled_at.clone() })],
+            Arc::new(MemoryCursors::default()),
+        );
+        let (sender, _receiver) = mpsc::unbounded_channel();
+
+        let handle = monitor.start_monitoring(sender, policy, CancellationToken::new()).await?;
+        let seen = polled_at.clone();
+        wait_for_condition(move || seen.lock().unwrap().len() >= 2, Duration::from_secs(30)).await?;
+        handle.stop().await?;
+
+        // The retry after a 429 waits out the cooldown, not the usual delay
+        let polled_at = polled_at.lock().unwrap().clone();
+        assert!(polled_at[1] - polled_at[0] >= policy.retry.rate_limit_cooldown);
+        assert_eq!(monitor.rate_limits().count("ethereum"), 1);
+        let registry = Registry::new();
+        monitor.rate_limits().register(&registry)?;
+        assert!(metrics::encode(&registry)?.contains("bridge_rpc_rate_limited_total{chain=\"ethereum\"} 1"));
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_failing_polls_retry_with_growing_backoff() -> TestResult<()> {
+    with_timeout(async {
+        let policy = RelayerPolicy::from_config(&create_test_relayer_config());
+        assert_eq!(policy.retry.max_retries, 3);
+
+        // Two failures fit within the retry budget of the first poll
+        let polled_at = Arc::new(Mutex::new(Vec::new()));
+        let cursors = Arc::new(MemoryCursors::default());
+        let monitor = EventMonitor::with_sources(
+            vec![Arc::new(FlakySource { failures: 2, polled_at: polled_at.clone() })],
+            cursors.clone(),
+        );
+        let (sender, _receiver) = mpsc::unbounded_channel();
+
+        let handle = monitor.start_monitoring(sender, policy, CancellationToken::new()).await?;
+        let seen = polled_at.clone();
+        wait_for_condition(move || seen.lock().unwrap().len() >= 3, Duration::from_secs(30)).await?;
+        handle.stop().await?;
+
+        assert_eq!(cursors.load_cursor("ethereum").await?, Some(1));
+
+        // Each retry waits at least twice as long as the one before, with at
+        // most a quarter of jitter on top
+        let polled_at = polled_at.lock().unwrap().clone();
+        let first = polled_at[1] - polled_at[0];
+        let second = polled_at[2] - polled_at[1];
+        let retry_delay = policy.retry.retry_delay;
+        assert!(first >= retry_delay && first <= retry_delay * 5 / 4);
+        assert!(second >= retry_delay * 2 && second <= retry_delay * 5 / 2);
+        assert!(second > first);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_events_older_than_max_age_are_held_not_relayed() -> TestResult<()> {
+    with_timeout(async {
+        let stale_events = Arc::new(MemoryStaleEvents::default());
+        let monitor = EventMonitor::with_sources(
+            vec![Arc::new(BacklogSource { polled: Arc::new(Mutex::new(false)) })],
+            Arc::new(MemoryCursors::default()),
+        )
+        .with_max_event_age(10, stale_events.clone());
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let policy = RelayerPolicy::from_config(&create_test_relayer_config());
+
+        let handle = monitor.start_monitoring(sender, policy, CancellationToken::new()).await?;
+        let relayed = receiver.recv().await.expect("fresh event relayed");
+        handle.stop().await?;
+
+        // The event 99 blocks behind the head is held; only the fresh one is relayed
+        assert_eq!(relayed.tx_hash(), "0xfresh");
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(*stale_events.held.lock().unwrap(), vec!["0xold".to_string()]);
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_reorg_rolls_back_and_does_not_relay_a_lock_twice() -> TestResult<()> {
+    with_timeout(async {
+        let reorged = Arc::new(AtomicBool::new(false));
+        let cursors = Arc::new(MemoryCursors::default());
+        let seen_events = Arc::new(MemorySeenEvents::default());
+        let monitor = EventMonitor::with_sources(
+            vec![Arc::new(ReorgSource { reorged: reorged.clone() })],
+            cursors.clone(),
+        )
+        .with_reorg_detection(seen_events.clone());
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let mut policy = RelayerPolicy::from_config(&create_test_relayer_config());
+        policy.timeouts.poll_interval = Duration::from_millis(10);
+
+        let handle = monitor.start_monitoring(sender, policy, CancellationToken::new()).await?;
+        let relayed = receiver.recv().await.expect("lock relayed");
+        assert_eq!(relayed.block_number(), 10);
+
+        // Block 10 is replaced and the lock is re-included in block 11
+        reorged.store(true, Ordering::SeqCst);
+        let seen = seen_events.clone();
+        wait_for_condition(
+            move || seen.events.lock().unwrap().get("0xlock").is_some_and(|event| event.block_number == 11),
+            Duration::from_secs(5),
+        ).await?;
+        handle.stop().await?;
+
+        // The rescan found the lock again but did not relay it a second time
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(monitor.reorgs().count("ethereum"), 1);
+        assert_eq!(cursors.load_cursor("ethereum").await?, Some(12));
+        assert_eq!(seen_events.events.lock().unwrap()["0xlock"].block_hash, "0xb");
+        let registry = Registry::new();
+        monitor.reorgs().register(&registry)?;
+        assert!(metrics::encode(&registry)?.contains("bridge_chain_reorgs_total{chain=\"ethereum\"} 1"));
+
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_backlog_is_scanned_in_batches_saving_progress() -> TestResult<()> {
+    with_timeout(async {
+        let scans = Arc::new(Mutex::new(Vec::new()));
+        let cursors = Arc::new(MemoryCursors::default());
+        let monitor = EventMonitor::with_sources(
+            vec![Arc::new(ChunkedSource { scans: scans.clone() })],
+            cursors.clone(),
+        )
+        .with_batch_size(100);
+        let (sender, _receiver) = mpsc::unbounded_channel();
+        let mut policy = RelayerPolicy::from_config(&create_test_relayer_config());
+        policy.timeouts.poll_interval = Duration::from_secs(60);
+
+        // A 1000-block gap is drained within the first iteration
+        let handle = monitor.start_monitoring(sender, policy, CancellationToken::new()).await?;
+        let cursor = cursors.clone();
+        wait_for_condition(
+            move || cursor.cursors.lock().unwrap().get("ethereum") == Some(&1000),
+            Duration::from_secs(5),
+        ).await?;
+        handle.stop().await?;
+
+        let expected: Vec<_> = (0..10).map(|chunk| (chunk * 100 + 1, chunk * 100 + 100)).collect();
+        assert_eq!(*scans.lock().unwrap(), expected);
+
+        // The cursor was saved after every batch and never moved backwards
+        let saved = cursors.saved.lock().unwrap().clone();
+        assert_eq!(saved[..10], (1..=10).map(|chunk| chunk * 100).collect::<Vec<_>>()[..]);
+        assert!(saved.windows(2).all(|pair| pair[0] <= pair[1]), "{:?}", saved);
+
+        Ok(())
+    }).await
+}
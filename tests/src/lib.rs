@@ -4,16 +4,32 @@
 //! validator consensus, and error handling scenarios.
 
 pub mod common;
-pub mod ethereum_tests;
-pub mod polkadot_tests;
-pub mod threshold_tests;
-pub mod bridge_tests;
-pub mod api_tests;
 
 #[cfg(test)]
-mod integration_tests {
-    use super::*;
+mod ethereum_tests;
+#[cfg(test)]
+mod polkadot_tests;
+#[cfg(test)]
+mod threshold_tests;
+#[cfg(test)]
+mod bridge_tests;
+#[cfg(test)]
+mod api_tests;
+#[cfg(test)]
+mod price_oracle_tests;
+#[cfg(test)]
+mod event_monitor_tests;
+#[cfg(test)]
+mod config_tests;
+#[cfg(test)]
+mod p2p_tests;
+#[cfg(test)]
+mod metrics_tests;
+#[cfg(all(test, feature = "integration"))]
+mod database_tests;
 
+#[cfg(test)]
+mod integration_tests {
     #[tokio::test]
     async fn test_basic_functionality() {
         // Basic smoke test
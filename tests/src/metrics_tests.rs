@@ -0,0 +1,108 @@
+//! Relayer metrics endpoint tests
+
+use crate::common::{
+    setup::{create_test_relayer_config, init_test_logging},
+    test_tx_hash, TestResult, with_timeout, TEST_RECIPIENT, TEST_TOKEN,
+};
+use relayer::{
+    allowlist::RecipientAllowlist,
+    coordinator::{BridgeEvent, MintPipeline},
+    database::Database,
+    event_sink::NoopEventSink,
+    metrics::{self, DuplicateEvents, RelayerMetrics},
+    mint_batcher::MintBatcher,
+    policy::RelayerPolicy,
+    polkadot::{MintSubmitter, PendingMint},
+    signature_coordinator::SignatureCoordinator,
+};
+use std::sync::Arc;
+use std::time::Duration;
+use threshold::{SimpleThresholdManager, ThresholdConfig};
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+/// Accepts every mint without a chain
+struct AcceptingSubmitter;
+
+#[async_trait::async_trait]
+impl MintSubmitter for AcceptingSubmitter {
+    async fn submit_mint(&self, mint: &PendingMint) -> relayer::Result<String> {
+        Ok(format!("0xmint_{}", mint.ethereum_tx_hash))
+    }
+
+    async fn submit_mint_batch(&self, _mints: &[PendingMint]) -> relayer::Result<String> {
+        Ok("0xbatch".to_string())
+    }
+}
+
+/// Value of the sample named exactly `series` in a scrape
+fn sample(scrape: &str, series: &str) -> Option<f64> {
+    scrape
+        .lines()
+        .find_map(|line| line.strip_prefix(series)?.strip_prefix(' ')?.parse().ok())
+}
+
+#[tokio::test]
+async fn test_metrics_endpoint_counts_handled_lock() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        let config = create_test_relayer_config();
+        let db = Arc::new(Database::new(&config.database).await?);
+
+        // A 1-of-1 validator signs and mints each lock on its own
+        let manager = Arc::new(SimpleThresholdManager::new(ThresholdConfig::new(1, 1, 256)?)?);
+        let signatures = Arc::new(SignatureCoordinator::new(
+            config.validator.clone(),
+            manager,
+            db.clone(),
+            RelayerPolicy::from_config(&config).timeouts,
+        ).await?);
+        let batcher = MintBatcher::new(Arc::new(AcceptingSubmitter), 1, Duration::from_secs(60));
+        let duplicates = DuplicateEvents::new();
+        let relayer_metrics = Arc::new(RelayerMetrics::new());
+        let allowlist = RecipientAllowlist::new(vec![]);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}/metrics", listener.local_addr()?);
+        let cancel = CancellationToken::new();
+        let shutdown = cancel.clone();
+        tokio::spawn(metrics::serve(
+            listener,
+            relayer_metrics.clone(),
+            signatures.clone(),
+            async move { shutdown.cancelled().await },
+        ));
+
+        let before = reqwest::get(&url).await?.text().await?;
+        assert_eq!(sample(&before, "bridge_processed_transactions_total{chain=\"ethereum\"}"), Some(0.0));
+
+        let pipeline = MintPipeline {
+            database: &db,
+            duplicate_events: &duplicates,
+            metrics: &relayer_metrics,
+            recipient_allowlist: &allowlist,
+            signature_coordinator: &signatures,
+            mint_batcher: &batcher,
+            event_sink: &NoopEventSink,
+            validator_enabled: config.validator.enabled,
+        };
+        pipeline.relay(&BridgeEvent::EthereumLock {
+            user: "0xuser".to_string(),
+            token: TEST_TOKEN.to_string(),
+            amount: "1000".to_string(),
+            polkadot_address: TEST_RECIPIENT.to_string(),
+            tx_hash: test_tx_hash("metrics_lock"),
+            block_number: 100,
+        }).await?;
+
+        let after = reqwest::get(&url).await?.text().await?;
+        assert_eq!(sample(&after, "bridge_processed_transactions_total{chain=\"ethereum\"}"), Some(1.0));
+        assert_eq!(sample(&after, "bridge_processed_transactions_total{chain=\"polkadot\"}"), Some(0.0));
+        assert_eq!(sample(&after, "bridge_event_processing_seconds_count{chain=\"ethereum\"}"), Some(1.0));
+        assert_eq!(sample(&after, "bridge_pending_signature_sessions"), Some(0.0));
+
+        cancel.cancel();
+        Ok(())
+    }).await
+}
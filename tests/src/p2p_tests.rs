@@ -0,0 +1,309 @@
+//! Validator-to-validator partial signature exchange tests
+
+use crate::common::{
+    generate_test_id, test_tx_hash,
+    setup::{create_test_relayer_config, init_test_logging, setup_test_threshold_manager, TestSigners},
+    TestResult, with_timeout, TEST_RECIPIENT, TEST_TOKEN,
+};
+use relayer::{
+    config::{ValidatorConfig, ValidatorPeer, ValidatorTlsConfig},
+    database::Database,
+    p2p::{self, PartialSignatureService, PeerTls},
+    policy::RelayerPolicy,
+    signature_coordinator::SignatureCoordinator,
+};
+use std::sync::Arc;
+use std::time::Duration;
+use threshold::{utils, PartialSignature};
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+/// Peer entry for a validator listening on `address` whose partials verify
+/// against `public_key`
+fn peer(id: &str, public_key: &[u8], address: std::net::SocketAddr) -> ValidatorPeer {
+    ValidatorPeer {
+        id: id.to_string(),
+        public_key: utils::bytes_to_hex(public_key),
+        address: address.to_string(),
+        active: true,
+        certificate_path: None,
+    }
+}
+
+/// Self-signed TLS identity naming `validator_id`, written to PEM files
+fn tls_identity(validator_id: &str) -> TestResult<ValidatorTlsConfig> {
+    let certified = rcgen::generate_simple_self_signed(vec![validator_id.to_string()])?;
+    let dir = std::env::temp_dir().join(format!("bridge_tls_{}", generate_test_id()));
+    std::fs::create_dir_all(&dir)?;
+
+    let certificate_path = dir.join(format!("{}.crt", validator_id));
+    let private_key_path = dir.join(format!("{}.key", validator_id));
+    std::fs::write(&certificate_path, certified.cert.pem())?;
+    std::fs::write(&private_key_path, certified.key_pair.serialize_pem())?;
+
+    Ok(ValidatorTlsConfig {
+        certificate_path: certificate_path.display().to_string(),
+        private_key_path: private_key_path.display().to_string(),
+    })
+}
+
+/// Two validators, A signing and B collecting, configured as each other's peers
+struct ValidatorPair {
+    signers: TestSigners,
+    validator_a: ValidatorConfig,
+    validator_b: ValidatorConfig,
+    listener_a: TcpListener,
+    listener_b: TcpListener,
+}
+
+impl ValidatorPair {
+    async fn new() -> TestResult<Self> {
+        let listener_a = TcpListener::bind("127.0.0.1:0").await?;
+        let listener_b = TcpListener::bind("127.0.0.1:0").await?;
+        let (addr_a, addr_b) = (listener_a.local_addr()?, listener_b.local_addr()?);
+
+        let signers = TestSigners::new(&["validator_a", "validator_b"]).await?;
+        let (share_a, share_b) = (signers.key_share("validator_a"), signers.key_share("validator_b"));
+
+        let mut validator_a = create_test_relayer_config().validator;
+        validator_a.validator_id = "validator_a".to_string();
+        validator_a.private_key = Some(utils::bytes_to_hex(&share_a.private_share));
+        validator_a.peers = vec![peer("validator_b", &share_b.public_share, addr_b)];
+
+        let mut validator_b = create_test_relayer_config().validator;
+        validator_b.validator_id = "validator_b".to_string();
+        validator_b.private_key = None;
+        validator_b.peers = vec![peer("validator_a", &share_a.public_share, addr_a)];
+
+        Ok(Self { signers, validator_a, validator_b, listener_a, listener_b })
+    }
+
+    /// Give both validators a TLS identity and pin each one's certificate
+    /// at the other
+    fn with_tls(mut self) -> TestResult<Self> {
+        let (tls_a, tls_b) = (tls_identity("validator_a")?, tls_identity("validator_b")?);
+        self.validator_a.peers[0].certificate_path = Some(tls_b.certificate_path.clone());
+        self.validator_b.peers[0].certificate_path = Some(tls_a.certificate_path.clone());
+        self.validator_a.tls = Some(tls_a);
+        self.validator_b.tls = Some(tls_b);
+        Ok(self)
+    }
+
+    /// Start both validators, serving partials until `cancel`, handing
+    /// back their coordinators and key shares
+    async fn start(
+        self,
+        cancel: &CancellationToken,
+    ) -> TestResult<(Arc<SignatureCoordinator>, Arc<SignatureCoordinator>, TestSigners)> {
+        let coordinator_a = start_validator(self.validator_a, &self.signers, self.listener_a, cancel).await?;
+        let coordinator_b = start_validator(self.validator_b, &self.signers, self.listener_b, cancel).await?;
+        Ok((coordinator_a, coordinator_b, self.signers))
+    }
+}
+
+/// Coordinator for `validator` serving partials on `listener` until `cancel`,
+/// verifying its peers' partials against `signers`' public key shares
+async fn start_validator(
+    validator: ValidatorConfig,
+    signers: &TestSigners,
+    listener: TcpListener,
+    cancel: &CancellationToken,
+) -> TestResult<Arc<SignatureCoordinator>> {
+    let config = create_test_relayer_config();
+    let coordinator = Arc::new(SignatureCoordinator::new(
+        validator.clone(),
+        setup_test_threshold_manager().await?,
+        Arc::new(Database::new(&config.database).await?),
+        RelayerPolicy::from_config(&config).timeouts,
+    ).await?
+    .with_public_key_shares(&signers.public_key_shares()?));
+
+    let service = PartialSignatureService::new(coordinator.clone(), &validator.peers)
+        .with_tls(PeerTls::from_config(&validator)?);
+    let cancel = cancel.clone();
+    tokio::spawn(async move { p2p::serve(listener, service, async move { cancel.cancelled().await }).await });
+
+    Ok(coordinator)
+}
+
+/// An unsigned partial claiming to be from `validator_id`
+fn forged_partial(validator_id: &str) -> PartialSignature {
+    PartialSignature {
+        validator_id: validator_id.to_string(),
+        signature: vec![1u8; 65],
+        commitment: None,
+        timestamp: std::time::SystemTime::now(),
+        key_epoch: 0,
+    }
+}
+
+#[tokio::test]
+async fn test_partial_signature_reaches_peer_session() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        let pair = ValidatorPair::new().await?;
+        let peer_b = pair.validator_a.peers[0].clone();
+        let cancel = CancellationToken::new();
+        let (coordinator_a, coordinator_b, _) = pair.start(&cancel).await?;
+
+        // B has the session open when A signs and broadcasts its partial
+        let tx_hash = &test_tx_hash("p2p_lock");
+        coordinator_b.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", tx_hash).await?;
+        coordinator_a.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", tx_hash).await?;
+
+        let local = coordinator_a.session(tx_hash).await.expect("A's session");
+        let received = coordinator_b.session(tx_hash).await.expect("B's session");
+        assert_eq!(
+            received.partial_signatures["validator_a"].signature,
+            local.partial_signatures["validator_a"].signature
+        );
+        assert!(received.sources["validator_a"].starts_with("127.0.0.1:"));
+
+        // Partials from validators outside B's peer set, or for sessions B
+        // does not have, are refused
+        let err = p2p::submit_partial(&peer_b, None, tx_hash, &forged_partial("validator_x")).await.unwrap_err();
+        assert!(err.to_string().contains("not a configured peer"), "{}", err);
+        let err = p2p::submit_partial(&peer_b, None, "0xunknown", &forged_partial("validator_a")).await.unwrap_err();
+        assert!(err.to_string().contains("No signature session"), "{}", err);
+
+        // A peer claiming to be A without A's key cannot overwrite A's partial
+        let err = p2p::submit_partial(&peer_b, None, tx_hash, &forged_partial("validator_a")).await.unwrap_err();
+        assert!(err.to_string().contains("does not verify"), "{}", err);
+        let received = coordinator_b.session(tx_hash).await.expect("B's session");
+        assert_eq!(received.partial_signatures.len(), 1);
+        assert_eq!(
+            received.partial_signatures["validator_a"].signature,
+            local.partial_signatures["validator_a"].signature
+        );
+
+        cancel.cancel();
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_missed_partial_is_resent_when_rerequested() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        let pair = ValidatorPair::new().await?;
+        let addr_b = pair.listener_b.local_addr()?;
+        drop(pair.listener_b);
+        let cancel = CancellationToken::new();
+        let coordinator_a = start_validator(pair.validator_a, &pair.signers, pair.listener_a, &cancel).await?;
+
+        // A signs while B is down, so its broadcast is lost
+        let tx_hash = &test_tx_hash("p2p_rerequest_lock");
+        coordinator_a.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", tx_hash).await?;
+
+        let listener_b = TcpListener::bind(addr_b).await?;
+        let coordinator_b = start_validator(pair.validator_b, &pair.signers, listener_b, &cancel).await?;
+        coordinator_b.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", tx_hash).await?;
+        assert!(coordinator_b.session(tx_hash).await.expect("B's session").partial_signatures.is_empty());
+
+        // A's sweep re-sends its partial to B, which has not contributed
+        let session = coordinator_a.session(tx_hash).await.expect("A's session");
+        let overdue = session.created_at + Duration::from_secs(61);
+        assert_eq!(coordinator_a.sweep_sessions(overdue).await.rerequested, vec![tx_hash.clone()]);
+        let received = coordinator_b.session(tx_hash).await.expect("B's session");
+        assert_eq!(
+            received.partial_signatures["validator_a"].signature,
+            session.partial_signatures["validator_a"].signature
+        );
+
+        cancel.cancel();
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_partial_signature_is_exchanged_over_tls() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        let mut pair = ValidatorPair::new().await?.with_tls()?;
+        // B also pins a third validator, whose partials A must not relay
+        let tls_c = tls_identity("validator_c")?;
+        pair.validator_b.peers.push(ValidatorPeer {
+            id: "validator_c".to_string(),
+            public_key: String::new(),
+            address: "127.0.0.1:1".to_string(),
+            active: true,
+            certificate_path: Some(tls_c.certificate_path),
+        });
+        let validator_a = pair.validator_a.clone();
+        let cancel = CancellationToken::new();
+        let (coordinator_a, coordinator_b, _) = pair.start(&cancel).await?;
+
+        let tx_hash = &test_tx_hash("p2p_tls_lock");
+        coordinator_b.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", tx_hash).await?;
+        coordinator_a.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", tx_hash).await?;
+
+        let local = coordinator_a.session(tx_hash).await.expect("A's session");
+        let received = coordinator_b.session(tx_hash).await.expect("B's session");
+        assert_eq!(
+            received.partial_signatures["validator_a"].signature,
+            local.partial_signatures["validator_a"].signature
+        );
+
+        // B does not speak plaintext once TLS is configured
+        let peer_b = &validator_a.peers[0];
+        let partial = &local.partial_signatures["validator_a"];
+        assert!(p2p::submit_partial(peer_b, None, tx_hash, partial).await.is_err());
+
+        // A's authenticated connection only carries A's own partials
+        let tls_a = PeerTls::from_config(&validator_a)?;
+        let err = p2p::submit_partial(peer_b, tls_a.as_ref(), tx_hash, &forged_partial("validator_c"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not authenticated as validator_c"), "{}", err);
+
+        cancel.cancel();
+        Ok(())
+    }).await
+}
+
+#[tokio::test]
+async fn test_peer_with_wrong_certificate_is_rejected() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        let pair = ValidatorPair::new().await?.with_tls()?;
+        let validator_a = pair.validator_a.clone();
+        let cancel = CancellationToken::new();
+        let (_, coordinator_b, signers) = pair.start(&cancel).await?;
+
+        let tx_hash = &test_tx_hash("p2p_impostor_lock");
+        coordinator_b.request_mint_signature(TEST_RECIPIENT, TEST_TOKEN, "1000", tx_hash).await?;
+        let partial = signers.partial(&coordinator_b, tx_hash, "validator_a").await?;
+        let peer_b = &validator_a.peers[0];
+
+        // An impostor relaying A's partial under a certificate that also
+        // names validator_a fails the handshake
+        let mut impostor = validator_a.clone();
+        impostor.tls = Some(tls_identity("validator_a")?);
+        let impostor_tls = PeerTls::from_config(&impostor)?;
+        assert!(p2p::submit_partial(peer_b, impostor_tls.as_ref(), tx_hash, &partial).await.is_err());
+
+        // So does A when B's address serves a certificate other than B's pin
+        let mut misconfigured = validator_a.clone();
+        misconfigured.peers[0].certificate_path = Some(tls_identity("validator_b")?.certificate_path);
+        let misconfigured_tls = PeerTls::from_config(&misconfigured)?;
+        assert!(p2p::submit_partial(peer_b, misconfigured_tls.as_ref(), tx_hash, &partial).await.is_err());
+        assert_eq!(coordinator_b.session(tx_hash).await.map(|s| s.partial_signatures.len()), Some(0));
+
+        // The same partial goes through on A's pinned connection
+        let tls_a = PeerTls::from_config(&validator_a)?;
+        p2p::submit_partial(peer_b, tls_a.as_ref(), tx_hash, &partial).await?;
+        assert_eq!(coordinator_b.session(tx_hash).await.map(|s| s.partial_signatures.len()), Some(1));
+
+        // Without a pinned certificate for an active peer TLS cannot be set up
+        let mut unpinned = validator_a;
+        unpinned.peers[0].certificate_path = None;
+        assert!(PeerTls::from_config(&unpinned).is_err());
+
+        cancel.cancel();
+        Ok(())
+    }).await
+}
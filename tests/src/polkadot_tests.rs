@@ -77,3 +77,248 @@ async fn test_polkadot_pallet_interaction() -> TestResult<()> {
         Ok(())
     }).await
 }
+
+#[tokio::test]
+async fn test_mint_failure_reports_recipient_problems() -> TestResult<()> {
+    use relayer::{polkadot::mint_failure, RelayerError};
+
+    let recipient = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+
+    let error = mint_failure(recipient, "RecipientBelowMinBalance");
+    assert!(matches!(error, RelayerError::RecipientBelowMinBalance { recipient: ref r } if r == recipient));
+    assert!(error.to_string().contains("minimum balance"));
+
+    let error = mint_failure(recipient, "RecipientCannotHoldAsset");
+    assert!(matches!(error, RelayerError::RecipientCannotHoldAsset { .. }));
+
+    // Other pallet errors keep their name in the message
+    let error = mint_failure(recipient, "InsufficientSignatures");
+    assert!(matches!(error, RelayerError::Polkadot { ref message } if message.contains("InsufficientSignatures")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_call_carries_decoded_arguments() -> TestResult<()> {
+    use relayer::{polkadot::mint_call, RelayerError};
+    use subxt::{dynamic::Value, ext::scale_value::Composite};
+
+    let recipient = [0x2au8; 32];
+    let token = [0x11u8; 20];
+    let tx_hash = [0x22u8; 32];
+    let signatures = vec![vec![1u8; 65], vec![2u8; 65]];
+
+    let recipient_hex = format!("0x{}", "2a".repeat(32));
+
+    let call = mint_call(
+        "bridge",
+        &recipient_hex,
+        &format!("0x{}", "11".repeat(20)),
+        "1000000000000000000",
+        &format!("0x{}", "22".repeat(32)),
+        &signatures,
+    )?;
+
+    assert_eq!(call.pallet_name(), "bridge");
+    assert_eq!(call.call_name(), "mint_tokens");
+    assert_eq!(
+        call.call_data(),
+        &Composite::Unnamed(vec![
+            Value::from_bytes(recipient),
+            Value::from_bytes(token),
+            Value::u128(1_000_000_000_000_000_000),
+            Value::from_bytes(tx_hash),
+            Value::unnamed_composite(vec![Value::from_bytes(&signatures[0]), Value::from_bytes(&signatures[1])]),
+        ])
+    );
+
+    // Arguments the pallet could not decode are refused before signing
+    let error = mint_call("bridge", "5Grw", "0x1111", "1", "0x22", &[]).unwrap_err();
+    assert!(matches!(error, RelayerError::InvalidDestination { .. }));
+    let error = mint_call("bridge", &recipient_hex, "0x1111", "1", "0x22", &[]).unwrap_err();
+    assert!(error.to_string().contains("token address"), "{}", error);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_batch_call_carries_one_tuple_per_mint() -> TestResult<()> {
+    use relayer::polkadot::{mint_batch_call, mint_call, PendingMint};
+    use subxt::{dynamic::Value, ext::scale_value::Composite};
+
+    let mint = |tx_byte: &str| PendingMint {
+        recipient: format!("0x{}", "2a".repeat(32)),
+        ethereum_address: format!("0x{}", "11".repeat(20)),
+        amount: "1000".to_string(),
+        ethereum_tx_hash: format!("0x{}", tx_byte.repeat(32)),
+        signatures: vec![vec![1u8; 65]],
+    };
+    let mints = vec![mint("22"), mint("33")];
+
+    let call = mint_batch_call("bridge", &mints)?;
+    assert_eq!(call.pallet_name(), "bridge");
+    assert_eq!(call.call_name(), "mint_tokens_batch");
+
+    // Each tuple holds the same arguments a single mint would
+    let tuples = mints
+        .iter()
+        .map(|m| {
+            let single = mint_call("bridge", &m.recipient, &m.ethereum_address, &m.amount, &m.ethereum_tx_hash, &m.signatures)?;
+            Ok(Value::unnamed_composite(single.call_data().values().cloned()))
+        })
+        .collect::<relayer::Result<Vec<_>>>()?;
+    assert_eq!(call.call_data(), &Composite::Unnamed(vec![Value::unnamed_composite(tuples)]));
+
+    // One undecodable mint fails the whole call
+    let mut bad = mint("44");
+    bad.amount = "-1".to_string();
+    assert!(mint_batch_call("bridge", &[mints[0].clone(), bad]).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pool_errors_map_to_submission_actions() -> TestResult<()> {
+    use relayer::polkadot::{classify_pool_error, PoolErrorAction};
+
+    // Stale or outbid nonces are resynced
+    assert_eq!(
+        classify_pool_error("Rpc error: RPC error: ErrorObject { code: 1010, message: \"Invalid Transaction\", data: \"Transaction is outdated\" }"),
+        PoolErrorAction::ResyncNonce
+    );
+    assert_eq!(classify_pool_error("Invalid(Stale)"), PoolErrorAction::ResyncNonce);
+    assert_eq!(classify_pool_error("1014: Priority is too low"), PoolErrorAction::ResyncNonce);
+
+    // Nonces ahead of the account's are waited out
+    assert_eq!(
+        classify_pool_error("Invalid Transaction (1010): Transaction will be valid in the future"),
+        PoolErrorAction::Wait
+    );
+    assert_eq!(classify_pool_error("Invalid(Future)"), PoolErrorAction::Wait);
+    assert_eq!(classify_pool_error("1012: Transaction is temporarily banned"), PoolErrorAction::Wait);
+
+    // Bad signatures and unpayable fees are terminal
+    assert_eq!(
+        classify_pool_error("Invalid Transaction (1010): Transaction has a bad signature"),
+        PoolErrorAction::Terminal
+    );
+    assert_eq!(classify_pool_error("Invalid(BadProof)"), PoolErrorAction::Terminal);
+    assert_eq!(
+        classify_pool_error("Inability to pay some fees (e.g. account balance too low)"),
+        PoolErrorAction::Terminal
+    );
+
+    // Anything else is an ordinary submission failure
+    assert_eq!(classify_pool_error("Rpc error: connection reset by peer"), PoolErrorAction::Other);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_consecutive_submissions_get_sequential_nonces() -> TestResult<()> {
+    use async_trait::async_trait;
+    use relayer::polkadot::{NonceSource, NonceTracker};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use subxt::utils::AccountId32;
+
+    /// Chain whose account nonce only moves when a test says so
+    struct FakeChain {
+        nonce: AtomicU64,
+        fetches: AtomicU64,
+    }
+
+    #[async_trait]
+    impl NonceSource for FakeChain {
+        async fn fetch_nonce(&self, _account: &AccountId32) -> relayer::error::Result<u64> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            Ok(self.nonce.load(Ordering::SeqCst))
+        }
+    }
+
+    init_test_logging();
+
+    let chain = FakeChain {
+        nonce: AtomicU64::new(7),
+        fetches: AtomicU64::new(0),
+    };
+    let account = AccountId32::from([1u8; 32]);
+    let tracker = NonceTracker::new();
+
+    // Fetched once on startup, then counted locally before the chain catches up
+    assert_eq!(tracker.sync(&chain, &account).await?, 7);
+    assert_eq!(tracker.reserve(&chain, &account).await?, 7);
+    assert_eq!(tracker.reserve(&chain, &account).await?, 8);
+    assert_eq!(chain.fetches.load(Ordering::SeqCst), 1);
+
+    // A stale nonce error resyncs from the chain
+    chain.nonce.store(12, Ordering::SeqCst);
+    assert_eq!(tracker.resync(&chain, &account).await?, 12);
+    assert_eq!(tracker.reserve(&chain, &account).await?, 13);
+
+    // An abandoned submission's nonce is fetched again rather than skipped
+    tracker.forget(&account).await;
+    assert_eq!(tracker.reserve(&chain, &account).await?, 12);
+    assert_eq!(chain.fetches.load(Ordering::SeqCst), 3);
+
+    // Other accounts are tracked separately
+    let other = AccountId32::from([2u8; 32]);
+    assert_eq!(tracker.reserve(&chain, &other).await?, 12);
+
+    Ok(())
+}
+
+#[test]
+fn test_lock_destinations_are_decoded_and_checked() {
+    use relayer::{polkadot::decode_destination, RelayerError};
+
+    let alice = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+    let alice_key = "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+
+    // The contract's hex form and SS58 decode to the same account
+    let from_hex = decode_destination("0xlock", alice_key).unwrap();
+    assert_eq!(from_hex, decode_destination("0xlock", alice).unwrap());
+    assert_eq!(from_hex.to_string(), alice);
+
+    let rejected = [
+        format!("0x{}", "00".repeat(32)),
+        "0xd43593c715fdd31c".to_string(),
+        "0xnot-hex".to_string(),
+        // Alice with the last character changed fails the SS58 checksum
+        "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ".to_string(),
+        "5Grw".to_string(),
+    ];
+    for destination in rejected {
+        let err = decode_destination("0xlock", &destination).unwrap_err();
+        assert!(matches!(err, RelayerError::InvalidDestination { .. }), "{} accepted", destination);
+        assert_eq!(err.failure_reason(), Some("invalid_destination"));
+    }
+}
+
+#[test]
+fn test_mint_message_matches_pallet_encoding() {
+    use ethers::utils::{hex, keccak256};
+    use relayer::polkadot::mint_message;
+
+    let alice = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+    let alice_key = hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d").unwrap();
+    let token = "0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8";
+    let tx_hash = format!("0x{}", "ab".repeat(32));
+
+    // Raw "mint", the account, the token, the amount as a little-endian
+    // u128 and the lock hash, with no length prefixes
+    let mut encoded = b"mint".to_vec();
+    encoded.extend_from_slice(&alice_key);
+    encoded.extend_from_slice(&hex::decode(&token[2..]).unwrap());
+    encoded.extend_from_slice(&1_000_000u128.to_le_bytes());
+    encoded.extend_from_slice(&[0xab; 32]);
+    assert_eq!(encoded.len(), 4 + 32 + 20 + 16 + 32);
+
+    let digest = mint_message(alice, token, "1000000", &tx_hash).unwrap();
+    assert_eq!(digest, keccak256(&encoded));
+    assert_ne!(digest, mint_message(alice, token, "1000001", &tx_hash).unwrap());
+
+    // Mints that could not be submitted cannot be signed either
+    assert!(mint_message("5Grw", token, "1000000", &tx_hash).is_err());
+    assert!(mint_message(alice, "0xtoken", "1000000", &tx_hash).is_err());
+    assert!(mint_message(alice, token, "1000000", "0xlock").is_err());
+}
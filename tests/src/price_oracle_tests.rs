@@ -0,0 +1,83 @@
+//! Price oracle tests
+
+use crate::common::TestResult;
+use async_trait::async_trait;
+use relayer::{
+    price_oracle::{usd_value, CachedPriceOracle, PriceOracle, StaticPriceOracle},
+    RelayerError,
+};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Oracle returning fixed prices and counting lookups
+struct MockOracle {
+    calls: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl PriceOracle for MockOracle {
+    async fn price(&self, token: &str) -> relayer::Result<Decimal> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        match token {
+            "0xweth" => Ok(Decimal::from(2000)),
+            "0xusdc" => Ok(Decimal::ONE),
+            _ => Err(RelayerError::PriceUnavailable { token: token.to_string() }),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_usd_normalization_with_mock_oracle() -> TestResult<()> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let oracle = MockOracle { calls: calls.clone() };
+
+    let value = usd_value(Some(&oracle), "0xweth", Decimal::from_str("1.5")?).await?;
+    assert_eq!(value, Some(Decimal::from(3000)));
+
+    let value = usd_value(Some(&oracle), "0xusdc", Decimal::from(250)).await?;
+    assert_eq!(value, Some(Decimal::from(250)));
+
+    assert!(usd_value(Some(&oracle), "0xunknown", Decimal::ONE).await.is_err());
+
+    // Without an oracle amounts stay in native units
+    assert_eq!(usd_value(None, "0xweth", Decimal::ONE).await?, None);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cached_oracle_reuses_prices_within_ttl() -> TestResult<()> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let oracle = CachedPriceOracle::new(
+        MockOracle { calls: calls.clone() },
+        Duration::from_secs(60),
+    );
+
+    assert_eq!(oracle.price("0xweth").await?, Decimal::from(2000));
+    assert_eq!(oracle.price("0xweth").await?, Decimal::from(2000));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    let expiring = CachedPriceOracle::new(MockOracle { calls: calls.clone() }, Duration::ZERO);
+    expiring.price("0xusdc").await?;
+    expiring.price("0xusdc").await?;
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_static_oracle_is_case_insensitive() -> TestResult<()> {
+    let oracle = StaticPriceOracle::new(HashMap::from([
+        ("0xA0b86a33E6441e6e80D0c4C34F4F6cA4C7C7B0c8".to_string(), Decimal::from(5)),
+    ]));
+
+    assert_eq!(oracle.price("0xa0b86a33e6441e6e80d0c4c34f4f6ca4c7c7b0c8").await?, Decimal::from(5));
+    assert!(oracle.price("0x0000000000000000000000000000000000000000").await.is_err());
+
+    Ok(())
+}
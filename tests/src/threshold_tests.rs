@@ -2,7 +2,6 @@
 
 use crate::common::{
     setup::{setup_test_threshold_manager, setup_test_validators, init_test_logging},
-    mock_data::{mock_threshold_signatures, mock_private_keys},
     assertions::{assert_valid_signature},
     TestResult, with_timeout,
 };
@@ -92,7 +91,7 @@ async fn test_signature_aggregation() -> TestResult<()> {
         
         // Create partial signatures from threshold number of validators
         let mut partial_sigs = Vec::new();
-        for (validator_id, key_share) in key_shares.iter().take(2) {
+        for key_share in key_shares.values().take(2) {
             let partial_sig = manager
                 .create_partial_signature(key_share, message, session_id)
                 .await?;
@@ -125,9 +124,13 @@ async fn test_signing_session_management() -> TestResult<()> {
         let message = b"test session management";
         let session_id = "session_789".to_string();
         
+        let validator_ids = setup_test_validators().await?;
+        let key_shares = manager.generate_key_shares(&validator_ids).await?;
+        let public_key_shares = utils::extract_public_key_shares(&key_shares)?;
+
         // Create signing session
         let mut session = manager
-            .create_signing_session(message, session_id.clone())
+            .create_signing_session(message, session_id.clone(), &public_key_shares)
             .await?;
         
         assert_eq!(session.id, session_id);
@@ -136,9 +139,6 @@ async fn test_signing_session_management() -> TestResult<()> {
         assert!(!manager.is_session_ready(&session));
         
         // Add partial signatures
-        let validator_ids = setup_test_validators().await?;
-        let key_shares = manager.generate_key_shares(&validator_ids).await?;
-        
         for (i, (validator_id, key_share)) in key_shares.iter().take(2).enumerate() {
             let partial_sig = manager
                 .create_partial_signature(key_share, message, &session_id)
@@ -225,7 +225,7 @@ async fn test_signature_verification() -> TestResult<()> {
         
         // Create and aggregate signatures
         let mut partial_sigs = Vec::new();
-        for (validator_id, key_share) in key_shares.iter().take(2) {
+        for key_share in key_shares.values().take(2) {
             let partial_sig = manager
                 .create_partial_signature(key_share, message, session_id)
                 .await?;
@@ -253,3 +253,36 @@ async fn test_signature_verification() -> TestResult<()> {
         Ok(())
     }).await
 }
+
+#[tokio::test]
+async fn test_key_share_loads_from_encrypted_keystore() -> TestResult<()> {
+    init_test_logging();
+
+    with_timeout(async {
+        let manager = setup_test_threshold_manager().await?;
+        let validator_ids = setup_test_validators().await?;
+        let key_shares = manager.generate_key_shares(&validator_ids).await?;
+        let stored = &key_shares[&validator_ids[0]];
+
+        let path = std::env::temp_dir().join(format!("validator-{}.keystore", rand::random::<u64>()));
+        std::fs::write(&path, threshold::keystore::encrypt_key_share(stored, "test passphrase")?)?;
+
+        let keystore = relayer::keystore::KeyStore::new(&path, "test passphrase");
+        let loaded = keystore.key_share().await?;
+        std::fs::remove_file(&path)?;
+
+        // The cached share is served without touching the file again
+        assert!(std::sync::Arc::ptr_eq(&loaded, &keystore.key_share().await?));
+        assert_eq!(loaded.validator_id, stored.validator_id);
+
+        let message = b"mint:5Grw:0xtoken:1000:0xlock";
+        let partial = manager.create_partial_signature(&loaded, message, "0xlock").await?;
+        assert!(manager.verify_partial_signature(&stored.public_share, message, "0xlock", &partial).await?);
+
+        // Once removed, a fresh keystore for the same path can no longer load
+        let missing = relayer::keystore::KeyStore::new(&path, "test passphrase");
+        assert!(missing.key_share().await.is_err());
+
+        Ok(())
+    }).await
+}
@@ -0,0 +1,45 @@
+//! Criterion benchmarks for key generation, signing, aggregation and
+//! verification across validator-set sizes
+//!
+//! Run with `cargo bench -p threshold --features bench`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use threshold::bench::BenchFixture;
+use tokio::runtime::Runtime;
+
+/// (k, n) validator sets to compare
+const SETS: [(u32, u32); 4] = [(2, 3), (3, 5), (5, 9), (10, 15)];
+
+/// Seed for reproducible key sets
+const SEED: u64 = 42;
+
+fn threshold_operations(c: &mut Criterion) {
+    let runtime = Runtime::new().expect("tokio runtime");
+
+    for (threshold, total) in SETS {
+        let fixture = runtime
+            .block_on(BenchFixture::new(threshold, total, SEED))
+            .expect("benchmark fixture");
+        let partials = runtime.block_on(fixture.partial_sign()).expect("partial signatures");
+        let aggregated = runtime
+            .block_on(fixture.aggregate_signatures(&partials))
+            .expect("aggregated signature");
+        let set = format!("{}-of-{}", threshold, total);
+
+        c.bench_with_input(BenchmarkId::new("generate_keys", &set), &fixture, |b, fixture| {
+            b.iter(|| runtime.block_on(fixture.generate_keys()).unwrap())
+        });
+        c.bench_with_input(BenchmarkId::new("partial_sign", &set), &fixture, |b, fixture| {
+            b.iter(|| runtime.block_on(fixture.partial_sign()).unwrap())
+        });
+        c.bench_with_input(BenchmarkId::new("aggregate_signatures", &set), &fixture, |b, fixture| {
+            b.iter(|| runtime.block_on(fixture.aggregate_signatures(&partials)).unwrap())
+        });
+        c.bench_with_input(BenchmarkId::new("verify_signature", &set), &fixture, |b, fixture| {
+            b.iter(|| runtime.block_on(fixture.verify_signature(&aggregated)).unwrap())
+        });
+    }
+}
+
+criterion_group!(benches, threshold_operations);
+criterion_main!(benches);
@@ -0,0 +1,164 @@
+//! Timing of threshold signature operations across validator-set sizes
+//!
+//! [`BenchFixture`] prepares a seeded k-of-n key set so each operation can be
+//! run in isolation. The criterion harness in `benches/` (feature `bench`)
+//! drives it repeatedly; [`time_operations`] times a single run so a node can
+//! report how its hardware copes with a proposed validator-set size.
+
+use crate::{
+    error::Result,
+    simple::SimpleThresholdManager,
+    types::{AggregatedSignature, KeyShare, PartialSignature, PublicKeyShare, ThresholdConfig, ValidatorId},
+    utils,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Message every fixture signs
+const BENCH_MESSAGE: &[u8] = b"threshold benchmark message";
+
+/// Session every fixture signs under
+const BENCH_SESSION: &str = "threshold_benchmark";
+
+/// A seeded k-of-n key set ready to sign
+pub struct BenchFixture {
+    manager: SimpleThresholdManager,
+    validator_ids: Vec<ValidatorId>,
+    key_shares: HashMap<ValidatorId, KeyShare>,
+    public_key_shares: Vec<PublicKeyShare>,
+    seed: u64,
+}
+
+impl BenchFixture {
+    /// Generate a `threshold`-of-`total` key set from `seed`
+    pub async fn new(threshold: u32, total: u32, seed: u64) -> Result<Self> {
+        let manager = SimpleThresholdManager::new(ThresholdConfig::new(threshold, total, 256)?)?;
+        let validator_ids = utils::generate_test_validator_ids(total as usize);
+        let key_shares = manager
+            .generate_key_shares_with_rng(&validator_ids, &mut StdRng::seed_from_u64(seed))
+            .await?;
+        let public_key_shares = utils::extract_public_key_shares(&key_shares)?;
+
+        Ok(Self {
+            manager,
+            validator_ids,
+            key_shares,
+            public_key_shares,
+            seed,
+        })
+    }
+
+    /// Signatures required
+    pub fn threshold(&self) -> u32 {
+        self.manager.config().threshold
+    }
+
+    /// Validators holding a key share
+    pub fn total(&self) -> u32 {
+        self.manager.config().total_validators
+    }
+
+    /// Generate a fresh key set for every validator
+    pub async fn generate_keys(&self) -> Result<HashMap<ValidatorId, KeyShare>> {
+        self.manager
+            .generate_key_shares_with_rng(&self.validator_ids, &mut StdRng::seed_from_u64(self.seed))
+            .await
+    }
+
+    /// Partially sign the benchmark message with the first `threshold` validators
+    pub async fn partial_sign(&self) -> Result<Vec<PartialSignature>> {
+        let mut partials = Vec::with_capacity(self.threshold() as usize);
+        for validator_id in self.validator_ids.iter().take(self.threshold() as usize) {
+            partials.push(
+                self.manager
+                    .create_partial_signature(&self.key_shares[validator_id], BENCH_MESSAGE, BENCH_SESSION)
+                    .await?,
+            );
+        }
+        Ok(partials)
+    }
+
+    /// Aggregate `partials` into a signature
+    pub async fn aggregate_signatures(&self, partials: &[PartialSignature]) -> Result<AggregatedSignature> {
+        self.manager
+            .aggregate_signatures(partials, &self.public_key_shares, BENCH_MESSAGE, BENCH_SESSION)
+            .await
+    }
+
+    /// Verify an aggregated signature against the first signer's key
+    pub async fn verify_signature(&self, signature: &AggregatedSignature) -> Result<bool> {
+        let public_key = &self.key_shares[&self.validator_ids[0]].public_share;
+        self.manager
+            .verify_signature(signature, BENCH_MESSAGE, public_key, BENCH_SESSION)
+            .await
+    }
+}
+
+/// Wall-clock time of one run of each operation for a k-of-n key set
+#[derive(Debug, Clone, Serialize)]
+pub struct OperationTimings {
+    pub threshold: u32,
+    pub total: u32,
+    pub generate_keys: Duration,
+    pub partial_sign: Duration,
+    pub aggregate_signatures: Duration,
+    pub verify_signature: Duration,
+}
+
+/// Time key generation, signing, aggregation and verification once for a
+/// `threshold`-of-`total` key set generated from `seed`
+pub async fn time_operations(threshold: u32, total: u32, seed: u64) -> Result<OperationTimings> {
+    let fixture = BenchFixture::new(threshold, total, seed).await?;
+
+    let started = Instant::now();
+    fixture.generate_keys().await?;
+    let generate_keys = started.elapsed();
+
+    let started = Instant::now();
+    let partials = fixture.partial_sign().await?;
+    let partial_sign = started.elapsed();
+
+    let started = Instant::now();
+    let aggregated = fixture.aggregate_signatures(&partials).await?;
+    let aggregate_signatures = started.elapsed();
+
+    let started = Instant::now();
+    fixture.verify_signature(&aggregated).await?;
+    let verify_signature = started.elapsed();
+
+    Ok(OperationTimings {
+        threshold,
+        total,
+        generate_keys,
+        partial_sign,
+        aggregate_signatures,
+        verify_signature,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_benchmark_operations_run_for_small_and_large_sets() {
+        for (threshold, total) in [(2, 3), (5, 9)] {
+            let fixture = BenchFixture::new(threshold, total, 7).await.unwrap();
+            let partials = fixture.partial_sign().await.unwrap();
+            assert_eq!(partials.len(), threshold as usize);
+
+            let aggregated = fixture.aggregate_signatures(&partials).await.unwrap();
+            assert!(fixture.verify_signature(&aggregated).await.unwrap());
+
+            // The same seed regenerates the same key set
+            let first = &fixture.validator_ids[0];
+            let regenerated = fixture.generate_keys().await.unwrap();
+            assert_eq!(regenerated[first].public_share, fixture.key_shares[first].public_share);
+
+            let timings = time_operations(threshold, total, 7).await.unwrap();
+            assert_eq!((timings.threshold, timings.total), (threshold, total));
+        }
+    }
+}
@@ -0,0 +1,761 @@
+//! Verifiable distribution of DKG shares between validators
+//!
+//! Without a trusted dealer, each validator deals shares of its own secret
+//! polynomial to every other validator over the network. Shares are encrypted
+//! to the recipient's secp256k1 public key with ECIES (ECDH, HKDF-SHA256 and
+//! ChaCha20-Poly1305), and checked on receipt against the dealer's Feldman
+//! commitments to the polynomial coefficients.
+//!
+//! A symmetric ciphertext cannot carry a public proof about its plaintext, so a
+//! cheating dealer is exposed by the recipient instead: [`disclose_share_key`]
+//! reveals the ECDH key for one blob together with a Chaum-Pedersen proof that
+//! it was derived from the recipient's public key, letting anyone decrypt that
+//! blob and confirm the share is inconsistent via [`verify_complaint`] without
+//! learning the recipient's private key.
+//!
+//! [`DkgSession`] runs the protocol for one participant: it deals its own
+//! polynomial, checks what the other participants deal, and combines the
+//! received shares into a [`KeyShare`] of a group key nobody ever held.
+
+use crate::{
+    error::{Result, ThresholdError},
+    types::{DkgCommitment, DkgParams, DkgShare, KeyEpoch, KeyShare, ValidatorId},
+};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    ChaCha20Poly1305, Nonce,
+};
+use hkdf::Hkdf;
+use k256::{
+    elliptic_curve::{
+        group::GroupEncoding,
+        ops::Reduce,
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+        Field, PrimeField,
+    },
+    AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar, U256,
+};
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// Length of a compressed SEC1 point
+const POINT_LEN: usize = 33;
+
+/// HKDF info string binding derived keys to share encryption
+const SHARE_KEY_INFO: &[u8] = b"bridge-dkg-share-v1";
+
+/// Domain separator for the key-disclosure proof challenge
+const DISCLOSURE_DOMAIN: &[u8] = b"bridge-dkg-disclosure-v1";
+
+/// Domain separator for the proof of knowledge of a dealer's secret
+const POSSESSION_DOMAIN: &[u8] = b"bridge-dkg-possession-v1";
+
+/// Proof that a disclosed ECDH key belongs to an encrypted share blob
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareDisclosure {
+    /// ECDH point shared between dealer and recipient (compressed SEC1)
+    pub shared_point: Vec<u8>,
+    /// Chaum-Pedersen challenge
+    pub challenge: Vec<u8>,
+    /// Chaum-Pedersen response
+    pub response: Vec<u8>,
+}
+
+/// Generate random polynomial coefficients for a `threshold`-of-n sharing
+///
+/// The first coefficient is the dealer's secret.
+pub fn generate_polynomial(threshold: u32) -> Vec<Vec<u8>> {
+    (0..threshold)
+        .map(|_| Scalar::random(&mut OsRng).to_bytes().to_vec())
+        .collect()
+}
+
+/// Feldman commitments `a_j * G` to each polynomial coefficient
+pub fn commit_polynomial(coefficients: &[Vec<u8>]) -> Result<Vec<Vec<u8>>> {
+    coefficients
+        .iter()
+        .map(|coefficient| {
+            let point = ProjectivePoint::GENERATOR * parse_scalar(coefficient)?;
+            Ok(encode_point(&point))
+        })
+        .collect()
+}
+
+/// Evaluate the polynomial at a participant's (1-based) index
+pub fn evaluate_polynomial(coefficients: &[Vec<u8>], index: u32) -> Result<Vec<u8>> {
+    let x = participant_scalar(index)?;
+    let mut result = Scalar::ZERO;
+
+    // Horner's method, highest-degree coefficient first
+    for coefficient in coefficients.iter().rev() {
+        result = result * x + parse_scalar(coefficient)?;
+    }
+
+    Ok(result.to_bytes().to_vec())
+}
+
+/// Check a share against the dealer's Feldman commitments
+///
+/// A share `s` for participant `i` is consistent when
+/// `s * G == sum(C_j * i^j)`.
+pub fn verify_share(share: &[u8], index: u32, commitments: &[Vec<u8>]) -> Result<bool> {
+    if commitments.is_empty() {
+        return Err(ThresholdError::InvalidKeyShare {
+            reason: "No Feldman commitments provided".to_string(),
+        });
+    }
+
+    let x = participant_scalar(index)?;
+    let mut expected = ProjectivePoint::IDENTITY;
+    let mut power = Scalar::ONE;
+
+    for commitment in commitments {
+        expected += parse_point(commitment)? * power;
+        power *= x;
+    }
+
+    Ok(ProjectivePoint::GENERATOR * parse_scalar(share)? == expected)
+}
+
+/// Encrypt a share to a recipient's secp256k1 public key
+///
+/// The returned blob is the ephemeral public key followed by the AEAD
+/// ciphertext, suitable for [`crate::types::DkgShare::share`].
+pub fn encrypt_share_to(recipient_pubkey: &[u8], share: &[u8]) -> Result<Vec<u8>> {
+    let recipient = parse_point(recipient_pubkey)?;
+    parse_scalar(share)?;
+
+    let ephemeral_secret = Scalar::random(&mut OsRng);
+    let ephemeral_public = encode_point(&(ProjectivePoint::GENERATOR * ephemeral_secret));
+    let shared_point = recipient * ephemeral_secret;
+
+    let cipher = share_cipher(&shared_point, &ephemeral_public, &encode_point(&recipient))?;
+    let ciphertext = cipher
+        .encrypt(&Nonce::default(), share)
+        .map_err(|_| ThresholdError::CryptographicError {
+            message: "Failed to encrypt share".to_string(),
+        })?;
+
+    let mut blob = ephemeral_public;
+    blob.extend_from_slice(&ciphertext);
+    Ok(blob)
+}
+
+/// Decrypt a share encrypted to this validator with [`encrypt_share_to`]
+pub fn decrypt_share(my_privkey: &[u8], blob: &[u8]) -> Result<Vec<u8>> {
+    let secret = parse_scalar(my_privkey)?;
+    let (ephemeral_public, _) = split_blob(blob)?;
+    let shared_point = parse_point(ephemeral_public)? * secret;
+
+    open_blob(&shared_point, ProjectivePoint::GENERATOR * secret, blob)
+}
+
+/// Decrypt a share and check it against the dealer's Feldman commitments
+pub fn decrypt_and_verify_share(
+    my_privkey: &[u8],
+    blob: &[u8],
+    index: u32,
+    commitments: &[Vec<u8>],
+) -> Result<Vec<u8>> {
+    let share = decrypt_share(my_privkey, blob)?;
+
+    if !verify_share(&share, index, commitments)? {
+        return Err(ThresholdError::InvalidKeyShare {
+            reason: format!("Share for participant {} does not match the dealer's commitments", index),
+        });
+    }
+
+    Ok(share)
+}
+
+/// Reveal the ECDH key of a received blob so others can audit its share
+pub fn disclose_share_key(my_privkey: &[u8], blob: &[u8]) -> Result<ShareDisclosure> {
+    let secret = parse_scalar(my_privkey)?;
+    let (ephemeral_public, _) = split_blob(blob)?;
+    let ephemeral = parse_point(ephemeral_public)?;
+
+    let public = ProjectivePoint::GENERATOR * secret;
+    let shared_point = ephemeral * secret;
+
+    // Chaum-Pedersen proof that log_G(public) == log_ephemeral(shared_point)
+    let nonce = Scalar::random(&mut OsRng);
+    let challenge = disclosure_challenge(
+        &public,
+        &ephemeral,
+        &shared_point,
+        &(ProjectivePoint::GENERATOR * nonce),
+        &(ephemeral * nonce),
+    );
+    let response = nonce + challenge * secret;
+
+    Ok(ShareDisclosure {
+        shared_point: encode_point(&shared_point),
+        challenge: challenge.to_bytes().to_vec(),
+        response: response.to_bytes().to_vec(),
+    })
+}
+
+/// Check a recipient's complaint against a dealer
+///
+/// Returns `true` when the disclosure is valid for `recipient_pubkey` and the
+/// blob does not decrypt to a share consistent with `commitments`, i.e. the
+/// dealer cheated. Returns `false` for a valid disclosure of a consistent
+/// share, and an error when the disclosure itself is invalid.
+pub fn verify_complaint(
+    recipient_pubkey: &[u8],
+    blob: &[u8],
+    disclosure: &ShareDisclosure,
+    index: u32,
+    commitments: &[Vec<u8>],
+) -> Result<bool> {
+    let public = parse_point(recipient_pubkey)?;
+    let (ephemeral_public, _) = split_blob(blob)?;
+    let ephemeral = parse_point(ephemeral_public)?;
+    let shared_point = parse_point(&disclosure.shared_point)?;
+    let challenge = parse_scalar(&disclosure.challenge)?;
+    let response = parse_scalar(&disclosure.response)?;
+
+    let commitment_g = ProjectivePoint::GENERATOR * response - public * challenge;
+    let commitment_e = ephemeral * response - shared_point * challenge;
+    if disclosure_challenge(&public, &ephemeral, &shared_point, &commitment_g, &commitment_e) != challenge {
+        return Err(ThresholdError::VerificationFailed {
+            reason: "Invalid share key disclosure proof".to_string(),
+        });
+    }
+
+    match open_blob(&shared_point, public, blob) {
+        // A plaintext that is not even a valid scalar is as much a cheat as a
+        // mismatched one
+        Ok(share) => Ok(!matches!(verify_share(&share, index, commitments), Ok(true))),
+        // A blob that does not decrypt under the proven key is itself a cheat
+        Err(_) => Ok(true),
+    }
+}
+
+/// Split a blob into its ephemeral public key and ciphertext
+fn split_blob(blob: &[u8]) -> Result<(&[u8], &[u8])> {
+    if blob.len() <= POINT_LEN {
+        return Err(ThresholdError::SerializationError {
+            message: "Encrypted share is too short".to_string(),
+        });
+    }
+    Ok(blob.split_at(POINT_LEN))
+}
+
+/// Decrypt a blob with a known ECDH point
+fn open_blob(shared_point: &ProjectivePoint, recipient: ProjectivePoint, blob: &[u8]) -> Result<Vec<u8>> {
+    let (ephemeral_public, ciphertext) = split_blob(blob)?;
+    let cipher = share_cipher(shared_point, ephemeral_public, &encode_point(&recipient))?;
+
+    cipher
+        .decrypt(&Nonce::default(), ciphertext)
+        .map_err(|_| ThresholdError::CryptographicError {
+            message: "Failed to decrypt share".to_string(),
+        })
+}
+
+/// Derive the per-blob AEAD key
+///
+/// Each blob uses a fresh ephemeral key, so a fixed nonce is never reused
+/// under the same key.
+fn share_cipher(shared_point: &ProjectivePoint, ephemeral_public: &[u8], recipient: &[u8]) -> Result<ChaCha20Poly1305> {
+    let hkdf = Hkdf::<Sha256>::new(Some(ephemeral_public), &encode_point(shared_point));
+    let mut key = [0u8; 32];
+    hkdf.expand_multi_info(&[SHARE_KEY_INFO, recipient], &mut key)
+        .map_err(|e| ThresholdError::CryptographicError {
+            message: format!("Failed to derive share key: {}", e),
+        })?;
+
+    Ok(ChaCha20Poly1305::new(&key.into()))
+}
+
+/// Fiat-Shamir challenge for the key-disclosure proof
+fn disclosure_challenge(
+    public: &ProjectivePoint,
+    ephemeral: &ProjectivePoint,
+    shared_point: &ProjectivePoint,
+    commitment_g: &ProjectivePoint,
+    commitment_e: &ProjectivePoint,
+) -> Scalar {
+    let mut hasher = Sha256::new();
+    hasher.update(DISCLOSURE_DOMAIN);
+    for point in [public, ephemeral, shared_point, commitment_g, commitment_e] {
+        hasher.update(point.to_bytes());
+    }
+    <Scalar as Reduce<U256>>::reduce_bytes(&hasher.finalize())
+}
+
+/// One participant's side of a distributed key generation
+///
+/// Every participant deals a share of its own random secret to everyone,
+/// including itself, and the group key is the sum of those secrets. A round
+/// goes:
+///
+/// 1. [`Self::contribute`] produces this participant's commitment, which is
+///    broadcast, and one encrypted share per participant, sent to its owner.
+/// 2. Each commitment received is checked with [`Self::receive_commitment`]
+///    and each share addressed to this participant with
+///    [`Self::receive_share`], which rejects shares inconsistent with their
+///    dealer's commitment.
+/// 3. Once every participant has dealt, [`Self::finalize`] sums the shares
+///    into this participant's [`KeyShare`].
+///
+/// Participant indices are 1-based positions in `params.participants`, so
+/// every participant must be given the same list in the same order.
+pub struct DkgSession {
+    params: DkgParams,
+    validator_id: ValidatorId,
+    index: u32,
+    /// Private key shares addressed to this participant are encrypted to
+    encryption_key: Vec<u8>,
+    coefficients: Vec<Vec<u8>>,
+    commitments: HashMap<ValidatorId, Vec<Vec<u8>>>,
+    shares: HashMap<ValidatorId, Vec<u8>>,
+}
+
+impl DkgSession {
+    /// Start a session for `validator_id`, drawing its secret polynomial
+    ///
+    /// `encryption_key` is the private key matching the public key the other
+    /// participants encrypt this validator's shares to.
+    pub fn new(params: DkgParams, validator_id: ValidatorId, encryption_key: &[u8]) -> Result<Self> {
+        params.config.validate()?;
+        parse_scalar(encryption_key)?;
+
+        if params.participants.len() != params.config.total_validators as usize {
+            return Err(ThresholdError::InvalidThreshold {
+                threshold: params.config.threshold,
+                total: params.participants.len() as u32,
+            });
+        }
+
+        let mut unique = params.participants.clone();
+        unique.sort();
+        unique.dedup();
+        if unique.len() != params.participants.len() {
+            return Err(ThresholdError::KeyGenerationFailed {
+                reason: "Participants must be distinct".to_string(),
+            });
+        }
+
+        let index = params.participant_index(&validator_id)?;
+        let coefficients = generate_polynomial(params.config.threshold);
+
+        Ok(Self {
+            params,
+            validator_id,
+            index,
+            encryption_key: encryption_key.to_vec(),
+            coefficients,
+            commitments: HashMap::new(),
+            shares: HashMap::new(),
+        })
+    }
+
+    /// This participant's 1-based index
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Deal this participant's polynomial
+    ///
+    /// Returns the commitment to broadcast and a share for every other
+    /// participant, encrypted to its key in `recipient_keys`. This
+    /// participant's own share is kept by the session.
+    pub fn contribute(
+        &mut self,
+        recipient_keys: &HashMap<ValidatorId, Vec<u8>>,
+    ) -> Result<(DkgCommitment, Vec<DkgShare>)> {
+        let commitments = commit_polynomial(&self.coefficients)?;
+        let commitment = DkgCommitment {
+            validator_id: self.validator_id.clone(),
+            proof: self.prove_possession(&commitments[0])?,
+            commitments: commitments.clone(),
+        };
+
+        let mut shares = Vec::new();
+        for (position, participant) in self.params.participants.iter().enumerate() {
+            let share = evaluate_polynomial(&self.coefficients, position as u32 + 1)?;
+            if *participant == self.validator_id {
+                self.shares.insert(self.validator_id.clone(), share);
+                continue;
+            }
+
+            let recipient_key = recipient_keys.get(participant).ok_or_else(|| ThresholdError::KeyGenerationFailed {
+                reason: format!("No encryption key for participant {}", participant),
+            })?;
+            shares.push(DkgShare {
+                from: self.validator_id.clone(),
+                to: participant.clone(),
+                share: encrypt_share_to(recipient_key, &share)?,
+                verification: self.params.session_id.as_bytes().to_vec(),
+            });
+        }
+
+        self.commitments.insert(self.validator_id.clone(), commitments);
+        Ok((commitment, shares))
+    }
+
+    /// Accept a participant's broadcast commitment
+    ///
+    /// The dealer must prove it knows the secret it committed to, which stops
+    /// it choosing its commitment to cancel out the others'. A dealer that
+    /// broadcasts two different commitments is rejected.
+    pub fn receive_commitment(&mut self, commitment: &DkgCommitment) -> Result<()> {
+        self.params.participant_index(&commitment.validator_id)?;
+
+        if commitment.commitments.len() != self.params.config.threshold as usize {
+            return Err(ThresholdError::KeyGenerationFailed {
+                reason: format!(
+                    "Commitment from {} has {} coefficients, expected {}",
+                    commitment.validator_id,
+                    commitment.commitments.len(),
+                    self.params.config.threshold
+                ),
+            });
+        }
+
+        if let Some(existing) = self.commitments.get(&commitment.validator_id) {
+            if *existing != commitment.commitments {
+                return Err(ThresholdError::KeyGenerationFailed {
+                    reason: format!("Participant {} sent conflicting commitments", commitment.validator_id),
+                });
+            }
+            return Ok(());
+        }
+
+        self.verify_possession(&commitment.validator_id, &commitment.commitments[0], &commitment.proof)?;
+        self.commitments
+            .insert(commitment.validator_id.clone(), commitment.commitments.clone());
+        Ok(())
+    }
+
+    /// Accept a share dealt to this participant
+    ///
+    /// The dealer's commitment must have been received first; a share that
+    /// does not match it fails with [`ThresholdError::InvalidKeyShare`] and
+    /// can be exposed with [`disclose_share_key`].
+    pub fn receive_share(&mut self, share: &DkgShare) -> Result<()> {
+        if share.to != self.validator_id {
+            return Err(ThresholdError::InvalidValidatorId { id: share.to.clone() });
+        }
+        if share.verification != self.params.session_id.as_bytes() {
+            return Err(ThresholdError::KeyGenerationFailed {
+                reason: format!("Share from {} belongs to another DKG session", share.from),
+            });
+        }
+
+        let commitments = self.commitments.get(&share.from).ok_or_else(|| ThresholdError::KeyGenerationFailed {
+            reason: format!("No commitment received from {}", share.from),
+        })?;
+        let value = decrypt_and_verify_share(&self.encryption_key, &share.share, self.index, commitments)?;
+
+        self.shares.insert(share.from.clone(), value);
+        Ok(())
+    }
+
+    /// Combine the received shares into this participant's key share
+    ///
+    /// The key share's `coefficients` hold the commitments to the group
+    /// polynomial, the first of which is the group public key.
+    pub fn finalize(&self) -> Result<KeyShare> {
+        let mut private_share = Scalar::ZERO;
+        let mut group_commitments = vec![ProjectivePoint::IDENTITY; self.params.config.threshold as usize];
+
+        for participant in &self.params.participants {
+            let share = self.shares.get(participant).ok_or_else(|| ThresholdError::KeyGenerationFailed {
+                reason: format!("No share received from {}", participant),
+            })?;
+            let commitments = self.commitments.get(participant).ok_or_else(|| ThresholdError::KeyGenerationFailed {
+                reason: format!("No commitment received from {}", participant),
+            })?;
+
+            private_share += parse_scalar(share)?;
+            for (total, commitment) in group_commitments.iter_mut().zip(commitments) {
+                *total += parse_point(commitment)?;
+            }
+        }
+
+        let public_share = (ProjectivePoint::GENERATOR * private_share).to_affine();
+
+        Ok(KeyShare {
+            validator_id: self.validator_id.clone(),
+            private_share: private_share.to_bytes().to_vec(),
+            public_share: public_share.to_encoded_point(false).as_bytes().to_vec(),
+            coefficients: group_commitments.iter().map(encode_point).collect(),
+            config: self.params.config.clone(),
+            key_epoch: self.key_epoch(),
+        })
+    }
+
+    /// Key epoch of the resulting shares, derived from the session ID so every
+    /// participant agrees on it
+    fn key_epoch(&self) -> KeyEpoch {
+        let digest = Sha256::digest(self.params.session_id.as_bytes());
+        KeyEpoch::from_be_bytes(digest[..8].try_into().expect("digest is 32 bytes"))
+    }
+
+    /// Schnorr proof of knowledge of this dealer's secret, bound to the session
+    fn prove_possession(&self, secret_commitment: &[u8]) -> Result<Vec<u8>> {
+        let nonce = Scalar::random(&mut OsRng);
+        let nonce_commitment = encode_point(&(ProjectivePoint::GENERATOR * nonce));
+        let challenge = self.possession_challenge(&self.validator_id, secret_commitment, &nonce_commitment);
+        let response = nonce + challenge * parse_scalar(&self.coefficients[0])?;
+
+        let mut proof = nonce_commitment;
+        proof.extend_from_slice(&response.to_bytes());
+        Ok(proof)
+    }
+
+    fn verify_possession(&self, dealer: &ValidatorId, secret_commitment: &[u8], proof: &[u8]) -> Result<()> {
+        if proof.len() != POINT_LEN + 32 {
+            return Err(ThresholdError::KeyGenerationFailed {
+                reason: format!("Malformed proof of possession from {}", dealer),
+            });
+        }
+        let (nonce_commitment, response) = proof.split_at(POINT_LEN);
+
+        let challenge = self.possession_challenge(dealer, secret_commitment, nonce_commitment);
+        let expected = parse_point(nonce_commitment)? + parse_point(secret_commitment)? * challenge;
+        if ProjectivePoint::GENERATOR * parse_scalar(response)? != expected {
+            return Err(ThresholdError::KeyGenerationFailed {
+                reason: format!("Invalid proof of possession from {}", dealer),
+            });
+        }
+        Ok(())
+    }
+
+    fn possession_challenge(&self, dealer: &ValidatorId, secret_commitment: &[u8], nonce_commitment: &[u8]) -> Scalar {
+        let mut hasher = Sha256::new();
+        hasher.update(POSSESSION_DOMAIN);
+        hasher.update(self.params.session_id.as_bytes());
+        hasher.update(dealer.as_bytes());
+        hasher.update(secret_commitment);
+        hasher.update(nonce_commitment);
+        <Scalar as Reduce<U256>>::reduce_bytes(&hasher.finalize())
+    }
+}
+
+/// Participant index as a polynomial evaluation point
+fn participant_scalar(index: u32) -> Result<Scalar> {
+    if index == 0 {
+        return Err(ThresholdError::InvalidKeyShare {
+            reason: "Participant indices start at 1".to_string(),
+        });
+    }
+    Ok(Scalar::from(index as u64))
+}
+
+pub(crate) fn parse_scalar(bytes: &[u8]) -> Result<Scalar> {
+    let bytes: [u8; 32] = bytes.try_into().map_err(|_| ThresholdError::InvalidKeyShare {
+        reason: "Scalar must be 32 bytes".to_string(),
+    })?;
+
+    Option::from(Scalar::from_repr(FieldBytes::from(bytes))).ok_or_else(|| ThresholdError::InvalidKeyShare {
+        reason: "Scalar is out of range".to_string(),
+    })
+}
+
+pub(crate) fn parse_point(bytes: &[u8]) -> Result<ProjectivePoint> {
+    let encoded = EncodedPoint::from_bytes(bytes).map_err(|_| ThresholdError::InvalidKeyShare {
+        reason: "Invalid point encoding".to_string(),
+    })?;
+
+    Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+        .map(ProjectivePoint::from)
+        .ok_or_else(|| ThresholdError::InvalidKeyShare {
+            reason: "Point is not on the curve".to_string(),
+        })
+}
+
+/// Check that a key share carries only compressed commitments in
+/// `coefficients`, never the secret polynomial scalars behind them
+pub(crate) fn check_commitments(share: &KeyShare) -> Result<()> {
+    if share.coefficients.iter().any(|c| c.len() != 33) {
+        return Err(ThresholdError::InvalidKeyShare {
+            reason: format!(
+                "Key share of {} must carry 33-byte polynomial commitments, not secret coefficients",
+                share.validator_id
+            ),
+        });
+    }
+    Ok(())
+}
+
+fn encode_point(point: &ProjectivePoint) -> Vec<u8> {
+    point.to_affine().to_encoded_point(true).as_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ThresholdConfig;
+
+    fn keypair() -> (Vec<u8>, Vec<u8>) {
+        let secret = Scalar::random(&mut OsRng);
+        (secret.to_bytes().to_vec(), encode_point(&(ProjectivePoint::GENERATOR * secret)))
+    }
+
+    #[test]
+    fn test_encrypted_share_round_trip() {
+        let coefficients = generate_polynomial(2);
+        let commitments = commit_polynomial(&coefficients).unwrap();
+        let (privkey, pubkey) = keypair();
+
+        let share = evaluate_polynomial(&coefficients, 3).unwrap();
+        let blob = encrypt_share_to(&pubkey, &share).unwrap();
+
+        assert_eq!(decrypt_share(&privkey, &blob).unwrap(), share);
+        assert_eq!(decrypt_and_verify_share(&privkey, &blob, 3, &commitments).unwrap(), share);
+
+        // Another validator cannot open the blob
+        let (other_privkey, _) = keypair();
+        assert!(decrypt_share(&other_privkey, &blob).is_err());
+
+        // An honest share gives no grounds for a complaint
+        let disclosure = disclose_share_key(&privkey, &blob).unwrap();
+        assert!(!verify_complaint(&pubkey, &blob, &disclosure, 3, &commitments).unwrap());
+    }
+
+    #[test]
+    fn test_cheating_dealer_share_is_detected() {
+        let coefficients = generate_polynomial(2);
+        let commitments = commit_polynomial(&coefficients).unwrap();
+        let (privkey, pubkey) = keypair();
+
+        // Dealer sends participant 2 the share meant for participant 1
+        let wrong_share = evaluate_polynomial(&coefficients, 1).unwrap();
+        let blob = encrypt_share_to(&pubkey, &wrong_share).unwrap();
+
+        let result = decrypt_and_verify_share(&privkey, &blob, 2, &commitments);
+        assert!(matches!(result, Err(ThresholdError::InvalidKeyShare { .. })));
+
+        // The recipient's disclosure lets anyone confirm the cheat
+        let disclosure = disclose_share_key(&privkey, &blob).unwrap();
+        assert!(verify_complaint(&pubkey, &blob, &disclosure, 2, &commitments).unwrap());
+
+        // A forged disclosure for someone else's key is rejected
+        let (_, other_pubkey) = keypair();
+        assert!(verify_complaint(&other_pubkey, &blob, &disclosure, 2, &commitments).is_err());
+    }
+
+    /// Run a full DKG between `participants`, delivering every message
+    fn run_dkg(threshold: u32, participants: &[ValidatorId]) -> Vec<KeyShare> {
+        let params = DkgParams {
+            config: ThresholdConfig::new(threshold, participants.len() as u32, 256).unwrap(),
+            participants: participants.to_vec(),
+            round: 0,
+            session_id: "dkg-test".to_string(),
+        };
+
+        let keys: Vec<_> = participants.iter().map(|_| keypair()).collect();
+        let recipient_keys: HashMap<_, _> = participants
+            .iter()
+            .zip(&keys)
+            .map(|(id, (_, pubkey))| (id.clone(), pubkey.clone()))
+            .collect();
+        let mut sessions: Vec<_> = participants
+            .iter()
+            .zip(&keys)
+            .map(|(id, (privkey, _))| DkgSession::new(params.clone(), id.clone(), privkey).unwrap())
+            .collect();
+
+        let contributions: Vec<_> = sessions
+            .iter_mut()
+            .map(|session| session.contribute(&recipient_keys).unwrap())
+            .collect();
+        for session in &mut sessions {
+            for (commitment, _) in &contributions {
+                session.receive_commitment(commitment).unwrap();
+            }
+        }
+        for (_, shares) in &contributions {
+            for share in shares {
+                let recipient = params.participant_index(&share.to).unwrap() as usize - 1;
+                sessions[recipient].receive_share(share).unwrap();
+            }
+        }
+
+        sessions.iter().map(|session| session.finalize().unwrap()).collect()
+    }
+
+    fn lagrange_at_zero(index: u32, signers: &[u32]) -> Scalar {
+        signers.iter().filter(|&&j| j != index).fold(Scalar::ONE, |acc, &j| {
+            let denominator = Scalar::from(j as u64) - Scalar::from(index as u64);
+            acc * Scalar::from(j as u64) * denominator.invert().unwrap()
+        })
+    }
+
+    #[test]
+    fn test_dkg_shares_sign_under_the_group_key() {
+        let participants = crate::utils::generate_test_validator_ids(3);
+        let key_shares = run_dkg(2, &participants);
+
+        let group_key = parse_point(&key_shares[0].coefficients[0]).unwrap();
+        for (position, share) in key_shares.iter().enumerate() {
+            assert_eq!(share.coefficients, key_shares[0].coefficients);
+            assert_eq!(share.key_epoch, key_shares[0].key_epoch);
+            crate::utils::verify_key_share(share, position as u32 + 1).unwrap();
+        }
+
+        // Validators 1 and 3 sign a Schnorr signature with their shares alone
+        let signers = [1u32, 3u32];
+        let nonces: Vec<Scalar> = signers.iter().map(|_| Scalar::random(&mut OsRng)).collect();
+        let nonce_point = nonces
+            .iter()
+            .fold(ProjectivePoint::IDENTITY, |acc, nonce| acc + ProjectivePoint::GENERATOR * nonce);
+
+        let mut hasher = Sha256::new();
+        hasher.update(encode_point(&nonce_point));
+        hasher.update(encode_point(&group_key));
+        hasher.update(b"message");
+        let challenge = <Scalar as Reduce<U256>>::reduce_bytes(&hasher.finalize());
+
+        let signature = signers.iter().zip(&nonces).fold(Scalar::ZERO, |acc, (&index, nonce)| {
+            let share = parse_scalar(&key_shares[index as usize - 1].private_share).unwrap();
+            acc + nonce + challenge * lagrange_at_zero(index, &signers) * share
+        });
+
+        assert_eq!(ProjectivePoint::GENERATOR * signature, nonce_point + group_key * challenge);
+    }
+
+    #[test]
+    fn test_dkg_rejects_inconsistent_share() {
+        let participants = crate::utils::generate_test_validator_ids(2);
+        let params = DkgParams {
+            config: ThresholdConfig::new(2, 2, 256).unwrap(),
+            participants: participants.clone(),
+            round: 0,
+            session_id: "dkg-test".to_string(),
+        };
+        let (dealer_key, dealer_pubkey) = keypair();
+        let (recipient_key, recipient_pubkey) = keypair();
+        let recipient_keys: HashMap<_, _> = [
+            (participants[0].clone(), dealer_pubkey),
+            (participants[1].clone(), recipient_pubkey.clone()),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut dealer = DkgSession::new(params.clone(), participants[0].clone(), &dealer_key).unwrap();
+        let mut recipient = DkgSession::new(params, participants[1].clone(), &recipient_key).unwrap();
+        let (commitment, mut shares) = dealer.contribute(&recipient_keys).unwrap();
+
+        // A share arriving before its dealer's commitment cannot be checked
+        assert!(recipient.receive_share(&shares[0]).is_err());
+        recipient.receive_commitment(&commitment).unwrap();
+
+        // The dealer swaps in the share for participant 1
+        let wrong_share = evaluate_polynomial(&dealer.coefficients, 1).unwrap();
+        shares[0].share = encrypt_share_to(&recipient_pubkey, &wrong_share).unwrap();
+        let result = recipient.receive_share(&shares[0]);
+        assert!(matches!(result, Err(ThresholdError::InvalidKeyShare { .. })));
+
+        // A forged proof of possession is rejected
+        let mut forged = commitment.clone();
+        forged.validator_id = participants[1].clone();
+        assert!(dealer.receive_commitment(&forged).is_err());
+    }
+}
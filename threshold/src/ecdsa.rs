@@ -1,84 +1,53 @@
 //! ECDSA threshold signature implementation
+//!
+//! Signers use independent nonces, so a key set with a threshold above one
+//! produces threshold multisignatures: each signer's recoverable signature,
+//! verified by interpolating the recovered shares to the group key. Only a
+//! 1-of-n key set yields a single 65-byte `r || s || recovery_id` signature,
+//! with `s` in its low form; [`AggregatedSignature::to_eth_signature`]
+//! converts it to the `v` = 27/28 form `ecrecover` expects.
 
 use crate::{
+    dkg,
     error::{Result, ThresholdError},
     types::{
-        AggregatedSignature, KeyShare, PartialSignature, PublicKeyShare, ThresholdConfig,
-        ValidatorId,
+        AggregatedSignature, KeyShare, PartialSignature, PublicKeyShare, SignerSignature,
+        ThresholdConfig, ValidatorId,
     },
-    ThresholdScheme,
+    scheme::ThresholdScheme,
+    utils,
 };
 use k256::{
     ecdsa::{RecoveryId, Signature as EcdsaSignature, VerifyingKey},
     elliptic_curve::{
-        group::GroupEncoding,
-        ops::{Reduce, ReduceNonZero},
+        ops::Reduce,
         point::AffineCoordinates,
-        Field, Group,
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+        Field, PrimeField,
     },
-    AffinePoint, ProjectivePoint, Scalar, U256,
+    AffinePoint, FieldBytes, ProjectivePoint, Scalar, U256,
 };
 use rand::{CryptoRng, RngCore};
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// ECDSA threshold signature implementation
-#[derive(Debug, Clone)]
-pub struct EcdsaThreshold {
-    /// Random number generator
-    rng: rand::rngs::OsRng,
-}
+#[derive(Debug, Clone, Default)]
+pub struct EcdsaThreshold;
 
 impl EcdsaThreshold {
     /// Create a new ECDSA threshold signature instance
     pub fn new() -> Self {
-        Self {
-            rng: rand::rngs::OsRng,
-        }
-    }
-
-    /// Generate a random scalar
-    fn random_scalar<R: RngCore + CryptoRng>(&self, rng: &mut R) -> Scalar {
-        Scalar::random(rng)
-    }
-
-    /// Hash message for ECDSA signing
-    fn hash_message(&self, message: &[u8]) -> Scalar {
-        let hash = Sha256::digest(message);
-        Scalar::reduce(U256::from_be_slice(&hash))
-    }
-
-    /// Generate Lagrange coefficient for interpolation
-    fn lagrange_coefficient(&self, i: u32, signers: &[u32]) -> Result<Scalar> {
-        let mut numerator = Scalar::ONE;
-        let mut denominator = Scalar::ONE;
-
-        for &j in signers {
-            if i != j {
-                numerator *= Scalar::from(j);
-                let diff = if j > i {
-                    Scalar::from(j - i)
-                } else {
-                    -Scalar::from(i - j)
-                };
-                denominator *= diff;
-            }
-        }
-
-        // Compute modular inverse
-        let inv_denominator = denominator
-            .invert()
-            .ok_or_else(|| ThresholdError::CryptographicError {
-                message: "Failed to compute Lagrange coefficient".to_string(),
-            })?;
-
-        Ok(numerator * inv_denominator)
+        Self
     }
-}
 
-#[async_trait::async_trait]
-impl ThresholdScheme for EcdsaThreshold {
-    async fn generate_keys(
+    /// Deal Shamir shares of a fresh secret key to `validator_ids`
+    ///
+    /// Shares are dealt in sorted ID order: the `i`-th smallest ID receives
+    /// the polynomial evaluated at `i + 1`, the index
+    /// [`utils::verify_key_share`] checks it against and aggregation
+    /// interpolates with.
+    pub async fn generate_keys(
         &self,
         config: &ThresholdConfig,
         validator_ids: &[ValidatorId],
@@ -92,6 +61,7 @@ impl ThresholdScheme for EcdsaThreshold {
 
         let mut rng = rand::rngs::OsRng;
         let mut key_shares = HashMap::new();
+        let key_epoch = utils::generate_key_epoch();
 
         // Generate polynomial coefficients
         let mut coefficients = Vec::new();
@@ -99,8 +69,20 @@ impl ThresholdScheme for EcdsaThreshold {
             coefficients.push(self.random_scalar(&mut rng));
         }
 
+        // Shares carry Feldman commitments to the coefficients for
+        // verification; the coefficients themselves, the secret among them,
+        // never leave the dealer
+        let serialized_coeffs: Vec<Vec<u8>> = coefficients
+            .iter()
+            .map(|c| c.to_bytes().to_vec())
+            .collect();
+        let commitments = dkg::commit_polynomial(&serialized_coeffs)?;
+
+        let mut sorted_ids: Vec<&ValidatorId> = validator_ids.iter().collect();
+        sorted_ids.sort();
+
         // Generate key shares for each validator
-        for (index, validator_id) in validator_ids.iter().enumerate() {
+        for (index, validator_id) in sorted_ids.into_iter().enumerate() {
             let x = Scalar::from((index + 1) as u32); // x-coordinate (1-indexed)
 
             // Evaluate polynomial at x
@@ -115,18 +97,13 @@ impl ThresholdScheme for EcdsaThreshold {
             // Compute public share
             let public_share = (ProjectivePoint::GENERATOR * private_share).to_affine();
 
-            // Serialize coefficients for verification
-            let serialized_coeffs: Vec<Vec<u8>> = coefficients
-                .iter()
-                .map(|c| c.to_bytes().to_vec())
-                .collect();
-
             let key_share = KeyShare {
                 validator_id: validator_id.clone(),
                 private_share: private_share.to_bytes().to_vec(),
                 public_share: public_share.to_encoded_point(false).as_bytes().to_vec(),
-                coefficients: serialized_coeffs,
+                coefficients: commitments.clone(),
                 config: config.clone(),
+                key_epoch,
             };
 
             key_shares.insert(validator_id.clone(), key_share);
@@ -135,6 +112,164 @@ impl ThresholdScheme for EcdsaThreshold {
         Ok(key_shares)
     }
 
+    /// Generate a random scalar
+    fn random_scalar<R: RngCore + CryptoRng>(&self, rng: &mut R) -> Scalar {
+        Scalar::random(rng)
+    }
+
+    /// Hash message for ECDSA signing
+    fn hash_message(&self, message: &[u8]) -> Scalar {
+        let hash = Sha256::digest(message);
+        Scalar::reduce(U256::from_be_slice(&hash))
+    }
+
+    /// The `s` scalar of a partial signature
+    fn parse_s(partial_sig: &PartialSignature) -> Result<Scalar> {
+        let sig_bytes: [u8; 32] = partial_sig
+            .signature
+            .as_slice()
+            .try_into()
+            .map_err(|_| ThresholdError::InvalidSignature {
+                reason: "Invalid signature length".to_string(),
+            })?;
+
+        Option::<Scalar>::from(Scalar::from_repr(FieldBytes::from(sig_bytes)))
+            .ok_or_else(|| ThresholdError::InvalidSignature {
+                reason: "Invalid signature scalar".to_string(),
+            })
+    }
+
+    /// Parse a SEC1-encoded public key
+    fn verifying_key(public_key: &[u8]) -> Result<VerifyingKey> {
+        VerifyingKey::from_encoded_point(
+            &k256::EncodedPoint::from_bytes(public_key)
+                .map_err(|_| ThresholdError::InvalidSignature {
+                    reason: "Invalid public key encoding".to_string(),
+                })?,
+        )
+        .map_err(|e| ThresholdError::InvalidSignature {
+            reason: format!("Invalid verifying key: {}", e),
+        })
+    }
+
+    /// Public key that made a 65-byte `r || s || v` signature of `message`,
+    /// or `None` if it is malformed or recovers no key
+    fn recover_signer(signature: &[u8], message: &[u8]) -> Option<VerifyingKey> {
+        let [rs @ .., v] = signature else {
+            return None;
+        };
+        let signature = EcdsaSignature::from_slice(rs).ok()?;
+        let recovery_id = RecoveryId::from_byte(v.checked_sub(27)?)?;
+        VerifyingKey::recover_from_msg(message, &signature, recovery_id).ok()
+    }
+
+    /// Verify a multi-signer aggregate against the group key `public_key`
+    ///
+    /// Each signer's share is recovered from its signature, so a signature
+    /// that is not over `message` recovers some unrelated key. The shares
+    /// must sit at distinct key-set indices and interpolate to the group
+    /// key, which fewer than `threshold` of them do not.
+    fn verify_signer_signatures(
+        &self,
+        signature: &AggregatedSignature,
+        message: &[u8],
+        public_key: &[u8],
+        session_id: &str,
+    ) -> Result<bool> {
+        let group_key = Self::verifying_key(public_key)?;
+        let indices: Vec<u32> = signature.signer_signatures.iter().map(|sig| sig.index).collect();
+        if indices.is_empty()
+            || indices.contains(&0)
+            || indices.iter().collect::<HashSet<_>>().len() != indices.len()
+        {
+            return Ok(false);
+        }
+
+        let message_with_context = utils::hash_with_domain(session_id, message);
+        let mut interpolated = ProjectivePoint::IDENTITY;
+        for signer in &signature.signer_signatures {
+            let Some(share) = Self::recover_signer(&signer.signature, &message_with_context) else {
+                return Ok(false);
+            };
+            if utils::ethereum_address(share.to_encoded_point(true).as_bytes())? != signer.address {
+                return Ok(false);
+            }
+            interpolated += ProjectivePoint::from(*share.as_affine())
+                * self.lagrange_coefficient(signer.index, &indices)?;
+        }
+
+        Ok(interpolated.to_affine() == *group_key.as_affine())
+    }
+
+    /// Parse the compressed nonce point a partial signature commits to
+    fn parse_nonce(commitment: Option<&Vec<u8>>) -> Result<AffinePoint> {
+        let commitment = commitment.ok_or_else(|| ThresholdError::InvalidSignature {
+            reason: "Missing nonce commitment".to_string(),
+        })?;
+
+        let encoded = k256::EncodedPoint::from_bytes(commitment)
+            .map_err(|_| ThresholdError::InvalidSignature {
+                reason: "Invalid nonce commitment encoding".to_string(),
+            })?;
+
+        Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+            .ok_or_else(|| ThresholdError::InvalidSignature {
+                reason: "Invalid nonce commitment point".to_string(),
+            })
+    }
+
+    /// Low-`s` signature `(r, s)` for `nonce`, with the recovery ID of the key
+    /// that produced it
+    ///
+    /// The recovery ID records the parity of the nonce point's `y` and
+    /// whether its `x` was reduced to form `r`. Negating a high `s` negates
+    /// the nonce too, so the parity flips along with it.
+    fn recoverable_signature(nonce: &AffinePoint, s: Scalar) -> Result<(EcdsaSignature, RecoveryId)> {
+        let x = nonce.x();
+        let r = <Scalar as Reduce<U256>>::reduce_bytes(&x);
+
+        let signature = EcdsaSignature::from_scalars(r.to_bytes(), s.to_bytes())
+            .map_err(|e| ThresholdError::InvalidSignature {
+                reason: format!("Invalid ECDSA signature: {}", e),
+            })?;
+
+        let is_y_odd = bool::from(nonce.y_is_odd());
+        let is_x_reduced = r.to_bytes() != x;
+        Ok(match signature.normalize_s() {
+            Some(normalized) => (normalized, RecoveryId::new(!is_y_odd, is_x_reduced)),
+            None => (signature, RecoveryId::new(is_y_odd, is_x_reduced)),
+        })
+    }
+
+    /// Generate Lagrange coefficient for interpolation
+    fn lagrange_coefficient(&self, i: u32, signers: &[u32]) -> Result<Scalar> {
+        let mut numerator = Scalar::ONE;
+        let mut denominator = Scalar::ONE;
+
+        for &j in signers {
+            if i != j {
+                numerator *= Scalar::from(j);
+                let diff = if j > i {
+                    Scalar::from(j - i)
+                } else {
+                    -Scalar::from(i - j)
+                };
+                denominator *= diff;
+            }
+        }
+
+        // Compute modular inverse
+        let inv_denominator = Option::<Scalar>::from(denominator.invert())
+            .ok_or_else(|| ThresholdError::CryptographicError {
+                message: "Failed to compute Lagrange coefficient".to_string(),
+            })?;
+
+        Ok(numerator * inv_denominator)
+    }
+}
+
+#[async_trait::async_trait]
+impl ThresholdScheme for EcdsaThreshold {
     async fn partial_sign(
         &self,
         key_share: &KeyShare,
@@ -150,7 +285,7 @@ impl ThresholdScheme for EcdsaThreshold {
                 reason: "Invalid private share length".to_string(),
             })?;
 
-        let private_share = Scalar::from_bytes(&private_share_bytes.into())
+        let private_share = Option::<Scalar>::from(Scalar::from_repr(FieldBytes::from(private_share_bytes)))
             .ok_or_else(|| ThresholdError::InvalidKeyShare {
                 reason: "Invalid private share scalar".to_string(),
             })?;
@@ -162,40 +297,88 @@ impl ThresholdScheme for EcdsaThreshold {
         let k_point = (ProjectivePoint::GENERATOR * k).to_affine();
 
         // Get r coordinate
-        let r = Scalar::reduce_nonzero(U256::from_be_slice(
-            &k_point.x().to_bytes(),
-        ))
-        .ok_or_else(|| ThresholdError::CryptographicError {
-            message: "Failed to compute r coordinate".to_string(),
-        })?;
+        let r = <Scalar as Reduce<U256>>::reduce_bytes(&k_point.x());
+        if bool::from(r.is_zero()) {
+            return Err(ThresholdError::CryptographicError {
+                message: "Failed to compute r coordinate".to_string(),
+            });
+        }
 
         // Hash the message
-        let message_hash = self.hash_message(message);
+        let message_hash = self.hash_message(&utils::hash_with_domain(session_id, message));
 
         // Compute k^(-1)
-        let k_inv = k.invert().ok_or_else(|| ThresholdError::CryptographicError {
+        let k_inv = Option::<Scalar>::from(k.invert()).ok_or_else(|| ThresholdError::CryptographicError {
             message: "Failed to invert k".to_string(),
         })?;
 
         // Compute partial signature: s_i = k^(-1) * (H(m) + r * x_i)
-        let s_partial = k_inv * (message_hash + r.as_ref() * private_share);
+        let s_partial = k_inv * (message_hash + r * private_share);
 
-        // Store r as commitment
-        let commitment = r.to_bytes().to_vec();
+        // Commit to the whole nonce point, not just r, so aggregation can
+        // derive the recovery ID from its y parity
+        let commitment = k_point.to_encoded_point(true).as_bytes().to_vec();
 
         Ok(PartialSignature {
             validator_id: key_share.validator_id.clone(),
             signature: s_partial.to_bytes().to_vec(),
             commitment: Some(commitment),
             timestamp: std::time::SystemTime::now(),
+            key_epoch: key_share.key_epoch,
         })
     }
 
+    async fn verify_partial(
+        &self,
+        public_share: &[u8],
+        message: &[u8],
+        session_id: &str,
+        partial_sig: &PartialSignature,
+    ) -> Result<bool> {
+        use k256::ecdsa::signature::Verifier;
+
+        // A partial is a plain ECDSA signature under the signer's share, with
+        // `r` taken from the nonce it commits to
+        let nonce = Self::parse_nonce(partial_sig.commitment.as_ref())?;
+        let r = <Scalar as Reduce<U256>>::reduce_bytes(&nonce.x());
+        let s: [u8; 32] = partial_sig
+            .signature
+            .as_slice()
+            .try_into()
+            .map_err(|_| ThresholdError::InvalidSignature {
+                reason: "Invalid signature length".to_string(),
+            })?;
+
+        let signature = EcdsaSignature::from_scalars(r.to_bytes(), s)
+            .map_err(|e| ThresholdError::InvalidSignature {
+                reason: format!("Invalid ECDSA signature: {}", e),
+            })?;
+        let signature = signature.normalize_s().unwrap_or(signature);
+
+        let verifying_key = VerifyingKey::from_sec1_bytes(public_share)
+            .map_err(|e| ThresholdError::InvalidSignature {
+                reason: format!("Invalid verifying key: {}", e),
+            })?;
+
+        let message_with_context = utils::hash_with_domain(session_id, message);
+        Ok(verifying_key.verify(&message_with_context, &signature).is_ok())
+    }
+
+    /// Aggregate the first `threshold` partials
+    ///
+    /// Signers draw independent nonces, so only a lone signer's partial is
+    /// an ECDSA signature under the group key; combining several into one
+    /// `(r, s)` would take a shared-nonce signing protocol this scheme does
+    /// not implement. A multi-signer aggregate instead leaves `signature`
+    /// empty and carries each signer's recoverable signature with its key-set
+    /// index, which [`verify_signature`](ThresholdScheme::verify_signature)
+    /// checks against the group key.
     async fn aggregate_signatures(
         &self,
         partial_sigs: &[PartialSignature],
         public_key_shares: &[PublicKeyShare],
         message: &[u8],
+        session_id: &str,
         config: &ThresholdConfig,
     ) -> Result<AggregatedSignature> {
         if partial_sigs.len() < config.threshold as usize {
@@ -205,92 +388,89 @@ impl ThresholdScheme for EcdsaThreshold {
             });
         }
 
-        // All partial signatures should have the same r value
-        let r_bytes = partial_sigs[0]
-            .commitment
-            .as_ref()
-            .ok_or_else(|| ThresholdError::InvalidSignature {
-                reason: "Missing r commitment".to_string(),
-            })?;
-
-        let r_array: [u8; 32] = r_bytes
-            .as_slice()
-            .try_into()
-            .map_err(|_| ThresholdError::InvalidSignature {
-                reason: "Invalid r length".to_string(),
-            })?;
-
-        let r = Scalar::from_bytes(&r_array.into())
-            .ok_or_else(|| ThresholdError::InvalidSignature {
-                reason: "Invalid r scalar".to_string(),
-            })?;
-
-        // Extract signer indices (assuming 1-indexed)
-        let signer_indices: Vec<u32> = (1..=partial_sigs.len() as u32).collect();
-
-        // Aggregate signatures using Lagrange interpolation
-        let mut aggregated_s = Scalar::ZERO;
-
-        for (i, partial_sig) in partial_sigs.iter().enumerate() {
-            let signer_index = signer_indices[i];
+        utils::ensure_matching_key_epoch(partial_sigs, public_key_shares)?;
+        let signing = &partial_sigs[..config.threshold as usize];
 
-            // Compute Lagrange coefficient
-            let lagrange_coeff = self.lagrange_coefficient(signer_index, &signer_indices)?;
-
-            // Deserialize partial signature
-            let sig_bytes: [u8; 32] = partial_sig
-                .signature
-                .as_slice()
-                .try_into()
-                .map_err(|_| ThresholdError::InvalidSignature {
-                    reason: "Invalid signature length".to_string(),
-                })?;
-
-            let partial_s = Scalar::from_bytes(&sig_bytes.into())
-                .ok_or_else(|| ThresholdError::InvalidSignature {
-                    reason: "Invalid signature scalar".to_string(),
-                })?;
-
-            // Add weighted partial signature
-            aggregated_s += lagrange_coeff * partial_s;
+        // Any subset of validators may sign, so each signer's index comes from
+        // its position in the key set rather than its position among signers
+        let signer_indices: Vec<u32> = signing
+            .iter()
+            .map(|sig| utils::key_set_index(public_key_shares, &sig.validator_id))
+            .collect::<Result<_>>()?;
+        if signer_indices.iter().collect::<HashSet<_>>().len() != signer_indices.len() {
+            return Err(ThresholdError::AggregationFailed {
+                reason: "A validator signed more than once".to_string(),
+            });
         }
 
-        // Compute aggregated public key
+        // Interpolate the group key from the signers' public shares
+        let message_with_context = utils::hash_with_domain(session_id, message);
+        let mut signer_signatures = Vec::with_capacity(signing.len());
         let mut aggregated_pubkey = ProjectivePoint::IDENTITY;
-        for (i, pubkey_share) in public_key_shares.iter().take(partial_sigs.len()).enumerate() {
-            let signer_index = signer_indices[i];
+        for (partial_sig, &signer_index) in signing.iter().zip(&signer_indices) {
             let lagrange_coeff = self.lagrange_coefficient(signer_index, &signer_indices)?;
+            let pubkey_share = public_key_shares
+                .iter()
+                .find(|share| share.validator_id == partial_sig.validator_id)
+                .expect("signer index was found among the public key shares");
 
             // Deserialize public key share
-            let pubkey_point = AffinePoint::from_encoded_point(
+            let pubkey_point = Option::<AffinePoint>::from(AffinePoint::from_encoded_point(
                 &k256::EncodedPoint::from_bytes(&pubkey_share.public_share)
                     .map_err(|_| ThresholdError::InvalidSignature {
                         reason: "Invalid public key encoding".to_string(),
                     })?,
-            )
+            ))
             .ok_or_else(|| ThresholdError::InvalidSignature {
                 reason: "Invalid public key point".to_string(),
             })?;
 
             aggregated_pubkey += ProjectivePoint::from(pubkey_point) * lagrange_coeff;
-        }
 
-        // Create ECDSA signature (r, s)
-        let mut signature_bytes = Vec::with_capacity(64);
-        signature_bytes.extend_from_slice(&r.to_bytes());
-        signature_bytes.extend_from_slice(&aggregated_s.to_bytes());
+            // Each partial is a plain ECDSA signature under its signer's
+            // share; converting it fails unless it verifies under that share
+            let partial_nonce = Self::parse_nonce(partial_sig.commitment.as_ref())?;
+            let partial_r = <Scalar as Reduce<U256>>::reduce_bytes(&partial_nonce.x());
+            let mut partial_bytes = partial_r.to_bytes().to_vec();
+            partial_bytes.extend_from_slice(&partial_sig.signature);
+            signer_signatures.push(SignerSignature {
+                validator_id: partial_sig.validator_id.clone(),
+                address: utils::ethereum_address(&pubkey_share.public_share)?,
+                index: signer_index,
+                signature: utils::to_recoverable_signature(
+                    &partial_bytes,
+                    &pubkey_share.public_share,
+                    &message_with_context,
+                )?,
+            });
+        }
+        signer_signatures.sort_by_key(|sig| sig.address);
+
+        // A lone signer's share is the group secret, so its partial is the
+        // group's signature: `r || s || recovery_id`
+        let signature = match signing {
+            [partial_sig] => {
+                let nonce = Self::parse_nonce(partial_sig.commitment.as_ref())?;
+                let (signature, recovery_id) = Self::recoverable_signature(&nonce, Self::parse_s(partial_sig)?)?;
+                let mut signature_bytes = signature.to_bytes().to_vec();
+                signature_bytes.push(recovery_id.to_byte());
+                signature_bytes
+            }
+            _ => Vec::new(),
+        };
 
-        let signers: Vec<ValidatorId> = partial_sigs
+        let signers: Vec<ValidatorId> = signing
             .iter()
             .map(|sig| sig.validator_id.clone())
             .collect();
 
         Ok(AggregatedSignature {
-            signature: signature_bytes,
+            signature,
             signers,
             public_key: aggregated_pubkey.to_affine().to_encoded_point(false).as_bytes().to_vec(),
             scheme: "ecdsa".to_string(),
             timestamp: std::time::SystemTime::now(),
+            signer_signatures,
         })
     }
 
@@ -299,8 +479,14 @@ impl ThresholdScheme for EcdsaThreshold {
         signature: &AggregatedSignature,
         message: &[u8],
         public_key: &[u8],
+        session_id: &str,
     ) -> Result<bool> {
-        if signature.signature.len() != 64 {
+        if signature.signature.is_empty() {
+            return self.verify_signer_signatures(signature, message, public_key, session_id);
+        }
+
+        // The recovery ID, if present, plays no part in verification
+        if signature.signature.len() != 64 && signature.signature.len() != 65 {
             return Err(ThresholdError::InvalidSignature {
                 reason: "Invalid ECDSA signature length".to_string(),
             });
@@ -326,32 +512,18 @@ impl ThresholdScheme for EcdsaThreshold {
             })?;
 
         // Create verifying key
-        let verifying_key = VerifyingKey::from_encoded_point(
-            &k256::EncodedPoint::from_bytes(public_key)
-                .map_err(|_| ThresholdError::InvalidSignature {
-                    reason: "Invalid public key encoding".to_string(),
-                })?,
-        )
-        .map_err(|e| ThresholdError::InvalidSignature {
-            reason: format!("Invalid verifying key: {}", e),
-        })?;
+        let verifying_key = Self::verifying_key(public_key)?;
 
         // Verify signature
         use k256::ecdsa::signature::Verifier;
-        Ok(verifying_key.verify(message, &ecdsa_sig).is_ok())
-    }
-}
-
-impl Default for EcdsaThreshold {
-    fn default() -> Self {
-        Self::new()
+        let message_with_context = utils::hash_with_domain(session_id, message);
+        Ok(verifying_key.verify(&message_with_context, &ecdsa_sig).is_ok())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tokio_test;
 
     #[tokio::test]
     async fn test_ecdsa_key_generation() {
@@ -366,4 +538,132 @@ mod tests {
         assert!(key_shares.contains_key("val2"));
         assert!(key_shares.contains_key("val3"));
     }
+
+    #[tokio::test]
+    async fn test_non_contiguous_signers_aggregate_verifies_against_the_group_key() {
+        let config = ThresholdConfig::new(2, 3, 256).unwrap();
+        let validator_ids = utils::generate_test_validator_ids(3);
+
+        let ecdsa = EcdsaThreshold::new();
+        let key_shares = ecdsa.generate_keys(&config, &validator_ids).await.unwrap();
+        let public_key_shares = utils::extract_public_key_shares(&key_shares).unwrap();
+
+        // The first and third validators sign; the second does not
+        let mut partials = Vec::new();
+        for id in [&validator_ids[0], &validator_ids[2]] {
+            let partial = ecdsa.partial_sign(&key_shares[id], b"message", "session").await.unwrap();
+            let public_share = &key_shares[id].public_share;
+            assert!(ecdsa.verify_partial(public_share, b"message", "session", &partial).await.unwrap());
+            assert!(!ecdsa.verify_partial(public_share, b"other", "session", &partial).await.unwrap());
+            partials.push(partial);
+        }
+
+        let aggregated = ecdsa
+            .aggregate_signatures(&partials, &public_key_shares, b"message", "session", &config)
+            .await
+            .unwrap();
+
+        let group_key = dkg::parse_point(&key_shares[&validator_ids[0]].coefficients[0])
+            .unwrap()
+            .to_affine()
+            .to_encoded_point(false);
+        assert_eq!(aggregated.public_key, group_key.as_bytes().to_vec());
+        assert_eq!(aggregated.signers, vec![validator_ids[0].clone(), validator_ids[2].clone()]);
+
+        // Signers {1, 3} verify against the group key, over this message only
+        assert!(aggregated.signature.is_empty());
+        let mut indices: Vec<u32> = aggregated.signer_signatures.iter().map(|sig| sig.index).collect();
+        indices.sort();
+        assert_eq!(indices, vec![1, 3]);
+        let group_key = group_key.as_bytes();
+        assert!(ecdsa.verify_signature(&aggregated, b"message", group_key, "session").await.unwrap());
+        assert!(!ecdsa.verify_signature(&aggregated, b"other", group_key, "session").await.unwrap());
+        assert!(!ecdsa.verify_signature(&aggregated, b"message", group_key, "other").await.unwrap());
+
+        // One signer short of the threshold does not interpolate to the group key
+        let mut short = aggregated.clone();
+        short.signer_signatures.truncate(1);
+        assert!(!ecdsa.verify_signature(&short, b"message", group_key, "session").await.unwrap());
+
+        // Nor do the right signatures claimed at the wrong indices
+        let mut misplaced = aggregated.clone();
+        misplaced.signer_signatures.iter_mut().for_each(|sig| sig.index = 4 - sig.index);
+        assert!(!ecdsa.verify_signature(&misplaced, b"message", group_key, "session").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_eth_signature_recovers_the_signer_address() {
+        use k256::ecdsa::signature::DigestVerifier;
+
+        // Only a 1-of-1 aggregate is a single signature: its signer's partial
+        let config = ThresholdConfig::new(1, 1, 256).unwrap();
+        let validator_ids = utils::generate_test_validator_ids(1);
+
+        let ecdsa = EcdsaThreshold::new();
+        let key_shares = ecdsa.generate_keys(&config, &validator_ids).await.unwrap();
+        let public_key_shares = utils::extract_public_key_shares(&key_shares).unwrap();
+        let key_share = &key_shares[&validator_ids[0]];
+
+        let partial = ecdsa.partial_sign(key_share, b"message", "session").await.unwrap();
+        let aggregated = ecdsa
+            .aggregate_signatures(&[partial], &public_key_shares, b"message", "session", &config)
+            .await
+            .unwrap();
+        assert_eq!(aggregated.signature.len(), 65);
+        assert!(!utils::is_high_s(&aggregated.signature[..64]).unwrap());
+
+        let eth_signature = aggregated.to_eth_signature().unwrap();
+        assert!(matches!(eth_signature[64], 27 | 28));
+
+        let digest = Sha256::new_with_prefix(utils::hash_with_domain("session", b"message"));
+        let signature = EcdsaSignature::from_slice(&eth_signature[..64]).unwrap();
+        let recovery_id = RecoveryId::from_byte(eth_signature[64] - 27).unwrap();
+        let recovered = VerifyingKey::recover_from_digest(digest.clone(), &signature, recovery_id).unwrap();
+        recovered.verify_digest(digest, &signature).unwrap();
+
+        let expected = utils::ethereum_address(&key_share.public_share).unwrap();
+        let recovered = utils::ethereum_address(recovered.to_encoded_point(true).as_bytes()).unwrap();
+        assert_eq!(recovered, expected);
+        assert_eq!(aggregated.to_contract_bytes(), vec![eth_signature]);
+    }
+
+    #[tokio::test]
+    async fn test_multi_signer_aggregate_has_only_per_signer_eth_signatures() {
+        let config = ThresholdConfig::new(2, 3, 256).unwrap();
+        let validator_ids = utils::generate_test_validator_ids(3);
+
+        let ecdsa = EcdsaThreshold::new();
+        let key_shares = ecdsa.generate_keys(&config, &validator_ids).await.unwrap();
+        let public_key_shares = utils::extract_public_key_shares(&key_shares).unwrap();
+
+        let mut partials = Vec::new();
+        for id in &validator_ids[1..] {
+            partials.push(ecdsa.partial_sign(&key_shares[id], b"message", "session").await.unwrap());
+        }
+        let aggregated = ecdsa
+            .aggregate_signatures(&partials, &public_key_shares, b"message", "session", &config)
+            .await
+            .unwrap();
+
+        // There is no single signature under the group key to hand to ecrecover
+        assert!(aggregated.to_eth_signature().is_err());
+
+        // Each per-signer signature recovers its signer's address
+        let message_with_context = utils::hash_with_domain("session", b"message");
+        let mut expected: Vec<[u8; 20]> = validator_ids[1..]
+            .iter()
+            .map(|id| utils::ethereum_address(&key_shares[id].public_share).unwrap())
+            .collect();
+        expected.sort();
+        let recovered: Vec<[u8; 20]> = aggregated
+            .to_contract_bytes()
+            .iter()
+            .map(|signature| {
+                assert!(matches!(signature[64], 27 | 28));
+                let key = EcdsaThreshold::recover_signer(signature, &message_with_context).unwrap();
+                utils::ethereum_address(key.to_encoded_point(true).as_bytes()).unwrap()
+            })
+            .collect();
+        assert_eq!(recovered, expected);
+    }
 }
@@ -60,6 +60,10 @@ pub enum ThresholdError {
     #[error("Serialization error: {message}")]
     SerializationError { message: String },
 
+    /// Persisted key material written by a newer or unknown layout
+    #[error("Unsupported key share version {version}, this build reads versions 1 to {supported}")]
+    UnsupportedKeyShareVersion { version: u32, supported: u32 },
+
     /// Network error during distributed operations
     #[error("Network error: {message}")]
     NetworkError { message: String },
@@ -0,0 +1,220 @@
+//! Passphrase-encrypted key share files
+//!
+//! A keystore file holds one key share, serialized with
+//! [`crate::storage::encode_key_share`] and sealed with ChaCha20-Poly1305
+//! under a key stretched from a passphrase with scrypt. The scrypt cost
+//! parameters and salt travel with the file, so they can be raised later
+//! without breaking existing keystores. Plaintext key material is wiped from
+//! memory as soon as it is no longer needed.
+
+use crate::error::{Result, ThresholdError};
+use crate::storage::{decode_key_share, encode_key_share};
+use crate::types::KeyShare;
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    ChaCha20Poly1305, Nonce,
+};
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Deref;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Keystore layout version written by this build
+pub const KEYSTORE_VERSION: u32 = 1;
+
+/// scrypt cost written into new keystores (N = 2^15, r = 8, p = 1)
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+/// On-disk keystore layout
+#[derive(Serialize, Deserialize)]
+struct KeystoreFile {
+    version: u32,
+    kdf: KdfParams,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// scrypt parameters the sealing key was derived with
+#[derive(Serialize, Deserialize)]
+struct KdfParams {
+    log_n: u8,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+/// A key share loaded from a keystore
+///
+/// The private share is zeroized when this is dropped. It deliberately does
+/// not implement `Clone`; share it behind an `Arc` instead of copying it.
+pub struct SecretKeyShare(KeyShare);
+
+impl SecretKeyShare {
+    /// Wrap a key share so its private share is wiped on drop
+    pub fn new(share: KeyShare) -> Self {
+        Self(share)
+    }
+}
+
+impl Deref for SecretKeyShare {
+    type Target = KeyShare;
+
+    fn deref(&self) -> &KeyShare {
+        &self.0
+    }
+}
+
+impl Drop for SecretKeyShare {
+    fn drop(&mut self) {
+        self.0.private_share.zeroize();
+    }
+}
+
+impl fmt::Debug for SecretKeyShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretKeyShare")
+            .field("validator_id", &self.0.validator_id)
+            .field("public_share", &hex::encode(&self.0.public_share))
+            .field("key_epoch", &self.0.key_epoch)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Seal a key share under `passphrase`, returning the keystore file contents
+pub fn encrypt_key_share(share: &KeyShare, passphrase: &str) -> Result<Vec<u8>> {
+    let plaintext = Zeroizing::new(encode_key_share(share)?);
+
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+
+    let kdf = KdfParams {
+        log_n: SCRYPT_LOG_N,
+        r: SCRYPT_R,
+        p: SCRYPT_P,
+        salt: hex::encode(salt),
+    };
+    let cipher = keystore_cipher(passphrase, &kdf, &salt)?;
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+        .map_err(|_| ThresholdError::CryptographicError {
+            message: "Failed to encrypt key share".to_string(),
+        })?;
+
+    let file = KeystoreFile {
+        version: KEYSTORE_VERSION,
+        kdf,
+        nonce: hex::encode(nonce),
+        ciphertext: hex::encode(ciphertext),
+    };
+
+    Ok(serde_json::to_vec_pretty(&file)?)
+}
+
+/// Open a keystore file sealed with [`encrypt_key_share`]
+///
+/// A wrong passphrase and a tampered file are indistinguishable and both
+/// fail with a [`ThresholdError::CryptographicError`].
+pub fn decrypt_key_share(bytes: &[u8], passphrase: &str) -> Result<SecretKeyShare> {
+    let file: KeystoreFile = serde_json::from_slice(bytes)?;
+    if file.version != KEYSTORE_VERSION {
+        return Err(ThresholdError::InvalidKeyShare {
+            reason: format!("Unsupported keystore version {}", file.version),
+        });
+    }
+
+    let salt = decode_hex("salt", &file.kdf.salt)?;
+    let nonce = decode_hex("nonce", &file.nonce)?;
+    if nonce.len() != NONCE_LEN {
+        return Err(ThresholdError::InvalidKeyShare {
+            reason: format!("Keystore nonce must be {} bytes, got {}", NONCE_LEN, nonce.len()),
+        });
+    }
+    let ciphertext = decode_hex("ciphertext", &file.ciphertext)?;
+
+    let cipher = keystore_cipher(passphrase, &file.kdf, &salt)?;
+    let plaintext = Zeroizing::new(
+        cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| ThresholdError::CryptographicError {
+                message: "Failed to decrypt key share: wrong passphrase or corrupted keystore".to_string(),
+            })?,
+    );
+
+    Ok(SecretKeyShare(decode_key_share(&plaintext)?))
+}
+
+/// Derive the sealing cipher for `passphrase` with the file's scrypt parameters
+fn keystore_cipher(passphrase: &str, kdf: &KdfParams, salt: &[u8]) -> Result<ChaCha20Poly1305> {
+    let params = scrypt::Params::new(kdf.log_n, kdf.r, kdf.p).map_err(|e| ThresholdError::InvalidKeyShare {
+        reason: format!("Invalid keystore scrypt parameters: {}", e),
+    })?;
+
+    let mut key = Zeroizing::new([0u8; 32]);
+    scrypt::scrypt(passphrase.as_bytes(), salt, &params, key.as_mut_slice()).map_err(|e| {
+        ThresholdError::CryptographicError {
+            message: format!("Failed to derive keystore key: {}", e),
+        }
+    })?;
+
+    ChaCha20Poly1305::new_from_slice(key.as_slice()).map_err(|_| ThresholdError::CryptographicError {
+        message: "Invalid keystore key length".to_string(),
+    })
+}
+
+fn decode_hex(field: &str, value: &str) -> Result<Vec<u8>> {
+    hex::decode(value).map_err(|e| ThresholdError::InvalidKeyShare {
+        reason: format!("Keystore {} is not valid hex: {}", field, e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ThresholdConfig;
+
+    fn key_share() -> KeyShare {
+        KeyShare {
+            validator_id: "validator_1".to_string(),
+            private_share: vec![7u8; 32],
+            public_share: vec![2u8; 33],
+            coefficients: vec![vec![3u8; 33]],
+            config: ThresholdConfig::new(2, 3, 256).unwrap(),
+            key_epoch: 1,
+        }
+    }
+
+    #[test]
+    fn test_keystore_round_trip() {
+        let sealed = encrypt_key_share(&key_share(), "correct horse").unwrap();
+        let opened = decrypt_key_share(&sealed, "correct horse").unwrap();
+
+        assert_eq!(opened.validator_id, "validator_1");
+        assert_eq!(opened.private_share, vec![7u8; 32]);
+        assert_eq!(opened.key_epoch, 1);
+    }
+
+    #[test]
+    fn test_keystore_does_not_contain_plaintext() {
+        let sealed = encrypt_key_share(&key_share(), "correct horse").unwrap();
+
+        assert!(!String::from_utf8_lossy(&sealed).contains("validator_1"));
+    }
+
+    #[test]
+    fn test_wrong_passphrase_is_rejected() {
+        let sealed = encrypt_key_share(&key_share(), "correct horse").unwrap();
+
+        assert!(matches!(
+            decrypt_key_share(&sealed, "battery staple"),
+            Err(ThresholdError::CryptographicError { .. })
+        ));
+    }
+}
@@ -6,14 +6,28 @@
 pub mod error;
 pub mod types;
 pub mod utils;
+pub mod scheme;
+pub mod ecdsa;
+pub mod schnorr;
 pub mod simple;
+pub mod dkg;
+pub mod reshare;
+pub mod storage;
+pub mod keystore;
+pub mod wire;
+pub mod bench;
 
 pub use error::{ThresholdError, Result};
 pub use types::{
-    ValidatorId, KeyShare, PublicKeyShare, Signature, ThresholdConfig,
-    SigningSession, PartialSignature, AggregatedSignature
+    ValidatorId, KeyEpoch, KeySet, KeyShare, PublicKeyShare, Signature, ThresholdConfig,
+    SigningSession, PartialSignature, AggregatedSignature, SignerSignature
 };
-pub use simple::SimpleThresholdManager;
+pub use scheme::ThresholdScheme;
+pub use ecdsa::EcdsaThreshold;
+pub use dkg::DkgSession;
+pub use reshare::reshare;
+pub use schnorr::SchnorrThreshold;
+pub use simple::{SimpleThresholdManager, ThresholdSchemeType};
 
 // Re-export the simple threshold manager as the main interface
 pub use simple::SimpleThresholdManager as ThresholdManager;
@@ -48,7 +62,7 @@ mod tests {
         let session_id = "test_session".to_string();
 
         let session = manager
-            .create_signing_session(message, session_id.clone())
+            .create_signing_session(message, session_id.clone(), &[])
             .await
             .unwrap();
 
@@ -0,0 +1,243 @@
+//! Proactive refresh of key shares
+//!
+//! Refreshing re-randomizes every validator's share while the group key stays
+//! the same, so shares leaked before the refresh become useless and nothing
+//! registered on-chain has to change. Each holder deals shares of a random
+//! polynomial whose constant term is zero, and every holder adds the
+//! corrections it receives to its own share. The zero polynomials move the
+//! sharing polynomial everywhere except at zero, where the secret lives.
+//!
+//! Holders are indexed by sorted validator ID, as
+//! [`crate::EcdsaThreshold::generate_keys`] deals them. A refresh may raise the
+//! threshold, since the corrections can have a higher degree than the original
+//! polynomial, but cannot lower it.
+
+use crate::{
+    dkg::{self, commit_polynomial, evaluate_polynomial, generate_polynomial, verify_share},
+    error::{Result, ThresholdError},
+    types::{KeyShare, ThresholdConfig, ValidatorId},
+    utils,
+};
+use k256::{elliptic_curve::sec1::ToEncodedPoint, ProjectivePoint, Scalar};
+use std::collections::HashMap;
+
+/// Refresh `old_shares` into shares of the same group key under `new_config`
+///
+/// The new shares belong to a new key epoch and do not combine with the old
+/// ones. Every old share is checked against the group's commitment polynomial
+/// first, so shares without one, such as the independent keys of
+/// [`crate::SimpleThresholdManager`], cannot be reshared.
+pub fn reshare(
+    old_shares: &HashMap<ValidatorId, KeyShare>,
+    new_config: &ThresholdConfig,
+) -> Result<HashMap<ValidatorId, KeyShare>> {
+    new_config.validate()?;
+
+    let mut holders: Vec<&ValidatorId> = old_shares.keys().collect();
+    holders.sort();
+
+    let first = holders
+        .first()
+        .map(|id| &old_shares[*id])
+        .ok_or_else(|| ThresholdError::InvalidKeyShare {
+            reason: "No key shares to reshare".to_string(),
+        })?;
+
+    if holders.len() != new_config.total_validators as usize {
+        return Err(ThresholdError::InvalidThreshold {
+            threshold: new_config.threshold,
+            total: holders.len() as u32,
+        });
+    }
+    if new_config.threshold < first.config.threshold {
+        return Err(ThresholdError::KeyGenerationFailed {
+            reason: format!(
+                "Resharing cannot lower the threshold from {} to {}",
+                first.config.threshold, new_config.threshold
+            ),
+        });
+    }
+
+    let mut group_commitments = group_commitments(first)?;
+    for (position, holder) in holders.iter().enumerate() {
+        let share = &old_shares[*holder];
+        if share.key_epoch != first.key_epoch || share.coefficients != first.coefficients {
+            return Err(ThresholdError::InvalidKeyShare {
+                reason: format!("Key share of {} belongs to another key set", holder),
+            });
+        }
+        utils::verify_key_share(share, position as u32 + 1)?;
+    }
+
+    // Every holder deals a zero polynomial of the new degree
+    let mut dealings = Vec::with_capacity(holders.len());
+    for _ in &holders {
+        let mut coefficients = generate_polynomial(new_config.threshold);
+        coefficients[0] = Scalar::ZERO.to_bytes().to_vec();
+        let commitments = commit_polynomial(&coefficients)?;
+        dealings.push((coefficients, commitments));
+    }
+
+    group_commitments.resize(new_config.threshold as usize, ProjectivePoint::IDENTITY);
+    for (_, commitments) in &dealings {
+        for (total, commitment) in group_commitments.iter_mut().zip(commitments) {
+            *total += dkg::parse_point(commitment)?;
+        }
+    }
+    let group_commitments: Vec<Vec<u8>> = group_commitments
+        .iter()
+        .map(|point| point.to_affine().to_encoded_point(true).as_bytes().to_vec())
+        .collect();
+
+    // Every holder checks the corrections dealt to it and adds them to its share
+    let key_epoch = utils::generate_key_epoch();
+    let mut new_shares = HashMap::with_capacity(holders.len());
+    for (position, holder) in holders.iter().enumerate() {
+        let index = position as u32 + 1;
+        let mut private_share = dkg::parse_scalar(&old_shares[*holder].private_share)?;
+
+        for (coefficients, commitments) in &dealings {
+            // A correction that does not vanish at zero would shift the secret
+            if dkg::parse_point(&commitments[0])? != ProjectivePoint::IDENTITY {
+                return Err(ThresholdError::KeyGenerationFailed {
+                    reason: "Correction polynomial does not vanish at zero".to_string(),
+                });
+            }
+
+            let correction = evaluate_polynomial(coefficients, index)?;
+            if !verify_share(&correction, index, commitments)? {
+                return Err(ThresholdError::KeyGenerationFailed {
+                    reason: format!("Correction for {} does not match its commitment", holder),
+                });
+            }
+            private_share += dkg::parse_scalar(&correction)?;
+        }
+
+        let public_share = (ProjectivePoint::GENERATOR * private_share).to_affine();
+        new_shares.insert(
+            (*holder).clone(),
+            KeyShare {
+                validator_id: (*holder).clone(),
+                private_share: private_share.to_bytes().to_vec(),
+                public_share: public_share.to_encoded_point(false).as_bytes().to_vec(),
+                coefficients: group_commitments.clone(),
+                config: new_config.clone(),
+                key_epoch,
+            },
+        );
+    }
+
+    Ok(new_shares)
+}
+
+/// Commitment polynomial of the group a share belongs to
+fn group_commitments(share: &KeyShare) -> Result<Vec<ProjectivePoint>> {
+    if share.coefficients.is_empty() {
+        return Err(ThresholdError::InvalidKeyShare {
+            reason: format!("Key share of {} has no commitment polynomial to reshare", share.validator_id),
+        });
+    }
+
+    dkg::check_commitments(share)?;
+    share.coefficients.iter().map(|c| dkg::parse_point(c)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcdsaThreshold;
+    use k256::ecdsa::{
+        signature::{Signer, Verifier},
+        Signature, SigningKey, VerifyingKey,
+    };
+
+    /// Interpolate the secret at zero from `(index, share)` pairs
+    fn combine(shares: &[(u32, &KeyShare)]) -> Scalar {
+        shares.iter().fold(Scalar::ZERO, |secret, &(index, share)| {
+            let lagrange = shares.iter().filter(|(j, _)| *j != index).fold(Scalar::ONE, |acc, &(j, _)| {
+                let denominator = Scalar::from(j as u64) - Scalar::from(index as u64);
+                acc * Scalar::from(j as u64) * denominator.invert().unwrap()
+            });
+            secret + lagrange * dkg::parse_scalar(&share.private_share).unwrap()
+        })
+    }
+
+    fn sign_with(secret: Scalar, message: &[u8]) -> Signature {
+        SigningKey::from_bytes(&secret.to_bytes()).unwrap().sign(message)
+    }
+
+    #[tokio::test]
+    async fn test_reshared_shares_keep_the_group_key() {
+        let config = ThresholdConfig::new(2, 3, 256).unwrap();
+        let ids = utils::generate_test_validator_ids(3);
+        let old_shares = EcdsaThreshold::new().generate_keys(&config, &ids).await.unwrap();
+
+        let secret = combine(&[(1, &old_shares[&ids[0]]), (2, &old_shares[&ids[1]])]);
+        let group_key = VerifyingKey::from_affine((ProjectivePoint::GENERATOR * secret).to_affine()).unwrap();
+
+        let new_shares = reshare(&old_shares, &config).unwrap();
+        for (position, id) in ids.iter().enumerate() {
+            let share = &new_shares[id];
+            assert_ne!(share.private_share, old_shares[id].private_share);
+            assert_ne!(share.key_epoch, old_shares[id].key_epoch);
+            assert!(share.coefficients.iter().all(|c| c.len() == 33));
+            utils::verify_key_share(share, position as u32 + 1).unwrap();
+        }
+
+        // Any two reshared shares sign under the original group key
+        let signature = sign_with(combine(&[(1, &new_shares[&ids[0]]), (3, &new_shares[&ids[2]])]), b"message");
+        assert!(group_key.verify(b"message", &signature).is_ok());
+
+        // An old share no longer combines with a new one
+        let mixed = combine(&[(1, &old_shares[&ids[0]]), (3, &new_shares[&ids[2]])]);
+        assert_ne!(mixed, secret);
+        assert!(group_key.verify(b"message", &sign_with(mixed, b"message")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reshare_can_raise_but_not_lower_the_threshold() {
+        let ids = utils::generate_test_validator_ids(3);
+        let old_shares = EcdsaThreshold::new()
+            .generate_keys(&ThresholdConfig::new(2, 3, 256).unwrap(), &ids)
+            .await
+            .unwrap();
+        let secret = combine(&[(1, &old_shares[&ids[0]]), (2, &old_shares[&ids[1]])]);
+
+        let raised = reshare(&old_shares, &ThresholdConfig::new(3, 3, 256).unwrap()).unwrap();
+        let all: Vec<(u32, &KeyShare)> = ids.iter().enumerate().map(|(i, id)| (i as u32 + 1, &raised[id])).collect();
+        assert_eq!(combine(&all), secret);
+        assert_ne!(combine(&all[..2]), secret);
+
+        let lowered = reshare(&raised, &ThresholdConfig::new(2, 3, 256).unwrap());
+        assert!(matches!(lowered, Err(ThresholdError::KeyGenerationFailed { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_reshare_outputs_only_commitments_and_rejects_secret_coefficients() {
+        let config = ThresholdConfig::new(2, 3, 256).unwrap();
+        let ids = utils::generate_test_validator_ids(3);
+        let old_shares = EcdsaThreshold::new().generate_keys(&config, &ids).await.unwrap();
+
+        // The refreshed commitments open to the group key, not to a scalar
+        let new_shares = reshare(&old_shares, &config).unwrap();
+        let secret = combine(&[(1, &old_shares[&ids[0]]), (2, &old_shares[&ids[1]])]);
+        for share in new_shares.values() {
+            assert_eq!(share.coefficients.len(), config.threshold as usize);
+            assert!(share.coefficients.iter().all(|c| c.len() == 33));
+            assert_eq!(dkg::parse_point(&share.coefficients[0]).unwrap(), ProjectivePoint::GENERATOR * secret);
+        }
+
+        // Shares carrying the secret polynomial itself are refused
+        let mut leaked = old_shares.clone();
+        let coefficients = vec![secret.to_bytes().to_vec(), Scalar::ONE.to_bytes().to_vec()];
+        for share in leaked.values_mut() {
+            share.coefficients = coefficients.clone();
+        }
+        match reshare(&leaked, &config) {
+            Err(ThresholdError::InvalidKeyShare { reason }) => {
+                assert!(reason.contains("not secret coefficients"), "{}", reason)
+            }
+            other => panic!("expected InvalidKeyShare, got {:?}", other.map(|shares| shares.len())),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Signature schemes the threshold manager can sign with
+
+use crate::{
+    error::Result,
+    types::{AggregatedSignature, KeyShare, PartialSignature, PublicKeyShare, ThresholdConfig},
+};
+
+/// Per-session signing operations of a threshold signature scheme
+///
+/// [`crate::SimpleThresholdManager`] checks the signer count and key epoch of
+/// the partials before aggregating, so implementations receive at least
+/// `config.threshold` partials from the epoch of `public_key_shares`.
+#[async_trait::async_trait]
+pub trait ThresholdScheme: std::fmt::Debug + Send + Sync {
+    /// Sign `message` in `session_id` with one validator's key share
+    async fn partial_sign(
+        &self,
+        key_share: &KeyShare,
+        message: &[u8],
+        session_id: &str,
+    ) -> Result<PartialSignature>;
+
+    /// Check one validator's partial signature over `message` in
+    /// `session_id` against that validator's public key share
+    async fn verify_partial(
+        &self,
+        public_share: &[u8],
+        message: &[u8],
+        session_id: &str,
+        partial_sig: &PartialSignature,
+    ) -> Result<bool>;
+
+    /// Combine the partials of a session into one signature
+    async fn aggregate_signatures(
+        &self,
+        partial_sigs: &[PartialSignature],
+        public_key_shares: &[PublicKeyShare],
+        message: &[u8],
+        session_id: &str,
+        config: &ThresholdConfig,
+    ) -> Result<AggregatedSignature>;
+
+    /// Check an aggregated signature over `message` in `session_id`
+    async fn verify_signature(
+        &self,
+        signature: &AggregatedSignature,
+        message: &[u8],
+        public_key: &[u8],
+        session_id: &str,
+    ) -> Result<bool>;
+}
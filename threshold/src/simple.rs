@@ -6,9 +6,11 @@
 
 use crate::{
     error::{Result, ThresholdError},
+    scheme::ThresholdScheme,
+    schnorr::SchnorrThreshold,
     types::{
-        AggregatedSignature, KeyShare, PartialSignature, PublicKeyShare, ThresholdConfig,
-        ValidatorId, SigningSession,
+        AggregatedSignature, KeySet, KeyShare, PartialSignature, PublicKeyShare, SignerSignature,
+        ThresholdConfig, ValidatorId, SigningSession,
     },
     utils,
 };
@@ -16,36 +18,114 @@ use k256::{
     ecdsa::{SigningKey, Signature as EcdsaSignature, VerifyingKey, signature::Signer},
     elliptic_curve::rand_core::OsRng,
 };
+use rand::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// How long a signing session accepts partials by default
+pub const DEFAULT_SIGNATURE_TIMEOUT: Duration = Duration::from_secs(300);
 
 /// Simplified threshold signature manager
 #[derive(Debug, Clone)]
 pub struct SimpleThresholdManager {
     config: ThresholdConfig,
     scheme: ThresholdSchemeType,
+    signer: Arc<dyn ThresholdScheme>,
+    key_set: Arc<RwLock<Option<KeySet>>>,
+    require_low_s: bool,
+    signature_timeout: Duration,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ThresholdSchemeType {
     Ecdsa,
+    Schnorr,
+}
+
+impl std::str::FromStr for ThresholdSchemeType {
+    type Err = ThresholdError;
+
+    fn from_str(scheme: &str) -> Result<Self> {
+        match scheme.to_ascii_lowercase().as_str() {
+            "ecdsa" => Ok(Self::Ecdsa),
+            "schnorr" => Ok(Self::Schnorr),
+            _ => Err(ThresholdError::Generic {
+                message: format!("Unknown signature scheme {:?}, expected ecdsa or schnorr", scheme),
+            }),
+        }
+    }
 }
 
 impl SimpleThresholdManager {
-    /// Create a new simple threshold manager
+    /// Create a new simple threshold manager signing with ECDSA
     pub fn new(config: ThresholdConfig) -> Result<Self> {
+        Self::with_scheme(config, ThresholdSchemeType::Ecdsa)
+    }
+
+    /// Create a manager signing with the scheme named by `scheme`
+    /// (`ecdsa` or `schnorr`)
+    pub fn new_with_scheme(config: ThresholdConfig, scheme: &str) -> Result<Self> {
+        Self::with_scheme(config, scheme.parse()?)
+    }
+
+    /// Create a manager signing with `scheme`
+    pub fn with_scheme(config: ThresholdConfig, scheme: ThresholdSchemeType) -> Result<Self> {
         config.validate()?;
         Ok(Self {
             config,
-            scheme: ThresholdSchemeType::Ecdsa,
+            scheme,
+            signer: Self::signer(scheme, true),
+            key_set: Arc::new(RwLock::new(None)),
+            require_low_s: true,
+            signature_timeout: DEFAULT_SIGNATURE_TIMEOUT,
         })
     }
 
+    /// Implementation of `scheme`
+    fn signer(scheme: ThresholdSchemeType, require_low_s: bool) -> Arc<dyn ThresholdScheme> {
+        match scheme {
+            ThresholdSchemeType::Ecdsa => Arc::new(SimpleEcdsa { require_low_s }),
+            ThresholdSchemeType::Schnorr => Arc::new(SchnorrThreshold::new()),
+        }
+    }
+
+    /// Whether verification rejects high-`s` signatures instead of
+    /// normalizing them first
+    ///
+    /// Strict by default: a high-`s` signature is the malleable twin of a
+    /// valid one, and the Ethereum contract refuses it. Only ECDSA
+    /// signatures are malleable this way.
+    pub fn with_require_low_s(mut self, require_low_s: bool) -> Self {
+        self.require_low_s = require_low_s;
+        self.signer = Self::signer(self.scheme, require_low_s);
+        self
+    }
+
+    /// Stop accepting partials for a session once it is `signature_timeout` old
+    pub fn with_signature_timeout(mut self, signature_timeout: Duration) -> Self {
+        self.signature_timeout = signature_timeout;
+        self
+    }
+
     /// Generate key shares for validators (simplified version)
     /// In a real implementation, this would use distributed key generation
     pub async fn generate_key_shares(
         &self,
         validator_ids: &[ValidatorId],
+    ) -> Result<HashMap<ValidatorId, KeyShare>> {
+        self.generate_key_shares_with_rng(validator_ids, &mut OsRng).await
+    }
+
+    /// Generate key shares drawing keys and the key epoch from `rng`
+    ///
+    /// A seeded `rng` reproduces the same key set, which benchmarks and
+    /// tests rely on; production callers use [`Self::generate_key_shares`].
+    pub async fn generate_key_shares_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        validator_ids: &[ValidatorId],
+        rng: &mut R,
     ) -> Result<HashMap<ValidatorId, KeyShare>> {
         if validator_ids.len() != self.config.total_validators as usize {
             return Err(ThresholdError::InvalidThreshold {
@@ -55,11 +135,12 @@ impl SimpleThresholdManager {
         }
 
         let mut key_shares = HashMap::new();
+        let key_epoch = rng.next_u64();
 
         // For simplicity, generate individual ECDSA keys for each validator
         // In a real threshold scheme, these would be shares of a single key
         for validator_id in validator_ids {
-            let signing_key = SigningKey::random(&mut OsRng);
+            let signing_key = SigningKey::random(rng);
             let verifying_key = VerifyingKey::from(&signing_key);
 
             let key_share = KeyShare {
@@ -68,6 +149,7 @@ impl SimpleThresholdManager {
                 public_share: verifying_key.to_encoded_point(false).as_bytes().to_vec(),
                 coefficients: vec![], // Not used in simplified version
                 config: self.config.clone(),
+                key_epoch,
             };
 
             key_shares.insert(validator_id.clone(), key_share);
@@ -76,48 +158,21 @@ impl SimpleThresholdManager {
         Ok(key_shares)
     }
 
-    /// Create a partial signature (simplified version)
+    /// Create a partial signature with the selected scheme
     pub async fn create_partial_signature(
         &self,
         key_share: &KeyShare,
         message: &[u8],
         session_id: &str,
     ) -> Result<PartialSignature> {
-        // Reconstruct signing key from bytes
-        let signing_key_bytes: [u8; 32] = key_share
-            .private_share
-            .as_slice()
-            .try_into()
-            .map_err(|_| ThresholdError::InvalidKeyShare {
-                reason: "Invalid private key length".to_string(),
-            })?;
-
-        let signing_key = SigningKey::from_bytes(&signing_key_bytes.into())
-            .map_err(|e| ThresholdError::InvalidKeyShare {
-                reason: format!("Invalid signing key: {}", e),
-            })?;
-
-        // Create message hash with session context
-        let message_with_context = utils::hash_with_domain(session_id, message);
-
-        // Sign the message
-        let signature: EcdsaSignature = signing_key.sign(&message_with_context);
-
-        Ok(PartialSignature {
-            validator_id: key_share.validator_id.clone(),
-            signature: signature.to_bytes().to_vec(),
-            commitment: None, // Not used in simplified version
-            timestamp: std::time::SystemTime::now(),
-        })
+        self.signer.partial_sign(key_share, message, session_id).await
     }
 
-    /// Aggregate partial signatures (simplified version)
-    /// In a real threshold scheme, this would combine signature shares
-    /// For simplicity, we just collect enough signatures and use the first valid one
+    /// Aggregate partial signatures with the selected scheme
     pub async fn aggregate_signatures(
         &self,
         partial_sigs: &[PartialSignature],
-        _public_key_shares: &[PublicKeyShare],
+        public_key_shares: &[PublicKeyShare],
         message: &[u8],
         session_id: &str,
     ) -> Result<AggregatedSignature> {
@@ -128,37 +183,201 @@ impl SimpleThresholdManager {
             });
         }
 
-        // For simplicity, use the first signature as the aggregated signature
-        // In a real implementation, this would mathematically combine the signatures
-        let first_sig = &partial_sigs[0];
+        // Partials from a different key-generation ceremony would silently
+        // produce an invalid signature
+        utils::ensure_matching_key_epoch(partial_sigs, public_key_shares)?;
 
-        // Verify the signature is valid
-        let _message_with_context = utils::hash_with_domain(session_id, message);
-        
-        let signers: Vec<ValidatorId> = partial_sigs
+        self.signer
+            .aggregate_signatures(partial_sigs, public_key_shares, message, session_id, &self.config)
+            .await
+    }
+
+    /// Verify an aggregated signature with the selected scheme
+    pub async fn verify_signature(
+        &self,
+        signature: &AggregatedSignature,
+        message: &[u8],
+        public_key: &[u8],
+        session_id: &str,
+    ) -> Result<bool> {
+        self.signer.verify_signature(signature, message, public_key, session_id).await
+    }
+
+    /// Check a single validator's partial signature against its public key share
+    pub async fn verify_partial_signature(
+        &self,
+        public_share: &[u8],
+        message: &[u8],
+        session_id: &str,
+        partial_sig: &PartialSignature,
+    ) -> Result<bool> {
+        self.signer.verify_partial(public_share, message, session_id, partial_sig).await
+    }
+
+    /// Create a signing session whose partials are checked against
+    /// `public_key_shares`
+    pub async fn create_signing_session(
+        &self,
+        message: &[u8],
+        session_id: String,
+        public_key_shares: &[PublicKeyShare],
+    ) -> Result<SigningSession> {
+        Ok(SigningSession {
+            id: session_id,
+            message: message.to_vec(),
+            partial_signatures: HashMap::new(),
+            public_key_shares: public_key_shares.to_vec(),
+            threshold: self.config.threshold,
+            total_validators: self.config.total_validators,
+            created_at: std::time::SystemTime::now(),
+        })
+    }
+
+    /// Add a partial signature to a signing session
+    ///
+    /// The partial is verified against the signer's public key share first,
+    /// so a bad partial is rejected here rather than failing aggregation.
+    /// Sessions older than the signature timeout accept no more partials.
+    pub async fn add_partial_signature(
+        &self,
+        session: &mut SigningSession,
+        validator_id: ValidatorId,
+        partial_sig: PartialSignature,
+    ) -> Result<()> {
+        if self.is_session_expired(session) {
+            return Err(ThresholdError::SessionExpired {
+                session_id: session.id.clone(),
+            });
+        }
+
+        if session.partial_signatures.contains_key(&validator_id) {
+            return Err(ThresholdError::DuplicateSignature { validator_id });
+        }
+
+        if partial_sig.validator_id != validator_id {
+            return Err(ThresholdError::InvalidSignature {
+                reason: format!(
+                    "Partial signature from {} submitted as {}",
+                    partial_sig.validator_id, validator_id
+                ),
+            });
+        }
+
+        let public_share = session
+            .public_key_shares
             .iter()
-            .take(self.config.threshold as usize)
-            .map(|sig| sig.validator_id.clone())
+            .find(|share| share.validator_id == validator_id)
+            .ok_or_else(|| ThresholdError::InvalidValidatorId { id: validator_id.clone() })?;
+
+        let valid = self
+            .verify_partial_signature(&public_share.public_share, &session.message, &session.id, &partial_sig)
+            .await?;
+        if !valid {
+            return Err(ThresholdError::InvalidSignature {
+                reason: format!("Partial signature from {} does not verify", validator_id),
+            });
+        }
+
+        session.partial_signatures.insert(validator_id, partial_sig);
+        Ok(())
+    }
+
+    /// Whether a session is past the signature timeout
+    ///
+    /// A creation time in the future cannot be measured against, so such a
+    /// session counts as expired, as with [`SigningSession::is_expired`].
+    pub fn is_session_expired(&self, session: &SigningSession) -> bool {
+        session
+            .created_at
+            .elapsed()
+            .map_or(true, |elapsed| elapsed > self.signature_timeout)
+    }
+
+    /// Check if a signing session has enough signatures
+    pub fn is_session_ready(&self, session: &SigningSession) -> bool {
+        session.partial_signatures.len() >= self.config.threshold as usize
+    }
+
+    /// Load the public key shares produced by a DKG or reshare as the active key set
+    ///
+    /// Every share must come from the same key epoch, and there must be at
+    /// least `threshold` of them.
+    pub fn load_key_set(
+        &self,
+        public_key_shares: &[PublicKeyShare],
+        group_public_key: Vec<u8>,
+    ) -> Result<KeySet> {
+        let key_epoch = utils::ensure_matching_key_epoch(&[], public_key_shares)?;
+
+        if public_key_shares.len() < self.config.threshold as usize {
+            return Err(ThresholdError::InvalidKeyShare {
+                reason: format!(
+                    "Key set has {} members, threshold is {}",
+                    public_key_shares.len(),
+                    self.config.threshold
+                ),
+            });
+        }
+
+        let mut members: Vec<ValidatorId> = public_key_shares
+            .iter()
+            .map(|share| share.validator_id.clone())
             .collect();
+        members.sort();
+        members.dedup();
 
-        Ok(AggregatedSignature {
-            signature: first_sig.signature.clone(),
-            signers,
-            public_key: vec![], // Would be computed from public key shares
-            scheme: "ecdsa-simple".to_string(),
-            timestamp: std::time::SystemTime::now(),
-        })
+        let key_set = KeySet {
+            key_epoch,
+            members,
+            group_public_key,
+        };
+        *self.key_set.write().unwrap_or_else(|e| e.into_inner()) = Some(key_set.clone());
+
+        Ok(key_set)
     }
 
-    /// Verify an aggregated signature (simplified version)
-    pub async fn verify_signature(
+    /// The active key set, if one has been loaded
+    pub fn key_set(&self) -> Option<KeySet> {
+        self.key_set.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// How long a session accepts partials
+    pub fn signature_timeout(&self) -> Duration {
+        self.signature_timeout
+    }
+
+    /// Whether high-`s` signatures are rejected on verify
+    pub fn requires_low_s(&self) -> bool {
+        self.require_low_s
+    }
+
+    /// Get the threshold configuration
+    pub fn config(&self) -> &ThresholdConfig {
+        &self.config
+    }
+
+    /// Get the signature scheme in use
+    pub fn scheme(&self) -> &ThresholdSchemeType {
+        &self.scheme
+    }
+}
+
+/// ECDSA signing as done by the simplified manager
+#[derive(Debug, Clone)]
+struct SimpleEcdsa {
+    require_low_s: bool,
+}
+
+impl SimpleEcdsa {
+    /// Check a 64-byte `r || s` signature over `message` in `session_id`
+    fn verify_ecdsa(
         &self,
-        signature: &AggregatedSignature,
+        signature: &[u8],
         message: &[u8],
         public_key: &[u8],
         session_id: &str,
     ) -> Result<bool> {
-        if signature.signature.len() != 64 {
+        if signature.len() != 64 {
             return Err(ThresholdError::InvalidSignature {
                 reason: "Invalid signature length".to_string(),
             });
@@ -166,8 +385,6 @@ impl SimpleThresholdManager {
 
         // Parse the signature
         let sig_bytes: [u8; 64] = signature
-            .signature
-            .as_slice()
             .try_into()
             .map_err(|_| ThresholdError::InvalidSignature {
                 reason: "Invalid signature format".to_string(),
@@ -178,6 +395,18 @@ impl SimpleThresholdManager {
                 reason: format!("Invalid ECDSA signature: {}", e),
             })?;
 
+        // A high-`s` signature verifies only after normalizing, which strict
+        // mode refuses to do on the caller's behalf
+        let ecdsa_sig = match ecdsa_sig.normalize_s() {
+            Some(_) if self.require_low_s => {
+                return Err(ThresholdError::InvalidSignature {
+                    reason: "Non-canonical high-s signature".to_string(),
+                })
+            }
+            Some(normalized) => normalized,
+            None => ecdsa_sig,
+        };
+
         // Parse the public key
         let verifying_key = VerifyingKey::from_encoded_point(
             &k256::EncodedPoint::from_bytes(public_key)
@@ -195,46 +424,119 @@ impl SimpleThresholdManager {
         use k256::ecdsa::signature::Verifier;
         Ok(verifying_key.verify(&message_with_context, &ecdsa_sig).is_ok())
     }
+}
 
-    /// Create a signing session
-    pub async fn create_signing_session(
+#[async_trait::async_trait]
+impl ThresholdScheme for SimpleEcdsa {
+    async fn partial_sign(
         &self,
+        key_share: &KeyShare,
         message: &[u8],
-        session_id: String,
-    ) -> Result<SigningSession> {
-        Ok(SigningSession {
-            id: session_id,
-            message: message.to_vec(),
-            partial_signatures: HashMap::new(),
-            threshold: self.config.threshold,
-            total_validators: self.config.total_validators,
-            created_at: std::time::SystemTime::now(),
+        session_id: &str,
+    ) -> Result<PartialSignature> {
+        // Reconstruct signing key from bytes
+        let signing_key_bytes: [u8; 32] = key_share
+            .private_share
+            .as_slice()
+            .try_into()
+            .map_err(|_| ThresholdError::InvalidKeyShare {
+                reason: "Invalid private key length".to_string(),
+            })?;
+
+        let signing_key = SigningKey::from_bytes(&signing_key_bytes.into())
+            .map_err(|e| ThresholdError::InvalidKeyShare {
+                reason: format!("Invalid signing key: {}", e),
+            })?;
+
+        // Create message hash with session context
+        let message_with_context = utils::hash_with_domain(session_id, message);
+
+        // Sign the message
+        let signature: EcdsaSignature = signing_key.sign(&message_with_context);
+
+        Ok(PartialSignature {
+            validator_id: key_share.validator_id.clone(),
+            signature: utils::normalize_low_s(&signature.to_bytes())?,
+            commitment: None, // Not used in simplified version
+            timestamp: std::time::SystemTime::now(),
+            key_epoch: key_share.key_epoch,
         })
     }
 
-    /// Add a partial signature to a signing session
-    pub async fn add_partial_signature(
+    async fn verify_partial(
         &self,
-        session: &mut SigningSession,
-        validator_id: ValidatorId,
-        partial_sig: PartialSignature,
-    ) -> Result<()> {
-        if session.partial_signatures.contains_key(&validator_id) {
-            return Err(ThresholdError::DuplicateSignature { validator_id });
-        }
-
-        session.partial_signatures.insert(validator_id, partial_sig);
-        Ok(())
+        public_share: &[u8],
+        message: &[u8],
+        session_id: &str,
+        partial_sig: &PartialSignature,
+    ) -> Result<bool> {
+        self.verify_ecdsa(&partial_sig.signature, message, public_share, session_id)
     }
 
-    /// Check if a signing session has enough signatures
-    pub fn is_session_ready(&self, session: &SigningSession) -> bool {
-        session.partial_signatures.len() >= self.config.threshold as usize
+    /// In a real threshold scheme, this would combine signature shares.
+    /// For simplicity, we just collect enough signatures and use the first one
+    async fn aggregate_signatures(
+        &self,
+        partial_sigs: &[PartialSignature],
+        public_key_shares: &[PublicKeyShare],
+        message: &[u8],
+        session_id: &str,
+        config: &ThresholdConfig,
+    ) -> Result<AggregatedSignature> {
+        // For simplicity, use the first signature as the aggregated signature
+        // In a real implementation, this would mathematically combine the signatures
+        let first_sig = &partial_sigs[0];
+
+        let message_with_context = utils::hash_with_domain(session_id, message);
+
+        let signers: Vec<ValidatorId> = partial_sigs
+            .iter()
+            .take(config.threshold as usize)
+            .map(|sig| sig.validator_id.clone())
+            .collect();
+
+        // Keep each signer's signature in recoverable form for the Ethereum
+        // contract, which checks a list of signatures rather than the aggregate
+        let mut signer_signatures = Vec::with_capacity(signers.len());
+        for sig in partial_sigs.iter().take(config.threshold as usize) {
+            let public_share = public_key_shares
+                .iter()
+                .find(|share| share.validator_id == sig.validator_id)
+                .ok_or_else(|| ThresholdError::InvalidKeyShare {
+                    reason: format!("No public key share for signer {}", sig.validator_id),
+                })?;
+
+            signer_signatures.push(SignerSignature {
+                validator_id: sig.validator_id.clone(),
+                address: utils::ethereum_address(&public_share.public_share)?,
+                index: utils::key_set_index(public_key_shares, &sig.validator_id)?,
+                signature: utils::to_recoverable_signature(
+                    &sig.signature,
+                    &public_share.public_share,
+                    &message_with_context,
+                )?,
+            });
+        }
+        signer_signatures.sort_by_key(|sig| sig.address);
+
+        Ok(AggregatedSignature {
+            signature: utils::normalize_low_s(&first_sig.signature)?,
+            signers,
+            public_key: vec![], // Would be computed from public key shares
+            scheme: "ecdsa-simple".to_string(),
+            timestamp: std::time::SystemTime::now(),
+            signer_signatures,
+        })
     }
 
-    /// Get the threshold configuration
-    pub fn config(&self) -> &ThresholdConfig {
-        &self.config
+    async fn verify_signature(
+        &self,
+        signature: &AggregatedSignature,
+        message: &[u8],
+        public_key: &[u8],
+        session_id: &str,
+    ) -> Result<bool> {
+        self.verify_ecdsa(&signature.signature, message, public_key, session_id)
     }
 }
 
@@ -256,14 +558,15 @@ mod tests {
         // Test signing session
         let message = b"test message";
         let session_id = utils::generate_session_id();
+        let public_key_shares = utils::extract_public_key_shares(&key_shares).unwrap();
         let mut session = manager
-            .create_signing_session(message, session_id.clone())
+            .create_signing_session(message, session_id.clone(), &public_key_shares)
             .await
             .unwrap();
 
         // Create partial signatures
         let mut partial_sigs = Vec::new();
-        for (_i, (validator_id, key_share)) in key_shares.iter().take(2).enumerate() {
+        for (validator_id, key_share) in key_shares.iter().take(2) {
             let partial_sig = manager
                 .create_partial_signature(key_share, message, &session_id)
                 .await
@@ -280,7 +583,6 @@ mod tests {
         assert!(manager.is_session_ready(&session));
 
         // Test aggregation
-        let public_key_shares = utils::extract_public_key_shares(&key_shares).unwrap();
         let aggregated_sig = manager
             .aggregate_signatures(&partial_sigs, &public_key_shares, message, &session_id)
             .await
@@ -289,4 +591,304 @@ mod tests {
         assert_eq!(aggregated_sig.signers.len(), 2);
         assert_eq!(aggregated_sig.scheme, "ecdsa-simple");
     }
+
+    #[tokio::test]
+    async fn test_session_rejects_partials_that_do_not_verify() {
+        for scheme in ["ecdsa", "schnorr"] {
+            let config = ThresholdConfig::new(2, 3, 256).unwrap();
+            let manager = SimpleThresholdManager::new_with_scheme(config, scheme).unwrap();
+
+            let validator_ids = utils::generate_test_validator_ids(3);
+            let key_shares = manager.generate_key_shares(&validator_ids).await.unwrap();
+            let public_key_shares = utils::extract_public_key_shares(&key_shares).unwrap();
+            let key_share = &key_shares[&validator_ids[0]];
+
+            let message = b"mint message";
+            let session_id = utils::generate_session_id();
+            let mut session = manager
+                .create_signing_session(message, session_id.clone(), &public_key_shares)
+                .await
+                .unwrap();
+
+            let wrong_message = manager
+                .create_partial_signature(key_share, b"other message", &session_id)
+                .await
+                .unwrap();
+            let wrong_session = manager
+                .create_partial_signature(key_share, message, "other session")
+                .await
+                .unwrap();
+            for partial in [wrong_message, wrong_session] {
+                let result = manager
+                    .add_partial_signature(&mut session, validator_ids[0].clone(), partial)
+                    .await;
+                assert!(matches!(result, Err(ThresholdError::InvalidSignature { .. })));
+            }
+            assert_eq!(session.signature_count(), 0);
+
+            // A valid partial submitted under another validator's ID is refused too
+            let partial = manager
+                .create_partial_signature(key_share, message, &session_id)
+                .await
+                .unwrap();
+            let result = manager
+                .add_partial_signature(&mut session, validator_ids[1].clone(), partial.clone())
+                .await;
+            assert!(matches!(result, Err(ThresholdError::InvalidSignature { .. })));
+
+            manager
+                .add_partial_signature(&mut session, validator_ids[0].clone(), partial)
+                .await
+                .unwrap();
+            assert_eq!(session.get_signers(), vec![validator_ids[0].clone()]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expired_session_rejects_partials() {
+        let config = ThresholdConfig::new(2, 3, 256).unwrap();
+        let manager = SimpleThresholdManager::new(config)
+            .unwrap()
+            .with_signature_timeout(Duration::from_millis(50));
+
+        let validator_ids = utils::generate_test_validator_ids(3);
+        let key_shares = manager.generate_key_shares(&validator_ids).await.unwrap();
+        let public_key_shares = utils::extract_public_key_shares(&key_shares).unwrap();
+
+        let message = b"mint message";
+        let session_id = utils::generate_session_id();
+        let mut session = manager
+            .create_signing_session(message, session_id.clone(), &public_key_shares)
+            .await
+            .unwrap();
+
+        let mut partials = Vec::new();
+        for validator_id in &validator_ids[..2] {
+            let partial = manager
+                .create_partial_signature(&key_shares[validator_id], message, &session_id)
+                .await
+                .unwrap();
+            partials.push(partial);
+        }
+
+        manager
+            .add_partial_signature(&mut session, validator_ids[0].clone(), partials.remove(0))
+            .await
+            .unwrap();
+        assert!(!manager.is_session_expired(&session));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(manager.is_session_expired(&session));
+
+        let result = manager
+            .add_partial_signature(&mut session, validator_ids[1].clone(), partials.remove(0))
+            .await;
+        match result {
+            Err(ThresholdError::SessionExpired { session_id: expired }) => assert_eq!(expired, session_id),
+            other => panic!("expected an expired session error, got {:?}", other),
+        }
+        assert_eq!(session.signature_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_aggregation_rejects_mixed_key_epochs() {
+        let config = ThresholdConfig::new(2, 3, 256).unwrap();
+        let manager = SimpleThresholdManager::new(config).unwrap();
+
+        let validator_ids = utils::generate_test_validator_ids(3);
+        let old_shares = manager.generate_key_shares(&validator_ids).await.unwrap();
+        let new_shares = manager.generate_key_shares(&validator_ids).await.unwrap();
+
+        let message = b"test message";
+        let session_id = utils::generate_session_id();
+
+        // One partial from each ceremony, aggregated against the new public shares
+        let old_partial = manager
+            .create_partial_signature(&old_shares[&validator_ids[0]], message, &session_id)
+            .await
+            .unwrap();
+        let new_partial = manager
+            .create_partial_signature(&new_shares[&validator_ids[1]], message, &session_id)
+            .await
+            .unwrap();
+
+        let public_key_shares = utils::extract_public_key_shares(&new_shares).unwrap();
+        let result = manager
+            .aggregate_signatures(&[old_partial, new_partial], &public_key_shares, message, &session_id)
+            .await;
+
+        assert!(matches!(result, Err(ThresholdError::InvalidKeyShare { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_contract_bytes_recover_to_sorted_signer_addresses() {
+        use k256::ecdsa::{RecoveryId, Signature as EcdsaSignature};
+
+        let config = ThresholdConfig::new(3, 3, 256).unwrap();
+        let manager = SimpleThresholdManager::new(config).unwrap();
+
+        let validator_ids = utils::generate_test_validator_ids(3);
+        let key_shares = manager.generate_key_shares(&validator_ids).await.unwrap();
+
+        let message = b"unlock message";
+        let session_id = utils::generate_session_id();
+
+        let mut partial_sigs = Vec::new();
+        for validator_id in &validator_ids {
+            let partial_sig = manager
+                .create_partial_signature(&key_shares[validator_id], message, &session_id)
+                .await
+                .unwrap();
+            partial_sigs.push(partial_sig);
+        }
+
+        let public_key_shares = utils::extract_public_key_shares(&key_shares).unwrap();
+        let aggregated_sig = manager
+            .aggregate_signatures(&partial_sigs, &public_key_shares, message, &session_id)
+            .await
+            .unwrap();
+
+        let mut expected: Vec<[u8; 20]> = validator_ids
+            .iter()
+            .map(|id| utils::ethereum_address(&key_shares[id].public_share).unwrap())
+            .collect();
+        expected.sort();
+
+        let digest = utils::hash_with_domain(&session_id, message);
+        let recovered: Vec<[u8; 20]> = aggregated_sig
+            .to_contract_bytes()
+            .iter()
+            .map(|sig| {
+                assert_eq!(sig.len(), 65);
+                let signature = EcdsaSignature::from_slice(&sig[..64]).unwrap();
+                let recovery_id = RecoveryId::from_byte(sig[64] - 27).unwrap();
+                let key = VerifyingKey::recover_from_msg(&digest, &signature, recovery_id).unwrap();
+                utils::ethereum_address(key.to_encoded_point(false).as_bytes()).unwrap()
+            })
+            .collect();
+
+        assert_eq!(recovered, expected);
+        // The raw aggregate is still available alongside the list
+        assert_eq!(aggregated_sig.signature.len(), 64);
+    }
+
+    /// Sign with `threshold` of three validators, aggregate and verify
+    /// against the first signer's key
+    async fn sign_and_verify(scheme: &str) -> (SimpleThresholdManager, AggregatedSignature) {
+        let config = ThresholdConfig::new(2, 3, 256).unwrap();
+        let manager = SimpleThresholdManager::new_with_scheme(config, scheme).unwrap();
+
+        let validator_ids = utils::generate_test_validator_ids(3);
+        let key_shares = manager.generate_key_shares(&validator_ids).await.unwrap();
+
+        let message = b"mint message";
+        let session_id = utils::generate_session_id();
+
+        let mut partial_sigs = Vec::new();
+        for validator_id in validator_ids.iter().take(2) {
+            let partial_sig = manager
+                .create_partial_signature(&key_shares[validator_id], message, &session_id)
+                .await
+                .unwrap();
+            partial_sigs.push(partial_sig);
+        }
+
+        let public_key_shares = utils::extract_public_key_shares(&key_shares).unwrap();
+        let aggregated = manager
+            .aggregate_signatures(&partial_sigs, &public_key_shares, message, &session_id)
+            .await
+            .unwrap();
+        assert_eq!(aggregated.signers, validator_ids[..2].to_vec());
+
+        let signer_key = &key_shares[&validator_ids[0]].public_share;
+        assert!(manager
+            .verify_signature(&aggregated, message, signer_key, &session_id)
+            .await
+            .unwrap());
+        assert!(!manager
+            .verify_signature(&aggregated, b"other message", signer_key, &session_id)
+            .await
+            .unwrap());
+
+        (manager, aggregated)
+    }
+
+    #[tokio::test]
+    async fn test_ecdsa_round_trip() {
+        let (manager, aggregated) = sign_and_verify("ecdsa").await;
+        assert_eq!(manager.scheme(), &ThresholdSchemeType::Ecdsa);
+        assert_eq!(aggregated.scheme, "ecdsa-simple");
+    }
+
+    #[tokio::test]
+    async fn test_schnorr_round_trip() {
+        let (manager, aggregated) = sign_and_verify("schnorr").await;
+        assert_eq!(manager.scheme(), &ThresholdSchemeType::Schnorr);
+        assert_eq!(aggregated.scheme, "schnorr-simple");
+        assert!(aggregated.signer_signatures.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_scheme_rejected() {
+        let config = ThresholdConfig::new(2, 3, 256).unwrap();
+        assert!(SimpleThresholdManager::new_with_scheme(config.clone(), "Schnorr").is_ok());
+        assert!(matches!(
+            SimpleThresholdManager::new_with_scheme(config, "bls"),
+            Err(ThresholdError::Generic { .. })
+        ));
+    }
+
+    /// The high-`s` twin of a 64-byte `r || s` signature
+    fn flip_s(signature: &[u8]) -> Vec<u8> {
+        let sig = EcdsaSignature::from_slice(signature).unwrap();
+        let (r, s) = sig.split_scalars();
+        EcdsaSignature::from_scalars(r, -*s).unwrap().to_bytes().to_vec()
+    }
+
+    #[tokio::test]
+    async fn test_high_s_signature_rejected_in_strict_mode() {
+        let config = ThresholdConfig::new(1, 1, 256).unwrap();
+        let strict = SimpleThresholdManager::new(config.clone()).unwrap();
+        let lenient = SimpleThresholdManager::new(config).unwrap().with_require_low_s(false);
+        assert!(strict.requires_low_s());
+
+        let validator_ids = utils::generate_test_validator_ids(1);
+        let key_shares = strict.generate_key_shares(&validator_ids).await.unwrap();
+        let key_share = &key_shares[&validator_ids[0]];
+
+        let message = b"mint message";
+        let session_id = utils::generate_session_id();
+        let partial = strict
+            .create_partial_signature(key_share, message, &session_id)
+            .await
+            .unwrap();
+        assert!(!utils::is_high_s(&partial.signature).unwrap());
+
+        let public_key_shares = utils::extract_public_key_shares(&key_shares).unwrap();
+        let mut aggregated = strict
+            .aggregate_signatures(std::slice::from_ref(&partial), &public_key_shares, message, &session_id)
+            .await
+            .unwrap();
+
+        aggregated.signature = flip_s(&partial.signature);
+        assert!(utils::is_high_s(&aggregated.signature).unwrap());
+
+        let result = strict
+            .verify_signature(&aggregated, message, &key_share.public_share, &session_id)
+            .await;
+        assert!(matches!(result, Err(ThresholdError::InvalidSignature { .. })));
+
+        // Lenient mode normalizes before verifying
+        assert!(lenient
+            .verify_signature(&aggregated, message, &key_share.public_share, &session_id)
+            .await
+            .unwrap());
+
+        aggregated.signature = utils::normalize_low_s(&aggregated.signature).unwrap();
+        assert_eq!(aggregated.signature, partial.signature);
+        assert!(strict
+            .verify_signature(&aggregated, message, &key_share.public_share, &session_id)
+            .await
+            .unwrap());
+    }
 }
@@ -0,0 +1,196 @@
+//! Versioned persistence of key shares
+//!
+//! Key shares are written inside an envelope carrying a layout version, and
+//! each past layout is kept here as a frozen copy of the structs it was
+//! written from. Loading an older blob deserializes it with its own layout and
+//! migrates it forward step by step, so changes to [`KeyShare`] or
+//! [`ThresholdConfig`] never make existing key material unreadable.
+//!
+//! Version history:
+//! - 1: the original layout, without a key epoch
+//! - 2: adds `key_epoch` to the share
+
+use crate::error::{Result, ThresholdError};
+use crate::types::{KeyEpoch, KeyShare, ThresholdConfig, ValidatorId};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Layout version written by this build
+pub const KEY_SHARE_VERSION: u32 = 2;
+
+/// Envelope around persisted key material
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    key_share: Value,
+}
+
+/// Threshold configuration as stored by layout versions 1 and 2
+#[derive(Serialize, Deserialize)]
+struct ThresholdConfigV1 {
+    threshold: u32,
+    total_validators: u32,
+    key_size: u32,
+}
+
+/// Key share as stored by layout version 1
+#[derive(Deserialize)]
+struct KeyShareV1 {
+    validator_id: ValidatorId,
+    private_share: Vec<u8>,
+    public_share: Vec<u8>,
+    coefficients: Vec<Vec<u8>>,
+    config: ThresholdConfigV1,
+}
+
+/// Key share as stored by layout version 2
+#[derive(Serialize, Deserialize)]
+struct KeyShareV2 {
+    validator_id: ValidatorId,
+    private_share: Vec<u8>,
+    public_share: Vec<u8>,
+    coefficients: Vec<Vec<u8>>,
+    config: ThresholdConfigV1,
+    key_epoch: KeyEpoch,
+}
+
+impl From<KeyShareV1> for KeyShareV2 {
+    /// Shares from before key epochs belong to the first ceremony
+    fn from(share: KeyShareV1) -> Self {
+        Self {
+            validator_id: share.validator_id,
+            private_share: share.private_share,
+            public_share: share.public_share,
+            coefficients: share.coefficients,
+            config: share.config,
+            key_epoch: 0,
+        }
+    }
+}
+
+impl From<KeyShareV2> for KeyShare {
+    fn from(share: KeyShareV2) -> Self {
+        Self {
+            validator_id: share.validator_id,
+            private_share: share.private_share,
+            public_share: share.public_share,
+            coefficients: share.coefficients,
+            config: ThresholdConfig {
+                threshold: share.config.threshold,
+                total_validators: share.config.total_validators,
+                key_size: share.config.key_size,
+            },
+            key_epoch: share.key_epoch,
+        }
+    }
+}
+
+impl From<&KeyShare> for KeyShareV2 {
+    fn from(share: &KeyShare) -> Self {
+        Self {
+            validator_id: share.validator_id.clone(),
+            private_share: share.private_share.clone(),
+            public_share: share.public_share.clone(),
+            coefficients: share.coefficients.clone(),
+            config: ThresholdConfigV1 {
+                threshold: share.config.threshold,
+                total_validators: share.config.total_validators,
+                key_size: share.config.key_size,
+            },
+            key_epoch: share.key_epoch,
+        }
+    }
+}
+
+/// Serialize a key share in the current layout
+pub fn encode_key_share(share: &KeyShare) -> Result<Vec<u8>> {
+    let envelope = Envelope {
+        version: KEY_SHARE_VERSION,
+        key_share: serde_json::to_value(KeyShareV2::from(share))?,
+    };
+
+    Ok(serde_json::to_vec(&envelope)?)
+}
+
+/// Load a key share written by this or any earlier layout version
+pub fn decode_key_share(bytes: &[u8]) -> Result<KeyShare> {
+    let envelope: Envelope = serde_json::from_slice(bytes)?;
+
+    let share: KeyShareV2 = match envelope.version {
+        1 => serde_json::from_value::<KeyShareV1>(envelope.key_share)?.into(),
+        2 => serde_json::from_value(envelope.key_share)?,
+        version => {
+            return Err(ThresholdError::UnsupportedKeyShareVersion {
+                version,
+                supported: KEY_SHARE_VERSION,
+            })
+        }
+    };
+
+    let share = KeyShare::from(share);
+    share.config.validate()?;
+    Ok(share)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_share() -> KeyShare {
+        KeyShare {
+            validator_id: "validator_1".to_string(),
+            private_share: vec![7u8; 32],
+            public_share: vec![2u8; 33],
+            coefficients: vec![vec![3u8; 33], vec![4u8; 33]],
+            config: ThresholdConfig::new(2, 3, 256).unwrap(),
+            key_epoch: 5,
+        }
+    }
+
+    #[test]
+    fn test_key_share_round_trip() {
+        let share = key_share();
+        let decoded = decode_key_share(&encode_key_share(&share).unwrap()).unwrap();
+
+        assert_eq!(decoded.validator_id, share.validator_id);
+        assert_eq!(decoded.private_share, share.private_share);
+        assert_eq!(decoded.coefficients, share.coefficients);
+        assert_eq!(decoded.config.threshold, 2);
+        assert_eq!(decoded.key_epoch, 5);
+    }
+
+    #[test]
+    fn test_v1_key_share_is_migrated() {
+        let blob = br#"{
+            "version": 1,
+            "key_share": {
+                "validator_id": "validator_1",
+                "private_share": [7, 7, 7],
+                "public_share": [2, 2],
+                "coefficients": [[3], [4]],
+                "config": { "threshold": 2, "total_validators": 3, "key_size": 256 }
+            }
+        }"#;
+
+        let share = decode_key_share(blob).unwrap();
+
+        assert_eq!(share.validator_id, "validator_1");
+        assert_eq!(share.private_share, vec![7, 7, 7]);
+        assert_eq!(share.config.total_validators, 3);
+        assert_eq!(share.config.key_size, 256);
+        assert_eq!(share.key_epoch, 0);
+    }
+
+    #[test]
+    fn test_unknown_key_share_version_is_rejected() {
+        let blob = br#"{ "version": 99, "key_share": {} }"#;
+
+        match decode_key_share(blob) {
+            Err(ThresholdError::UnsupportedKeyShareVersion { version, supported }) => {
+                assert_eq!(version, 99);
+                assert_eq!(supported, KEY_SHARE_VERSION);
+            }
+            other => panic!("expected an unsupported version error, got {:?}", other),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Type definitions for threshold signature operations
 
+use crate::error::{Result, ThresholdError};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::SystemTime;
@@ -8,6 +9,9 @@ use std::time::SystemTime;
 /// Unique identifier for a validator
 pub type ValidatorId = String;
 
+/// Identifier of the key-generation ceremony a share belongs to
+pub type KeyEpoch = u64;
+
 /// Configuration for threshold signature scheme
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThresholdConfig {
@@ -67,6 +71,9 @@ pub struct KeyShare {
     pub coefficients: Vec<Vec<u8>>,
     /// Threshold configuration
     pub config: ThresholdConfig,
+    /// Key-generation ceremony this share was produced by
+    #[serde(default)]
+    pub key_epoch: KeyEpoch,
 }
 
 /// Public key share for verification
@@ -78,6 +85,20 @@ pub struct PublicKeyShare {
     pub public_share: Vec<u8>,
     /// Verification key
     pub verification_key: Vec<u8>,
+    /// Key-generation ceremony this share was produced by
+    #[serde(default)]
+    pub key_epoch: KeyEpoch,
+}
+
+/// The key set validators currently sign with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeySet {
+    /// Key-generation ceremony the shares were produced by
+    pub key_epoch: KeyEpoch,
+    /// Validators holding a share, sorted by ID
+    pub members: Vec<ValidatorId>,
+    /// Group public key the shares combine to
+    pub group_public_key: Vec<u8>,
 }
 
 /// Partial signature from a single validator
@@ -91,6 +112,9 @@ pub struct PartialSignature {
     pub commitment: Option<Vec<u8>>,
     /// Timestamp when signature was created
     pub timestamp: SystemTime,
+    /// Key-generation ceremony of the share that produced this signature
+    #[serde(default)]
+    pub key_epoch: KeyEpoch,
 }
 
 /// Aggregated signature from multiple validators
@@ -106,6 +130,64 @@ pub struct AggregatedSignature {
     pub scheme: String,
     /// Timestamp when aggregation completed
     pub timestamp: SystemTime,
+    /// Per-signer recoverable signatures for on-chain verification
+    #[serde(default)]
+    pub signer_signatures: Vec<SignerSignature>,
+}
+
+impl AggregatedSignature {
+    /// Signatures in the form the Ethereum contract's `bytes[] signatures`
+    /// expects: one 65-byte `r || s || v` signature per signer, ordered by
+    /// ascending signer address
+    pub fn to_contract_bytes(&self) -> Vec<Vec<u8>> {
+        let mut signer_signatures: Vec<&SignerSignature> = self.signer_signatures.iter().collect();
+        signer_signatures.sort_by_key(|sig| sig.address);
+
+        signer_signatures
+            .into_iter()
+            .map(|sig| sig.signature.clone())
+            .collect()
+    }
+
+    /// The signature as Ethereum's `ecrecover` takes it: `r || s || v`, with
+    /// the trailing recovery ID shifted to `v` = 27 or 28
+    ///
+    /// Only single-signer ECDSA aggregates, from a 1-of-n key set, have one.
+    /// Aggregates of several signers carry no combined signature; submit
+    /// their [`to_contract_bytes`](Self::to_contract_bytes) instead.
+    pub fn to_eth_signature(&self) -> Result<Vec<u8>> {
+        match self.signature.as_slice() {
+            [] if !self.signer_signatures.is_empty() => Err(ThresholdError::InvalidSignature {
+                reason: format!(
+                    "Aggregate of {} signers has no single Ethereum signature; use its per-signer signatures",
+                    self.signer_signatures.len()
+                ),
+            }),
+            [rs @ .., recovery_id] if rs.len() == 64 && *recovery_id <= 1 => {
+                let mut eth_signature = rs.to_vec();
+                eth_signature.push(27 + recovery_id);
+                Ok(eth_signature)
+            }
+            _ => Err(ThresholdError::InvalidSignature {
+                reason: "Expected a 65-byte signature ending in a recovery ID".to_string(),
+            }),
+        }
+    }
+}
+
+/// A single signer's contribution to an aggregated signature
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignerSignature {
+    /// Validator ID of the signer
+    pub validator_id: ValidatorId,
+    /// Ethereum address derived from the signer's public key share
+    pub address: [u8; 20],
+    /// 1-based index of the signer's share among the key set's sorted
+    /// validator IDs, which interpolating the group key needs
+    #[serde(default)]
+    pub index: u32,
+    /// 65-byte recoverable signature (`r || s || v`, with `v` in {27, 28})
+    pub signature: Vec<u8>,
 }
 
 /// Generic signature type
@@ -128,6 +210,8 @@ pub struct SigningSession {
     pub message: Vec<u8>,
     /// Collected partial signatures
     pub partial_signatures: HashMap<ValidatorId, PartialSignature>,
+    /// Public key shares partials are verified against before they are collected
+    pub public_key_shares: Vec<PublicKeyShare>,
     /// Required threshold
     pub threshold: u32,
     /// Total number of validators
@@ -175,6 +259,19 @@ pub struct DkgParams {
     pub session_id: String,
 }
 
+impl DkgParams {
+    /// 1-based index of `validator_id` among the participants
+    pub fn participant_index(&self, validator_id: &ValidatorId) -> crate::Result<u32> {
+        self.participants
+            .iter()
+            .position(|participant| participant == validator_id)
+            .map(|position| position as u32 + 1)
+            .ok_or_else(|| crate::ThresholdError::InvalidValidatorId {
+                id: validator_id.clone(),
+            })
+    }
+}
+
 /// DKG commitment for verification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DkgCommitment {
@@ -223,6 +320,7 @@ mod tests {
             id: "test".to_string(),
             message: b"test message".to_vec(),
             partial_signatures: HashMap::new(),
+            public_key_shares: vec![],
             threshold: 2,
             total_validators: 3,
             created_at: SystemTime::now(),
@@ -237,6 +335,7 @@ mod tests {
             signature: vec![1, 2, 3],
             commitment: None,
             timestamp: SystemTime::now(),
+            key_epoch: 0,
         };
 
         session.partial_signatures.insert("validator1".to_string(), partial_sig);
@@ -249,6 +348,7 @@ mod tests {
             signature: vec![4, 5, 6],
             commitment: None,
             timestamp: SystemTime::now(),
+            key_epoch: 0,
         };
 
         session.partial_signatures.insert("validator2".to_string(), partial_sig2);
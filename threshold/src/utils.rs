@@ -1,11 +1,17 @@
 //! Utility functions for threshold signatures
 
 use crate::{
+    dkg,
     error::{Result, ThresholdError},
-    types::{KeyShare, PublicKeyShare, ThresholdConfig, ValidatorId},
+    types::{KeyEpoch, KeyShare, PartialSignature, PublicKeyShare, ThresholdConfig, ValidatorId},
 };
 use rand::{CryptoRng, RngCore};
+use k256::{
+    ecdsa::{RecoveryId, Signature as EcdsaSignature, VerifyingKey},
+    ProjectivePoint,
+};
 use sha2::{Digest, Sha256};
+use sha3::Keccak256;
 use std::collections::HashMap;
 
 /// Generate a cryptographically secure random session ID
@@ -16,6 +22,133 @@ pub fn generate_session_id() -> String {
     hex::encode(random_bytes)
 }
 
+/// Generate a random identifier for a new key-generation ceremony
+pub fn generate_key_epoch() -> KeyEpoch {
+    rand::rngs::OsRng.next_u64()
+}
+
+/// Ensure partial signatures come from the same key-generation ceremony as the
+/// public key shares they will be aggregated against, returning that epoch
+pub fn ensure_matching_key_epoch(
+    partial_sigs: &[PartialSignature],
+    public_key_shares: &[PublicKeyShare],
+) -> Result<KeyEpoch> {
+    let epoch = public_key_shares
+        .first()
+        .map(|share| share.key_epoch)
+        .ok_or_else(|| ThresholdError::InvalidKeyShare {
+            reason: "No public key shares provided".to_string(),
+        })?;
+
+    if let Some(share) = public_key_shares.iter().find(|share| share.key_epoch != epoch) {
+        return Err(ThresholdError::InvalidKeyShare {
+            reason: format!(
+                "Public key share for {} is from key epoch {}, expected {}",
+                share.validator_id, share.key_epoch, epoch
+            ),
+        });
+    }
+
+    for sig in partial_sigs {
+        if sig.key_epoch != epoch {
+            return Err(ThresholdError::InvalidKeyShare {
+                reason: format!(
+                    "Partial signature from {} is from key epoch {}, expected {}",
+                    sig.validator_id, sig.key_epoch, epoch
+                ),
+            });
+        }
+        if !public_key_shares.iter().any(|share| share.validator_id == sig.validator_id) {
+            return Err(ThresholdError::InvalidKeyShare {
+                reason: format!("No public key share for signer {}", sig.validator_id),
+            });
+        }
+    }
+
+    Ok(epoch)
+}
+
+/// 1-based index of `validator_id` among the sorted validator IDs of the key
+/// set described by `public_key_shares`
+///
+/// This is the point a dealt share was evaluated at, whichever validators
+/// take part in a given signature.
+pub fn key_set_index(public_key_shares: &[PublicKeyShare], validator_id: &ValidatorId) -> Result<u32> {
+    let mut sorted_ids: Vec<&ValidatorId> = public_key_shares
+        .iter()
+        .map(|share| &share.validator_id)
+        .collect();
+    sorted_ids.sort();
+
+    sorted_ids
+        .binary_search(&validator_id)
+        .map(|position| position as u32 + 1)
+        .map_err(|_| ThresholdError::InvalidKeyShare {
+            reason: format!("No public key share for signer {}", validator_id),
+        })
+}
+
+/// Derive the Ethereum address of a SEC1-encoded secp256k1 public key
+pub fn ethereum_address(public_key: &[u8]) -> Result<[u8; 20]> {
+    let verifying_key = VerifyingKey::from_sec1_bytes(public_key)
+        .map_err(|e| ThresholdError::InvalidKeyShare {
+            reason: format!("Invalid public key: {}", e),
+        })?;
+
+    let encoded = verifying_key.to_encoded_point(false);
+    let hash = Keccak256::digest(&encoded.as_bytes()[1..]);
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    Ok(address)
+}
+
+/// Extend a 64-byte `r || s` signature of `message` with the Ethereum
+/// recovery byte (`v` = 27 or 28) that recovers `public_key`
+pub fn to_recoverable_signature(
+    signature: &[u8],
+    public_key: &[u8],
+    message: &[u8],
+) -> Result<Vec<u8>> {
+    // Ethereum's ecrecover consumers only accept low-`s` signatures
+    let ecdsa_sig = parse_signature(signature)?;
+    let ecdsa_sig = ecdsa_sig.normalize_s().unwrap_or(ecdsa_sig);
+    let verifying_key = VerifyingKey::from_sec1_bytes(public_key)
+        .map_err(|e| ThresholdError::InvalidKeyShare {
+            reason: format!("Invalid public key: {}", e),
+        })?;
+
+    let recovery_id = RecoveryId::trial_recovery_from_msg(&verifying_key, message, &ecdsa_sig)
+        .map_err(|_| ThresholdError::InvalidSignature {
+            reason: "Signature does not match signer public key".to_string(),
+        })?;
+
+    let mut recoverable = ecdsa_sig.to_bytes().to_vec();
+    recoverable.push(27 + recovery_id.to_byte());
+    Ok(recoverable)
+}
+
+/// Parse a 64-byte `r || s` ECDSA signature
+fn parse_signature(signature: &[u8]) -> Result<EcdsaSignature> {
+    EcdsaSignature::from_slice(signature).map_err(|e| ThresholdError::InvalidSignature {
+        reason: format!("Invalid ECDSA signature: {}", e),
+    })
+}
+
+/// Whether a 64-byte `r || s` signature has an `s` in the upper half of the
+/// curve order, making it the malleable twin of a low-`s` signature
+pub fn is_high_s(signature: &[u8]) -> Result<bool> {
+    Ok(parse_signature(signature)?.normalize_s().is_some())
+}
+
+/// Rewrite a 64-byte `r || s` signature into its canonical low-`s` form
+///
+/// Low-`s` signatures are returned unchanged.
+pub fn normalize_low_s(signature: &[u8]) -> Result<Vec<u8>> {
+    let ecdsa_sig = parse_signature(signature)?;
+    Ok(ecdsa_sig.normalize_s().unwrap_or(ecdsa_sig).to_bytes().to_vec())
+}
+
 /// Validate threshold configuration
 pub fn validate_threshold_config(config: &ThresholdConfig) -> Result<()> {
     if config.threshold == 0 {
@@ -57,6 +190,7 @@ pub fn extract_public_key_shares(
             validator_id: validator_id.clone(),
             public_share: key_share.public_share.clone(),
             verification_key,
+            key_epoch: key_share.key_epoch,
         };
 
         public_shares.push(public_share);
@@ -80,8 +214,16 @@ pub fn compute_combined_public_key(key_shares: &[PublicKeyShare]) -> Result<Vec<
     Ok(key_shares[0].public_share.clone())
 }
 
-/// Verify that a key share is valid (simplified version)
-pub fn verify_key_share(key_share: &KeyShare) -> Result<()> {
+/// Verify a key share against the polynomial it was dealt from
+///
+/// `index` is the validator's 1-based position in the list the keys were
+/// generated for. This is Feldman VSS: the share's coefficients are committed
+/// to as `a_j * G`, unless they are already commitments as in shares from
+/// [`crate::dkg::DkgSession`], and `private_share * G` must equal that
+/// commitment polynomial evaluated at `index`. The private share must also match
+/// `public_share`. Shares without coefficients, such as the independent keys
+/// of [`crate::SimpleThresholdManager`], only get the latter check.
+pub fn verify_key_share(key_share: &KeyShare, index: u32) -> Result<()> {
     // Check that private share length is correct
     if key_share.private_share.len() != 32 {
         return Err(ThresholdError::InvalidKeyShare {
@@ -96,8 +238,35 @@ pub fn verify_key_share(key_share: &KeyShare) -> Result<()> {
         });
     }
 
-    // For the simplified version, we just check the basic format
-    // In a full implementation, we would verify the cryptographic relationship
+    if !key_share.coefficients.is_empty() {
+        if key_share.coefficients.len() != key_share.config.threshold as usize {
+            return Err(ThresholdError::InvalidKeyShare {
+                reason: format!(
+                    "Expected {} polynomial coefficients, found {}",
+                    key_share.config.threshold,
+                    key_share.coefficients.len()
+                ),
+            });
+        }
+
+        dkg::check_commitments(key_share)?;
+        if !dkg::verify_share(&key_share.private_share, index, &key_share.coefficients)? {
+            return Err(ThresholdError::InvalidKeyShare {
+                reason: format!(
+                    "Private share of {} does not match the commitment polynomial at index {}",
+                    key_share.validator_id, index
+                ),
+            });
+        }
+    }
+
+    let public_share = ProjectivePoint::GENERATOR * dkg::parse_scalar(&key_share.private_share)?;
+    if public_share != dkg::parse_point(&key_share.public_share)? {
+        return Err(ThresholdError::InvalidKeyShare {
+            reason: format!("Public share of {} does not match its private share", key_share.validator_id),
+        });
+    }
+
     Ok(())
 }
 
@@ -238,4 +407,42 @@ mod tests {
         assert_eq!(hash1, hash3);
         assert_eq!(hash1.len(), 32); // SHA256 output
     }
+
+    #[tokio::test]
+    async fn test_verify_key_share_accepts_dealt_shares() {
+        let config = create_test_config(2, 3).unwrap();
+        let validator_ids = generate_test_validator_ids(3);
+        let key_shares = crate::EcdsaThreshold::new()
+            .generate_keys(&config, &validator_ids)
+            .await
+            .unwrap();
+
+        for (index, validator_id) in validator_ids.iter().enumerate() {
+            verify_key_share(&key_shares[validator_id], index as u32 + 1).unwrap();
+        }
+
+        // A share checked at another validator's index does not match
+        let result = verify_key_share(&key_shares[&validator_ids[0]], 2);
+        assert!(matches!(result, Err(ThresholdError::InvalidKeyShare { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_verify_key_share_rejects_tampered_private_share() {
+        let config = create_test_config(2, 3).unwrap();
+        let validator_ids = generate_test_validator_ids(3);
+        let key_shares = crate::EcdsaThreshold::new()
+            .generate_keys(&config, &validator_ids)
+            .await
+            .unwrap();
+
+        let mut share = key_shares[&validator_ids[1]].clone();
+        share.private_share[31] ^= 1;
+
+        match verify_key_share(&share, 2) {
+            Err(ThresholdError::InvalidKeyShare { reason }) => {
+                assert!(reason.contains("commitment polynomial at index 2"), "{}", reason)
+            }
+            other => panic!("expected InvalidKeyShare, got {:?}", other),
+        }
+    }
 }
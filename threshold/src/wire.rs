@@ -0,0 +1,263 @@
+//! Wire format for broadcasting partial signatures between validators
+//!
+//! A message is a one-byte format version, a big-endian `u32` payload length,
+//! then the payload:
+//!
+//! - `session_id`, `validator_id` and the signature bytes, each as a
+//!   big-endian `u16` length followed by that many bytes
+//! - the commitment, as a presence byte (0 or 1) followed by a length-prefixed
+//!   value when present
+//! - the creation timestamp, as `u64` seconds and `u32` nanoseconds since the
+//!   Unix epoch
+//! - the key epoch, as a `u64`
+//!
+//! Bytes come from untrusted peers, so decoding checks every length against
+//! the input before reading and rejects unknown versions, truncated payloads
+//! and trailing bytes.
+//!
+//! Version history:
+//! - 1: the original layout
+
+use crate::error::{Result, ThresholdError};
+use crate::types::PartialSignature;
+use std::time::{Duration, UNIX_EPOCH};
+
+/// Format version written by this build
+pub const WIRE_VERSION: u8 = 1;
+
+/// Bytes before the payload: the version and the payload length
+const HEADER_LEN: usize = 5;
+
+/// A partial signature together with the session it belongs to
+#[derive(Debug, Clone)]
+pub struct PartialSignatureMessage {
+    /// Signing session the partial was created for
+    pub session_id: String,
+    /// The partial signature itself
+    pub partial_signature: PartialSignature,
+}
+
+/// Serialize a partial signature message in the current wire format
+pub fn encode(message: &PartialSignatureMessage) -> Result<Vec<u8>> {
+    let partial = &message.partial_signature;
+    let mut payload = Vec::new();
+
+    put_bytes(&mut payload, "session_id", message.session_id.as_bytes())?;
+    put_bytes(&mut payload, "validator_id", partial.validator_id.as_bytes())?;
+    put_bytes(&mut payload, "signature", &partial.signature)?;
+    match &partial.commitment {
+        Some(commitment) => {
+            payload.push(1);
+            put_bytes(&mut payload, "commitment", commitment)?;
+        }
+        None => payload.push(0),
+    }
+
+    let since_epoch = partial.timestamp.duration_since(UNIX_EPOCH).map_err(|_| {
+        malformed("timestamp is before the Unix epoch")
+    })?;
+    payload.extend_from_slice(&since_epoch.as_secs().to_be_bytes());
+    payload.extend_from_slice(&since_epoch.subsec_nanos().to_be_bytes());
+    payload.extend_from_slice(&partial.key_epoch.to_be_bytes());
+
+    let payload_len = u32::try_from(payload.len())
+        .map_err(|_| malformed("payload does not fit a u32 length"))?;
+
+    let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+    bytes.push(WIRE_VERSION);
+    bytes.extend_from_slice(&payload_len.to_be_bytes());
+    bytes.extend_from_slice(&payload);
+    Ok(bytes)
+}
+
+/// Parse a partial signature message received from a peer
+pub fn decode(bytes: &[u8]) -> Result<PartialSignatureMessage> {
+    let mut reader = Reader { bytes };
+
+    let version = reader.u8("version")?;
+    if version != WIRE_VERSION {
+        return Err(malformed(&format!(
+            "unsupported wire version {}, this build reads version {}",
+            version, WIRE_VERSION
+        )));
+    }
+
+    let payload_len = reader.u32("payload length")? as usize;
+    if reader.bytes.len() != payload_len {
+        return Err(malformed(&format!(
+            "payload is {} bytes, header declares {}",
+            reader.bytes.len(),
+            payload_len
+        )));
+    }
+
+    let session_id = reader.string("session_id")?;
+    let validator_id = reader.string("validator_id")?;
+    let signature = reader.prefixed("signature")?.to_vec();
+    let commitment = match reader.u8("commitment flag")? {
+        0 => None,
+        1 => Some(reader.prefixed("commitment")?.to_vec()),
+        flag => return Err(malformed(&format!("invalid commitment flag {}", flag))),
+    };
+
+    let secs = reader.u64("timestamp")?;
+    let nanos = reader.u32("timestamp")?;
+    if nanos >= 1_000_000_000 {
+        return Err(malformed("timestamp nanoseconds out of range"));
+    }
+    let timestamp = UNIX_EPOCH
+        .checked_add(Duration::new(secs, nanos))
+        .ok_or_else(|| malformed("timestamp out of range"))?;
+
+    let key_epoch = reader.u64("key_epoch")?;
+
+    if !reader.bytes.is_empty() {
+        return Err(malformed(&format!("{} trailing bytes", reader.bytes.len())));
+    }
+
+    Ok(PartialSignatureMessage {
+        session_id,
+        partial_signature: PartialSignature {
+            validator_id,
+            signature,
+            commitment,
+            timestamp,
+            key_epoch,
+        },
+    })
+}
+
+fn malformed(reason: &str) -> ThresholdError {
+    ThresholdError::SerializationError {
+        message: format!("Malformed partial signature message: {}", reason),
+    }
+}
+
+/// Append a `u16` length followed by `value`
+fn put_bytes(out: &mut Vec<u8>, field: &str, value: &[u8]) -> Result<()> {
+    let len = u16::try_from(value.len())
+        .map_err(|_| malformed(&format!("{} is longer than {} bytes", field, u16::MAX)))?;
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(value);
+    Ok(())
+}
+
+/// Bounds-checked cursor over untrusted input
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, field: &str, len: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < len {
+            return Err(malformed(&format!("truncated {}", field)));
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self, field: &str) -> Result<[u8; N]> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.take(field, N)?);
+        Ok(array)
+    }
+
+    fn u8(&mut self, field: &str) -> Result<u8> {
+        Ok(self.array::<1>(field)?[0])
+    }
+
+    fn u32(&mut self, field: &str) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.array(field)?))
+    }
+
+    fn u64(&mut self, field: &str) -> Result<u64> {
+        Ok(u64::from_be_bytes(self.array(field)?))
+    }
+
+    fn prefixed(&mut self, field: &str) -> Result<&'a [u8]> {
+        let len = u16::from_be_bytes(self.array(field)?) as usize;
+        self.take(field, len)
+    }
+
+    fn string(&mut self, field: &str) -> Result<String> {
+        let bytes = self.prefixed(field)?;
+        String::from_utf8(bytes.to_vec())
+            .map_err(|_| malformed(&format!("{} is not valid UTF-8", field)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn message() -> PartialSignatureMessage {
+        PartialSignatureMessage {
+            session_id: "0xabc".to_string(),
+            partial_signature: PartialSignature {
+                validator_id: "validator_1".to_string(),
+                signature: vec![7u8; 64],
+                commitment: Some(vec![2u8; 33]),
+                timestamp: UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789),
+                key_epoch: 5,
+            },
+        }
+    }
+
+    #[test]
+    fn test_partial_signature_round_trip() {
+        for commitment in [Some(vec![2u8; 33]), None] {
+            let mut original = message();
+            original.partial_signature.commitment = commitment;
+
+            let decoded = decode(&encode(&original).unwrap()).unwrap();
+            let (sent, received) = (&original.partial_signature, &decoded.partial_signature);
+            assert_eq!(decoded.session_id, original.session_id);
+            assert_eq!(received.validator_id, sent.validator_id);
+            assert_eq!(received.signature, sent.signature);
+            assert_eq!(received.commitment, sent.commitment);
+            assert_eq!(received.timestamp, sent.timestamp);
+            assert_eq!(received.key_epoch, sent.key_epoch);
+        }
+    }
+
+    #[test]
+    fn test_unknown_version_and_truncation_are_rejected() {
+        let mut bytes = encode(&message()).unwrap();
+
+        for len in 0..bytes.len() {
+            assert!(matches!(
+                decode(&bytes[..len]),
+                Err(ThresholdError::SerializationError { .. })
+            ));
+        }
+
+        bytes.push(0);
+        assert!(decode(&bytes).is_err());
+        bytes.pop();
+
+        bytes[0] = WIRE_VERSION + 1;
+        match decode(&bytes) {
+            Err(ThresholdError::SerializationError { message }) => {
+                assert!(message.contains("unsupported wire version"))
+            }
+            other => panic!("expected a serialization error, got {:?}", other),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_decoding_random_bytes_never_panics(bytes in proptest::collection::vec(any::<u8>(), 0..256)) {
+            let _ = decode(&bytes);
+        }
+
+        #[test]
+        fn test_decoding_corrupted_messages_never_panics(position in 0usize..128, value in any::<u8>()) {
+            let mut bytes = encode(&message()).unwrap();
+            let position = position % bytes.len();
+            bytes[position] = value;
+            let _ = decode(&bytes);
+        }
+    }
+}